@@ -1,6 +1,6 @@
-use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use pie::parser::parse;
-use std::{borrow::Cow, format, fs};
+use std::{format, fs};
 
 fn bench_parsing(c: &mut Criterion) {
     let filenames = ["applications", "atoms", "declarations", "lambdas"];
@@ -16,11 +16,7 @@ fn bench_parsing(c: &mut Criterion) {
 
     for (i, source) in sources.iter().enumerate() {
         group.bench_function(BenchmarkId::from_parameter(filenames[i]), |b| {
-            b.iter_batched_ref(
-                || -> Cow<str> { Cow::from(source) },
-                parse,
-                BatchSize::SmallInput,
-            );
+            b.iter(|| parse(source));
         });
     }
     group.finish();
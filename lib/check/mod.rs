@@ -0,0 +1,1854 @@
+use crate::conv::{self, Difference};
+use crate::eval::{self, Closure, Neutral, Value};
+use crate::syntax::{
+    Add1, Apply, Arrow, Atom, Base, Binder, Car, Cdr, CheckSame, Cong, Cons, Definition, Either,
+    Equal, Expression, Head, Identifier, IndAbsurd, IndEither, IndEq, IndList, IndNat, IndVec,
+    IterNat, Lambda, Left, List, ListCons, Nil, Numeral, Pair, Pi, RecList, RecNat, Replace, Right,
+    Same, Sigma, Sole, Statement, Symm, Tail, The, Todo, Trans, Type as Ty, TypedBinder, Universe,
+    Variable, VecCons, VecNil, Vector, WhichNat, Zero,
+};
+use crate::term::{self, fresh};
+use miette::{Diagnostic, SourceSpan};
+use std::fmt;
+use thiserror::Error;
+
+////////////////////////////////////////////////
+// Type aliases
+pub type Result<T> = std::result::Result<T, TypeError>;
+
+////////////////////////////////////////////////
+// Errors
+#[derive(Debug, Diagnostic, Error)]
+#[error("Type mismatch")]
+#[diagnostic()]
+pub struct Mismatch {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub expected: String,
+    pub actual: String,
+    // sub-terms that actually differ
+    pub difference: Box<Difference>,
+    // where the found side of the difference is written inside `loc', if
+    // it is written there at all
+    #[label("expected `{}' here", self.difference.lhs)]
+    pub part: Option<SourceSpan>,
+    #[help]
+    pub help: String,
+}
+
+impl Mismatch {
+    fn new(
+        at: &Expression<SourceSpan>,
+        expected: String,
+        actual: String,
+        difference: Difference,
+    ) -> Self {
+        let loc = *at.ann();
+        let part = locate(at, &difference.rhs).filter(|part| *part != loc);
+        let (lhs, rhs) = (difference.lhs.to_string(), difference.rhs.to_string());
+        let help = if lhs == expected && rhs == actual {
+            format!("expected `{expected}' but found `{actual}'")
+        } else {
+            format!(
+                "expected `{expected}' but found `{actual}', `{lhs}' is not the same as `{rhs}'"
+            )
+        };
+        Mismatch {
+            loc,
+            expected,
+            actual,
+            difference: Box::new(difference),
+            part,
+            help,
+        }
+    }
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Not the same")]
+#[diagnostic()]
+pub struct NotTheSame {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub ty: String,
+    pub left: String,
+    pub right: String,
+    // sub-terms that actually differ
+    pub difference: Box<Difference>,
+    // where each side of the difference is written, if it is
+    #[label("not the same as `{}'", self.difference.rhs)]
+    pub left_part: Option<SourceSpan>,
+    #[label("not the same as `{}'", self.difference.lhs)]
+    pub right_part: Option<SourceSpan>,
+    #[help]
+    pub help: String,
+}
+
+impl NotTheSame {
+    fn new(
+        loc: SourceSpan,
+        ty: String,
+        (left, left_at): (String, &Expression<SourceSpan>),
+        (right, right_at): (String, &Expression<SourceSpan>),
+        difference: Difference,
+    ) -> Self {
+        let left_part = locate(left_at, &difference.lhs);
+        let right_part = locate(right_at, &difference.rhs);
+        let (lhs, rhs) = (difference.lhs.to_string(), difference.rhs.to_string());
+        let help = if lhs == left && rhs == right {
+            format!("`{left}' is not the same `{ty}' as `{right}'")
+        } else {
+            format!("`{left}' is not the same `{ty}' as `{right}', `{lhs}' differs from `{rhs}'")
+        };
+        NotTheSame {
+            loc,
+            ty,
+            left,
+            right,
+            difference: Box::new(difference),
+            left_part,
+            right_part,
+            help,
+        }
+    }
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Not a type")]
+#[diagnostic(help("expected a type, like `Atom', `U' or `(→ Atom Atom)'"))]
+pub struct NotAType {
+    #[label("this")]
+    pub loc: SourceSpan,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Unexpected type")]
+#[diagnostic(
+    help("expected {}, but the type here is `{}'", self.expected, self.ty)
+)]
+pub struct WrongType {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub expected: &'static str,
+    pub ty: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Unknown variable")]
+#[diagnostic(help("{}", self.help))]
+pub struct Unbound {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub help: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Can't determine the type")]
+#[diagnostic(help("{}", self.help))]
+pub struct CantSynth {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub help: &'static str,
+}
+
+/// Unfinished part of a program, which doesn't stop checking.
+#[derive(Clone, Debug, Diagnostic, Error)]
+#[error("Unfinished expression")]
+#[diagnostic(severity(Warning), help("{}", self.help))]
+pub struct Hole {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub ty: String,
+    // local variables in scope, with their types
+    pub context: Vec<(Identifier, String)>,
+    pub help: String,
+}
+
+impl Hole {
+    fn new(loc: SourceSpan, ty: String, context: Vec<(Identifier, String)>) -> Self {
+        let mut help = format!("expected `{ty}'");
+        if !context.is_empty() {
+            help.push_str(", in context:");
+            for (ident, ty) in context.iter() {
+                help.push_str(&format!("\n  {ident} : {ty}"));
+            }
+        }
+        Hole {
+            loc,
+            ty,
+            context,
+            help,
+        }
+    }
+}
+
+/// Type of a variable at `loc', found while checking.
+#[derive(Clone, Debug)]
+pub struct Typing {
+    pub loc: SourceSpan,
+    pub ident: Identifier,
+    pub ty: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+pub enum TypeError {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Mismatch(#[from] Mismatch),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    NotTheSame(#[from] Box<NotTheSame>),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    NotAType(#[from] NotAType),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    WrongType(#[from] WrongType),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Unbound(#[from] Unbound),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    CantSynth(#[from] CantSynth),
+}
+
+////////////////////////////////////////////////
+// Normal forms
+/// Normal form of a top-level expression, together with its type.
+#[derive(Clone, Debug)]
+pub struct Normal {
+    pub ty: Expression,
+    pub expr: Expression,
+}
+
+impl fmt::Display for Normal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let the = Expression::The(The {
+            ann: (),
+            ty: Box::new(self.ty.clone()),
+            expr: Box::new(self.expr.clone()),
+        });
+        fmt::Display::fmt(&the, f)
+    }
+}
+
+////////////////////////////////////////////////
+// Context
+#[derive(Clone, Debug)]
+enum Binding {
+    // type of a definition, whose value is in the environment
+    Def(Value),
+    // lambda argument, standing for the neutral variable `var'
+    Local { ty: Value, var: Identifier },
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Context {
+    bindings: Vec<(Identifier, Binding)>,
+    // values of the bindings, for evaluating elaborated expressions
+    env: eval::Env,
+    holes: Vec<Hole>,
+    // only kept when asked for, as showing types takes time
+    typings: Option<Vec<Typing>>,
+}
+
+/// What checking a top-level statement found: the normal form of an
+/// expression, or the type error that stopped it, and its holes.
+#[derive(Debug)]
+pub struct Checked {
+    pub normal: Result<Option<Normal>>,
+    pub holes: Vec<Hole>,
+}
+
+/// Check `statements' one by one in `ctx', up to the first type error.
+/// `defs' are what `elab::definitions' paired up for their `define's,
+/// in order and without finding any errors.
+pub fn check(
+    ctx: &mut Context,
+    statements: &[Statement<SourceSpan>],
+    defs: &[Definition<SourceSpan>],
+) -> Vec<Checked> {
+    let mut defs = defs.iter();
+    let mut checked = vec![];
+    for stmt in statements {
+        let normal = match stmt {
+            Statement::Def(_) => ctx
+                .definition(defs.next().expect("a definition for each `define'"))
+                .map(|_| None),
+            _ => ctx.statement(stmt),
+        };
+        let failed = normal.is_err();
+        checked.push(Checked {
+            normal,
+            holes: ctx.holes(),
+        });
+        if failed {
+            break;
+        }
+    }
+    checked
+}
+
+////////////////////////////////////////////////
+// Utils
+fn ref_(ident: Identifier) -> Expression {
+    Expression::Ref(Variable { ann: (), ident })
+}
+
+fn type_(ty: Base) -> Expression {
+    Expression::Ty(Ty { ann: (), ty })
+}
+
+// `(→ dom cod)'
+fn arrow(dom: Value, cod: Value) -> Value {
+    Value::Pi {
+        dom: Box::new(dom),
+        cod: Closure::Const(Box::new(cod)),
+    }
+}
+
+// the outermost part of `expr' written as `part', up to renaming bound
+// variables
+fn locate(expr: &Expression<SourceSpan>, part: &Expression) -> Option<SourceSpan> {
+    if term::alpha_equivalent(expr, part) {
+        return Some(*expr.ann());
+    }
+    expr.children()
+        .into_iter()
+        .find_map(|child| locate(child, part))
+}
+
+impl Context {
+    /// Context that also remembers the types of variables, see
+    /// `typings'.
+    pub fn recording() -> Self {
+        Context {
+            typings: Some(vec![]),
+            ..Default::default()
+        }
+    }
+
+    // check the claim of `def' and its body against it
+    fn definition(&mut self, def: &Definition<SourceSpan>) -> Result<()> {
+        let (_, claim) = &def.claim;
+        let (_, body) = &def.body;
+        let ty = self.is_type(claim)?;
+        let ty = self.eval(&ty);
+        let body = self.check(body, &ty)?;
+        let val = self.eval(&body);
+        self.env = self.env.push((def.ident, val));
+        self.bindings.push((def.ident, Binding::Def(ty)));
+        Ok(())
+    }
+
+    // check a single top-level statement, returning the normal form of
+    // expressions; claims and definitions are left to `definition'
+    fn statement(&mut self, stmt: &Statement<SourceSpan>) -> Result<Option<Normal>> {
+        match stmt {
+            Statement::Claim(_) | Statement::Def(_) => Ok(None),
+            Statement::CheckSame(CheckSame {
+                ann,
+                ty,
+                left,
+                right,
+            }) => {
+                let ty = self.is_type(ty)?;
+                let ty = self.eval(&ty);
+                let (left_at, right_at) = (left, right);
+                let left = self.check(left, &ty)?;
+                let right = self.check(right, &ty)?;
+                let (left, right) = (self.eval(&left), self.eval(&right));
+                let names = self.names();
+                conv::same(&names, &ty, &left, &right).map_err(|diff| {
+                    TypeError::from(Box::new(NotTheSame::new(
+                        *ann,
+                        self.show(&ty),
+                        (eval::read_back(&names, &ty, &left).to_string(), left_at),
+                        (eval::read_back(&names, &ty, &right).to_string(), right_at),
+                        diff,
+                    )))
+                })?;
+                Ok(None)
+            }
+            Statement::Expr(expr) => {
+                let (ty, expr) = self.synth(expr)?;
+                let val = self.eval(&expr);
+                let names = self.names();
+                Ok(Some(Normal {
+                    ty: eval::read_back_type(&names, &ty),
+                    expr: eval::read_back(&names, &ty, &val),
+                }))
+            }
+        }
+    }
+
+    // take `TODO's found so far
+    fn holes(&mut self) -> Vec<Hole> {
+        std::mem::take(&mut self.holes)
+    }
+
+    /// Take types of variables found so far, if they are recorded.
+    pub fn typings(&mut self) -> Vec<Typing> {
+        self.typings
+            .as_mut()
+            .map(std::mem::take)
+            .unwrap_or_default()
+    }
+
+    /// Type of top-level `ident', once it is defined.
+    pub fn claimed(&self, ident: &Identifier) -> Option<Expression> {
+        match self.binding(ident)? {
+            Binding::Def(ty) => Some(self.read_back_type(ty)),
+            Binding::Local { .. } => None,
+        }
+    }
+
+    // remember that `ident' at `loc' is of type `ty'
+    fn typed(&mut self, loc: SourceSpan, ident: Identifier, ty: &Value) {
+        if self.typings.is_none() {
+            return;
+        }
+        let ty = self.show(ty);
+        if let Some(typings) = self.typings.as_mut() {
+            typings.push(Typing { loc, ident, ty });
+        }
+    }
+
+    // remember `TODO' at `loc' standing for a `ty'
+    fn hole(&mut self, loc: SourceSpan, ty: &Value) -> Expression {
+        let context = self
+            .bindings
+            .iter()
+            .filter_map(|(name, binding)| match binding {
+                Binding::Local { ty, .. } => Some((*name, self.show(ty))),
+                _ => None,
+            })
+            .collect();
+        self.holes.push(Hole::new(loc, self.show(ty), context));
+        self.annotate(ty, Expression::Todo(Todo { ann: () }))
+    }
+
+    fn binding(&self, ident: &Identifier) -> Option<&Binding> {
+        self.bindings
+            .iter()
+            .rev()
+            .find(|(name, _)| name == ident)
+            .map(|(_, binding)| binding)
+    }
+
+    // names that can't be used when inventing new variables
+    fn names(&self) -> Vec<Identifier> {
+        self.bindings
+            .iter()
+            .flat_map(|(name, binding)| match binding {
+                Binding::Local { var, .. } => vec![*name, *var],
+                _ => vec![*name],
+            })
+            .collect()
+    }
+
+    // forget the bindings made since there were `depth' of them
+    fn unbind(&mut self, depth: usize) {
+        for _ in depth..self.bindings.len() {
+            self.env = self.env.pop();
+        }
+        self.bindings.truncate(depth);
+    }
+
+    // evaluate what checking elaborated, lowering it once
+    fn eval(&self, expr: &Expression) -> Value {
+        eval::eval(&self.env, &term::lower(expr))
+    }
+
+    fn show(&self, ty: &Value) -> String {
+        eval::read_back_type(&self.names(), ty).to_string()
+    }
+
+    fn read_back_type(&self, ty: &Value) -> Expression {
+        eval::read_back_type(&self.names(), ty)
+    }
+
+    fn lookup(&self, ann: SourceSpan, ident: &Identifier) -> Result<(Value, Expression)> {
+        let help = match self.binding(ident) {
+            Some(Binding::Def(ty)) => return Ok((ty.clone(), ref_(*ident))),
+            Some(Binding::Local { ty, var }) => return Ok((ty.clone(), ref_(*var))),
+            None => format!("`{ident}' is not defined"),
+        };
+        Err(TypeError::from(Unbound { loc: ann, help }))
+    }
+
+    /// The "is a type" judgement: check that `expr' describes a type,
+    /// and elaborate it.
+    fn is_type(&mut self, expr: &Expression<SourceSpan>) -> Result<Expression> {
+        let ann = *expr.ann();
+        match expr {
+            Expression::Ty(Ty { ty, .. }) => Ok(type_(*ty)),
+            Expression::Universe(_) => Ok(Expression::Universe(Universe { ann: () })),
+            Expression::Todo(Todo { ann }) => Ok(self.hole(*ann, &Value::Universe)),
+            Expression::Atom(_) | Expression::Abs(_) => Err(TypeError::from(NotAType { loc: ann })),
+            Expression::Arrow(Arrow { doms, cod, .. }) => Ok(Expression::Arrow(Arrow {
+                ann: (),
+                doms: doms
+                    .iter()
+                    .map(|dom| self.is_type(dom).map(Box::new))
+                    .collect::<Result<_>>()?,
+                cod: Box::new(self.is_type(cod)?),
+            })),
+            Expression::Pi(Pi { binders, cod, .. }) => {
+                let (binders, cod) = self.telescope(binders, cod, |ctx, expr| ctx.is_type(expr))?;
+                Ok(Expression::Pi(Pi {
+                    ann: (),
+                    binders,
+                    cod: Box::new(cod),
+                }))
+            }
+            Expression::Sigma(Sigma {
+                binders, cdr_ty, ..
+            }) => {
+                let (binders, cdr_ty) =
+                    self.telescope(binders, cdr_ty, |ctx, expr| ctx.is_type(expr))?;
+                Ok(Expression::Sigma(Sigma {
+                    ann: (),
+                    binders,
+                    cdr_ty: Box::new(cdr_ty),
+                }))
+            }
+            Expression::Pair(Pair { car_ty, cdr_ty, .. }) => Ok(Expression::Pair(Pair {
+                ann: (),
+                car_ty: Box::new(self.is_type(car_ty)?),
+                cdr_ty: Box::new(self.is_type(cdr_ty)?),
+            })),
+            Expression::List(List { elem, .. }) => Ok(Expression::List(List {
+                ann: (),
+                elem: Box::new(self.is_type(elem)?),
+            })),
+            Expression::Vector(Vector { elem, len, .. }) => Ok(Expression::Vector(Vector {
+                ann: (),
+                elem: Box::new(self.is_type(elem)?),
+                len: Box::new(self.check(len, &Value::Nat)?),
+            })),
+            Expression::Equal(Equal { ty, from, to, .. }) => {
+                let ty = self.is_type(ty)?;
+                let ty_ = self.eval(&ty);
+                Ok(Expression::Equal(Equal {
+                    ann: (),
+                    ty: Box::new(ty),
+                    from: Box::new(self.check(from, &ty_)?),
+                    to: Box::new(self.check(to, &ty_)?),
+                }))
+            }
+            Expression::Either(Either { left, right, .. }) => Ok(Expression::Either(Either {
+                ann: (),
+                left: Box::new(self.is_type(left)?),
+                right: Box::new(self.is_type(right)?),
+            })),
+            Expression::Cons(_)
+            | Expression::Nil(_)
+            | Expression::VecNil(_)
+            | Expression::Same(_)
+            | Expression::Left(_)
+            | Expression::Right(_)
+            | Expression::Sole(_) => Err(TypeError::from(NotAType { loc: ann })),
+            expr => match self.synth(expr)? {
+                (Value::Universe, expr) => Ok(expr),
+                _ => Err(TypeError::from(NotAType { loc: ann })),
+            },
+        }
+    }
+
+    /// Synthesis mode: figure out the type of `expr', and elaborate it.
+    fn synth(&mut self, expr: &Expression<SourceSpan>) -> Result<(Value, Expression)> {
+        match expr {
+            Expression::Atom(Atom { ident, .. }) => Ok((
+                Value::Atom,
+                Expression::Atom(Atom {
+                    ann: (),
+                    ident: *ident,
+                }),
+            )),
+            Expression::Ty(Ty { ty, .. }) => Ok((Value::Universe, type_(*ty))),
+            // there is no universe above `U', so it is not a `U' itself
+            Expression::Universe(Universe { ann }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`U' is a type, but it is not a `U' itself",
+            })),
+            Expression::Ref(Variable { ann, ident }) => {
+                let (ty, expr) = self.lookup(*ann, ident)?;
+                self.typed(*ann, *ident, &ty);
+                Ok((ty, expr))
+            }
+            Expression::Abs(Lambda { ann, .. }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "lambda expressions can only be checked, try claiming their type first",
+            })),
+            Expression::App(Apply { fun, args, .. }) => {
+                let (mut ty, fun_) = self.synth(fun)?;
+                let mut args_ = Vec::with_capacity(args.len());
+                for arg in args.iter() {
+                    match ty {
+                        Value::Pi { dom, cod } => {
+                            let arg = self.check(arg, &dom)?;
+                            ty = cod.apply(self.eval(&arg));
+                            args_.push(Box::new(arg));
+                        }
+                        ty => {
+                            return Err(TypeError::from(WrongType {
+                                loc: *fun.ann(),
+                                expected: "a function",
+                                ty: self.show(&ty),
+                            }))
+                        }
+                    }
+                }
+                Ok((
+                    ty,
+                    Expression::App(Apply {
+                        ann: (),
+                        fun: Box::new(fun_),
+                        args: args_,
+                    }),
+                ))
+            }
+            // function types are `U's, as long as their parts are
+            Expression::Arrow(Arrow { doms, cod, .. }) => {
+                let doms = doms
+                    .iter()
+                    .map(|dom| self.check(dom, &Value::Universe).map(Box::new))
+                    .collect::<Result<_>>()?;
+                let cod = Box::new(self.check(cod, &Value::Universe)?);
+                Ok((
+                    Value::Universe,
+                    Expression::Arrow(Arrow { ann: (), doms, cod }),
+                ))
+            }
+            Expression::Pi(Pi { binders, cod, .. }) => {
+                let (binders, cod) =
+                    self.telescope(binders, cod, |ctx, expr| ctx.check(expr, &Value::Universe))?;
+                Ok((
+                    Value::Universe,
+                    Expression::Pi(Pi {
+                        ann: (),
+                        binders,
+                        cod: Box::new(cod),
+                    }),
+                ))
+            }
+            Expression::Sigma(Sigma {
+                binders, cdr_ty, ..
+            }) => {
+                let (binders, cdr_ty) = self.telescope(binders, cdr_ty, |ctx, expr| {
+                    ctx.check(expr, &Value::Universe)
+                })?;
+                Ok((
+                    Value::Universe,
+                    Expression::Sigma(Sigma {
+                        ann: (),
+                        binders,
+                        cdr_ty: Box::new(cdr_ty),
+                    }),
+                ))
+            }
+            Expression::Pair(Pair { car_ty, cdr_ty, .. }) => {
+                let car_ty = Box::new(self.check(car_ty, &Value::Universe)?);
+                let cdr_ty = Box::new(self.check(cdr_ty, &Value::Universe)?);
+                Ok((
+                    Value::Universe,
+                    Expression::Pair(Pair {
+                        ann: (),
+                        car_ty,
+                        cdr_ty,
+                    }),
+                ))
+            }
+            // when both parts can synthesize, this is a `Pair'
+            Expression::Cons(Cons { car, cdr, .. }) => {
+                let (car_ty, car) = self.synth(car)?;
+                let (cdr_ty, cdr) = self.synth(cdr)?;
+                Ok((
+                    Value::Sigma {
+                        car_ty: Box::new(car_ty),
+                        cdr_ty: Closure::Const(Box::new(cdr_ty)),
+                    },
+                    Expression::Cons(Cons {
+                        ann: (),
+                        car: Box::new(car),
+                        cdr: Box::new(cdr),
+                    }),
+                ))
+            }
+            Expression::Car(Car { pair, .. }) => match self.synth(pair)? {
+                (Value::Sigma { car_ty, .. }, pair) => Ok((
+                    *car_ty,
+                    Expression::Car(Car {
+                        ann: (),
+                        pair: Box::new(pair),
+                    }),
+                )),
+                (ty, _) => Err(self.not_a(pair, "a pair", &ty)),
+            },
+            Expression::Cdr(Cdr { pair, .. }) => match self.synth(pair)? {
+                (Value::Sigma { cdr_ty, .. }, pair) => Ok((
+                    cdr_ty.apply(eval::car(&self.eval(&pair))),
+                    Expression::Cdr(Cdr {
+                        ann: (),
+                        pair: Box::new(pair),
+                    }),
+                )),
+                (ty, _) => Err(self.not_a(pair, "a pair", &ty)),
+            },
+            Expression::The(The { ty, expr, .. }) => {
+                let ty = self.is_type(ty)?;
+                let ty_ = self.eval(&ty);
+                let expr = self.check(expr, &ty_)?;
+                Ok((
+                    ty_,
+                    Expression::The(The {
+                        ann: (),
+                        ty: Box::new(ty),
+                        expr: Box::new(expr),
+                    }),
+                ))
+            }
+            Expression::Zero(_) => Ok((Value::Nat, Expression::Zero(Zero { ann: () }))),
+            Expression::Add1(Add1 { n, .. }) => {
+                let n = self.check(n, &Value::Nat)?;
+                Ok((
+                    Value::Nat,
+                    Expression::Add1(Add1 {
+                        ann: (),
+                        n: Box::new(n),
+                    }),
+                ))
+            }
+            Expression::Numeral(Numeral { n, .. }) => {
+                Ok((Value::Nat, Expression::Numeral(Numeral { ann: (), n: *n })))
+            }
+            Expression::WhichNat(WhichNat {
+                target, base, step, ..
+            }) => {
+                let (target, ty, base) = self.nat_elim(target, base)?;
+                let step = self.check(step, &arrow(Value::Nat, ty.clone()))?;
+                Ok((
+                    ty,
+                    Expression::WhichNat(WhichNat {
+                        ann: (),
+                        target,
+                        base,
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::IterNat(IterNat {
+                target, base, step, ..
+            }) => {
+                let (target, ty, base) = self.nat_elim(target, base)?;
+                let step = self.check(step, &arrow(ty.clone(), ty.clone()))?;
+                Ok((
+                    ty,
+                    Expression::IterNat(IterNat {
+                        ann: (),
+                        target,
+                        base,
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::RecNat(RecNat {
+                target, base, step, ..
+            }) => {
+                let (target, ty, base) = self.nat_elim(target, base)?;
+                let step = self.check(step, &arrow(Value::Nat, arrow(ty.clone(), ty.clone())))?;
+                Ok((
+                    ty,
+                    Expression::RecNat(RecNat {
+                        ann: (),
+                        target,
+                        base,
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::IndNat(IndNat {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => {
+                let target = self.check(target, &Value::Nat)?;
+                let motive = self.check(motive, &arrow(Value::Nat, Value::Universe))?;
+                let mot = self.eval(&motive);
+                let base = self.check(base, &eval::apply(mot.clone(), Value::Zero))?;
+                let step = self.check(step, &eval::ind_nat_step(&mot))?;
+                Ok((
+                    eval::apply(mot, self.eval(&target)),
+                    Expression::IndNat(IndNat {
+                        ann: (),
+                        target: Box::new(target),
+                        motive: Box::new(motive),
+                        base: Box::new(base),
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::List(List { elem, .. }) => {
+                let elem = self.check(elem, &Value::Universe)?;
+                Ok((
+                    Value::Universe,
+                    Expression::List(List {
+                        ann: (),
+                        elem: Box::new(elem),
+                    }),
+                ))
+            }
+            Expression::Nil(Nil { ann }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`nil' can only be checked, try claiming its type first",
+            })),
+            Expression::ListCons(ListCons { head, tail, .. }) => {
+                let (elem, head) = self.synth(head)?;
+                let ty = Value::List(Box::new(elem));
+                let tail = self.check(tail, &ty)?;
+                Ok((
+                    ty,
+                    Expression::ListCons(ListCons {
+                        ann: (),
+                        head: Box::new(head),
+                        tail: Box::new(tail),
+                    }),
+                ))
+            }
+            Expression::RecList(RecList {
+                target, base, step, ..
+            }) => {
+                let (elem, target) = self.list_target(target)?;
+                let (ty, base) = self.base(base)?;
+                let list = Value::List(Box::new(elem.clone()));
+                let step_ty = arrow(elem, arrow(list, arrow(ty.clone(), ty.clone())));
+                let step = self.check(step, &step_ty)?;
+                Ok((
+                    ty,
+                    Expression::RecList(RecList {
+                        ann: (),
+                        target,
+                        base,
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::IndList(IndList {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => {
+                let (elem, target) = self.list_target(target)?;
+                let list = Value::List(Box::new(elem.clone()));
+                let motive = self.check(motive, &arrow(list, Value::Universe))?;
+                let mot = self.eval(&motive);
+                let base = self.check(base, &eval::apply(mot.clone(), Value::Nil))?;
+                let step = self.check(step, &eval::ind_list_step(&elem, &mot))?;
+                Ok((
+                    eval::apply(mot, self.eval(&target)),
+                    Expression::IndList(IndList {
+                        ann: (),
+                        target,
+                        motive: Box::new(motive),
+                        base: Box::new(base),
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::Vector(Vector { elem, len, .. }) => {
+                let elem = self.check(elem, &Value::Universe)?;
+                let len = self.check(len, &Value::Nat)?;
+                Ok((
+                    Value::Universe,
+                    Expression::Vector(Vector {
+                        ann: (),
+                        elem: Box::new(elem),
+                        len: Box::new(len),
+                    }),
+                ))
+            }
+            Expression::VecNil(VecNil { ann }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`vecnil' can only be checked, try claiming its type first",
+            })),
+            Expression::VecCons(VecCons { ann, .. }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`vec::' can only be checked, try claiming its type first",
+            })),
+            Expression::Head(Head { vec, .. }) => {
+                let (elem, _, vec) = self.non_empty_vec(vec)?;
+                Ok((
+                    elem,
+                    Expression::Head(Head {
+                        ann: (),
+                        vec: Box::new(vec),
+                    }),
+                ))
+            }
+            Expression::Tail(Tail { vec, .. }) => {
+                let (elem, len, vec) = self.non_empty_vec(vec)?;
+                Ok((
+                    Value::Vec(Box::new(elem), Box::new(len)),
+                    Expression::Tail(Tail {
+                        ann: (),
+                        vec: Box::new(vec),
+                    }),
+                ))
+            }
+            Expression::IndVec(IndVec {
+                len,
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => {
+                let len_ = self.check(len, &Value::Nat)?;
+                let n = self.eval(&len_);
+                // `len' has to be the length of the target
+                let (elem, target) = match self.synth(target)? {
+                    (Value::Vec(elem, target_len), target) => {
+                        self.same_value(len, &Value::Nat, &target_len, &n)?;
+                        (*elem, target)
+                    }
+                    (ty, _) => return Err(self.not_a(target, "a vector", &ty)),
+                };
+                let motive = self.check(motive, &eval::ind_vec_motive(&elem))?;
+                let mot = self.eval(&motive);
+                let base_ty = eval::apply(eval::apply(mot.clone(), Value::Zero), Value::VecNil);
+                let base = self.check(base, &base_ty)?;
+                let step = self.check(step, &eval::ind_vec_step(&elem, &mot))?;
+                Ok((
+                    eval::apply(eval::apply(mot, n), self.eval(&target)),
+                    Expression::IndVec(IndVec {
+                        ann: (),
+                        len: Box::new(len_),
+                        target: Box::new(target),
+                        motive: Box::new(motive),
+                        base: Box::new(base),
+                        step: Box::new(step),
+                    }),
+                ))
+            }
+            Expression::Equal(Equal { ty, from, to, .. }) => {
+                let ty = self.check(ty, &Value::Universe)?;
+                let ty_ = self.eval(&ty);
+                let from = self.check(from, &ty_)?;
+                let to = self.check(to, &ty_)?;
+                Ok((
+                    Value::Universe,
+                    Expression::Equal(Equal {
+                        ann: (),
+                        ty: Box::new(ty),
+                        from: Box::new(from),
+                        to: Box::new(to),
+                    }),
+                ))
+            }
+            Expression::Same(Same { ann, .. }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`same' can only be checked, try claiming its type first",
+            })),
+            Expression::Cong(Cong { target, fun, .. }) => {
+                let (ty, from, to, target) = self.equality(target)?;
+                let (fun_ty, fun_) = self.synth(fun)?;
+                let cod = match (&fun_ty, self.read_back_type(&fun_ty)) {
+                    (Value::Pi { dom, cod }, Expression::Arrow(_)) => {
+                        self.same_type(fun, dom, &ty)?;
+                        cod.apply(from.clone())
+                    }
+                    _ => return Err(self.not_a(fun, "a non-dependent function", &fun_ty)),
+                };
+                let f = self.eval(&fun_);
+                Ok((
+                    Value::Equal {
+                        ty: Box::new(cod),
+                        from: Box::new(eval::apply(f.clone(), from)),
+                        to: Box::new(eval::apply(f, to)),
+                    },
+                    Expression::Cong(Cong {
+                        ann: (),
+                        target: Box::new(target),
+                        fun: Box::new(self.annotate(&fun_ty, fun_)),
+                    }),
+                ))
+            }
+            Expression::Replace(Replace {
+                target,
+                motive,
+                base,
+                ..
+            }) => {
+                let (ty, from, to, target) = self.equality(target)?;
+                let motive = self.check(motive, &arrow(ty, Value::Universe))?;
+                let mot = self.eval(&motive);
+                let base = self.check(base, &eval::apply(mot.clone(), from))?;
+                Ok((
+                    eval::apply(mot, to),
+                    Expression::Replace(Replace {
+                        ann: (),
+                        target: Box::new(target),
+                        motive: Box::new(motive),
+                        base: Box::new(base),
+                    }),
+                ))
+            }
+            Expression::Symm(Symm { target, .. }) => {
+                let (ty, from, to, target) = self.equality(target)?;
+                Ok((
+                    Value::Equal {
+                        ty: Box::new(ty),
+                        from: Box::new(to),
+                        to: Box::new(from),
+                    },
+                    Expression::Symm(Symm {
+                        ann: (),
+                        target: Box::new(target),
+                    }),
+                ))
+            }
+            // the middle ends of both equalities have to be the same
+            Expression::Trans(Trans { left, right, .. }) => {
+                let (ty, from, mid, left_) = self.equality(left)?;
+                let (right_ty, mid_, to, right_) = self.equality(right)?;
+                self.same_type(right, &ty, &right_ty)?;
+                self.same_value(right, &ty, &mid, &mid_)?;
+                let left_ty = Value::Equal {
+                    ty: Box::new(ty.clone()),
+                    from: Box::new(from.clone()),
+                    to: Box::new(mid),
+                };
+                let right_ty = Value::Equal {
+                    ty: Box::new(right_ty),
+                    from: Box::new(mid_),
+                    to: Box::new(to.clone()),
+                };
+                Ok((
+                    Value::Equal {
+                        ty: Box::new(ty),
+                        from: Box::new(from),
+                        to: Box::new(to),
+                    },
+                    Expression::Trans(Trans {
+                        ann: (),
+                        left: Box::new(self.annotate(&left_ty, left_)),
+                        right: Box::new(self.annotate(&right_ty, right_)),
+                    }),
+                ))
+            }
+            Expression::IndEq(IndEq {
+                target,
+                motive,
+                base,
+                ..
+            }) => {
+                let (ty, from, to, target) = self.equality(target)?;
+                let motive = self.check(motive, &eval::ind_eq_motive(&ty, &from))?;
+                let mot = self.eval(&motive);
+                let same = Value::Same(Box::new(from.clone()));
+                let base = self.check(base, &eval::apply(eval::apply(mot.clone(), from), same))?;
+                Ok((
+                    eval::apply(eval::apply(mot, to), self.eval(&target)),
+                    Expression::IndEq(IndEq {
+                        ann: (),
+                        target: Box::new(target),
+                        motive: Box::new(motive),
+                        base: Box::new(base),
+                    }),
+                ))
+            }
+            Expression::Either(Either { left, right, .. }) => {
+                let left = self.check(left, &Value::Universe)?;
+                let right = self.check(right, &Value::Universe)?;
+                Ok((
+                    Value::Universe,
+                    Expression::Either(Either {
+                        ann: (),
+                        left: Box::new(left),
+                        right: Box::new(right),
+                    }),
+                ))
+            }
+            Expression::Left(Left { ann, .. }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`left' can only be checked, try claiming its type first",
+            })),
+            Expression::Right(Right { ann, .. }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`right' can only be checked, try claiming its type first",
+            })),
+            Expression::IndEither(IndEither {
+                target,
+                motive,
+                base_left,
+                base_right,
+                ..
+            }) => {
+                let (ty, target_) = self.synth(target)?;
+                let (left, right) = match &ty {
+                    Value::Either(left, right) => (left.as_ref(), right.as_ref()),
+                    ty => return Err(self.not_a(target, "an `Either'", ty)),
+                };
+                let motive = self.check(motive, &arrow(ty.clone(), Value::Universe))?;
+                let mot = self.eval(&motive);
+                let (left_ty, right_ty) = eval::ind_either_bases(left, right, &mot);
+                let base_left = self.check(base_left, &left_ty)?;
+                let base_right = self.check(base_right, &right_ty)?;
+                Ok((
+                    eval::apply(mot, self.eval(&target_)),
+                    Expression::IndEither(IndEither {
+                        ann: (),
+                        target: Box::new(target_),
+                        motive: Box::new(motive),
+                        base_left: Box::new(base_left),
+                        base_right: Box::new(base_right),
+                    }),
+                ))
+            }
+            Expression::Sole(_) => Ok((Value::Trivial, Expression::Sole(Sole { ann: () }))),
+            Expression::Todo(Todo { ann }) => Err(TypeError::from(CantSynth {
+                loc: *ann,
+                help: "`TODO' can only be checked, try claiming its type first",
+            })),
+            // the motive is just a `U', since there are no values of
+            // `Absurd' to depend on
+            Expression::IndAbsurd(IndAbsurd { target, motive, .. }) => {
+                let target = self.check(target, &Value::Absurd)?;
+                let motive = self.check(motive, &Value::Universe)?;
+                Ok((
+                    self.eval(&motive),
+                    Expression::IndAbsurd(IndAbsurd {
+                        ann: (),
+                        target: Box::new(target),
+                        motive: Box::new(motive),
+                    }),
+                ))
+            }
+        }
+    }
+
+    // synthesize the type of an equality `target', returning its type
+    // and both of its ends
+    fn equality(
+        &mut self,
+        target: &Expression<SourceSpan>,
+    ) -> Result<(Value, Value, Value, Expression)> {
+        match self.synth(target)? {
+            (Value::Equal { ty, from, to }, expr) => Ok((*ty, *from, *to, expr)),
+            (ty, _) => Err(self.not_a(target, "an equality", &ty)),
+        }
+    }
+
+    // synthesize the type of a list `target', returning type of its
+    // elements
+    fn list_target(&mut self, target: &Expression<SourceSpan>) -> Result<(Value, Box<Expression>)> {
+        match self.synth(target)? {
+            (Value::List(elem), expr) => Ok((*elem, Box::new(expr))),
+            (ty, _) => Err(self.not_a(target, "a list", &ty)),
+        }
+    }
+
+    // synthesize the type of a vector with at least one element,
+    // returning type of its elements and length of its tail
+    fn non_empty_vec(
+        &mut self,
+        vec: &Expression<SourceSpan>,
+    ) -> Result<(Value, Value, Expression)> {
+        match self.synth(vec)? {
+            (Value::Vec(elem, len), expr) => match eval::sub1(&len) {
+                Some(len) => Ok((*elem, len, expr)),
+                None => Err(self.not_a(vec, "a non-empty vector", &Value::Vec(elem, len))),
+            },
+            (ty, _) => Err(self.not_a(vec, "a vector", &ty)),
+        }
+    }
+
+    // check the target of a non-dependent eliminator for `Nat', and its
+    // base
+    fn nat_elim(
+        &mut self,
+        target: &Expression<SourceSpan>,
+        base: &Expression<SourceSpan>,
+    ) -> Result<(Box<Expression>, Value, Box<Expression>)> {
+        let target = self.check(target, &Value::Nat)?;
+        let (ty, base) = self.base(base)?;
+        Ok((Box::new(target), ty, base))
+    }
+
+    // figure out the type of a non-dependent eliminator's base, which is
+    // the type of the whole elimination; the base is elaborated to
+    // `(the B base)'
+    fn base(&mut self, base: &Expression<SourceSpan>) -> Result<(Value, Box<Expression>)> {
+        let (ty, base) = self.synth(base)?;
+        let base = self.annotate(&ty, base);
+        Ok((ty, Box::new(base)))
+    }
+
+    // `(the ty expr)'
+    fn annotate(&self, ty: &Value, expr: Expression) -> Expression {
+        Expression::The(The {
+            ann: (),
+            ty: Box::new(self.read_back_type(ty)),
+            expr: Box::new(expr),
+        })
+    }
+
+    // conversion check between types, `actual' one found at `at'
+    fn same_type(
+        &self,
+        at: &Expression<SourceSpan>,
+        expected: &Value,
+        actual: &Value,
+    ) -> Result<()> {
+        conv::same_type(&self.names(), expected, actual).map_err(|diff| {
+            TypeError::from(Mismatch::new(
+                at,
+                self.show(expected),
+                self.show(actual),
+                diff,
+            ))
+        })
+    }
+
+    // conversion check between values of type `ty', `actual' one found
+    // at `at'
+    fn same_value(
+        &self,
+        at: &Expression<SourceSpan>,
+        ty: &Value,
+        expected: &Value,
+        actual: &Value,
+    ) -> Result<()> {
+        let names = self.names();
+        conv::same(&names, ty, expected, actual).map_err(|diff| {
+            TypeError::from(Mismatch::new(
+                at,
+                eval::read_back(&names, ty, expected).to_string(),
+                eval::read_back(&names, ty, actual).to_string(),
+                diff,
+            ))
+        })
+    }
+
+    fn not_a(
+        &self,
+        expr: &Expression<SourceSpan>,
+        expected: &'static str,
+        ty: &Value,
+    ) -> TypeError {
+        TypeError::from(WrongType {
+            loc: *expr.ann(),
+            expected,
+            ty: self.show(ty),
+        })
+    }
+
+    // judge types of `binders' and `body', each in scope of the
+    // preceding binders
+    fn telescope(
+        &mut self,
+        binders: &[TypedBinder<SourceSpan>],
+        body: &Expression<SourceSpan>,
+        judge: impl Fn(&mut Self, &Expression<SourceSpan>) -> Result<Expression>,
+    ) -> Result<(Vec<TypedBinder>, Expression)> {
+        let depth = self.bindings.len();
+        let res = binders
+            .iter()
+            .map(|TypedBinder { ann, ident, ty }| {
+                let ty = judge(self, ty)?;
+                let ty_ = self.eval(&ty);
+                self.typed(*ann, *ident, &ty_);
+                let ident = self.bind(*ident, ty_);
+                Ok(TypedBinder {
+                    ann: (),
+                    ident,
+                    ty: Box::new(ty),
+                })
+            })
+            .collect::<Result<_>>()
+            .and_then(|binders| Ok((binders, judge(self, body)?)));
+        self.unbind(depth);
+        res
+    }
+
+    // introduce local variable `ident' of type `ty', returning the
+    // internal name of the neutral variable it stands for
+    fn bind(&mut self, ident: Identifier, ty: Value) -> Identifier {
+        let var = fresh(&self.names(), &ident);
+        // elaborated expressions refer to locals by their internal names,
+        // just like read back ones
+        let neutral = Value::Neutral {
+            ty: Box::new(ty.clone()),
+            neu: Neutral::Var(var),
+        };
+        self.env = self.env.push((var, neutral));
+        self.bindings.push((ident, Binding::Local { ty, var }));
+        var
+    }
+
+    /// Checking mode: make sure that `expr' has type `ty', and elaborate
+    /// it.
+    fn check(&mut self, expr: &Expression<SourceSpan>, ty: &Value) -> Result<Expression> {
+        match expr {
+            Expression::Abs(Lambda { ann, args, body }) => {
+                let depth = self.bindings.len();
+                let res = self.check_lambda(*ann, args, body, ty);
+                self.unbind(depth);
+                res
+            }
+            Expression::Cons(Cons { car, cdr, .. }) => match ty {
+                Value::Sigma { car_ty, cdr_ty } => {
+                    let car = self.check(car, car_ty)?;
+                    let cdr_ty = cdr_ty.apply(self.eval(&car));
+                    let cdr = self.check(cdr, &cdr_ty)?;
+                    Ok(Expression::Cons(Cons {
+                        ann: (),
+                        car: Box::new(car),
+                        cdr: Box::new(cdr),
+                    }))
+                }
+                ty => Err(self.not_a(expr, "a pair", ty)),
+            },
+            Expression::Nil(_) => match ty {
+                Value::List(_) => Ok(Expression::Nil(Nil { ann: () })),
+                ty => Err(self.not_a(expr, "a list", ty)),
+            },
+            Expression::ListCons(ListCons { head, tail, .. }) => match ty {
+                Value::List(elem) => {
+                    let head = self.check(head, elem)?;
+                    let tail = self.check(tail, ty)?;
+                    Ok(Expression::ListCons(ListCons {
+                        ann: (),
+                        head: Box::new(head),
+                        tail: Box::new(tail),
+                    }))
+                }
+                ty => Err(self.not_a(expr, "a list", ty)),
+            },
+            Expression::VecNil(_) => match ty {
+                Value::Vec(elem, _) => {
+                    let empty = Value::Vec(elem.clone(), Box::new(Value::Zero));
+                    self.same_type(expr, ty, &empty)?;
+                    Ok(Expression::VecNil(VecNil { ann: () }))
+                }
+                ty => Err(self.not_a(expr, "a vector", ty)),
+            },
+            Expression::VecCons(VecCons { head, tail, .. }) => match ty {
+                Value::Vec(elem, len) => match eval::sub1(len) {
+                    Some(len) => {
+                        let head = self.check(head, elem)?;
+                        let tail = self.check(tail, &Value::Vec(elem.clone(), Box::new(len)))?;
+                        Ok(Expression::VecCons(VecCons {
+                            ann: (),
+                            head: Box::new(head),
+                            tail: Box::new(tail),
+                        }))
+                    }
+                    None => Err(self.not_a(expr, "a non-empty vector", ty)),
+                },
+                ty => Err(self.not_a(expr, "a vector", ty)),
+            },
+            // both ends have to be the same as the expression
+            Expression::Same(Same { expr: val, .. }) => match ty {
+                Value::Equal {
+                    ty: val_ty,
+                    from,
+                    to,
+                } => {
+                    let val = self.check(val, val_ty)?;
+                    let val_ = self.eval(&val);
+                    self.same_value(expr, val_ty, from, &val_)?;
+                    self.same_value(expr, val_ty, to, &val_)?;
+                    Ok(Expression::Same(Same {
+                        ann: (),
+                        expr: Box::new(val),
+                    }))
+                }
+                ty => Err(self.not_a(expr, "an equality", ty)),
+            },
+            Expression::Left(Left { expr: val, .. }) => match ty {
+                Value::Either(left, _) => Ok(Expression::Left(Left {
+                    ann: (),
+                    expr: Box::new(self.check(val, left)?),
+                })),
+                ty => Err(self.not_a(expr, "an `Either'", ty)),
+            },
+            Expression::Right(Right { expr: val, .. }) => match ty {
+                Value::Either(_, right) => Ok(Expression::Right(Right {
+                    ann: (),
+                    expr: Box::new(self.check(val, right)?),
+                })),
+                ty => Err(self.not_a(expr, "an `Either'", ty)),
+            },
+            Expression::Todo(Todo { ann }) => Ok(self.hole(*ann, ty)),
+            expr => {
+                let (actual, elab) = self.synth(expr)?;
+                self.same_type(expr, ty, &actual)?;
+                Ok(elab)
+            }
+        }
+    }
+
+    fn check_lambda(
+        &mut self,
+        ann: SourceSpan,
+        args: &[Binder<SourceSpan>],
+        body: &Expression<SourceSpan>,
+        ty: &Value,
+    ) -> Result<Expression> {
+        let mut ty = ty.clone();
+        let mut vars = Vec::with_capacity(args.len());
+        for Binder { ann: loc, ident } in args.iter() {
+            let (dom, cod): (Value, Closure) = match ty {
+                Value::Pi { dom, cod } => (*dom, cod),
+                ty => {
+                    return Err(TypeError::from(WrongType {
+                        loc: ann,
+                        expected: "a function",
+                        ty: self.show(&ty),
+                    }))
+                }
+            };
+            self.typed(*loc, *ident, &dom);
+            let var = self.bind(*ident, dom.clone());
+            ty = cod.apply(Value::Neutral {
+                ty: Box::new(dom),
+                neu: Neutral::Var(var),
+            });
+            vars.push(Binder {
+                ann: (),
+                ident: var,
+            });
+        }
+        Ok(Expression::Abs(Lambda {
+            ann: (),
+            args: vars,
+            body: Box::new(self.check(body, &ty)?),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{desugar, elab, parser};
+
+    // holes found checking `text' and the normal forms of its
+    // expressions, or its first type error
+    fn checked(text: &str) -> Result<(Vec<Hole>, Vec<String>)> {
+        let (mut source, errors) = parser::parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        assert!(desugar::desugar(&mut source).is_empty());
+        let (defs, problems) = elab::definitions(&source);
+        assert!(problems.is_empty(), "bad definitions: {problems:?}");
+        let mut ctx = Context::default();
+        let (mut holes, mut normals) = (vec![], vec![]);
+        for checked in check(&mut ctx, &source.statements, &defs) {
+            holes.extend(checked.holes);
+            normals.extend(checked.normal?.map(|normal| normal.to_string()));
+        }
+        Ok((holes, normals))
+    }
+
+    fn run(text: &str) -> Result<Vec<String>> {
+        checked(text).map(|(_, normals)| normals)
+    }
+
+    // text that the first type error in `text' points at
+    fn error(text: &str) -> (TypeError, String) {
+        let err = run(text).unwrap_err();
+        let loc = match &err {
+            TypeError::NotTheSame(err) => err.loc,
+            TypeError::Mismatch(Mismatch { loc, .. })
+            | TypeError::NotAType(NotAType { loc })
+            | TypeError::WrongType(WrongType { loc, .. })
+            | TypeError::Unbound(Unbound { loc, .. })
+            | TypeError::CantSynth(CantSynth { loc, .. }) => *loc,
+        };
+        (
+            err,
+            text[loc.offset()..loc.offset() + loc.len()].to_string(),
+        )
+    }
+
+    // labels of the first type error in `text' and what they point at
+    fn labels(text: &str) -> Vec<(String, String)> {
+        let err = run(text).unwrap_err();
+        err.labels()
+            .into_iter()
+            .flatten()
+            .map(|label| {
+                let at = &text[label.offset()..label.offset() + label.len()];
+                (
+                    label.label().unwrap_or_default().to_string(),
+                    at.to_string(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_claim_define() {
+        let text = "(claim id (→ Atom Atom))
+                    (define id (λ (a) a))
+                    (id 'pea)";
+        assert_eq!(run(text).unwrap(), ["(the Atom 'pea)"]);
+        assert_eq!(
+            run("Atom (-> Atom Atom)").unwrap(),
+            ["(the U Atom)", "(the U (→ Atom Atom))"]
+        );
+    }
+
+    #[test]
+    fn test_define_mismatch() {
+        let (err, loc) = error("(claim f (→ Atom Atom)) (define f 'one)");
+        let TypeError::Mismatch(Mismatch {
+            expected, actual, ..
+        }) = err
+        else {
+            panic!("expected a mismatch, got {err:?}");
+        };
+        assert_eq!(loc, "'one");
+        assert_eq!(
+            (expected.as_str(), actual.as_str()),
+            ("(→ Atom Atom)", "Atom")
+        );
+    }
+
+    #[test]
+    fn test_not_a_type() {
+        let (err, loc) = error("(claim x 'pea) (define x 'pea)");
+        assert!(matches!(err, TypeError::NotAType(_)));
+        assert_eq!(loc, "'pea");
+        let (err, loc) = error("(claim x zero) (define x 'pea)");
+        assert!(matches!(err, TypeError::NotAType(_)));
+        assert_eq!(loc, "zero");
+    }
+
+    #[test]
+    fn test_types_as_values() {
+        let text = "(claim fun U)
+                    (define fun (→ Atom Atom))
+                    (claim f fun)
+                    (define f (λ (a) a))
+                    f";
+        assert_eq!(run(text).unwrap(), ["(the (→ Atom Atom) (λ (a) a))"]);
+        let (err, loc) = error("U");
+        assert!(matches!(err, TypeError::CantSynth(_)));
+        assert_eq!(loc, "U");
+    }
+
+    #[test]
+    fn test_application() {
+        let text = "(claim k (→ Atom Atom Atom))
+                    (define k (λ (a b) a))";
+        assert_eq!(
+            run(&format!("{text} (k 'pea 'pod)")).unwrap(),
+            ["(the Atom 'pea)"]
+        );
+        assert_eq!(
+            run(&format!("{text} (k 'pea)")).unwrap(),
+            ["(the (→ Atom Atom) (λ (b) 'pea))"]
+        );
+        let (err, loc) = error("(claim x Atom) (define x 'pea) (x 'pod)");
+        assert!(matches!(err, TypeError::WrongType(_)));
+        assert_eq!(loc, "x");
+        let (err, loc) = error(&format!("{text} (k 'pea k)"));
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "k");
+    }
+
+    #[test]
+    fn test_lambda() {
+        let (err, loc) = error("(λ (x) x)");
+        assert!(matches!(err, TypeError::CantSynth(_)));
+        assert_eq!(loc, "(λ (x) x)");
+        let (err, loc) = error("(claim x Atom) (define x (λ (y) y))");
+        assert!(matches!(err, TypeError::WrongType(_)));
+        assert_eq!(loc, "(λ (y) y)");
+    }
+
+    #[test]
+    fn test_unbound() {
+        let (err, loc) = error("(claim x Atom) (define x y)");
+        assert!(matches!(err, TypeError::Unbound(_)));
+        assert_eq!(loc, "y");
+    }
+
+    #[test]
+    fn test_pi() {
+        let text = "(claim id (Π ((t U)) (→ t t)))
+                    (define id (λ (t a) a))";
+        assert_eq!(
+            run(&format!("{text} (id Atom 'pea)")).unwrap(),
+            ["(the Atom 'pea)"]
+        );
+        assert_eq!(
+            run(&format!("{text} (id (→ Atom Atom))")).unwrap(),
+            ["(the (→ (→ Atom Atom) Atom Atom) (λ (a x) (a x)))"]
+        );
+        let (err, loc) = error(&format!("{text} (id Atom Atom)"));
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "Atom");
+    }
+
+    #[test]
+    fn test_pairs() {
+        let text = "(claim p (Σ ((t U)) t))
+                    (define p (cons Atom 'pea))";
+        assert_eq!(
+            run(&format!("{text} (car p) (cdr p)")).unwrap(),
+            ["(the U Atom)", "(the Atom 'pea)"]
+        );
+        let (err, loc) = error("(claim p (Pair Atom (→ Atom Atom))) (define p (cons 'pea 'pod))");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "'pod");
+        let (err, loc) = error("(claim x Atom) (define x 'pea) (car x)");
+        assert!(matches!(err, TypeError::WrongType(_)));
+        assert_eq!(loc, "x");
+    }
+
+    #[test]
+    fn test_naturals() {
+        assert_eq!(
+            run("(claim x Nat) (define x 3) x").unwrap(),
+            ["(the Nat 3)"]
+        );
+        let plus = "(claim + (→ Nat Nat Nat))
+                    (define + (λ (n j) (iter-Nat n j (λ (k) (add1 k)))))";
+        assert_eq!(run(&format!("{plus} (+ 2 3)")).unwrap(), ["(the Nat 5)"]);
+        assert_eq!(
+            run("(which-Nat 3 0 (λ (n-1) n-1))").unwrap(),
+            ["(the Nat 2)"]
+        );
+        assert_eq!(
+            run("(rec-Nat 3 0 (λ (n-1 sum) (add1 (add1 sum))))").unwrap(),
+            ["(the Nat 6)"]
+        );
+        let (err, loc) = error("(iter-Nat 'a 0 (λ (k) k))");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "'a");
+    }
+
+    const LIST: &str = "(claim l (List Atom))
+                        (define l (:: 'a (:: 'b nil)))";
+
+    const VEC: &str = "(claim v (Vec Atom 2))
+                       (define v (vec:: 'a (vec:: 'b vecnil)))";
+
+    const LENGTH: &str = "(λ (k es) Nat) 0 (λ (k e es n) (add1 n))";
+
+    #[test]
+    fn test_lists() {
+        assert_eq!(
+            run(&format!("{LIST} (rec-List l 0 (λ (e es n) (add1 n)))")).unwrap(),
+            ["(the Nat 2)"]
+        );
+        let (err, loc) = error("(claim l (List Atom)) (define l (:: 'a (:: 1 nil)))");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "1");
+        let (err, _) = error("nil");
+        assert!(matches!(err, TypeError::CantSynth(_)));
+    }
+
+    #[test]
+    fn test_vectors() {
+        assert_eq!(
+            run(&format!("{VEC} (head (tail v))")).unwrap(),
+            ["(the Atom 'b)"]
+        );
+        let (err, loc) = error("(claim v (Vec Atom 1)) (define v vecnil)");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "vecnil");
+    }
+
+    #[test]
+    fn test_ind_vec() {
+        assert_eq!(
+            run(&format!("{VEC} (ind-Vec 2 v {LENGTH})")).unwrap(),
+            ["(the Nat 2)"]
+        );
+    }
+
+    #[test]
+    fn test_ind_vec_length() {
+        let (err, loc) = error(&format!("{VEC} (ind-Vec 3 v {LENGTH})"));
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "3");
+    }
+
+    #[test]
+    fn test_ind_vec_target_checked_once() {
+        let text = format!("(ind-Vec 2 (the (Vec Atom 2) TODO) {LENGTH})");
+        let (holes, _) = checked(&text).unwrap();
+        assert_eq!(holes.len(), 1);
+    }
+
+    #[test]
+    fn test_equality() {
+        let text = "(claim one=one (= Nat 1 (add1 zero)))
+                    (define one=one (same 1))
+                    (symm one=one)";
+        assert_eq!(run(text).unwrap(), ["(the (= Nat 1 1) (same 1))"]);
+        let (err, loc) = error("(claim one=two (= Nat 1 2)) (define one=two (same 1))");
+        let TypeError::Mismatch(Mismatch { help, .. }) = err else {
+            panic!("expected a mismatch, got {err:?}");
+        };
+        assert_eq!(loc, "(same 1)");
+        assert_eq!(
+            help,
+            "expected `2' but found `1', `1' is not the same as `0'"
+        );
+    }
+
+    #[test]
+    fn test_either() {
+        let text = "(claim e (Either Atom Nat))
+                    (define e (right 3))
+                    (ind-Either e (λ (e) Nat) (λ (l) 0) (λ (r) r))";
+        assert_eq!(run(text).unwrap(), ["(the Nat 3)"]);
+        let (err, loc) = error("(claim e (Either Atom Nat)) (define e (left 3))");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "3");
+    }
+
+    #[test]
+    fn test_trivial() {
+        assert_eq!(run("sole").unwrap(), ["(the Trivial sole)"]);
+        let (err, loc) = error("(claim a Atom) (define a sole)");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "sole");
+    }
+
+    #[test]
+    fn test_ind_absurd() {
+        let text = "(claim absurd (→ Absurd Atom))
+                    (define absurd (λ (x) (ind-Absurd x Atom)))
+                    absurd";
+        assert_eq!(
+            run(text).unwrap(),
+            ["(the (→ Absurd Atom) (λ (x) (ind-Absurd (the Absurd x) Atom)))"]
+        );
+    }
+
+    #[test]
+    fn test_ind_absurd_universe() {
+        let (err, loc) = error("(the (→ Absurd U) (λ (x) (ind-Absurd x U)))");
+        assert!(matches!(err, TypeError::CantSynth(_)));
+        assert_eq!(loc, "U");
+    }
+
+    #[test]
+    fn test_universe() {
+        assert_eq!(run("Atom").unwrap(), ["(the U Atom)"]);
+        let (err, loc) = error("U");
+        assert!(matches!(err, TypeError::CantSynth(_)));
+        assert_eq!(loc, "U");
+    }
+
+    #[test]
+    fn test_the() {
+        assert_eq!(
+            run("((the (→ Atom Atom) (λ (a) a)) 'pea)").unwrap(),
+            ["(the Atom 'pea)"]
+        );
+        let (err, loc) = error("(the Atom 1)");
+        assert!(matches!(err, TypeError::Mismatch(_)));
+        assert_eq!(loc, "1");
+    }
+
+    #[test]
+    fn test_holes() {
+        let (holes, normals) = checked("(the (→ Atom Nat) (λ (a) TODO))").unwrap();
+        assert_eq!(normals, ["(the (→ Atom Nat) (λ (a) (the Nat TODO)))"]);
+        assert_eq!(holes.len(), 1);
+        assert_eq!(holes[0].help, "expected `Nat', in context:\n  a : Atom");
+    }
+
+    #[test]
+    fn test_check_same() {
+        assert!(run("(check-same (→ Nat Nat) (λ (n) n) (λ (k) k))").is_ok());
+        let (err, loc) = error("(check-same Atom 'pea 'pod)");
+        assert!(matches!(err, TypeError::NotTheSame(_)));
+        assert_eq!(loc, "(check-same Atom 'pea 'pod)");
+    }
+
+    #[test]
+    fn test_check_same_labels() {
+        let text = "(check-same (Pair Atom Atom) (cons 'pea 'pod) (cons 'pea 'bean))";
+        assert_eq!(
+            labels(text),
+            [
+                ("this".to_string(), text.to_string()),
+                ("not the same as `'bean'".to_string(), "'pod".to_string()),
+                ("not the same as `'pod'".to_string(), "'bean".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_mismatch_labels() {
+        let text = "(claim f (→ Atom Atom)) (define f (the (→ Atom Nat) (λ (a) 1)))";
+        assert_eq!(
+            labels(text),
+            [
+                (
+                    "this".to_string(),
+                    "(the (→ Atom Nat) (λ (a) 1))".to_string()
+                ),
+                ("expected `Atom' here".to_string(), "Nat".to_string()),
+            ]
+        );
+        // the expected side is written in the claim, so only the whole
+        // expression is labelled
+        let text = "(claim one=two (= Nat 1 2)) (define one=two (same 1))";
+        assert_eq!(labels(text), [("this".to_string(), "(same 1)".to_string())]);
+    }
+
+    #[test]
+    fn test_numerals() {
+        assert_eq!(
+            run("(claim x Nat) (define x 100) x").unwrap(),
+            ["(the Nat 100)"]
+        );
+        assert_eq!(
+            run("(the Nat 99999999999)").unwrap(),
+            ["(the Nat 99999999999)"]
+        );
+        assert_eq!(
+            run("(which-Nat 10000 0 (λ (n-1) n-1))").unwrap(),
+            ["(the Nat 9999)"]
+        );
+    }
+
+    #[test]
+    fn test_numerals_eliminated() {
+        let plus = "(claim + (→ Nat Nat Nat))
+                    (define + (λ (n j) (iter-Nat n j (λ (k) (add1 k)))))";
+        assert_eq!(
+            run(&format!("{plus} (+ 10000 10000)")).unwrap(),
+            ["(the Nat 20000)"]
+        );
+        assert!(run(&format!("{plus} (check-same Nat (+ 5000 5000) 10000)")).is_ok());
+        assert_eq!(
+            run("(rec-Nat 10000 0 (λ (n-1 sum) (add1 sum)))").unwrap(),
+            ["(the Nat 10000)"]
+        );
+    }
+
+    #[test]
+    fn test_numerals_are_add1s() {
+        assert!(run("(check-same Nat 2 (add1 (add1 zero)))").is_ok());
+        assert_eq!(
+            run("(the (→ Nat Nat) (λ (n) (add1 (add1 n))))").unwrap(),
+            ["(the (→ Nat Nat) (λ (n) (add1 (add1 n))))"]
+        );
+        let err = run("(check-same Nat 10000 10001)").unwrap_err();
+        assert!(matches!(err, TypeError::NotTheSame(_)));
+    }
+}
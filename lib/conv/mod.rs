@@ -0,0 +1,455 @@
+use crate::eval::{
+    add1s, apply, car, cdr, read_back, read_back_type, sub1, Neutral, Normal, Value,
+};
+use crate::syntax::{Expression, Identifier};
+use crate::term::fresh;
+
+////////////////////////////////////////////////
+// Type aliases
+pub type Result = std::result::Result<(), Difference>;
+
+////////////////////////////////////////////////
+// Differences
+/// Innermost sub-terms of two normal forms that are not the same.
+#[derive(Clone, Debug)]
+pub struct Difference {
+    pub lhs: Expression,
+    pub rhs: Expression,
+}
+
+////////////////////////////////////////////////
+// Sameness
+/// Decide whether `lhs' and `rhs' are the same type.  Variables in
+/// `names' are the ones in scope.
+pub fn same_type(names: &[Identifier], lhs: &Value, rhs: &Value) -> Result {
+    Conv(names.to_vec()).same_type(lhs, rhs)
+}
+
+/// Decide whether `lhs' is the same `ty' as `rhs'.
+pub fn same(names: &[Identifier], ty: &Value, lhs: &Value, rhs: &Value) -> Result {
+    Conv(names.to_vec()).same(ty, lhs, rhs)
+}
+
+struct Conv(Vec<Identifier>);
+
+impl Conv {
+    fn differ_types(&self, lhs: &Value, rhs: &Value) -> Result {
+        Err(Difference {
+            lhs: read_back_type(&self.0, lhs),
+            rhs: read_back_type(&self.0, rhs),
+        })
+    }
+
+    fn differ(&self, ty: &Value, lhs: &Value, rhs: &Value) -> Result {
+        Err(Difference {
+            lhs: read_back(&self.0, ty, lhs),
+            rhs: read_back(&self.0, ty, rhs),
+        })
+    }
+
+    // compare bodies of binders, under the same fresh variable
+    fn under<T>(
+        &mut self,
+        hint: &Identifier,
+        ty: &Value,
+        body: impl FnOnce(&mut Self, Value) -> T,
+    ) -> T {
+        let var = fresh(&self.0, hint);
+        let arg = Value::Neutral {
+            ty: Box::new(ty.clone()),
+            neu: Neutral::Var(var),
+        };
+        self.0.push(var);
+        let res = body(self, arg);
+        self.0.pop();
+        res
+    }
+
+    fn same_type(&mut self, lhs: &Value, rhs: &Value) -> Result {
+        match (lhs, rhs) {
+            (Value::Universe, Value::Universe)
+            | (Value::Atom, Value::Atom)
+            | (Value::Nat, Value::Nat)
+            | (Value::Trivial, Value::Trivial)
+            | (Value::Absurd, Value::Absurd) => Ok(()),
+            (
+                Value::Pi {
+                    dom: lhs_dom,
+                    cod: lhs_cod,
+                },
+                Value::Pi {
+                    dom: rhs_dom,
+                    cod: rhs_cod,
+                },
+            ) => {
+                self.same_type(lhs_dom, rhs_dom)?;
+                self.under(&lhs_cod.var(), lhs_dom, |conv, arg| {
+                    conv.same_type(&lhs_cod.apply(arg.clone()), &rhs_cod.apply(arg))
+                })
+            }
+            (
+                Value::Sigma {
+                    car_ty: lhs_car,
+                    cdr_ty: lhs_cdr,
+                },
+                Value::Sigma {
+                    car_ty: rhs_car,
+                    cdr_ty: rhs_cdr,
+                },
+            ) => {
+                self.same_type(lhs_car, rhs_car)?;
+                self.under(&lhs_cdr.var(), lhs_car, |conv, arg| {
+                    conv.same_type(&lhs_cdr.apply(arg.clone()), &rhs_cdr.apply(arg))
+                })
+            }
+            (Value::List(lhs_elem), Value::List(rhs_elem)) => self.same_type(lhs_elem, rhs_elem),
+            (Value::Vec(lhs_elem, lhs_len), Value::Vec(rhs_elem, rhs_len)) => {
+                self.same_type(lhs_elem, rhs_elem)?;
+                self.same(&Value::Nat, lhs_len, rhs_len)
+            }
+            (
+                Value::Equal {
+                    ty: lhs_ty,
+                    from: lhs_from,
+                    to: lhs_to,
+                },
+                Value::Equal {
+                    ty: rhs_ty,
+                    from: rhs_from,
+                    to: rhs_to,
+                },
+            ) => {
+                self.same_type(lhs_ty, rhs_ty)?;
+                self.same(lhs_ty, lhs_from, rhs_from)?;
+                self.same(lhs_ty, lhs_to, rhs_to)
+            }
+            (Value::Either(lhs_left, lhs_right), Value::Either(rhs_left, rhs_right)) => {
+                self.same_type(lhs_left, rhs_left)?;
+                self.same_type(lhs_right, rhs_right)
+            }
+            (Value::Neutral { neu: lhs, .. }, Value::Neutral { neu: rhs, .. }) => {
+                self.same_neutral(lhs, rhs)
+            }
+            (lhs, rhs) => self.differ_types(lhs, rhs),
+        }
+    }
+
+    fn same(&mut self, ty: &Value, lhs: &Value, rhs: &Value) -> Result {
+        match ty {
+            Value::Universe => self.same_type(lhs, rhs),
+            // eta: functions are the same when they give same results
+            Value::Pi { dom, cod } => {
+                let hint = match (lhs, rhs) {
+                    (Value::Lam(clo), _) | (_, Value::Lam(clo)) => clo.var(),
+                    _ => cod.var(),
+                };
+                self.under(&hint, dom, |conv, arg| {
+                    conv.same(
+                        &cod.apply(arg.clone()),
+                        &apply(lhs.clone(), arg.clone()),
+                        &apply(rhs.clone(), arg),
+                    )
+                })
+            }
+            // eta: pairs are the same when their parts are the same
+            Value::Sigma { car_ty, cdr_ty } => {
+                let (lhs_car, rhs_car) = (car(lhs), car(rhs));
+                self.same(car_ty, &lhs_car, &rhs_car)?;
+                self.same(&cdr_ty.apply(lhs_car), &cdr(lhs), &cdr(rhs))
+            }
+            // eta: everything is `sole', and nothing is absurd
+            Value::Trivial | Value::Absurd => Ok(()),
+            _ => match (lhs, rhs) {
+                (Value::Quote(lhs_atom), Value::Quote(rhs_atom)) if lhs_atom == rhs_atom => Ok(()),
+                (Value::Zero, Value::Zero) => Ok(()),
+                // peel off the `add1's both of them have
+                (Value::Add1(lhs_count, lhs), Value::Add1(rhs_count, rhs)) => {
+                    let common = lhs_count.min(rhs_count);
+                    self.same(
+                        ty,
+                        &add1s(lhs_count - common, lhs.as_ref().clone()),
+                        &add1s(rhs_count - common, rhs.as_ref().clone()),
+                    )
+                }
+                (Value::Nil, Value::Nil) | (Value::VecNil, Value::VecNil) => Ok(()),
+                (Value::ListCons(lhs_head, lhs_tail), Value::ListCons(rhs_head, rhs_tail)) => {
+                    let Value::List(elem) = ty else {
+                        unreachable!("list is a list")
+                    };
+                    self.same(elem, lhs_head, rhs_head)?;
+                    self.same(ty, lhs_tail, rhs_tail)
+                }
+                (Value::Same(lhs), Value::Same(rhs)) => {
+                    let Value::Equal { ty, .. } = ty else {
+                        unreachable!("equality is an equality")
+                    };
+                    self.same(ty, lhs, rhs)
+                }
+                (Value::Left(lhs), Value::Left(rhs)) => {
+                    let Value::Either(left, _) = ty else {
+                        unreachable!("either is an either")
+                    };
+                    self.same(left, lhs, rhs)
+                }
+                (Value::Right(lhs), Value::Right(rhs)) => {
+                    let Value::Either(_, right) = ty else {
+                        unreachable!("either is an either")
+                    };
+                    self.same(right, lhs, rhs)
+                }
+                (Value::VecCons(lhs_head, lhs_tail), Value::VecCons(rhs_head, rhs_tail)) => {
+                    let Value::Vec(elem, len) = ty else {
+                        unreachable!("vector is a vector")
+                    };
+                    let Some(len) = sub1(len) else {
+                        unreachable!("non-empty vector has non-zero length")
+                    };
+                    self.same(elem, lhs_head, rhs_head)?;
+                    self.same(&Value::Vec(elem.clone(), Box::new(len)), lhs_tail, rhs_tail)
+                }
+                (Value::Neutral { neu: lhs, .. }, Value::Neutral { neu: rhs, .. }) => {
+                    self.same_neutral(lhs, rhs)
+                }
+                (lhs, rhs) => self.differ(ty, lhs, rhs),
+            },
+        }
+    }
+
+    fn same_neutral(&mut self, lhs: &Neutral, rhs: &Neutral) -> Result {
+        match (lhs, rhs) {
+            (Neutral::Var(lhs_var), Neutral::Var(rhs_var)) if lhs_var == rhs_var => Ok(()),
+            (Neutral::App(lhs_fun, lhs_arg), Neutral::App(rhs_fun, rhs_arg)) => {
+                self.same_neutral(lhs_fun, rhs_fun)?;
+                self.same_normal(lhs_arg, rhs_arg)
+            }
+            (Neutral::Car(lhs), Neutral::Car(rhs))
+            | (Neutral::Cdr(lhs), Neutral::Cdr(rhs))
+            | (Neutral::Head(lhs), Neutral::Head(rhs))
+            | (Neutral::Tail(lhs), Neutral::Tail(rhs))
+            | (Neutral::Symm(lhs), Neutral::Symm(rhs)) => self.same_neutral(lhs, rhs),
+            (Neutral::Cong(lhs_target, lhs_fun), Neutral::Cong(rhs_target, rhs_fun)) => {
+                self.same_neutral(lhs_target, rhs_target)?;
+                self.same_type(&lhs_fun.ty, &rhs_fun.ty)?;
+                self.same_normal(lhs_fun, rhs_fun)
+            }
+            (
+                Neutral::Replace(lhs_target, lhs_motive, lhs_base),
+                Neutral::Replace(rhs_target, rhs_motive, rhs_base),
+            )
+            | (
+                Neutral::IndEq(lhs_target, lhs_motive, lhs_base),
+                Neutral::IndEq(rhs_target, rhs_motive, rhs_base),
+            ) => {
+                self.same_neutral(lhs_target, rhs_target)?;
+                self.same_normal(lhs_motive, rhs_motive)?;
+                self.same_normal(lhs_base, rhs_base)
+            }
+            (Neutral::Trans(lhs_left, lhs_right), Neutral::Trans(rhs_left, rhs_right)) => {
+                self.same_normal(lhs_left, rhs_left)?;
+                self.same_normal(lhs_right, rhs_right)
+            }
+            (
+                Neutral::IndVec(lhs_len, lhs_target, lhs_motive, lhs_base, lhs_step),
+                Neutral::IndVec(rhs_len, rhs_target, rhs_motive, rhs_base, rhs_step),
+            ) => {
+                self.same_normal(lhs_len, rhs_len)?;
+                self.same_neutral(lhs_target, rhs_target)?;
+                self.same_normal(lhs_motive, rhs_motive)?;
+                self.same_normal(lhs_base, rhs_base)?;
+                self.same_normal(lhs_step, rhs_step)
+            }
+            (
+                Neutral::WhichNat(lhs_target, lhs_base, lhs_step),
+                Neutral::WhichNat(rhs_target, rhs_base, rhs_step),
+            )
+            | (
+                Neutral::IterNat(lhs_target, lhs_base, lhs_step),
+                Neutral::IterNat(rhs_target, rhs_base, rhs_step),
+            )
+            | (
+                Neutral::RecNat(lhs_target, lhs_base, lhs_step),
+                Neutral::RecNat(rhs_target, rhs_base, rhs_step),
+            )
+            | (
+                Neutral::RecList(lhs_target, lhs_base, lhs_step),
+                Neutral::RecList(rhs_target, rhs_base, rhs_step),
+            ) => {
+                self.same_neutral(lhs_target, rhs_target)?;
+                self.same_type(&lhs_base.ty, &rhs_base.ty)?;
+                self.same_normal(lhs_base, rhs_base)?;
+                self.same_normal(lhs_step, rhs_step)
+            }
+            (
+                Neutral::IndNat(lhs_target, lhs_motive, lhs_base, lhs_step),
+                Neutral::IndNat(rhs_target, rhs_motive, rhs_base, rhs_step),
+            )
+            | (
+                Neutral::IndList(lhs_target, lhs_motive, lhs_base, lhs_step),
+                Neutral::IndList(rhs_target, rhs_motive, rhs_base, rhs_step),
+            )
+            | (
+                Neutral::IndEither(lhs_target, lhs_motive, lhs_base, lhs_step),
+                Neutral::IndEither(rhs_target, rhs_motive, rhs_base, rhs_step),
+            ) => {
+                self.same_neutral(lhs_target, rhs_target)?;
+                self.same_normal(lhs_motive, rhs_motive)?;
+                self.same_normal(lhs_base, rhs_base)?;
+                self.same_normal(lhs_step, rhs_step)
+            }
+            // all proofs of `Absurd' are the same, so only motives matter
+            (Neutral::IndAbsurd(_, lhs_motive), Neutral::IndAbsurd(_, rhs_motive)) => {
+                self.same_normal(lhs_motive, rhs_motive)
+            }
+            // holes can't be told apart, other than by their types
+            (Neutral::Todo(lhs_ty), Neutral::Todo(rhs_ty)) => self.same_type(lhs_ty, rhs_ty),
+            (lhs, rhs) => Err(Difference {
+                lhs: self.read_back_neutral(lhs),
+                rhs: self.read_back_neutral(rhs),
+            }),
+        }
+    }
+
+    // arguments of eliminators, at the type of the left one
+    fn same_normal(&mut self, lhs: &Normal, rhs: &Normal) -> Result {
+        self.same(&lhs.ty, &lhs.val, &rhs.val)
+    }
+
+    fn read_back_neutral(&self, neu: &Neutral) -> Expression {
+        // the type doesn't matter for reading back neutrals, unless
+        // it's one of the eta-expanded ones
+        let val = Value::Neutral {
+            ty: Box::new(Value::Atom),
+            neu: neu.clone(),
+        };
+        read_back(&self.0, &Value::Atom, &val)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::check::{check, Context};
+    use crate::eval::{eval, Env};
+    use crate::parser::parse;
+    use crate::{desugar, elab, term};
+
+    // type and value of `text' of type `ty' as a function of `vars', so
+    // that they are neutral in it
+    fn closed(vars: &[(&str, &str)], ty: &str, text: &str) -> (Value, Value) {
+        let binders: Vec<String> = (vars.iter())
+            .map(|(name, ty)| format!("({name} {ty})"))
+            .collect();
+        let names: Vec<&str> = vars.iter().map(|(name, _)| *name).collect();
+        let text = match vars {
+            [] => format!("(claim it {ty}) (define it {text}) it"),
+            _ => format!(
+                "(claim it (Π ({}) {ty})) (define it (λ ({}) {text})) it",
+                binders.join(" "),
+                names.join(" ")
+            ),
+        };
+        let (mut source, errors) = parse(&text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        assert!(desugar::desugar(&mut source).is_empty());
+        let (defs, problems) = elab::definitions(&source);
+        assert!(problems.is_empty(), "bad definitions: {problems:?}");
+        let mut ctx = Context::default();
+        let checked = check(&mut ctx, &source.statements, &defs);
+        let normal = checked.into_iter().last().unwrap().normal.unwrap();
+        let normal = normal.unwrap();
+        let env = Env::default();
+        (
+            eval(&env, &term::lower(&normal.ty)),
+            eval(&env, &term::lower(&normal.expr)),
+        )
+    }
+
+    // are `lhs' and `rhs' the same `ty' when `vars' are in scope?  If
+    // not, which of their parts differ
+    fn compare(
+        vars: &[(&str, &str)],
+        ty: &str,
+        lhs: &str,
+        rhs: &str,
+    ) -> std::result::Result<(), (String, String)> {
+        let (ty_val, lhs) = closed(vars, ty, lhs);
+        let (_, rhs) = closed(vars, ty, rhs);
+        same(&[], &ty_val, &lhs, &rhs).map_err(|diff| (diff.lhs.to_string(), diff.rhs.to_string()))
+    }
+
+    fn differs(lhs: &str, rhs: &str) -> std::result::Result<(), (String, String)> {
+        Err((lhs.to_string(), rhs.to_string()))
+    }
+
+    #[test]
+    fn test_alpha() {
+        assert!(compare(&[], "(→ Atom Atom)", "(λ (x) x)", "(λ (y) y)").is_ok());
+        assert!(compare(&[], "U", "(Π ((a Atom)) Atom)", "(→ Atom Atom)").is_ok());
+        assert!(compare(
+            &[],
+            "U",
+            "(Π ((n Nat)) (Pair Atom Nat))",
+            "(Π ((k Nat)) (Pair Atom Nat))"
+        )
+        .is_ok());
+    }
+
+    #[test]
+    fn test_eta_function() {
+        let vars = [("f", "(→ Atom Atom)")];
+        assert!(compare(&vars, "(→ Atom Atom)", "f", "(λ (x) (f x))").is_ok());
+        assert_eq!(
+            compare(&vars, "(→ Atom Atom)", "f", "(λ (x) x)"),
+            differs("(f x)", "x")
+        );
+    }
+
+    #[test]
+    fn test_eta_pair() {
+        let vars = [("p", "(Pair Atom Nat)")];
+        assert!(compare(&vars, "(Pair Atom Nat)", "p", "(cons (car p) (cdr p))").is_ok());
+        assert_eq!(
+            compare(&vars, "(Pair Atom Nat)", "p", "(cons (car p) 0)"),
+            differs("(cdr p)", "0")
+        );
+    }
+
+    #[test]
+    fn test_eta_trivial() {
+        let vars = [("t", "Trivial")];
+        assert!(compare(&vars, "Trivial", "t", "sole").is_ok());
+    }
+
+    #[test]
+    fn test_eta_absurd() {
+        let vars = [("a", "Absurd"), ("b", "Absurd")];
+        assert!(compare(&vars, "Absurd", "a", "b").is_ok());
+    }
+
+    #[test]
+    fn test_difference() {
+        let ty = "(Pair Atom (Pair Atom Atom))";
+        assert_eq!(
+            compare(&[], ty, "(cons 'a (cons 'b 'c))", "(cons 'a (cons 'd 'c))"),
+            differs("'b", "'d")
+        );
+        assert_eq!(
+            compare(
+                &[],
+                "U",
+                "(→ Atom (Pair Nat Atom))",
+                "(→ Atom (Pair Nat Nat))"
+            ),
+            differs("Atom", "Nat")
+        );
+    }
+
+    #[test]
+    fn test_difference_neutral() {
+        let vars = [("n", "Nat"), ("f", "(→ Nat Nat)")];
+        assert_eq!(
+            compare(&vars, "Nat", "(f (add1 n))", "(f n)"),
+            differs("(add1 n)", "n")
+        );
+    }
+}
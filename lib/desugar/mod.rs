@@ -0,0 +1,434 @@
+use crate::syntax::{Apply, Expression, Lambda, Source, Statement, TypedBinder};
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+////////////////////////////////////////////////
+// Errors
+#[derive(Debug, Diagnostic, Error)]
+#[error("Function without arguments")]
+#[diagnostic(help("Pie has no functions of zero arguments, a `λ' needs at least one"))]
+pub struct NoArguments {
+    #[label("this")]
+    pub loc: SourceSpan,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Application without arguments")]
+#[diagnostic(help(
+    "Pie has no functions of zero arguments, drop the parentheses around `{}'",
+    self.fun
+))]
+pub struct NothingApplied {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub fun: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+pub enum DesugarError {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    NoArguments(#[from] NoArguments),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    NothingApplied(#[from] NothingApplied),
+}
+
+////////////////////////////////////////////////
+// Desugaring
+/// Curry all the functions in `source': `(λ (x y) b)' becomes
+/// `(λ (x) (λ (y) b))' and `(f a b)' becomes `((f a) b)', every part
+/// keeping the span of the original.  Lambdas and applications without
+/// arguments are reported and replaced with their body or function.
+pub fn desugar(source: &mut Source<SourceSpan>) -> Vec<DesugarError> {
+    let mut problems = vec![];
+    source.statements = std::mem::take(&mut source.statements)
+        .into_iter()
+        .map(|stmt| statement(&mut problems, stmt))
+        .collect();
+    problems
+}
+
+fn statement(
+    problems: &mut Vec<DesugarError>,
+    stmt: Statement<SourceSpan>,
+) -> Statement<SourceSpan> {
+    match stmt {
+        Statement::Claim(mut claim) => {
+            claim.expr = boxed(problems, claim.expr);
+            Statement::Claim(claim)
+        }
+        Statement::Def(mut def) => {
+            def.body = boxed(problems, def.body);
+            Statement::Def(def)
+        }
+        Statement::CheckSame(mut check) => {
+            check.ty = boxed(problems, check.ty);
+            check.left = boxed(problems, check.left);
+            check.right = boxed(problems, check.right);
+            Statement::CheckSame(check)
+        }
+        Statement::Expr(expr) => Statement::Expr(expression(problems, expr)),
+    }
+}
+
+// `(λ (x y ...) body)', as nested lambdas of one argument
+fn curry_lambda(Lambda { ann, args, body }: Lambda<SourceSpan>) -> Expression<SourceSpan> {
+    args.into_iter().rev().fold(*body, |body, arg| {
+        Expression::Abs(Lambda {
+            ann,
+            args: vec![arg],
+            body: Box::new(body),
+        })
+    })
+}
+
+// `(f a b ...)', as nested applications to one argument
+fn curry_apply(Apply { ann, fun, args }: Apply<SourceSpan>) -> Expression<SourceSpan> {
+    args.into_iter().fold(*fun, |fun, arg| {
+        Expression::App(Apply {
+            ann,
+            fun: Box::new(fun),
+            args: vec![arg],
+        })
+    })
+}
+
+// desugar `expr' where it is, keeping its allocation
+fn boxed(
+    problems: &mut Vec<DesugarError>,
+    mut expr: Box<Expression<SourceSpan>>,
+) -> Box<Expression<SourceSpan>> {
+    *expr = expression(problems, *expr);
+    expr
+}
+
+fn telescope(
+    problems: &mut Vec<DesugarError>,
+    binders: Vec<TypedBinder<SourceSpan>>,
+) -> Vec<TypedBinder<SourceSpan>> {
+    binders
+        .into_iter()
+        .map(|mut binder| {
+            binder.ty = boxed(problems, binder.ty);
+            binder
+        })
+        .collect()
+}
+
+fn expression(
+    problems: &mut Vec<DesugarError>,
+    expr: Expression<SourceSpan>,
+) -> Expression<SourceSpan> {
+    match expr {
+        Expression::Atom(_)
+        | Expression::Ref(_)
+        | Expression::Ty(_)
+        | Expression::Universe(_)
+        | Expression::Zero(_)
+        | Expression::Numeral(_)
+        | Expression::Nil(_)
+        | Expression::VecNil(_)
+        | Expression::Sole(_)
+        | Expression::Todo(_) => expr,
+        Expression::Abs(Lambda { ann, args, body }) => {
+            let body = boxed(problems, body);
+            if args.is_empty() {
+                problems.push(DesugarError::from(NoArguments { loc: ann }));
+            }
+            curry_lambda(Lambda { ann, args, body })
+        }
+        Expression::App(Apply { ann, fun, args }) => {
+            let fun = boxed(problems, fun);
+            let args: Vec<_> = args.into_iter().map(|arg| boxed(problems, arg)).collect();
+            if args.is_empty() {
+                problems.push(DesugarError::from(NothingApplied {
+                    loc: ann,
+                    fun: fun.to_string(),
+                }));
+            }
+            curry_apply(Apply { ann, fun, args })
+        }
+        Expression::Arrow(mut arrow) => {
+            arrow.doms = arrow
+                .doms
+                .into_iter()
+                .map(|dom| boxed(problems, dom))
+                .collect();
+            arrow.cod = boxed(problems, arrow.cod);
+            Expression::Arrow(arrow)
+        }
+        Expression::Pi(mut pi) => {
+            pi.binders = telescope(problems, pi.binders);
+            pi.cod = boxed(problems, pi.cod);
+            Expression::Pi(pi)
+        }
+        Expression::Sigma(mut sigma) => {
+            sigma.binders = telescope(problems, sigma.binders);
+            sigma.cdr_ty = boxed(problems, sigma.cdr_ty);
+            Expression::Sigma(sigma)
+        }
+        Expression::Add1(mut add1) => {
+            add1.n = boxed(problems, add1.n);
+            Expression::Add1(add1)
+        }
+        Expression::Car(mut car) => {
+            car.pair = boxed(problems, car.pair);
+            Expression::Car(car)
+        }
+        Expression::Cdr(mut cdr) => {
+            cdr.pair = boxed(problems, cdr.pair);
+            Expression::Cdr(cdr)
+        }
+        Expression::List(mut list) => {
+            list.elem = boxed(problems, list.elem);
+            Expression::List(list)
+        }
+        Expression::Head(mut head) => {
+            head.vec = boxed(problems, head.vec);
+            Expression::Head(head)
+        }
+        Expression::Tail(mut tail) => {
+            tail.vec = boxed(problems, tail.vec);
+            Expression::Tail(tail)
+        }
+        Expression::Same(mut same) => {
+            same.expr = boxed(problems, same.expr);
+            Expression::Same(same)
+        }
+        Expression::Symm(mut symm) => {
+            symm.target = boxed(problems, symm.target);
+            Expression::Symm(symm)
+        }
+        Expression::Left(mut left) => {
+            left.expr = boxed(problems, left.expr);
+            Expression::Left(left)
+        }
+        Expression::Right(mut right) => {
+            right.expr = boxed(problems, right.expr);
+            Expression::Right(right)
+        }
+        Expression::Pair(mut pair) => {
+            pair.car_ty = boxed(problems, pair.car_ty);
+            pair.cdr_ty = boxed(problems, pair.cdr_ty);
+            Expression::Pair(pair)
+        }
+        Expression::Cons(mut cons) => {
+            cons.car = boxed(problems, cons.car);
+            cons.cdr = boxed(problems, cons.cdr);
+            Expression::Cons(cons)
+        }
+        Expression::The(mut the) => {
+            the.ty = boxed(problems, the.ty);
+            the.expr = boxed(problems, the.expr);
+            Expression::The(the)
+        }
+        Expression::ListCons(mut list_cons) => {
+            list_cons.head = boxed(problems, list_cons.head);
+            list_cons.tail = boxed(problems, list_cons.tail);
+            Expression::ListCons(list_cons)
+        }
+        Expression::VecCons(mut vec_cons) => {
+            vec_cons.head = boxed(problems, vec_cons.head);
+            vec_cons.tail = boxed(problems, vec_cons.tail);
+            Expression::VecCons(vec_cons)
+        }
+        Expression::Vector(mut vector) => {
+            vector.elem = boxed(problems, vector.elem);
+            vector.len = boxed(problems, vector.len);
+            Expression::Vector(vector)
+        }
+        Expression::Cong(mut cong) => {
+            cong.target = boxed(problems, cong.target);
+            cong.fun = boxed(problems, cong.fun);
+            Expression::Cong(cong)
+        }
+        Expression::Trans(mut trans) => {
+            trans.left = boxed(problems, trans.left);
+            trans.right = boxed(problems, trans.right);
+            Expression::Trans(trans)
+        }
+        Expression::Either(mut either) => {
+            either.left = boxed(problems, either.left);
+            either.right = boxed(problems, either.right);
+            Expression::Either(either)
+        }
+        Expression::IndAbsurd(mut ind_absurd) => {
+            ind_absurd.target = boxed(problems, ind_absurd.target);
+            ind_absurd.motive = boxed(problems, ind_absurd.motive);
+            Expression::IndAbsurd(ind_absurd)
+        }
+        Expression::WhichNat(mut which_nat) => {
+            which_nat.target = boxed(problems, which_nat.target);
+            which_nat.base = boxed(problems, which_nat.base);
+            which_nat.step = boxed(problems, which_nat.step);
+            Expression::WhichNat(which_nat)
+        }
+        Expression::IterNat(mut iter_nat) => {
+            iter_nat.target = boxed(problems, iter_nat.target);
+            iter_nat.base = boxed(problems, iter_nat.base);
+            iter_nat.step = boxed(problems, iter_nat.step);
+            Expression::IterNat(iter_nat)
+        }
+        Expression::RecNat(mut rec_nat) => {
+            rec_nat.target = boxed(problems, rec_nat.target);
+            rec_nat.base = boxed(problems, rec_nat.base);
+            rec_nat.step = boxed(problems, rec_nat.step);
+            Expression::RecNat(rec_nat)
+        }
+        Expression::RecList(mut rec_list) => {
+            rec_list.target = boxed(problems, rec_list.target);
+            rec_list.base = boxed(problems, rec_list.base);
+            rec_list.step = boxed(problems, rec_list.step);
+            Expression::RecList(rec_list)
+        }
+        Expression::Replace(mut replace) => {
+            replace.target = boxed(problems, replace.target);
+            replace.motive = boxed(problems, replace.motive);
+            replace.base = boxed(problems, replace.base);
+            Expression::Replace(replace)
+        }
+        Expression::IndEq(mut ind_eq) => {
+            ind_eq.target = boxed(problems, ind_eq.target);
+            ind_eq.motive = boxed(problems, ind_eq.motive);
+            ind_eq.base = boxed(problems, ind_eq.base);
+            Expression::IndEq(ind_eq)
+        }
+        Expression::Equal(mut equal) => {
+            equal.ty = boxed(problems, equal.ty);
+            equal.from = boxed(problems, equal.from);
+            equal.to = boxed(problems, equal.to);
+            Expression::Equal(equal)
+        }
+        Expression::IndNat(mut ind_nat) => {
+            ind_nat.target = boxed(problems, ind_nat.target);
+            ind_nat.motive = boxed(problems, ind_nat.motive);
+            ind_nat.base = boxed(problems, ind_nat.base);
+            ind_nat.step = boxed(problems, ind_nat.step);
+            Expression::IndNat(ind_nat)
+        }
+        Expression::IndList(mut ind_list) => {
+            ind_list.target = boxed(problems, ind_list.target);
+            ind_list.motive = boxed(problems, ind_list.motive);
+            ind_list.base = boxed(problems, ind_list.base);
+            ind_list.step = boxed(problems, ind_list.step);
+            Expression::IndList(ind_list)
+        }
+        Expression::IndEither(mut ind_either) => {
+            ind_either.target = boxed(problems, ind_either.target);
+            ind_either.motive = boxed(problems, ind_either.motive);
+            ind_either.base_left = boxed(problems, ind_either.base_left);
+            ind_either.base_right = boxed(problems, ind_either.base_right);
+            Expression::IndEither(ind_either)
+        }
+        Expression::IndVec(mut ind_vec) => {
+            ind_vec.len = boxed(problems, ind_vec.len);
+            ind_vec.target = boxed(problems, ind_vec.target);
+            ind_vec.motive = boxed(problems, ind_vec.motive);
+            ind_vec.base = boxed(problems, ind_vec.base);
+            ind_vec.step = boxed(problems, ind_vec.step);
+            Expression::IndVec(ind_vec)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use crate::syntax::{Binder, Variable};
+
+    // `text' with its functions curried, and the problems found
+    fn desugared(text: &str) -> (Expression<SourceSpan>, Vec<DesugarError>) {
+        let (mut source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        let problems = desugar(&mut source);
+        match source.statements.pop() {
+            Some(Statement::Expr(expr)) => (expr, problems),
+            _ => panic!("expected a single expression"),
+        }
+    }
+
+    fn span(offset: usize, len: usize) -> SourceSpan {
+        SourceSpan::from((offset, len))
+    }
+
+    #[test]
+    fn test_curry_lambda() {
+        let text = "(λ (x y) b)";
+        let (expr, problems) = desugared(text);
+        assert!(problems.is_empty());
+        let whole = span(0, text.len());
+        match expr {
+            Expression::Abs(Lambda { ann, args, body }) => {
+                assert_eq!(ann, whole);
+                assert!(matches!(&args[..], [Binder { ident, ann }]
+                                 if ident.as_str() == "x" && *ann == span(5, 1)));
+                match *body {
+                    Expression::Abs(Lambda { ann, args, body }) => {
+                        assert_eq!(ann, whole);
+                        assert!(matches!(&args[..], [Binder { ident, ann }]
+                                         if ident.as_str() == "y" && *ann == span(7, 1)));
+                        assert!(matches!(*body, Expression::Ref(Variable { ann, .. })
+                                         if ann == span(10, 1)));
+                    }
+                    body => panic!("expected an inner λ, got {body}"),
+                }
+            }
+            expr => panic!("expected a λ, got {expr}"),
+        }
+    }
+
+    #[test]
+    fn test_curry_apply() {
+        let text = "(f a b)";
+        let (expr, problems) = desugared(text);
+        assert!(problems.is_empty());
+        let whole = span(0, text.len());
+        match expr {
+            Expression::App(Apply { ann, fun, args }) => {
+                assert_eq!(ann, whole);
+                assert!(matches!(&args[..], [arg] if *arg.ann() == span(5, 1)));
+                match *fun {
+                    Expression::App(Apply { ann, fun, args }) => {
+                        assert_eq!(ann, whole);
+                        assert_eq!(*fun.ann(), span(1, 1));
+                        assert!(matches!(&args[..], [arg] if *arg.ann() == span(3, 1)));
+                    }
+                    fun => panic!("expected an inner application, got {fun}"),
+                }
+            }
+            expr => panic!("expected an application, got {expr}"),
+        }
+    }
+
+    #[test]
+    fn test_curry_nested() {
+        let (expr, problems) = desugared("(the (→ Atom Atom Atom) (λ (x y) ((λ (a b) a) x y)))");
+        assert!(problems.is_empty());
+        let (expected, _) =
+            desugared("(the (→ Atom Atom Atom) (λ (x) (λ (y) (((λ (a) (λ (b) a)) x) y))))");
+        assert!(crate::term::alpha_equivalent(&expr, &expected));
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        let text = "(λ () 'a)";
+        let (expr, problems) = desugared(text);
+        assert!(matches!(expr, Expression::Atom(_)));
+        assert!(
+            matches!(&problems[..], [DesugarError::NoArguments(NoArguments { loc })]
+                         if *loc == span(0, text.len()))
+        );
+    }
+
+    #[test]
+    fn test_nothing_applied() {
+        let (expr, problems) = desugared("(cons (f) 'a)");
+        assert!(matches!(expr, Expression::Cons(_)));
+        assert!(
+            matches!(&problems[..], [DesugarError::NothingApplied(NothingApplied { loc, fun })]
+                         if *loc == span(6, 3) && fun == "f")
+        );
+    }
+}
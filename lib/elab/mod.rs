@@ -0,0 +1,302 @@
+use crate::syntax::{Claim, Define, Definition, Expression, Identifier, Source, Statement};
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+////////////////////////////////////////////////
+// Errors
+#[derive(Debug, Diagnostic, Error)]
+#[error("Missing claim")]
+#[diagnostic(help("`{}' has to be claimed before it is defined", self.ident))]
+pub struct Unclaimed {
+    #[label("defined here")]
+    pub loc: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Defined before claimed")]
+#[diagnostic(help("move the claim of `{}' above its definition", self.ident))]
+pub struct DefinedBeforeClaim {
+    #[label("defined here")]
+    pub loc: SourceSpan,
+    #[label("but claimed only here")]
+    pub claim: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Already claimed")]
+#[diagnostic(help("`{}' can be claimed only once", self.ident))]
+pub struct ClaimedTwice {
+    #[label("claimed again here")]
+    pub loc: SourceSpan,
+    #[label("first claimed here")]
+    pub first: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Already defined")]
+#[diagnostic(help("`{}' can be defined only once", self.ident))]
+pub struct DefinedTwice {
+    #[label("defined again here")]
+    pub loc: SourceSpan,
+    #[label("first defined here")]
+    pub first: SourceSpan,
+    pub ident: Identifier,
+}
+
+/// Only a warning: a claim on its own is harmless, since nothing can
+/// refer to a name before it is defined, and a file with unfinished
+/// claims is still worth checking.  The REPL drops it entirely, the
+/// definition may come on a later line.
+#[derive(Debug, Diagnostic, Error)]
+#[error("Missing definition")]
+#[diagnostic(
+    severity(Warning),
+    help("`{}' is claimed, but it is never defined", self.ident)
+)]
+pub struct Undefined {
+    #[label("claimed here")]
+    pub loc: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+pub enum DefinitionError {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Unclaimed(#[from] Unclaimed),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    DefinedBeforeClaim(#[from] DefinedBeforeClaim),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    ClaimedTwice(#[from] ClaimedTwice),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    DefinedTwice(#[from] DefinedTwice),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Undefined(#[from] Undefined),
+}
+
+////////////////////////////////////////////////
+// Definitions
+// claim waiting for its definition
+struct Pending<'a> {
+    ident: &'a Identifier,
+    loc: SourceSpan,
+    ty: &'a Expression<SourceSpan>,
+    defined: Option<SourceSpan>,
+}
+
+/// Pair every claim in `source' with its definition, in order of the
+/// definitions.  All the problems found on the way are reported too,
+/// not only the first one.
+pub fn definitions(
+    source: &Source<SourceSpan>,
+) -> (Vec<Definition<SourceSpan>>, Vec<DefinitionError>) {
+    let mut claims: Vec<Pending> = vec![];
+    let mut defs = vec![];
+    let mut errors = vec![];
+    // names already reported as defined before their claims
+    let mut early: Vec<&Identifier> = vec![];
+
+    for (n, stmt) in source.statements.iter().enumerate() {
+        match stmt {
+            Statement::Claim(Claim { ann, ident, expr }) => {
+                match claims.iter().find(|claim| claim.ident == ident) {
+                    Some(first) => errors.push(DefinitionError::from(ClaimedTwice {
+                        loc: *ann,
+                        first: first.loc,
+                        ident: *ident,
+                    })),
+                    None => claims.push(Pending {
+                        ident,
+                        loc: *ann,
+                        ty: expr,
+                        defined: None,
+                    }),
+                }
+            }
+            Statement::Def(Define { ann, ident, body }) => {
+                match claims.iter_mut().find(|claim| claim.ident == ident) {
+                    Some(Pending {
+                        defined: Some(first),
+                        ..
+                    }) => errors.push(DefinitionError::from(DefinedTwice {
+                        loc: *ann,
+                        first: *first,
+                        ident: *ident,
+                    })),
+                    Some(claim) => {
+                        claim.defined = Some(*ann);
+                        defs.push(Definition {
+                            ident: *ident,
+                            claim: (claim.loc, Box::new(claim.ty.clone())),
+                            body: (*ann, body.clone()),
+                        });
+                    }
+                    None => match later_claim(&source.statements[n + 1..], ident) {
+                        Some(claim) => {
+                            early.push(ident);
+                            errors.push(DefinitionError::from(DefinedBeforeClaim {
+                                loc: *ann,
+                                claim,
+                                ident: *ident,
+                            }))
+                        }
+                        None => errors.push(DefinitionError::from(Unclaimed {
+                            loc: *ann,
+                            ident: *ident,
+                        })),
+                    },
+                }
+            }
+            Statement::CheckSame(_) | Statement::Expr(_) => (),
+        }
+    }
+
+    errors.extend(
+        claims
+            .iter()
+            .filter(|claim| claim.defined.is_none() && !early.contains(&claim.ident))
+            .map(|claim| {
+                DefinitionError::from(Undefined {
+                    loc: claim.loc,
+                    ident: *claim.ident,
+                })
+            }),
+    );
+
+    (defs, errors)
+}
+
+fn later_claim(statements: &[Statement<SourceSpan>], ident: &Identifier) -> Option<SourceSpan> {
+    statements.iter().find_map(|stmt| match stmt {
+        Statement::Claim(Claim {
+            ann, ident: name, ..
+        }) if name == ident => Some(*ann),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use miette::Severity;
+
+    // problem and its labels, with the text they point at
+    type Labelled<'a> = (DefinitionError, Vec<(String, &'a str)>);
+
+    // names of the definitions and the problems found
+    fn elaborate(text: &str) -> (Vec<String>, Vec<Labelled<'_>>) {
+        let (source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        let (defs, problems) = definitions(&source);
+        let defs = defs.iter().map(|def| def.ident.to_string()).collect();
+        let problems = problems
+            .into_iter()
+            .map(|problem| {
+                let labels = problem
+                    .labels()
+                    .into_iter()
+                    .flatten()
+                    .map(|label| {
+                        let at = &text[label.offset()..label.offset() + label.len()];
+                        (label.label().unwrap_or_default().to_string(), at)
+                    })
+                    .collect();
+                (problem, labels)
+            })
+            .collect();
+        (defs, problems)
+    }
+
+    fn label(text: &str, at: &'static str) -> (String, &'static str) {
+        (text.to_string(), at)
+    }
+
+    #[test]
+    fn test_definitions() {
+        let (defs, problems) =
+            elaborate("(claim a Atom)\n(claim n Nat)\n(define n 1)\n(define a 'a)");
+        assert_eq!(defs, ["n", "a"]);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_unclaimed() {
+        let (defs, problems) = elaborate("(define a 'a)");
+        assert!(defs.is_empty());
+        match &problems[..] {
+            [(DefinitionError::Unclaimed(_), labels)] => {
+                assert_eq!(labels, &[label("defined here", "(define a 'a)")])
+            }
+            _ => panic!("expected a missing claim, got {problems:?}"),
+        }
+    }
+
+    #[test]
+    fn test_defined_before_claim() {
+        let (defs, problems) = elaborate("(define a 'a)\n(claim a Atom)");
+        assert!(defs.is_empty());
+        match &problems[..] {
+            [(DefinitionError::DefinedBeforeClaim(_), labels)] => assert_eq!(
+                labels,
+                &[
+                    label("defined here", "(define a 'a)"),
+                    label("but claimed only here", "(claim a Atom)"),
+                ]
+            ),
+            _ => panic!("expected only a late claim, got {problems:?}"),
+        }
+    }
+
+    #[test]
+    fn test_claimed_twice() {
+        let (defs, problems) = elaborate("(claim a Atom)\n(claim a Nat)\n(define a 'a)");
+        assert_eq!(defs, ["a"]);
+        match &problems[..] {
+            [(DefinitionError::ClaimedTwice(_), labels)] => assert_eq!(
+                labels,
+                &[
+                    label("claimed again here", "(claim a Nat)"),
+                    label("first claimed here", "(claim a Atom)"),
+                ]
+            ),
+            _ => panic!("expected a second claim, got {problems:?}"),
+        }
+    }
+
+    #[test]
+    fn test_defined_twice() {
+        let (defs, problems) = elaborate("(claim a Atom)\n(define a 'a)\n(define a 'b)");
+        assert_eq!(defs, ["a"]);
+        match &problems[..] {
+            [(DefinitionError::DefinedTwice(_), labels)] => assert_eq!(
+                labels,
+                &[
+                    label("defined again here", "(define a 'b)"),
+                    label("first defined here", "(define a 'a)"),
+                ]
+            ),
+            _ => panic!("expected a second definition, got {problems:?}"),
+        }
+    }
+
+    #[test]
+    fn test_undefined() {
+        let (defs, problems) = elaborate("(claim a Atom)");
+        assert!(defs.is_empty());
+        match &problems[..] {
+            [(problem @ DefinitionError::Undefined(_), labels)] => {
+                assert_eq!(labels, &[label("claimed here", "(claim a Atom)")]);
+                assert_eq!(problem.severity(), Some(Severity::Warning));
+            }
+            _ => panic!("expected a missing definition, got {problems:?}"),
+        }
+    }
+}
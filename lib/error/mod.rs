@@ -1,3 +1,4 @@
+use crate::check::TypeError;
 use crate::parser::ts::ParseError;
 use miette::Diagnostic;
 use rustyline::error::ReadlineError;
@@ -20,6 +21,9 @@ pub enum PieError {
     Reading(#[from] ReadingError),
     #[error(transparent)]
     Frontend(#[from] ParseError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Typing(#[from] TypeError),
 }
 
 impl From<std::io::Error> for PieError {
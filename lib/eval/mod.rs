@@ -0,0 +1,1337 @@
+use crate::syntax::{
+    Add1, Apply, Arrow, Base, Equal, Expression, Identifier, Left, List, ListCons, Pi, Right, Type,
+    TypedBinder, Universe, Variable, VecCons, Vector,
+};
+use crate::term::{self, fresh, Hint, Term};
+use std::rc::Rc;
+
+////////////////////////////////////////////////
+// Type aliases
+// values of free variables, like definitions
+pub type Env = Stack<(Identifier, Value)>;
+
+////////////////////////////////////////////////
+// Environments
+/// Persistent stack, so that closures share the environment they are
+/// made in instead of copying it.
+#[derive(Debug)]
+pub struct Stack<T>(Option<Rc<(T, Stack<T>)>>);
+
+impl<T> Clone for Stack<T> {
+    fn clone(&self) -> Self {
+        Stack(self.0.clone())
+    }
+}
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Stack(None)
+    }
+}
+
+impl<T> Stack<T> {
+    pub fn push(&self, top: T) -> Stack<T> {
+        Stack(Some(Rc::new((top, self.clone()))))
+    }
+
+    /// Everything but the top.
+    pub fn pop(&self) -> Stack<T> {
+        self.0
+            .as_ref()
+            .map_or_else(Stack::default, |node| node.1.clone())
+    }
+
+    /// Elements from the top down.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        std::iter::successors(self.0.as_deref(), |(_, below)| below.0.as_deref())
+            .map(|(top, _)| top)
+    }
+}
+
+impl<T> FromIterator<T> for Stack<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(Stack::default(), |stack, top| stack.push(top))
+    }
+}
+
+////////////////////////////////////////////////
+// Semantic domain
+#[derive(Clone, Debug)]
+pub enum Value {
+    Universe,
+    Atom,
+    Quote(Identifier),
+    Pi {
+        dom: Box<Value>,
+        cod: Closure,
+    },
+    Lam(Closure),
+    Sigma {
+        car_ty: Box<Value>,
+        cdr_ty: Closure,
+    },
+    Cons(Box<Value>, Box<Value>),
+    Nat,
+    Zero,
+    // that many `add1's around `zero' or a neutral
+    Add1(usize, Box<Value>),
+    List(Box<Value>),
+    Nil,
+    ListCons(Box<Value>, Box<Value>),
+    // element type and length
+    Vec(Box<Value>, Box<Value>),
+    VecNil,
+    VecCons(Box<Value>, Box<Value>),
+    Equal {
+        ty: Box<Value>,
+        from: Box<Value>,
+        to: Box<Value>,
+    },
+    Same(Box<Value>),
+    Either(Box<Value>, Box<Value>),
+    Left(Box<Value>),
+    Right(Box<Value>),
+    Trivial,
+    Sole,
+    Absurd,
+    // stuck computation, together with its type
+    Neutral {
+        ty: Box<Value>,
+        neu: Neutral,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub enum Closure {
+    Term {
+        env: Env,
+        bound: Stack<Value>,
+        var: Identifier,
+        body: Rc<Term>,
+    },
+    // body ignoring its argument, like codomain of `(→ A B)'
+    Const(Box<Value>),
+}
+
+#[derive(Clone, Debug)]
+pub enum Neutral {
+    Var(Identifier),
+    App(Box<Neutral>, Box<Normal>),
+    Car(Box<Neutral>),
+    Cdr(Box<Neutral>),
+    // target, base and step; bases know their types
+    WhichNat(Box<Neutral>, Box<Normal>, Box<Normal>),
+    IterNat(Box<Neutral>, Box<Normal>, Box<Normal>),
+    RecNat(Box<Neutral>, Box<Normal>, Box<Normal>),
+    // target, motive, base and step
+    IndNat(Box<Neutral>, Box<Normal>, Box<Normal>, Box<Normal>),
+    RecList(Box<Neutral>, Box<Normal>, Box<Normal>),
+    IndList(Box<Neutral>, Box<Normal>, Box<Normal>, Box<Normal>),
+    Head(Box<Neutral>),
+    Tail(Box<Neutral>),
+    // length, target, motive, base and step
+    IndVec(
+        Box<Normal>,
+        Box<Neutral>,
+        Box<Normal>,
+        Box<Normal>,
+        Box<Normal>,
+    ),
+    // target and function
+    Cong(Box<Neutral>, Box<Normal>),
+    // target, motive and base
+    Replace(Box<Neutral>, Box<Normal>, Box<Normal>),
+    Symm(Box<Neutral>),
+    // either of them is stuck
+    Trans(Box<Normal>, Box<Normal>),
+    IndEq(Box<Neutral>, Box<Normal>, Box<Normal>),
+    // target, motive and both bases
+    IndEither(Box<Neutral>, Box<Normal>, Box<Normal>, Box<Normal>),
+    // target and motive
+    IndAbsurd(Box<Neutral>, Box<Normal>),
+    // unfinished part of the program, of the given type
+    Todo(Box<Value>),
+}
+
+#[derive(Clone, Debug)]
+pub struct Normal {
+    pub ty: Value,
+    pub val: Value,
+}
+
+impl Closure {
+    pub fn var(&self) -> Identifier {
+        match self {
+            Closure::Term { var, .. } => *var,
+            Closure::Const(_) => Identifier::new("x"),
+        }
+    }
+
+    pub fn apply(&self, arg: Value) -> Value {
+        match self {
+            Closure::Term {
+                env, bound, body, ..
+            } => eval_term(env, &bound.push(arg), body),
+            Closure::Const(val) => val.as_ref().clone(),
+        }
+    }
+}
+
+////////////////////////////////////////////////
+// Utils
+fn ref_(ident: Identifier) -> Expression {
+    Expression::Ref(Variable { ann: (), ident })
+}
+
+fn app(fun: Expression, args: Vec<Expression>) -> Expression {
+    Expression::App(Apply {
+        ann: (),
+        fun: Box::new(fun),
+        args: args.into_iter().map(Box::new).collect(),
+    })
+}
+
+fn type_(ty: Base) -> Expression {
+    Expression::Ty(Type { ann: (), ty })
+}
+
+fn universe() -> Expression {
+    Expression::Universe(Universe { ann: () })
+}
+
+// `(→ dom cod)'
+fn arrow(dom: Value, cod: Value) -> Value {
+    Value::Pi {
+        dom: Box::new(dom),
+        cod: Closure::Const(Box::new(cod)),
+    }
+}
+
+fn var(name: &str) -> Expression {
+    ref_(Identifier::new(name))
+}
+
+// `(Π ((x X) ...) cod)', for building types of eliminator arguments
+fn pi(binders: &[(&str, Expression)], cod: Expression) -> Expression {
+    Expression::Pi(Pi {
+        ann: (),
+        binders: binders
+            .iter()
+            .map(|(ident, ty)| TypedBinder {
+                ann: (),
+                ident: Identifier::new(ident),
+                ty: Box::new(ty.clone()),
+            })
+            .collect(),
+        cod: Box::new(cod),
+    })
+}
+
+fn arrow_(dom: Expression, cod: Expression) -> Expression {
+    Expression::Arrow(Arrow {
+        ann: (),
+        doms: vec![Box::new(dom)],
+        cod: Box::new(cod),
+    })
+}
+
+// evaluate type built from syntax, mentioning values bound in `env'
+fn eval_with(env: &[(&str, &Value)], ty: &Expression) -> Value {
+    let env = env
+        .iter()
+        .map(|(name, val)| (Identifier::new(name), (*val).clone()))
+        .collect();
+    eval(&env, &term::lower(ty))
+}
+
+/// Type of the step of `ind-Nat' with `motive', that is
+/// `(Π ((n-1 Nat)) (→ (motive n-1) (motive (add1 n-1))))'.
+pub fn ind_nat_step(motive: &Value) -> Value {
+    let add1 = Expression::Add1(Add1 {
+        ann: (),
+        n: Box::new(var("n-1")),
+    });
+    let ty = pi(
+        &[("n-1", type_(Base::Nat))],
+        arrow_(
+            app(var("motive"), vec![var("n-1")]),
+            app(var("motive"), vec![add1]),
+        ),
+    );
+    eval_with(&[("motive", motive)], &ty)
+}
+
+/// Type of the step of `ind-List' over `elem's with `motive', that is
+/// `(Π ((e E) (es (List E))) (→ (motive es) (motive (:: e es))))'.
+pub fn ind_list_step(elem: &Value, motive: &Value) -> Value {
+    let list = Expression::List(List {
+        ann: (),
+        elem: Box::new(var("E")),
+    });
+    let cons = Expression::ListCons(ListCons {
+        ann: (),
+        head: Box::new(var("e")),
+        tail: Box::new(var("es")),
+    });
+    let ty = pi(
+        &[("e", var("E")), ("es", list)],
+        arrow_(
+            app(var("motive"), vec![var("es")]),
+            app(var("motive"), vec![cons]),
+        ),
+    );
+    eval_with(&[("E", elem), ("motive", motive)], &ty)
+}
+
+/// Type of the motive of `ind-Vec' over `elem's, that is
+/// `(Π ((k Nat)) (→ (Vec E k) U))'.
+pub fn ind_vec_motive(elem: &Value) -> Value {
+    let vec = Expression::Vector(Vector {
+        ann: (),
+        elem: Box::new(var("E")),
+        len: Box::new(var("k")),
+    });
+    let ty = pi(&[("k", type_(Base::Nat))], arrow_(vec, universe()));
+    eval_with(&[("E", elem)], &ty)
+}
+
+/// Type of the step of `ind-Vec' over `elem's with `motive', that is
+/// `(Π ((k Nat) (e E) (es (Vec E k)))
+///    (→ (motive k es) (motive (add1 k) (vec:: e es))))'.
+pub fn ind_vec_step(elem: &Value, motive: &Value) -> Value {
+    let vec = Expression::Vector(Vector {
+        ann: (),
+        elem: Box::new(var("E")),
+        len: Box::new(var("k")),
+    });
+    let add1 = Expression::Add1(Add1 {
+        ann: (),
+        n: Box::new(var("k")),
+    });
+    let cons = Expression::VecCons(VecCons {
+        ann: (),
+        head: Box::new(var("e")),
+        tail: Box::new(var("es")),
+    });
+    let ty = pi(
+        &[("k", type_(Base::Nat)), ("e", var("E")), ("es", vec)],
+        arrow_(
+            app(var("motive"), vec![var("k"), var("es")]),
+            app(var("motive"), vec![add1, cons]),
+        ),
+    );
+    eval_with(&[("E", elem), ("motive", motive)], &ty)
+}
+
+/// Type of the motive of `ind-Eq' for equalities starting `from' an
+/// `X', that is `(Π ((to X)) (→ (= X from to) U))'.
+pub fn ind_eq_motive(ty: &Value, from: &Value) -> Value {
+    let eq = Expression::Equal(Equal {
+        ann: (),
+        ty: Box::new(var("X")),
+        from: Box::new(var("from")),
+        to: Box::new(var("to")),
+    });
+    let ty_ = pi(&[("to", var("X"))], arrow_(eq, universe()));
+    eval_with(&[("X", ty), ("from", from)], &ty_)
+}
+
+/// Types of the bases of `ind-Either' on `(Either L R)' with `motive',
+/// that is `(Π ((l L)) (motive (left l)))' and
+/// `(Π ((r R)) (motive (right r)))'.
+pub fn ind_either_bases(left: &Value, right: &Value, motive: &Value) -> (Value, Value) {
+    let left_ = Expression::Left(Left {
+        ann: (),
+        expr: Box::new(var("l")),
+    });
+    let right_ = Expression::Right(Right {
+        ann: (),
+        expr: Box::new(var("r")),
+    });
+    let env = [("L", left), ("R", right), ("motive", motive)];
+    (
+        eval_with(
+            &env,
+            &pi(&[("l", var("L"))], app(var("motive"), vec![left_])),
+        ),
+        eval_with(
+            &env,
+            &pi(&[("r", var("R"))], app(var("motive"), vec![right_])),
+        ),
+    )
+}
+
+////////////////////////////////////////////////
+// Evaluation
+/// Evaluate well typed `term' in `env'.
+pub fn eval(env: &Env, term: &Term) -> Value {
+    eval_term(env, &Stack::default(), term)
+}
+
+/// Evaluate well typed `term', with values of its free variables in
+/// `env' and of the bound ones in `bound', innermost on top.
+pub fn eval_term(env: &Env, bound: &Stack<Value>, term: &Term) -> Value {
+    let eval = |term: &Term| eval_term(env, bound, term);
+    let closure = |var: &Hint, body: &Rc<Term>| Closure::Term {
+        env: env.clone(),
+        bound: bound.clone(),
+        var: var.0,
+        body: body.clone(),
+    };
+    match term {
+        Term::Bound(index) => bound
+            .iter()
+            .nth(*index)
+            .cloned()
+            .unwrap_or_else(|| unreachable!("bound variable out of scope")),
+        Term::Free(ident) => env
+            .iter()
+            .find(|(name, _)| name == ident)
+            .map(|(_, val)| val.clone())
+            .unwrap_or_else(|| unreachable!("unbound variable `{ident}'")),
+        Term::Atom(ident) => Value::Quote(*ident),
+        Term::Base(ty) => match ty {
+            Base::Atom => Value::Atom,
+            Base::Nat => Value::Nat,
+            Base::Trivial => Value::Trivial,
+            Base::Absurd => Value::Absurd,
+        },
+        Term::Universe => Value::Universe,
+        Term::Lam(var, body) => Value::Lam(closure(var, body)),
+        Term::App(fun, arg) => apply(eval(fun), eval(arg)),
+        Term::Pi(var, dom, cod) => Value::Pi {
+            dom: Box::new(eval(dom)),
+            cod: closure(var, cod),
+        },
+        Term::Sigma(var, car_ty, cdr_ty) => Value::Sigma {
+            car_ty: Box::new(eval(car_ty)),
+            cdr_ty: closure(var, cdr_ty),
+        },
+        Term::Cons(car, cdr) => Value::Cons(Box::new(eval(car)), Box::new(eval(cdr))),
+        Term::Car(pair) => self::car(&eval(pair)),
+        Term::Cdr(pair) => self::cdr(&eval(pair)),
+        Term::The(ty, expr) => match expr.as_ref() {
+            Term::Todo => {
+                let ty = eval(ty);
+                Value::Neutral {
+                    ty: Box::new(ty.clone()),
+                    neu: Neutral::Todo(Box::new(ty)),
+                }
+            }
+            expr => eval(expr),
+        },
+        Term::Zero => Value::Zero,
+        Term::Add1(count, n) => add1s(*count, eval(n)),
+        Term::WhichNat(target, base, step) => {
+            which_nat(eval(target), base_(env, bound, base), eval(step))
+        }
+        Term::IterNat(target, base, step) => {
+            iter_nat(eval(target), base_(env, bound, base), eval(step))
+        }
+        Term::RecNat(target, base, step) => {
+            rec_nat(eval(target), base_(env, bound, base), eval(step))
+        }
+        Term::IndNat(target, motive, base, step) => {
+            ind_nat(eval(target), eval(motive), eval(base), eval(step))
+        }
+        Term::List(elem) => Value::List(Box::new(eval(elem))),
+        Term::Nil => Value::Nil,
+        Term::ListCons(head, tail) => Value::ListCons(Box::new(eval(head)), Box::new(eval(tail))),
+        Term::RecList(target, base, step) => {
+            rec_list(eval(target), base_(env, bound, base), eval(step))
+        }
+        Term::IndList(target, motive, base, step) => {
+            ind_list(eval(target), eval(motive), eval(base), eval(step))
+        }
+        Term::Vec(elem, len) => Value::Vec(Box::new(eval(elem)), Box::new(eval(len))),
+        Term::VecNil => Value::VecNil,
+        Term::VecCons(head, tail) => Value::VecCons(Box::new(eval(head)), Box::new(eval(tail))),
+        Term::Head(vec) => self::head(&eval(vec)),
+        Term::Tail(vec) => self::tail(&eval(vec)),
+        Term::IndVec(len, target, motive, base, step) => ind_vec(
+            eval(len),
+            eval(target),
+            eval(motive),
+            eval(base),
+            eval(step),
+        ),
+        Term::Equal(ty, from, to) => Value::Equal {
+            ty: Box::new(eval(ty)),
+            from: Box::new(eval(from)),
+            to: Box::new(eval(to)),
+        },
+        Term::Same(expr) => Value::Same(Box::new(eval(expr))),
+        Term::Cong(target, fun) => cong(eval(target), base_(env, bound, fun)),
+        Term::Replace(target, motive, base) => replace(eval(target), eval(motive), eval(base)),
+        Term::Symm(target) => symm(eval(target)),
+        Term::Trans(left, right) => trans(base_(env, bound, left), base_(env, bound, right)),
+        Term::IndEq(target, motive, base) => ind_eq(eval(target), eval(motive), eval(base)),
+        Term::Either(left, right) => Value::Either(Box::new(eval(left)), Box::new(eval(right))),
+        Term::Left(expr) => Value::Left(Box::new(eval(expr))),
+        Term::Right(expr) => Value::Right(Box::new(eval(expr))),
+        Term::IndEither(target, motive, base_left, base_right) => ind_either(
+            eval(target),
+            eval(motive),
+            eval(base_left),
+            eval(base_right),
+        ),
+        Term::Sole => Value::Sole,
+        Term::IndAbsurd(target, motive) => ind_absurd(eval(target), eval(motive)),
+        Term::Todo => unreachable!("holes are annotated with their type"),
+    }
+}
+
+// bases of eliminators (and a few other arguments) are elaborated to
+// `(the B base)', so that stuck eliminations know their type
+fn base_(env: &Env, bound: &Stack<Value>, base: &Term) -> Normal {
+    match base {
+        Term::The(ty, expr) => Normal {
+            ty: eval_term(env, bound, ty),
+            val: eval_term(env, bound, expr),
+        },
+        _ => unreachable!("argument is annotated with its type"),
+    }
+}
+
+/// `count' more `add1's around `n'.
+pub fn add1s(count: usize, n: Value) -> Value {
+    match n {
+        n if count == 0 => n,
+        Value::Add1(more, n) => Value::Add1(count + more, n),
+        n => Value::Add1(count, Box::new(n)),
+    }
+}
+
+/// The `Nat' one less than `n', unless it is `zero' or neutral.
+pub fn sub1(n: &Value) -> Option<Value> {
+    match n {
+        Value::Add1(count, n) => Some(add1s(count - 1, n.as_ref().clone())),
+        _ => None,
+    }
+}
+
+pub fn apply(fun: Value, arg: Value) -> Value {
+    match fun {
+        Value::Lam(clo) => clo.apply(arg),
+        Value::Neutral { ty, neu } => match *ty {
+            Value::Pi { dom, cod } => Value::Neutral {
+                ty: Box::new(cod.apply(arg.clone())),
+                neu: Neutral::App(Box::new(neu), Box::new(Normal { ty: *dom, val: arg })),
+            },
+            _ => unreachable!("applied neutral is a function"),
+        },
+        _ => unreachable!("applied value is a function"),
+    }
+}
+
+pub fn car(pair: &Value) -> Value {
+    match pair {
+        Value::Cons(car, _) => car.as_ref().clone(),
+        Value::Neutral { ty, neu } => match ty.as_ref() {
+            Value::Sigma { car_ty, .. } => Value::Neutral {
+                ty: car_ty.clone(),
+                neu: Neutral::Car(Box::new(neu.clone())),
+            },
+            _ => unreachable!("neutral is a pair"),
+        },
+        _ => unreachable!("value is a pair"),
+    }
+}
+
+pub fn cdr(pair: &Value) -> Value {
+    match pair {
+        Value::Cons(_, cdr) => cdr.as_ref().clone(),
+        Value::Neutral { ty, neu } => match ty.as_ref() {
+            Value::Sigma { cdr_ty, .. } => Value::Neutral {
+                ty: Box::new(cdr_ty.apply(car(pair))),
+                neu: Neutral::Cdr(Box::new(neu.clone())),
+            },
+            _ => unreachable!("neutral is a pair"),
+        },
+        _ => unreachable!("value is a pair"),
+    }
+}
+
+pub fn which_nat(target: Value, base: Normal, step: Value) -> Value {
+    match target {
+        Value::Zero => base.val,
+        Value::Add1(count, n) => apply(step, add1s(count - 1, *n)),
+        Value::Neutral { neu, .. } => {
+            let ty = base.ty.clone();
+            let step = Normal {
+                ty: arrow(Value::Nat, ty.clone()),
+                val: step,
+            };
+            Value::Neutral {
+                ty: Box::new(ty),
+                neu: Neutral::WhichNat(Box::new(neu), Box::new(base), Box::new(step)),
+            }
+        }
+        _ => unreachable!("target is a Nat"),
+    }
+}
+
+pub fn iter_nat(target: Value, base: Normal, step: Value) -> Value {
+    match target {
+        Value::Zero => base.val,
+        // one step for each `add1', without recurring on them
+        Value::Add1(count, n) => {
+            let iter = iter_nat(*n, base, step.clone());
+            (0..count).fold(iter, |iter, _| apply(step.clone(), iter))
+        }
+        Value::Neutral { neu, .. } => {
+            let ty = base.ty.clone();
+            let step = Normal {
+                ty: arrow(ty.clone(), ty.clone()),
+                val: step,
+            };
+            Value::Neutral {
+                ty: Box::new(ty),
+                neu: Neutral::IterNat(Box::new(neu), Box::new(base), Box::new(step)),
+            }
+        }
+        _ => unreachable!("target is a Nat"),
+    }
+}
+
+pub fn rec_nat(target: Value, base: Normal, step: Value) -> Value {
+    match target {
+        Value::Zero => base.val,
+        Value::Add1(count, n) => {
+            let rec = rec_nat(n.as_ref().clone(), base, step.clone());
+            (0..count).fold(rec, |rec, k| {
+                apply(apply(step.clone(), add1s(k, n.as_ref().clone())), rec)
+            })
+        }
+        Value::Neutral { neu, .. } => {
+            let ty = base.ty.clone();
+            let step = Normal {
+                ty: arrow(Value::Nat, arrow(ty.clone(), ty.clone())),
+                val: step,
+            };
+            Value::Neutral {
+                ty: Box::new(ty),
+                neu: Neutral::RecNat(Box::new(neu), Box::new(base), Box::new(step)),
+            }
+        }
+        _ => unreachable!("target is a Nat"),
+    }
+}
+
+pub fn ind_nat(target: Value, motive: Value, base: Value, step: Value) -> Value {
+    match target {
+        Value::Zero => base,
+        Value::Add1(count, n) => {
+            let ind = ind_nat(n.as_ref().clone(), motive, base, step.clone());
+            (0..count).fold(ind, |ind, k| {
+                apply(apply(step.clone(), add1s(k, n.as_ref().clone())), ind)
+            })
+        }
+        Value::Neutral { neu, .. } => {
+            let target = Value::Neutral {
+                ty: Box::new(Value::Nat),
+                neu: neu.clone(),
+            };
+            Value::Neutral {
+                ty: Box::new(apply(motive.clone(), target)),
+                neu: Neutral::IndNat(
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: arrow(Value::Nat, Value::Universe),
+                        val: motive.clone(),
+                    }),
+                    Box::new(Normal {
+                        ty: apply(motive.clone(), Value::Zero),
+                        val: base,
+                    }),
+                    Box::new(Normal {
+                        ty: ind_nat_step(&motive),
+                        val: step,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is a Nat"),
+    }
+}
+
+pub fn rec_list(target: Value, base: Normal, step: Value) -> Value {
+    match target {
+        Value::Nil => base.val,
+        Value::ListCons(head, tail) => {
+            let rec = rec_list(tail.as_ref().clone(), base, step.clone());
+            apply(apply(apply(step, *head), *tail), rec)
+        }
+        Value::Neutral { ty, neu } => {
+            let elem = match *ty {
+                Value::List(elem) => *elem,
+                _ => unreachable!("neutral is a list"),
+            };
+            let ty = base.ty.clone();
+            let list = Value::List(Box::new(elem.clone()));
+            let step = Normal {
+                ty: arrow(elem, arrow(list, arrow(ty.clone(), ty.clone()))),
+                val: step,
+            };
+            Value::Neutral {
+                ty: Box::new(ty),
+                neu: Neutral::RecList(Box::new(neu), Box::new(base), Box::new(step)),
+            }
+        }
+        _ => unreachable!("target is a list"),
+    }
+}
+
+pub fn ind_list(target: Value, motive: Value, base: Value, step: Value) -> Value {
+    match target {
+        Value::Nil => base,
+        Value::ListCons(head, tail) => {
+            let ind = ind_list(tail.as_ref().clone(), motive, base, step.clone());
+            apply(apply(apply(step, *head), *tail), ind)
+        }
+        Value::Neutral { ty, neu } => {
+            let elem = match ty.as_ref() {
+                Value::List(elem) => elem.as_ref(),
+                _ => unreachable!("neutral is a list"),
+            };
+            let target = Value::Neutral {
+                ty: ty.clone(),
+                neu: neu.clone(),
+            };
+            Value::Neutral {
+                ty: Box::new(apply(motive.clone(), target)),
+                neu: Neutral::IndList(
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: arrow(ty.as_ref().clone(), Value::Universe),
+                        val: motive.clone(),
+                    }),
+                    Box::new(Normal {
+                        ty: apply(motive.clone(), Value::Nil),
+                        val: base,
+                    }),
+                    Box::new(Normal {
+                        ty: ind_list_step(elem, &motive),
+                        val: step,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is a list"),
+    }
+}
+
+pub fn head(vec: &Value) -> Value {
+    match vec {
+        Value::VecCons(head, _) => head.as_ref().clone(),
+        Value::Neutral { ty, neu } => match ty.as_ref() {
+            Value::Vec(elem, _) => Value::Neutral {
+                ty: elem.clone(),
+                neu: Neutral::Head(Box::new(neu.clone())),
+            },
+            _ => unreachable!("neutral is a vector"),
+        },
+        _ => unreachable!("value is a non-empty vector"),
+    }
+}
+
+pub fn tail(vec: &Value) -> Value {
+    match vec {
+        Value::VecCons(_, tail) => tail.as_ref().clone(),
+        Value::Neutral { ty, neu } => match ty.as_ref() {
+            Value::Vec(elem, len) => match sub1(len) {
+                Some(len) => Value::Neutral {
+                    ty: Box::new(Value::Vec(elem.clone(), Box::new(len))),
+                    neu: Neutral::Tail(Box::new(neu.clone())),
+                },
+                None => unreachable!("neutral is a non-empty vector"),
+            },
+            _ => unreachable!("neutral is a vector"),
+        },
+        _ => unreachable!("value is a non-empty vector"),
+    }
+}
+
+pub fn ind_vec(len: Value, target: Value, motive: Value, base: Value, step: Value) -> Value {
+    match (sub1(&len), target) {
+        (_, Value::VecNil) => base,
+        (Some(len), Value::VecCons(head, tail)) => {
+            let ind = ind_vec(
+                len.clone(),
+                tail.as_ref().clone(),
+                motive,
+                base,
+                step.clone(),
+            );
+            [len, *head, *tail, ind].into_iter().fold(step, apply)
+        }
+        (_, Value::Neutral { ty, neu }) => {
+            let elem = match ty.as_ref() {
+                Value::Vec(elem, _) => elem.as_ref(),
+                _ => unreachable!("neutral is a vector"),
+            };
+            let target = Value::Neutral {
+                ty: ty.clone(),
+                neu: neu.clone(),
+            };
+            let base_ty = apply(apply(motive.clone(), Value::Zero), Value::VecNil);
+            Value::Neutral {
+                ty: Box::new(apply(apply(motive.clone(), len.clone()), target)),
+                neu: Neutral::IndVec(
+                    Box::new(Normal {
+                        ty: Value::Nat,
+                        val: len,
+                    }),
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: ind_vec_motive(elem),
+                        val: motive.clone(),
+                    }),
+                    Box::new(Normal {
+                        ty: base_ty,
+                        val: base,
+                    }),
+                    Box::new(Normal {
+                        ty: ind_vec_step(elem, &motive),
+                        val: step,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is a vector of the given length"),
+    }
+}
+
+// the type of stuck equality eliminations' targets
+fn equal(ty: &Value) -> (&Value, &Value, &Value) {
+    match ty {
+        Value::Equal { ty, from, to } => (ty, from, to),
+        _ => unreachable!("neutral is an equality"),
+    }
+}
+
+pub fn cong(target: Value, fun: Normal) -> Value {
+    match target {
+        Value::Same(val) => Value::Same(Box::new(apply(fun.val, *val))),
+        Value::Neutral { ty, neu } => {
+            let (_, from, to) = equal(&ty);
+            let cod = match &fun.ty {
+                Value::Pi { cod, .. } => cod.apply(from.clone()),
+                _ => unreachable!("function is a function"),
+            };
+            let ty = Value::Equal {
+                ty: Box::new(cod),
+                from: Box::new(apply(fun.val.clone(), from.clone())),
+                to: Box::new(apply(fun.val.clone(), to.clone())),
+            };
+            Value::Neutral {
+                ty: Box::new(ty),
+                neu: Neutral::Cong(Box::new(neu), Box::new(fun)),
+            }
+        }
+        _ => unreachable!("target is an equality"),
+    }
+}
+
+pub fn replace(target: Value, motive: Value, base: Value) -> Value {
+    match target {
+        Value::Same(_) => base,
+        Value::Neutral { ty, neu } => {
+            let (ty, from, to) = equal(&ty);
+            Value::Neutral {
+                ty: Box::new(apply(motive.clone(), to.clone())),
+                neu: Neutral::Replace(
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: arrow(ty.clone(), Value::Universe),
+                        val: motive.clone(),
+                    }),
+                    Box::new(Normal {
+                        ty: apply(motive, from.clone()),
+                        val: base,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is an equality"),
+    }
+}
+
+pub fn symm(target: Value) -> Value {
+    match target {
+        Value::Same(val) => Value::Same(val),
+        Value::Neutral { ty, neu } => {
+            let (ty, from, to) = equal(&ty);
+            Value::Neutral {
+                ty: Box::new(Value::Equal {
+                    ty: Box::new(ty.clone()),
+                    from: Box::new(to.clone()),
+                    to: Box::new(from.clone()),
+                }),
+                neu: Neutral::Symm(Box::new(neu)),
+            }
+        }
+        _ => unreachable!("target is an equality"),
+    }
+}
+
+pub fn trans(left: Normal, right: Normal) -> Value {
+    match (&left.val, &right.val) {
+        (Value::Same(val), Value::Same(_)) => Value::Same(val.clone()),
+        _ => {
+            let (ty, from, _) = equal(&left.ty);
+            let (_, _, to) = equal(&right.ty);
+            Value::Neutral {
+                ty: Box::new(Value::Equal {
+                    ty: Box::new(ty.clone()),
+                    from: Box::new(from.clone()),
+                    to: Box::new(to.clone()),
+                }),
+                neu: Neutral::Trans(Box::new(left), Box::new(right)),
+            }
+        }
+    }
+}
+
+pub fn ind_eq(target: Value, motive: Value, base: Value) -> Value {
+    match target {
+        Value::Same(_) => base,
+        Value::Neutral { ty, neu } => {
+            let (ty_, from, to) = equal(&ty);
+            let target = Value::Neutral {
+                ty: ty.clone(),
+                neu: neu.clone(),
+            };
+            let same = Value::Same(Box::new(from.clone()));
+            Value::Neutral {
+                ty: Box::new(apply(apply(motive.clone(), to.clone()), target)),
+                neu: Neutral::IndEq(
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: ind_eq_motive(ty_, from),
+                        val: motive.clone(),
+                    }),
+                    Box::new(Normal {
+                        ty: apply(apply(motive, from.clone()), same),
+                        val: base,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is an equality"),
+    }
+}
+
+pub fn ind_either(target: Value, motive: Value, base_left: Value, base_right: Value) -> Value {
+    match target {
+        Value::Left(val) => apply(base_left, *val),
+        Value::Right(val) => apply(base_right, *val),
+        Value::Neutral { ty, neu } => {
+            let (left, right) = match ty.as_ref() {
+                Value::Either(left, right) => (left.as_ref(), right.as_ref()),
+                _ => unreachable!("neutral is an Either"),
+            };
+            let (left_ty, right_ty) = ind_either_bases(left, right, &motive);
+            let target = Value::Neutral {
+                ty: ty.clone(),
+                neu: neu.clone(),
+            };
+            Value::Neutral {
+                ty: Box::new(apply(motive.clone(), target)),
+                neu: Neutral::IndEither(
+                    Box::new(neu),
+                    Box::new(Normal {
+                        ty: arrow(ty.as_ref().clone(), Value::Universe),
+                        val: motive,
+                    }),
+                    Box::new(Normal {
+                        ty: left_ty,
+                        val: base_left,
+                    }),
+                    Box::new(Normal {
+                        ty: right_ty,
+                        val: base_right,
+                    }),
+                ),
+            }
+        }
+        _ => unreachable!("target is an Either"),
+    }
+}
+
+// there are no values of type `Absurd', so this is always stuck
+pub fn ind_absurd(target: Value, motive: Value) -> Value {
+    match target {
+        Value::Neutral { neu, .. } => Value::Neutral {
+            ty: Box::new(motive.clone()),
+            neu: Neutral::IndAbsurd(
+                Box::new(neu),
+                Box::new(Normal {
+                    ty: Value::Universe,
+                    val: motive,
+                }),
+            ),
+        },
+        _ => unreachable!("target is Absurd"),
+    }
+}
+
+////////////////////////////////////////////////
+// Reading back
+/// Read back the normal form of `val' of type `ty', avoiding `names'
+/// when inventing binders.
+pub fn read_back(names: &[Identifier], ty: &Value, val: &Value) -> Expression {
+    term::raise(&read_back_(&mut names.to_vec(), ty, val), names)
+}
+
+/// Read back the normal form of type `val'.
+pub fn read_back_type(names: &[Identifier], val: &Value) -> Expression {
+    term::raise(&read_back_type_(&mut names.to_vec(), val), names)
+}
+
+fn boxed(term: Term) -> Box<Term> {
+    Box::new(term)
+}
+
+fn read_back_(names: &mut Vec<Identifier>, ty: &Value, val: &Value) -> Term {
+    match (ty, val) {
+        (Value::Universe, val) => read_back_type_(names, val),
+        (Value::Atom, Value::Quote(ident)) => Term::Atom(*ident),
+        (Value::Nat, Value::Zero) => Term::Zero,
+        (Value::Nat, Value::Add1(count, n)) => Term::Add1(*count, boxed(read_back_(names, ty, n))),
+        (Value::List(_), Value::Nil) => Term::Nil,
+        (Value::List(elem), Value::ListCons(head, tail)) => Term::ListCons(
+            boxed(read_back_(names, elem, head)),
+            boxed(read_back_(names, ty, tail)),
+        ),
+        (Value::Vec(_, _), Value::VecNil) => Term::VecNil,
+        (Value::Vec(elem, len), Value::VecCons(head, tail)) => {
+            let len =
+                sub1(len).unwrap_or_else(|| unreachable!("non-empty vector has non-zero length"));
+            Term::VecCons(
+                boxed(read_back_(names, elem, head)),
+                boxed(read_back_(
+                    names,
+                    &Value::Vec(elem.clone(), Box::new(len)),
+                    tail,
+                )),
+            )
+        }
+        (Value::Equal { ty, .. }, Value::Same(val)) => {
+            Term::Same(boxed(read_back_(names, ty, val)))
+        }
+        (Value::Either(left, _), Value::Left(val)) => {
+            Term::Left(boxed(read_back_(names, left, val)))
+        }
+        (Value::Either(_, right), Value::Right(val)) => {
+            Term::Right(boxed(read_back_(names, right, val)))
+        }
+        // functions are read back eta-expanded
+        (Value::Pi { dom, cod }, fun) => {
+            let hint = match fun {
+                Value::Lam(clo) => clo.var(),
+                _ => cod.var(),
+            };
+            let var = fresh(names, &hint);
+            let arg = Value::Neutral {
+                ty: dom.clone(),
+                neu: Neutral::Var(var),
+            };
+            names.push(var);
+            let body = read_back_(names, &cod.apply(arg.clone()), &apply(fun.clone(), arg));
+            names.pop();
+            Term::Lam(Hint(hint), Rc::new(body.close(&var)))
+        }
+        // pairs are read back eta-expanded too
+        (Value::Sigma { car_ty, cdr_ty }, pair) => {
+            let car_ = car(pair);
+            let cdr_ = read_back_(names, &cdr_ty.apply(car_.clone()), &cdr(pair));
+            let car_ = read_back_(names, car_ty, &car_);
+            Term::Cons(boxed(car_), boxed(cdr_))
+        }
+        // there is just one value of type `Trivial'
+        (Value::Trivial, _) => Term::Sole,
+        (Value::Absurd, Value::Neutral { neu, .. }) => Term::The(
+            boxed(Term::Base(Base::Absurd)),
+            boxed(read_back_neutral(names, neu)),
+        ),
+        (_, Value::Neutral { neu, .. }) => read_back_neutral(names, neu),
+        _ => unreachable!("value doesn't match its type"),
+    }
+}
+
+fn read_back_type_(names: &mut Vec<Identifier>, val: &Value) -> Term {
+    match val {
+        Value::Universe => Term::Universe,
+        Value::Atom => Term::Base(Base::Atom),
+        Value::Nat => Term::Base(Base::Nat),
+        Value::Either(left, right) => Term::Either(
+            boxed(read_back_type_(names, left)),
+            boxed(read_back_type_(names, right)),
+        ),
+        Value::List(elem) => Term::List(boxed(read_back_type_(names, elem))),
+        Value::Vec(elem, len) => Term::Vec(
+            boxed(read_back_type_(names, elem)),
+            boxed(read_back_(names, &Value::Nat, len)),
+        ),
+        Value::Equal { ty, from, to } => Term::Equal(
+            boxed(read_back_type_(names, ty)),
+            boxed(read_back_(names, ty, from)),
+            boxed(read_back_(names, ty, to)),
+        ),
+        Value::Pi { dom, cod } => {
+            let (hint, dom, cod) = read_back_binder(names, dom, cod);
+            Term::Pi(hint, boxed(dom), Rc::new(cod))
+        }
+        Value::Sigma { car_ty, cdr_ty } => {
+            let (hint, car_ty, cdr_ty) = read_back_binder(names, car_ty, cdr_ty);
+            Term::Sigma(hint, boxed(car_ty), Rc::new(cdr_ty))
+        }
+        Value::Trivial => Term::Base(Base::Trivial),
+        Value::Absurd => Term::Base(Base::Absurd),
+        Value::Neutral { neu, .. } => read_back_neutral(names, neu),
+        _ => unreachable!("value is a type"),
+    }
+}
+
+// read back type binding a fresh variable of type `dom' in `body'
+fn read_back_binder(
+    names: &mut Vec<Identifier>,
+    dom: &Value,
+    body: &Closure,
+) -> (Hint, Term, Term) {
+    let hint = body.var();
+    let var = fresh(names, &hint);
+    let arg = Value::Neutral {
+        ty: Box::new(dom.clone()),
+        neu: Neutral::Var(var),
+    };
+    let dom = read_back_type_(names, dom);
+    names.push(var);
+    let body = read_back_type_(names, &body.apply(arg));
+    names.pop();
+    (Hint(hint), dom, body.close(&var))
+}
+
+fn read_back_neutral(names: &mut Vec<Identifier>, neu: &Neutral) -> Term {
+    match neu {
+        Neutral::Var(ident) => Term::Free(*ident),
+        Neutral::App(fun, arg) => Term::App(
+            boxed(read_back_neutral(names, fun)),
+            boxed(read_back_normal(names, arg)),
+        ),
+        Neutral::Car(pair) => Term::Car(boxed(read_back_neutral(names, pair))),
+        Neutral::Cdr(pair) => Term::Cdr(boxed(read_back_neutral(names, pair))),
+        Neutral::WhichNat(target, base, step) => Term::WhichNat(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_base(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::IterNat(target, base, step) => Term::IterNat(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_base(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::RecNat(target, base, step) => Term::RecNat(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_base(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::IndNat(target, motive, base, step) => Term::IndNat(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::RecList(target, base, step) => Term::RecList(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_base(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::IndList(target, motive, base, step) => Term::IndList(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::Head(vec) => Term::Head(boxed(read_back_neutral(names, vec))),
+        Neutral::Tail(vec) => Term::Tail(boxed(read_back_neutral(names, vec))),
+        Neutral::IndVec(len, target, motive, base, step) => Term::IndVec(
+            boxed(read_back_normal(names, len)),
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base)),
+            boxed(read_back_normal(names, step)),
+        ),
+        Neutral::Cong(target, fun) => Term::Cong(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_base(names, fun)),
+        ),
+        Neutral::Replace(target, motive, base) => Term::Replace(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base)),
+        ),
+        Neutral::Symm(target) => Term::Symm(boxed(read_back_neutral(names, target))),
+        Neutral::Trans(left, right) => Term::Trans(
+            boxed(read_back_normal(names, left)),
+            boxed(read_back_normal(names, right)),
+        ),
+        Neutral::IndEq(target, motive, base) => Term::IndEq(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base)),
+        ),
+        Neutral::IndEither(target, motive, base_left, base_right) => Term::IndEither(
+            boxed(read_back_neutral(names, target)),
+            boxed(read_back_normal(names, motive)),
+            boxed(read_back_normal(names, base_left)),
+            boxed(read_back_normal(names, base_right)),
+        ),
+        // the target is read back as `(the Absurd target)' like any other
+        // neutral `Absurd'
+        Neutral::IndAbsurd(target, motive) => Term::IndAbsurd(
+            boxed(Term::The(
+                boxed(Term::Base(Base::Absurd)),
+                boxed(read_back_neutral(names, target)),
+            )),
+            boxed(read_back_normal(names, motive)),
+        ),
+        Neutral::Todo(ty) => Term::The(boxed(read_back_type_(names, ty)), boxed(Term::Todo)),
+    }
+}
+
+fn read_back_normal(names: &mut Vec<Identifier>, normal: &Normal) -> Term {
+    read_back_(names, &normal.ty, &normal.val)
+}
+
+// bases keep their type, so that the normal form can be checked again
+fn read_back_base(names: &mut Vec<Identifier>, base: &Normal) -> Term {
+    Term::The(
+        boxed(read_back_type_(names, &base.ty)),
+        boxed(read_back_normal(names, base)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::check::{check, Context};
+    use crate::parser::parse;
+    use crate::syntax::{Expression, Lambda};
+    use crate::{desugar, elab};
+
+    // normal form of the last statement of `text'
+    fn run(text: &str) -> Expression {
+        let (mut source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        assert!(desugar::desugar(&mut source).is_empty());
+        let (defs, problems) = elab::definitions(&source);
+        assert!(problems.is_empty(), "bad definitions: {problems:?}");
+        let mut ctx = Context::default();
+        let checked = check(&mut ctx, &source.statements, &defs);
+        let normal = checked.into_iter().last().unwrap().normal.unwrap();
+        normal.unwrap().expr
+    }
+
+    // normal form of `text' of type `ty', with `vars' in scope; the
+    // checker elaborates it as a function of the `vars'
+    fn normal(vars: &[(&str, &str)], ty: &str, text: &str) -> String {
+        let claim = match vars {
+            [] => ty.to_string(),
+            _ => {
+                let binders: Vec<String> = (vars.iter())
+                    .map(|(name, ty)| format!("({name} {ty})"))
+                    .collect();
+                format!("(Π ({}) {ty})", binders.join(" "))
+            }
+        };
+        let def = match vars {
+            [] => text.to_string(),
+            _ => {
+                let names: Vec<&str> = vars.iter().map(|(name, _)| *name).collect();
+                format!("(λ ({}) {text})", names.join(" "))
+            }
+        };
+        let text = format!("(claim it {claim}) (define it {def}) it");
+        match (run(&text), vars.len()) {
+            (expr, 0) => expr.to_string(),
+            (Expression::Abs(Lambda { args, body, .. }), n) if args.len() == n => body.to_string(),
+            (Expression::Abs(Lambda { ann, args, body }), n) => Expression::Abs(Lambda {
+                ann,
+                args: args[n..].to_vec(),
+                body,
+            })
+            .to_string(),
+            (expr, _) => panic!("expected a function, got {expr}"),
+        }
+    }
+
+    #[test]
+    fn test_eliminate_pair() {
+        assert_eq!(normal(&[], "Atom", "(car (cons 'a 'b))"), "'a");
+        assert_eq!(normal(&[], "Atom", "(cdr (cons 'a 'b))"), "'b");
+    }
+
+    #[test]
+    fn test_beta() {
+        let text = "(claim twice (→ (→ Nat Nat) Nat Nat))
+                    (define twice (λ (f x) (f (f x))))
+                    (twice (λ (n) (add1 n)) 3)";
+        assert_eq!(run(text).to_string(), "5");
+    }
+
+    #[test]
+    fn test_eta_function() {
+        let vars = [("f", "(→ Atom Atom)")];
+        assert_eq!(normal(&vars, "(→ Atom Atom)", "f"), "(λ (x) (f x))");
+    }
+
+    #[test]
+    fn test_eta_pair() {
+        let vars = [("p", "(Pair Atom Nat)")];
+        assert_eq!(
+            normal(&vars, "(Pair Atom Nat)", "p"),
+            "(cons (car p) (cdr p))"
+        );
+    }
+
+    #[test]
+    fn test_eta_trivial() {
+        let vars = [("t", "Trivial")];
+        assert_eq!(normal(&vars, "Trivial", "t"), "sole");
+    }
+
+    #[test]
+    fn test_absurd() {
+        let vars = [("a", "Absurd")];
+        assert_eq!(normal(&vars, "Absurd", "a"), "(the Absurd a)");
+        assert_eq!(
+            normal(&vars, "Atom", "(ind-Absurd a Atom)"),
+            "(ind-Absurd (the Absurd a) Atom)"
+        );
+    }
+
+    #[test]
+    fn test_fresh_binders() {
+        let text = "(claim const (→ Atom Atom Atom))
+                    (define const (λ (y) (λ (x) y)))";
+        let text = format!("{text} (claim it (→ Atom Atom Atom)) (define it (λ (x) (const x))) it");
+        assert_eq!(run(&text).to_string(), "(λ (x x₁) x)");
+    }
+
+    #[test]
+    fn test_nat_eliminators() {
+        let vars = [("n", "Nat")];
+        let double = "(iter-Nat 3 0 (λ (k) (add1 (add1 k))))";
+        assert_eq!(normal(&vars, "Nat", double), "6");
+        assert_eq!(normal(&vars, "Nat", "(add1 (add1 n))"), "(add1 (add1 n))");
+        let stuck = "(iter-Nat n 0 (λ (k) (add1 k)))";
+        assert_eq!(
+            normal(&vars, "Nat", stuck),
+            "(iter-Nat n (the Nat 0) (λ (k) (add1 k)))"
+        );
+        let gauss = "(rec-Nat 4 0 (λ (n-1 sum) (iter-Nat n-1 (add1 sum) (λ (k) (add1 k)))))";
+        assert_eq!(normal(&vars, "Nat", gauss), "10");
+    }
+}
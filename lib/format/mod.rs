@@ -0,0 +1,280 @@
+use crate::parser::ts::{syntax_errors, ParseError, Result};
+use pretty::{DocAllocator, RcAllocator, RcDoc};
+use tree_sitter as TS;
+use tree_sitter::Node;
+use tree_sitter_pie as pie;
+
+type Doc = RcDoc<'static>;
+
+////////////////////////////////////////////////
+// Concrete syntax
+// what is left of the tree once the layout is thrown away
+enum Element {
+    Token(String),
+    // `(...)', either a whole node or a part of one, like the
+    // arguments of a `λ'
+    Form { node: bool, elements: Vec<Element> },
+    // `trailing' comments follow some code on the same line
+    Comment { text: String, trailing: bool },
+}
+
+// lines taken by each top-level element, to keep blank lines between
+struct Lines {
+    first: usize,
+    last: usize,
+}
+
+struct Reader<'a> {
+    source: &'a str,
+    // row where the last token ended
+    row: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn leaf(&mut self, node: &Node) -> String {
+        self.row = node.end_position().row;
+        self.source[node.byte_range()].to_string()
+    }
+
+    fn comment(&mut self, node: &Node) -> Element {
+        let trailing = node.start_position().row == self.row;
+        let text = self.leaf(node).trim_end().to_string();
+        Element::Comment { text, trailing }
+    }
+
+    // append everything in `node' to `out'
+    fn node(&mut self, node: &Node, out: &mut Vec<Element>) {
+        let mut cursor = node.walk();
+        let children: Vec<Node> = node.children(&mut cursor).collect();
+        match node.kind() {
+            "comment" => out.push(self.comment(node)),
+            _ if children.is_empty() => out.push(Element::Token(self.leaf(node))),
+            // `'atom', without the space that may follow the quote
+            "atom" => {
+                let mut comments = vec![];
+                let mut atom = String::new();
+                for child in children.iter() {
+                    match child.kind() {
+                        "comment" => comments.push(self.comment(child)),
+                        _ => atom.push_str(&self.leaf(child)),
+                    }
+                }
+                out.push(Element::Token(atom));
+                out.extend(comments);
+            }
+            _ if children[0].kind() == "(" => self.form(&children, out),
+            _ => children.iter().for_each(|child| self.node(child, out)),
+        }
+    }
+
+    // a node in parentheses, possibly with more parentheses inside
+    fn form(&mut self, children: &[Node], out: &mut Vec<Element>) {
+        let mut stack: Vec<Vec<Element>> = vec![];
+        let mut elements = vec![];
+        for child in children.iter() {
+            match child.kind() {
+                "(" => {
+                    self.leaf(child);
+                    stack.push(std::mem::take(&mut elements));
+                }
+                ")" => {
+                    self.leaf(child);
+                    let inner = std::mem::replace(&mut elements, stack.pop().unwrap_or_default());
+                    elements.push(Element::Form {
+                        node: stack.is_empty(),
+                        elements: inner,
+                    });
+                }
+                _ => self.node(child, &mut elements),
+            }
+        }
+        // comments after the closing parenthesis belong outside
+        out.extend(elements);
+    }
+}
+
+////////////////////////////////////////////////
+// Layout
+fn align(doc: Doc) -> Doc {
+    RcAllocator.nil().append(doc).align().into_doc()
+}
+
+fn element(elem: &Element) -> Doc {
+    match elem {
+        Element::Token(text) | Element::Comment { text, .. } => Doc::text(text.clone()),
+        Element::Form { node, elements } => form(*node, elements),
+    }
+}
+
+// `(head first rest ...)' like expressions are printed, comments break
+// the line after them; parts of a node like `(x y)' in `(λ (x y) x)'
+// are laid out as a plain list
+fn form(node: bool, elements: &[Element]) -> Doc {
+    let mut doc = Doc::nil();
+    let mut items = 0;
+    let mut previous: Option<&Element> = None;
+    for elem in elements.iter() {
+        let separator = match (previous, elem) {
+            (None, _) => Doc::nil(),
+            (_, Element::Comment { trailing: true, .. }) => Doc::text(" "),
+            (Some(Element::Comment { .. }), _) => Doc::hardline(),
+            _ if node && items == 1 => Doc::text(" "),
+            _ => Doc::line(),
+        };
+        if !matches!(elem, Element::Comment { .. }) {
+            items += 1;
+        }
+        doc = doc.append(separator).append(align(element(elem)));
+        previous = Some(elem);
+    }
+    let close = match previous {
+        Some(Element::Comment { .. }) => Doc::hardline().append(")"),
+        _ => Doc::text(")"),
+    };
+    let doc = if node { doc.nest(2) } else { align(doc) };
+    Doc::text("(").append(doc).append(close).group()
+}
+
+// top-level elements, keeping at most one blank line between them
+fn source(elements: &[(Element, Lines)]) -> Doc {
+    let mut doc = Doc::nil();
+    let mut previous: Option<&Lines> = None;
+    for (elem, lines) in elements.iter() {
+        let separator = match (previous, elem) {
+            (None, _) => Doc::nil(),
+            (_, Element::Comment { trailing: true, .. }) => Doc::text(" "),
+            (Some(previous), _) if lines.first > previous.last + 1 => {
+                Doc::hardline().append(Doc::hardline())
+            }
+            _ => Doc::hardline(),
+        };
+        doc = doc.append(separator).append(element(elem));
+        previous = Some(lines);
+    }
+    doc
+}
+
+////////////////////////////////////////////////
+// Formatting
+/// Lay out `text' in the canonical way, keeping all of its comments.
+/// Text with syntax errors is returned as it is, together with the
+/// errors.
+pub fn format(text: &str, width: usize) -> Result<(String, Vec<ParseError>)> {
+    let mut parser = TS::Parser::new();
+    parser.set_language(pie::language())?;
+
+    let tree = parser.parse(text, None).ok_or(ParseError::Aborted)?;
+    let root = tree.root_node();
+    let errors = syntax_errors(&root, text)?;
+    if !errors.is_empty() {
+        return Ok((text.to_string(), errors));
+    }
+
+    let mut reader = Reader {
+        source: text,
+        row: usize::MAX,
+    };
+    let mut elements = vec![];
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        let mut parts = vec![];
+        reader.node(&child, &mut parts);
+        let mut lines = Lines {
+            first: child.start_position().row,
+            last: child.end_position().row,
+        };
+        for part in parts {
+            elements.push((part, lines));
+            // only the first part keeps its position
+            lines = Lines {
+                first: child.end_position().row,
+                last: child.end_position().row,
+            };
+        }
+    }
+
+    let mut formatted = String::new();
+    source(&elements)
+        .render_fmt(width, &mut formatted)
+        .expect("writing to a string");
+    if !formatted.is_empty() {
+        formatted.push('\n');
+    }
+    Ok((formatted, errors))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn formatted(text: &str, width: usize) -> String {
+        let (formatted, errors) = format(text, width).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        formatted
+    }
+
+    #[test]
+    fn test_spacing() {
+        assert_eq!(formatted("( claim  x\n   Nat )", 80), "(claim x Nat)\n");
+        assert_eq!(
+            formatted("(define   id (λ( x )x))\n\n\n(id  'a )", 80),
+            "(define id (λ (x) x))\n\n(id 'a)\n"
+        );
+    }
+
+    #[test]
+    fn test_comments() {
+        let text = fs::read_to_string("examples/applications.pie").unwrap();
+        assert!(formatted(&text, 80).contains("(fun)\n\n; type level\n(Fun arg)"));
+        assert_eq!(
+            formatted("(define x   ; the answer\n  42)", 80),
+            "(define x ; the answer\n  42)\n"
+        );
+        assert_eq!(
+            formatted("(claim f\n  ; a function\n  (→ Atom Atom))", 80),
+            "(claim f\n  ; a function\n  (→ Atom Atom))\n"
+        );
+    }
+
+    #[test]
+    fn test_syntax_errors() {
+        let text = "(claim x Nat\n";
+        let (formatted, errors) = format(text, 80).unwrap();
+        assert_eq!(formatted, text);
+        assert!(!errors.is_empty());
+    }
+
+    #[test]
+    fn test_idempotent() {
+        for entry in fs::read_dir("examples").unwrap() {
+            let text = fs::read_to_string(entry.unwrap().path()).unwrap();
+            for width in [80, 20] {
+                let (once, _) = format(&text, width).unwrap();
+                let (twice, _) = format(&once, width).unwrap();
+                assert_eq!(once, twice);
+            }
+        }
+    }
+
+    // the examples written for this implementation, the others are
+    // kept as they came
+    const EXAMPLES: [&str; 7] = [
+        "either",
+        "equality",
+        "functions",
+        "holes",
+        "lists",
+        "naturals",
+        "pairs",
+    ];
+
+    #[test]
+    fn test_examples_formatted() {
+        for name in EXAMPLES {
+            let path = format!("examples/{name}.pie");
+            let text = fs::read_to_string(&path).unwrap();
+            assert_eq!(formatted(&text, 80), text, "{path} isn't formatted");
+        }
+    }
+}
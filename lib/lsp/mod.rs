@@ -0,0 +1,794 @@
+use crate::check::{self, Context, Typing};
+use crate::parser::{self, ts::ParseError};
+use crate::syntax::{Claim, Identifier, Statement};
+use crate::{desugar, elab, resolve};
+use crossbeam_channel::SendError;
+use lsp_server::{Connection, ErrorCode, Message, Notification, ProtocolError, Request, Response};
+use lsp_types::notification::{
+    DidChangeTextDocument, DidCloseTextDocument, DidOpenTextDocument, LogMessage,
+    Notification as NotificationTrait, PublishDiagnostics,
+};
+use lsp_types::request::{
+    DocumentSymbolRequest, GotoDefinition, HoverRequest, Request as RequestTrait,
+};
+use lsp_types::{
+    Diagnostic as LspDiagnostic, DiagnosticSeverity, DocumentSymbol, DocumentSymbolResponse,
+    GotoDefinitionResponse, Hover, HoverContents, HoverProviderCapability, Location,
+    LogMessageParams, MarkupContent, MarkupKind, MessageType, OneOf, Position,
+    PublishDiagnosticsParams, Range, ServerCapabilities, SymbolKind,
+    TextDocumentContentChangeEvent, TextDocumentSyncCapability, TextDocumentSyncKind, Url,
+};
+use miette::{Diagnostic, Severity};
+use std::collections::HashMap;
+use thiserror::Error;
+use tree_sitter::Node;
+
+////////////////////////////////////////////////
+// Errors
+#[derive(Debug, Diagnostic, Error)]
+#[error("Change out of range")]
+#[diagnostic(help(
+    "line {} is past the end of a text with {} lines",
+    self.line + 1,
+    self.lines
+))]
+pub struct OutOfRange {
+    pub line: u32,
+    pub lines: usize,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+pub enum ServerError {
+    #[error("Language server protocol error")]
+    Protocol(#[from] ProtocolError),
+    #[error("Malformed message")]
+    Json(#[from] serde_json::Error),
+    #[error("Client went away")]
+    Disconnected(#[from] SendError<Message>),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Parser(#[from] ParseError),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    OutOfRange(#[from] OutOfRange),
+}
+
+pub type Result<T> = std::result::Result<T, ServerError>;
+
+////////////////////////////////////////////////
+// Positions
+// positions count lines and UTF-16 code units, like the protocol does
+fn position(text: &str, offset: usize) -> Position {
+    let before = &text[..offset.min(text.len())];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    Position {
+        line: before.matches('\n').count() as u32,
+        character: before[line_start..].encode_utf16().count() as u32,
+    }
+}
+
+// characters past the end of a line stand for its end, as the protocol
+// asks; lines past the end of the text don't make sense
+fn offset(text: &str, position: Position) -> std::result::Result<usize, OutOfRange> {
+    let lines = text.matches('\n').count() + 1;
+    if position.line as usize >= lines {
+        return Err(OutOfRange {
+            line: position.line,
+            lines,
+        });
+    }
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(position.line as usize)
+        .map(str::len)
+        .sum();
+    let mut units = 0;
+    for (offset, char) in text[line_start..].char_indices() {
+        if units >= position.character as usize || char == '\n' {
+            return Ok(line_start + offset);
+        }
+        units += char.len_utf16();
+    }
+    Ok(text.len())
+}
+
+fn range(text: &str, bytes: std::ops::Range<usize>) -> Range {
+    Range {
+        start: position(text, bytes.start),
+        end: position(text, bytes.end),
+    }
+}
+
+////////////////////////////////////////////////
+// Documents
+/// Open file, with everything found out about it.
+pub struct Document {
+    parsed: parser::Document,
+    diagnostics: Vec<LspDiagnostic>,
+    typings: Vec<Typing>,
+    // types of top-level names
+    globals: Vec<(Identifier, String)>,
+}
+
+fn diagnostic(text: &str, problem: &dyn Diagnostic) -> LspDiagnostic {
+    let bytes = problem
+        .labels()
+        .and_then(|mut labels| labels.next())
+        .map_or(0..0, |label| label.offset()..label.offset() + label.len());
+    let severity = match problem.severity() {
+        Some(Severity::Advice) => DiagnosticSeverity::HINT,
+        Some(Severity::Warning) => DiagnosticSeverity::WARNING,
+        _ => DiagnosticSeverity::ERROR,
+    };
+    let mut message = problem.to_string();
+    if let Some(help) = problem.help() {
+        message.push_str(&format!("\n{help}"));
+    }
+    LspDiagnostic {
+        range: range(text, bytes),
+        severity: Some(severity),
+        source: Some("pie".to_string()),
+        message,
+        ..Default::default()
+    }
+}
+
+impl Document {
+    /// Parse and check `text', going as far as `pie' would.
+    pub fn new(text: String) -> Result<Document> {
+        let mut doc = Document {
+            parsed: parser::Document::new(text)?,
+            diagnostics: vec![],
+            typings: vec![],
+            globals: vec![],
+        };
+        doc.check();
+        Ok(doc)
+    }
+
+    /// Apply `changes' in order and check the result again.  Only the
+    /// statements they touch are parsed again.  A change whose range
+    /// doesn't make sense is rejected along with the ones after it,
+    /// which were meant for the text it would have made; the text stays
+    /// as it was before it, and the client has to send it all again.
+    pub fn edit(&mut self, changes: Vec<TextDocumentContentChangeEvent>) -> Result<()> {
+        let res = changes
+            .into_iter()
+            .try_for_each(|change| self.change(change));
+        self.check();
+        res
+    }
+
+    fn change(&mut self, change: TextDocumentContentChangeEvent) -> Result<()> {
+        let text = self.parsed.text();
+        let bytes = match change.range {
+            Some(range) => offset(text, range.start)?..offset(text, range.end)?,
+            None => 0..text.len(),
+        };
+        self.parsed.edit(bytes, &change.text)?;
+        Ok(())
+    }
+
+    // add `problems' to the diagnostics, returning how many are errors
+    fn report<E: Diagnostic>(&mut self, problems: impl IntoIterator<Item = E>) -> usize {
+        let mut fatal = 0;
+        for problem in problems {
+            if problem.severity() != Some(Severity::Warning) {
+                fatal += 1;
+            }
+            let diagnostic = diagnostic(self.parsed.text(), &problem);
+            self.diagnostics.push(diagnostic);
+        }
+        fatal
+    }
+
+    fn check(&mut self) {
+        let text = self.parsed.text();
+        self.diagnostics = self
+            .parsed
+            .errors()
+            .map(|err| diagnostic(text, err))
+            .collect();
+        self.typings.clear();
+        self.globals.clear();
+
+        let mut source = self.parsed.source();
+        if !self.diagnostics.is_empty() || self.report(desugar::desugar(&mut source)) > 0 {
+            return;
+        }
+        let (defs, problems) = elab::definitions(&source);
+        if self.report(problems) > 0 || self.report(resolve::resolve(&source)) > 0 {
+            return;
+        }
+
+        let mut ctx = Context::recording();
+        for checked in check::check(&mut ctx, &source.statements, &defs) {
+            self.report(checked.holes);
+            if let Err(err) = checked.normal {
+                self.report([err]);
+            }
+        }
+        self.typings = ctx.typings();
+        self.globals = source
+            .statements
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::Claim(Claim { ident, .. }) => {
+                    Some((*ident, ctx.claimed(ident)?.to_string()))
+                }
+                _ => None,
+            })
+            .collect();
+    }
+
+    fn text(&self, node: &Node) -> &str {
+        &self.parsed.text()[node.byte_range()]
+    }
+
+    fn range(&self, node: &Node) -> Range {
+        range(self.parsed.text(), node.byte_range())
+    }
+
+    // variable under the cursor, also right after it
+    fn identifier_at(&self, position: Position) -> Option<Node<'_>> {
+        let offset = offset(self.parsed.text(), position).ok()?;
+        let root = self.parsed.tree().root_node();
+        [offset, offset.saturating_sub(1)]
+            .into_iter()
+            .filter_map(|offset| root.descendant_for_byte_range(offset, offset))
+            .find(|node| {
+                node.kind() == "identifier"
+                    && node.parent().map(|parent| parent.kind()) != Some("atom")
+            })
+    }
+
+    /// Type of the variable under the cursor.
+    pub fn hover(&self, position: Position) -> Option<Hover> {
+        let node = self.identifier_at(position)?;
+        let ident = Identifier::new(self.text(&node));
+        let start = node.start_byte();
+        let ty = self
+            .typings
+            .iter()
+            .filter(|typing| typing.ident == ident)
+            .filter(|typing| {
+                (typing.loc.offset()..typing.loc.offset() + typing.loc.len()).contains(&start)
+            })
+            .min_by_key(|typing| typing.loc.len())
+            .map(|typing| &typing.ty)
+            .or_else(|| {
+                self.globals
+                    .iter()
+                    .find(|(name, _)| *name == ident)
+                    .map(|(_, ty)| ty)
+            })?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```pie\n{ident} : {ty}\n```"),
+            }),
+            range: Some(self.range(&node)),
+        })
+    }
+
+    // the variable called `name' bound by `node' where `reference' is,
+    // if any: `Π' and `Σ' binders are in scope in the types of the ones
+    // after them, and every binder is its own definition
+    fn binder<'a>(&self, node: Node<'a>, name: &str, reference: Node) -> Option<Node<'a>> {
+        let mut cursor = node.walk();
+        // binders with the extent of the text they are not in scope of
+        let binders: Vec<(Node, Node)> = match node.kind() {
+            "lambda" => node
+                .children_by_field_name("arguments", &mut cursor)
+                .map(|binder| (binder, binder))
+                .collect(),
+            "pi_type" | "sigma_type" => node
+                .children_by_field_name("binders", &mut cursor)
+                .filter_map(|typed| Some((typed.child_by_field_name("identifier")?, typed)))
+                .collect(),
+            _ => vec![],
+        };
+        binders
+            .into_iter()
+            .filter(|(binder, extent)| {
+                *binder == reference || extent.end_byte() <= reference.start_byte()
+            })
+            .map(|(binder, _)| binder)
+            .rfind(|binder| self.text(binder) == name)
+    }
+
+    /// Where the variable under the cursor is bound: the closest `λ',
+    /// `Π' or `Σ', or else its `claim' and `define'.
+    pub fn definition(&self, position: Position) -> Vec<Range> {
+        let Some(node) = self.identifier_at(position) else {
+            return vec![];
+        };
+        let name = self.text(&node);
+        let mut parent = node.parent();
+        while let Some(scope) = parent {
+            if let Some(binder) = self.binder(scope, name, node) {
+                return vec![self.range(&binder)];
+            }
+            parent = scope.parent();
+        }
+        let root = self.parsed.tree().root_node();
+        let mut cursor = root.walk();
+        let globals = root
+            .children(&mut cursor)
+            .filter(|stmt| matches!(stmt.kind(), "claim" | "define"))
+            .filter_map(|stmt| stmt.child_by_field_name("identifier"))
+            .filter(|ident| self.text(ident) == name)
+            .map(|ident| self.range(&ident))
+            .collect();
+        globals
+    }
+
+    /// Every top-level `claim'.
+    pub fn symbols(&self) -> Vec<DocumentSymbol> {
+        let root = self.parsed.tree().root_node();
+        let mut cursor = root.walk();
+        let symbols = root
+            .children(&mut cursor)
+            .filter(|stmt| stmt.kind() == "claim")
+            .filter_map(|claim| {
+                let ident = claim.child_by_field_name("identifier")?;
+                let ty = claim.child_by_field_name("type")?;
+                let name = self.text(&ident);
+                let detail = self
+                    .globals
+                    .iter()
+                    .find(|(global, _)| global.as_str() == name)
+                    .map_or_else(|| self.text(&ty).to_string(), |(_, ty)| ty.clone());
+                let kind = match ty.child(0).map(|ty| ty.kind()) {
+                    Some("function_type" | "pi_type") => SymbolKind::FUNCTION,
+                    _ => SymbolKind::CONSTANT,
+                };
+                #[allow(deprecated)]
+                Some(DocumentSymbol {
+                    name: name.to_string(),
+                    detail: Some(detail),
+                    kind,
+                    tags: None,
+                    deprecated: None,
+                    range: self.range(&claim),
+                    selection_range: self.range(&ident),
+                    children: None,
+                })
+            })
+            .collect();
+        symbols
+    }
+}
+
+////////////////////////////////////////////////
+// Server
+fn capabilities() -> ServerCapabilities {
+    ServerCapabilities {
+        text_document_sync: Some(TextDocumentSyncCapability::Kind(
+            TextDocumentSyncKind::INCREMENTAL,
+        )),
+        hover_provider: Some(HoverProviderCapability::Simple(true)),
+        definition_provider: Some(OneOf::Left(true)),
+        document_symbol_provider: Some(OneOf::Left(true)),
+        ..Default::default()
+    }
+}
+
+// answer `req' with `handle', if its parameters make sense
+fn respond<R: RequestTrait>(req: Request, handle: impl FnOnce(R::Params) -> R::Result) -> Response {
+    match serde_json::from_value::<R::Params>(req.params) {
+        Ok(params) => Response::new_ok(req.id, handle(params)),
+        Err(err) => Response::new_err(req.id, ErrorCode::InvalidParams as i32, err.to_string()),
+    }
+}
+
+fn request(documents: &HashMap<Url, Document>, req: Request) -> Response {
+    match req.method.as_str() {
+        HoverRequest::METHOD => respond::<HoverRequest>(req, |params| {
+            let at = params.text_document_position_params;
+            documents.get(&at.text_document.uri)?.hover(at.position)
+        }),
+        GotoDefinition::METHOD => respond::<GotoDefinition>(req, |params| {
+            let at = params.text_document_position_params;
+            let uri = at.text_document.uri;
+            let ranges = documents.get(&uri)?.definition(at.position);
+            let locations = ranges
+                .into_iter()
+                .map(|range| Location::new(uri.clone(), range))
+                .collect();
+            Some(GotoDefinitionResponse::Array(locations))
+        }),
+        DocumentSymbolRequest::METHOD => respond::<DocumentSymbolRequest>(req, |params| {
+            let doc = documents.get(&params.text_document.uri)?;
+            Some(DocumentSymbolResponse::Nested(doc.symbols()))
+        }),
+        method => Response::new_err(
+            req.id,
+            ErrorCode::MethodNotFound as i32,
+            format!("`{method}' is not supported"),
+        ),
+    }
+}
+
+fn publish(connection: &Connection, uri: Url, diagnostics: Vec<LspDiagnostic>) -> Result<()> {
+    let params = PublishDiagnosticsParams {
+        uri,
+        diagnostics,
+        version: None,
+    };
+    let not = Notification::new(PublishDiagnostics::METHOD.to_string(), params);
+    connection.sender.send(Message::Notification(not))?;
+    Ok(())
+}
+
+fn log(connection: &Connection, typ: MessageType, message: String) -> Result<()> {
+    let params = LogMessageParams { typ, message };
+    let not = Notification::new(LogMessage::METHOD.to_string(), params);
+    connection.sender.send(Message::Notification(not))?;
+    Ok(())
+}
+
+/// Answer the client on the other end of `connection' until it shuts
+/// the server down.  Use `Connection::stdio' for editors, or
+/// `Connection::memory' to talk to the server in-process.  Names in
+/// the documents stay interned after they are edited away, see
+/// `Identifier'.
+pub fn serve(connection: &Connection) -> Result<()> {
+    connection.initialize(serde_json::to_value(capabilities())?)?;
+
+    let mut documents: HashMap<Url, Document> = HashMap::new();
+    for msg in &connection.receiver {
+        match msg {
+            Message::Request(req) => {
+                if connection.handle_shutdown(&req)? {
+                    return Ok(());
+                }
+                connection
+                    .sender
+                    .send(Message::Response(request(&documents, req)))?;
+            }
+            Message::Notification(not) => match not.method.as_str() {
+                DidOpenTextDocument::METHOD => {
+                    let params = not.extract::<lsp_types::DidOpenTextDocumentParams>(
+                        DidOpenTextDocument::METHOD,
+                    );
+                    let Ok(params) = params else { continue };
+                    let uri = params.text_document.uri;
+                    match Document::new(params.text_document.text) {
+                        Ok(doc) => {
+                            publish(connection, uri.clone(), doc.diagnostics.clone())?;
+                            documents.insert(uri, doc);
+                        }
+                        // the other documents are still fine
+                        Err(err) => {
+                            documents.remove(&uri);
+                            let message = format!("{err}; {uri} is not checked");
+                            log(connection, MessageType::ERROR, message)?;
+                            publish(connection, uri, vec![])?;
+                        }
+                    }
+                }
+                DidChangeTextDocument::METHOD => {
+                    let params = not.extract::<lsp_types::DidChangeTextDocumentParams>(
+                        DidChangeTextDocument::METHOD,
+                    );
+                    let Ok(params) = params else { continue };
+                    let uri = params.text_document.uri;
+                    if let Some(doc) = documents.get_mut(&uri) {
+                        if let Err(err) = doc.edit(params.content_changes) {
+                            let help = err.help().map_or(String::new(), |help| help.to_string());
+                            let message = format!(
+                                "{err}: {help}; {uri} is out of sync, reopen it to send all of it again"
+                            );
+                            log(connection, MessageType::ERROR, message)?;
+                        }
+                        publish(connection, uri, doc.diagnostics.clone())?;
+                    }
+                }
+                DidCloseTextDocument::METHOD => {
+                    let params = not.extract::<lsp_types::DidCloseTextDocumentParams>(
+                        DidCloseTextDocument::METHOD,
+                    );
+                    if let Ok(params) = params {
+                        documents.remove(&params.text_document.uri);
+                        publish(connection, params.text_document.uri, vec![])?;
+                    }
+                }
+                _ => (),
+            },
+            Message::Response(_) => (),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use lsp_server::RequestId;
+    use serde_json::{json, Value};
+    use std::thread::JoinHandle;
+    use std::time::Duration;
+
+    const URI: &str = "file:///test.pie";
+    const TEXT: &str =
+        "(claim one Nat)\n(define one 1)\n\n(claim id (→ Nat Nat))\n(define id (λ (x) x))\n";
+
+    // editor talking to a server running in another thread
+    struct Client {
+        connection: Connection,
+        server: JoinHandle<Result<()>>,
+        requests: i32,
+    }
+
+    impl Client {
+        fn new() -> (Client, Value) {
+            let (server, connection) = Connection::memory();
+            let mut client = Client {
+                connection,
+                server: std::thread::spawn(move || serve(&server)),
+                requests: 0,
+            };
+            let init = client.request("initialize", json!({ "capabilities": {} }));
+            client.notify("initialized", json!({}));
+            (client, init)
+        }
+
+        fn open(text: &str) -> (Client, Vec<LspDiagnostic>) {
+            let (client, _) = Client::new();
+            client.notify(
+                DidOpenTextDocument::METHOD,
+                json!({ "textDocument": {
+                    "uri": URI, "languageId": "pie", "version": 1, "text": text,
+                }}),
+            );
+            let diagnostics = client.diagnostics();
+            (client, diagnostics)
+        }
+
+        fn receive(&self) -> Message {
+            self.connection
+                .receiver
+                .recv_timeout(Duration::from_secs(10))
+                .expect("server didn't answer")
+        }
+
+        fn notify(&self, method: &str, params: Value) {
+            let not = Notification::new(method.to_string(), params);
+            self.connection
+                .sender
+                .send(Message::Notification(not))
+                .unwrap();
+        }
+
+        fn request(&mut self, method: &str, params: Value) -> Value {
+            self.requests += 1;
+            let id = RequestId::from(self.requests);
+            let req = Request::new(id.clone(), method.to_string(), params);
+            self.connection.sender.send(Message::Request(req)).unwrap();
+            match self.receive() {
+                Message::Response(res) if res.id == id => res.result.unwrap(),
+                msg => panic!("expected an answer, got {msg:?}"),
+            }
+        }
+
+        fn at(&mut self, method: &str, line: u32, character: u32) -> Value {
+            self.request(
+                method,
+                json!({
+                    "textDocument": { "uri": URI },
+                    "position": { "line": line, "character": character },
+                }),
+            )
+        }
+
+        // next notification, which has to be `method'
+        fn notification(&self, method: &str) -> Value {
+            match self.receive() {
+                Message::Notification(not) if not.method == method => not.params,
+                msg => panic!("expected `{method}', got {msg:?}"),
+            }
+        }
+
+        fn diagnostics(&self) -> Vec<LspDiagnostic> {
+            let params = self.notification(PublishDiagnostics::METHOD);
+            serde_json::from_value::<PublishDiagnosticsParams>(params)
+                .unwrap()
+                .diagnostics
+        }
+
+        fn change(&self, changes: Value) {
+            self.notify(
+                DidChangeTextDocument::METHOD,
+                json!({
+                    "textDocument": { "uri": URI, "version": 2 },
+                    "contentChanges": changes,
+                }),
+            );
+        }
+
+        fn shutdown(mut self) {
+            self.request("shutdown", Value::Null);
+            self.notify("exit", Value::Null);
+            self.server.join().unwrap().unwrap();
+        }
+    }
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Value {
+        json!({
+            "start": { "line": start.0, "character": start.1 },
+            "end": { "line": end.0, "character": end.1 },
+        })
+    }
+
+    #[test]
+    fn test_initialize() {
+        let (client, init) = Client::new();
+        let capabilities = &init["capabilities"];
+        assert_eq!(capabilities["textDocumentSync"], json!(2));
+        assert_eq!(capabilities["hoverProvider"], json!(true));
+        assert_eq!(capabilities["definitionProvider"], json!(true));
+        assert_eq!(capabilities["documentSymbolProvider"], json!(true));
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let (client, diagnostics) = Client::open(TEXT);
+        assert!(diagnostics.is_empty());
+        client.shutdown();
+
+        let (client, diagnostics) = Client::open("(claim one Nat)\n(define one 'one)\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(diagnostics[0].range.start, Position::new(1, 12));
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_hover() {
+        let (mut client, _) = Client::open(TEXT);
+        let hover = client.at(HoverRequest::METHOD, 4, 18);
+        assert_eq!(hover["contents"]["value"], json!("```pie\nx : Nat\n```"));
+        let hover = client.at(HoverRequest::METHOD, 1, 9);
+        assert_eq!(hover["contents"]["value"], json!("```pie\none : Nat\n```"));
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_definition() {
+        let (mut client, _) = Client::open(TEXT);
+        let local = client.at(GotoDefinition::METHOD, 4, 18);
+        assert_eq!(
+            local,
+            json!([{ "uri": URI, "range": range((4, 15), (4, 16)) }])
+        );
+        let global = client.at(GotoDefinition::METHOD, 1, 9);
+        assert_eq!(
+            global,
+            json!([
+                { "uri": URI, "range": range((0, 7), (0, 10)) },
+                { "uri": URI, "range": range((1, 8), (1, 11)) },
+            ])
+        );
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_definition_scope() {
+        let text = "(claim n Nat)\n(claim f (Π ((A U) (x A) (A Nat) (n (Vec A n))) A))\n";
+        let (mut client, _) = Client::open(text);
+        // the type of `x' only sees the first `A'
+        let earlier = client.at(GotoDefinition::METHOD, 1, 22);
+        assert_eq!(
+            earlier,
+            json!([{ "uri": URI, "range": range((1, 14), (1, 15)) }])
+        );
+        let later = client.at(GotoDefinition::METHOD, 1, 48);
+        assert_eq!(
+            later,
+            json!([{ "uri": URI, "range": range((1, 26), (1, 27)) }])
+        );
+        // nor is a binder in scope in its own type
+        let own = client.at(GotoDefinition::METHOD, 1, 43);
+        assert_eq!(own, json!([{ "uri": URI, "range": range((0, 7), (0, 8)) }]));
+        let binder = client.at(GotoDefinition::METHOD, 1, 26);
+        assert_eq!(
+            binder,
+            json!([{ "uri": URI, "range": range((1, 26), (1, 27)) }])
+        );
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let (mut client, _) = Client::open(TEXT);
+        let symbols = client.request(
+            DocumentSymbolRequest::METHOD,
+            json!({ "textDocument": { "uri": URI } }),
+        );
+        let symbols: Vec<DocumentSymbol> = serde_json::from_value(symbols).unwrap();
+        let symbols: Vec<_> = symbols
+            .iter()
+            .map(|symbol| (symbol.name.as_str(), symbol.kind, symbol.detail.as_deref()))
+            .collect();
+        assert_eq!(
+            symbols,
+            [
+                ("one", SymbolKind::CONSTANT, Some("Nat")),
+                ("id", SymbolKind::FUNCTION, Some("(→ Nat Nat)")),
+            ]
+        );
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_incremental_change() {
+        let (mut client, _) = Client::open(TEXT);
+        // `1' becomes an atom, then a number again along with a missing
+        // parenthesis further down
+        client.change(json!([{ "range": range((1, 12), (1, 13)), "text": "'a" }]));
+        let diagnostics = client.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].range,
+            serde_json::from_value(range((1, 12), (1, 14))).unwrap()
+        );
+        client.change(json!([
+            { "range": range((1, 12), (1, 14)), "text": "1" },
+            { "range": range((4, 19), (4, 20)), "text": "" },
+        ]));
+        let diagnostics = client.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.starts_with("Missing `)'"));
+        // the tree still answers for the edited text
+        let local = client.at(GotoDefinition::METHOD, 4, 18);
+        assert_eq!(
+            local,
+            json!([{ "uri": URI, "range": range((4, 15), (4, 16)) }])
+        );
+        client.change(json!([{ "text": TEXT }]));
+        assert!(client.diagnostics().is_empty());
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_reversed_range() {
+        let (client, _) = Client::open(TEXT);
+        client.change(json!([
+            { "range": range((0, 7), (0, 10)), "text": "two" },
+            { "range": range((4, 0), (1, 0)), "text": "(claim three Nat)" },
+            { "range": range((1, 8), (1, 11)), "text": "two" },
+        ]));
+        let log = client.notification(LogMessage::METHOD);
+        assert_eq!(log["type"], json!(MessageType::ERROR));
+        // the first change stands, the ones from the reversed range on
+        // are dropped: `one' is defined without a claim
+        let diagnostics = client.diagnostics();
+        let messages: Vec<_> = diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.message.lines().next().unwrap())
+            .collect();
+        assert_eq!(messages, ["Missing claim", "Missing definition"]);
+        assert_eq!(diagnostics[0].range.start, Position::new(1, 0));
+        // until the client sends all of the text again
+        client.change(json!([{ "text": TEXT }]));
+        assert!(client.diagnostics().is_empty());
+        client.shutdown();
+    }
+
+    #[test]
+    fn test_range_past_the_end() {
+        let (mut client, _) = Client::open(TEXT);
+        client.change(json!([{ "range": range((9, 0), (9, 0)), "text": "'a" }]));
+        let log = client.notification(LogMessage::METHOD);
+        assert_eq!(log["type"], json!(MessageType::ERROR));
+        assert!(client.diagnostics().is_empty());
+        let symbols = client.request(
+            DocumentSymbolRequest::METHOD,
+            json!({ "textDocument": { "uri": URI } }),
+        );
+        assert_eq!(symbols.as_array().map(Vec::len), Some(2));
+        client.shutdown();
+    }
+}
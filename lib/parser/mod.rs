@@ -1,17 +1,269 @@
 pub mod ts;
 
-use crate::parser::ts::{Parser, Result};
+use crate::parser::ts::{syntax_errors, ParseError, Parser, Result};
 use crate::syntax;
 use miette::SourceSpan;
-use std::{borrow::Cow, io::Cursor};
+use std::collections::HashMap;
+use std::ops::Range;
 use tree_sitter as TS;
 use tree_sitter_pie as pie;
 
-pub fn parse<'a>(text: &mut Cow<'a, str>) -> Result<syntax::Source<SourceSpan>> {
+/// Parse `text', together with syntax errors in it.  Statements with
+/// errors are left out of the returned source.
+pub fn parse(text: &str) -> Result<(syntax::Source<SourceSpan>, Vec<ParseError>)> {
     let mut parser = TS::Parser::new();
     parser.set_language(pie::language())?;
 
-    let tree = parser.parse(text.to_mut(), None).unwrap();
-    let mut cursor = Cursor::new(text.to_mut());
-    Parser::new(&tree.root_node(), &mut cursor)
+    let tree = parser.parse(text, None).ok_or(ParseError::Aborted)?;
+    let mut errors = syntax_errors(&tree.root_node(), text)?;
+    let (source, problems) = ts::source(&tree.root_node(), text);
+    errors.extend(problems);
+    Ok((source, errors))
+}
+
+////////////////////////////////////////////////
+// Documents
+// top-level node of a document, as it was parsed
+struct Entry {
+    bytes: Range<usize>,
+    statement: Option<syntax::Statement<SourceSpan>>,
+    errors: Vec<ParseError>,
+}
+
+fn entry(node: &TS::Node, text: &str) -> Entry {
+    let mut errors = syntax_errors(node, text).unwrap_or_else(|err| vec![err]);
+    let mut statement = None;
+    if node.is_named() && !node.has_error() {
+        match <syntax::Statement<SourceSpan> as Parser>::new(node, text) {
+            Ok(stmt) => statement = Some(stmt),
+            Err(err) => errors.push(err),
+        }
+    }
+    Entry {
+        bytes: node.byte_range(),
+        statement,
+        errors,
+    }
+}
+
+// row and byte column of `offset' in `text', which has to be a character
+// boundary
+fn point(text: &str, offset: usize) -> TS::Point {
+    let before = &text[..offset];
+    let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+    TS::Point {
+        row: before.matches('\n').count(),
+        column: offset - line_start,
+    }
+}
+
+/// Text that is parsed again after every edit, reusing the previous
+/// tree and the statements that the edit didn't touch.
+pub struct Document {
+    parser: TS::Parser,
+    text: String,
+    tree: TS::Tree,
+    entries: Vec<Entry>,
+}
+
+impl Document {
+    /// Parse `text' from scratch.
+    pub fn new(text: String) -> Result<Document> {
+        let mut parser = TS::Parser::new();
+        parser.set_language(pie::language())?;
+        let tree = parser.parse(&text, None).ok_or(ParseError::Aborted)?;
+        let mut doc = Document {
+            parser,
+            text,
+            tree,
+            entries: vec![],
+        };
+        doc.rebuild(vec![]);
+        Ok(doc)
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn tree(&self) -> &TS::Tree {
+        &self.tree
+    }
+
+    /// Statements without syntax errors.
+    pub fn source(&self) -> syntax::Source<SourceSpan> {
+        let root = self.tree.root_node();
+        syntax::Source {
+            ann: SourceSpan::from(root.byte_range()),
+            statements: self
+                .entries
+                .iter()
+                .filter_map(|entry| entry.statement.clone())
+                .collect(),
+        }
+    }
+
+    /// Syntax errors, statement by statement.
+    pub fn errors(&self) -> impl Iterator<Item = &ParseError> {
+        self.entries.iter().flat_map(|entry| entry.errors.iter())
+    }
+
+    /// Replace `bytes' of the text with `replacement'.  The range has to
+    /// go forward and lie on character boundaries of the text.
+    pub fn edit(&mut self, bytes: Range<usize>, replacement: &str) -> Result<()> {
+        if bytes.start > bytes.end
+            || !self.text.is_char_boundary(bytes.start)
+            || !self.text.is_char_boundary(bytes.end)
+        {
+            return Err(ParseError::from(ts::Edit {
+                bytes,
+                len: self.text.len(),
+            }));
+        }
+        let start = bytes.start;
+        let old_end = bytes.end;
+        let new_end = start + replacement.len();
+        let start_position = point(&self.text, start);
+        let old_end_position = point(&self.text, old_end);
+        self.text.replace_range(bytes, replacement);
+        self.tree.edit(&TS::InputEdit {
+            start_byte: start,
+            old_end_byte: old_end,
+            new_end_byte: new_end,
+            start_position,
+            old_end_position,
+            new_end_position: point(&self.text, new_end),
+        });
+        self.tree = self
+            .parser
+            .parse(&self.text, Some(&self.tree))
+            .ok_or(ParseError::Aborted)?;
+
+        // statements around the edit are still the same, once they are
+        // moved to where they are now
+        let delta = new_end as isize - old_end as isize;
+        let mut entries = std::mem::take(&mut self.entries);
+        entries.retain(|entry| {
+            entry.errors.is_empty()
+                && entry.statement.is_some()
+                && (entry.bytes.end <= start || entry.bytes.start >= old_end)
+        });
+        for entry in entries
+            .iter_mut()
+            .filter(|entry| entry.bytes.start >= old_end)
+        {
+            let shift = |offset: usize| (offset as isize + delta) as usize;
+            entry.bytes = shift(entry.bytes.start)..shift(entry.bytes.end);
+            if let Some(stmt) = entry.statement.as_mut() {
+                stmt.for_each_ann(&mut |ann| {
+                    *ann = SourceSpan::from(shift(ann.offset())..shift(ann.offset() + ann.len()));
+                });
+            }
+        }
+        self.rebuild(entries);
+        Ok(())
+    }
+
+    // entries for the current tree, taken from `old' where possible
+    fn rebuild(&mut self, old: Vec<Entry>) {
+        let root = self.tree.root_node();
+        if root.kind() != "source" {
+            let (_, problems) = ts::source(&root, &self.text);
+            let mut entry = entry(&root, &self.text);
+            entry.errors.extend(problems);
+            self.entries = vec![entry];
+            return;
+        }
+        let mut old: HashMap<Range<usize>, Entry> = old
+            .into_iter()
+            .map(|entry| (entry.bytes.clone(), entry))
+            .collect();
+        let mut cursor = root.walk();
+        self.entries = root
+            .children(&mut cursor)
+            .filter(|node| node.kind() != "comment")
+            .map(|node| match old.remove(&node.byte_range()) {
+                Some(entry) if !node.has_error() => entry,
+                _ => entry(&node, &self.text),
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "(claim one Nat)\n(define one 1)\n\n(claim two Nat)\n(define two 2)\n";
+
+    // `doc' has to look as if its text was parsed from scratch
+    fn same_as_parse(doc: &Document) {
+        let (source, errors) = parse(doc.text()).unwrap();
+        assert_eq!(format!("{:?}", doc.source()), format!("{source:?}"));
+        let doc_errors: Vec<_> = doc.errors().map(|err| format!("{err:?}")).collect();
+        let errors: Vec<_> = errors.iter().map(|err| format!("{err:?}")).collect();
+        assert_eq!(doc_errors, errors);
+    }
+
+    fn edited(edits: &[(Range<usize>, &str)]) -> Document {
+        let mut doc = Document::new(TEXT.to_string()).unwrap();
+        for (bytes, replacement) in edits {
+            doc.edit(bytes.clone(), replacement).unwrap();
+            same_as_parse(&doc);
+        }
+        doc
+    }
+
+    #[test]
+    fn test_insert_statement() {
+        let doc = edited(&[(31..31, "(claim zero Nat)\n")]);
+        assert_eq!(doc.source().statements.len(), 5);
+    }
+
+    #[test]
+    fn test_delete_statement() {
+        let doc = edited(&[(16..31, "")]);
+        assert_eq!(doc.source().statements.len(), 3);
+    }
+
+    #[test]
+    fn test_edit_across_statements() {
+        let doc = edited(&[(10..39, " ")]);
+        assert!(doc.text().starts_with("(claim one two Nat)"));
+    }
+
+    #[test]
+    fn test_edit_inside_statement() {
+        edited(&[(28..29, "(add1 0)"), (28..36, "0")]);
+    }
+
+    #[test]
+    fn test_break_and_mend() {
+        let doc = edited(&[(14..15, "")]);
+        assert!(doc.errors().next().is_some());
+        let doc = edited(&[(14..15, ""), (14..14, ")")]);
+        assert!(doc.errors().next().is_none());
+    }
+
+    #[test]
+    fn test_reused_statements_move() {
+        let mut doc = Document::new(TEXT.to_string()).unwrap();
+        doc.edit(0..0, "\n\n\n").unwrap();
+        let syntax::Statement::Claim(claim) = &doc.source().statements[2] else {
+            panic!("expected a claim");
+        };
+        assert_eq!(claim.ann, SourceSpan::from(35..50));
+        assert_eq!(claim.expr.ann(), &SourceSpan::from(46..49));
+    }
+
+    #[test]
+    fn test_bad_edits() {
+        let mut doc = Document::new("(claim λ-x Nat)".to_string()).unwrap();
+        // backwards, past the end, and inside `λ'
+        for (start, end) in [(3, 1), (0, 100), (8, 9)] {
+            let res = doc.edit(start..end, "x");
+            assert!(matches!(res, Err(ParseError::Edit(_))));
+        }
+        assert_eq!(doc.text(), "(claim λ-x Nat)");
+    }
 }
@@ -1,21 +1,19 @@
 #[allow(unused_imports)]
 use crate::syntax::{
-    Apply, Atom, Claim, Define, Expression, Identifier, Lambda, Source, Statement, Type, Variable,
+    Add1, Apply, Arrow, Atom, Base, Binder, Car, Cdr, CheckSame, Claim, Cong, Cons, Define, Either,
+    Equal, Expression, Head, Identifier, IndAbsurd, IndEither, IndEq, IndList, IndNat, IndVec,
+    IterNat, Lambda, Left, List, ListCons, Nil, Numeral, Pair, Pi, RecList, RecNat, Replace, Right,
+    Same, Sigma, Sole, Source, Statement, Symm, Tail, The, Todo, Trans, Type, TypedBinder,
+    Universe, Variable, VecCons, VecNil, Vector, WhichNat, Zero,
 };
 use miette::{Diagnostic, SourceOffset, SourceSpan};
 use std::ops::Range;
-use std::{
-    io::{Read, Seek, SeekFrom},
-    num::TryFromIntError,
-};
 use thiserror::Error;
 use tree_sitter::Node;
 
 ////////////////////////////////////////////////
 // Type aliases
 pub type Result<T> = std::result::Result<T, ParseError>;
-// see: https://github.com/rust-lang/rust/issues/63063
-//pub type Soure = impl Read + Seek;
 
 ////////////////////////////////////////////////
 // Errors
@@ -31,6 +29,25 @@ pub struct Mismatch {
     pub expected: String,
 }
 
+#[derive(Debug, Diagnostic, Error)]
+#[error("Unexpected input")]
+#[diagnostic(help("didn't expect `{}' here", self.token))]
+pub struct Unexpected {
+    #[label("here")]
+    pub loc: SourceSpan,
+    // first token that doesn't fit
+    pub token: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Missing `{}'", self.token)]
+#[diagnostic(help("insert `{}' here", self.token))]
+pub struct Incomplete {
+    #[label("expected here")]
+    pub loc: SourceSpan,
+    pub token: String,
+}
+
 #[derive(Debug, Diagnostic, Error)]
 #[error("Missing data")]
 #[diagnostic(
@@ -44,30 +61,61 @@ pub struct Missing {
 }
 
 #[derive(Debug, Diagnostic, Error)]
-#[error("Couldn't read source")]
+#[error("Invalid number")]
 #[diagnostic()]
-pub struct Reading {
-    #[label("this thing right here")]
+pub struct Number {
+    #[label("this")]
     pub loc: SourceSpan,
     #[help]
     pub help: String,
 }
 
+#[derive(Debug, Diagnostic, Error)]
+#[error("Invalid edit")]
+#[diagnostic(help(
+    "can't replace bytes {}..{} of a text {} bytes long, edits have to go forward and lie on character boundaries",
+    self.bytes.start, self.bytes.end, self.len
+))]
+pub struct Edit {
+    pub bytes: Range<usize>,
+    pub len: usize,
+}
+
+// all the problems found in a source, to be shown in one report
+#[derive(Debug, Diagnostic, Error)]
+#[error("Found {} syntax errors", self.errors.len())]
+pub struct ParseErrors {
+    #[related]
+    pub errors: Vec<ParseError>,
+}
+
 #[derive(Debug, Diagnostic, Error)]
 pub enum ParseError {
     #[error("Input isn't valid UTF-8")]
     Encoding(#[from] std::str::Utf8Error),
     #[error("Tree Sitter error")]
     TreeSitter(#[from] tree_sitter::LanguageError),
+    // only happens if parsing is cancelled or times out
+    #[error("Tree Sitter gave up on the input")]
+    Aborted,
     #[error(transparent)]
     #[diagnostic(transparent)]
-    Reading(#[from] Reading),
+    Number(#[from] Number),
     #[error(transparent)]
     #[diagnostic(transparent)]
     Mismatch(#[from] Mismatch),
     #[error(transparent)]
     #[diagnostic(transparent)]
     Missing(#[from] Missing),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Unexpected(#[from] Unexpected),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Incomplete(#[from] Incomplete),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Edit(#[from] Edit),
 }
 
 fn mismatch(loc: SourceSpan, actual: impl Into<String>, expected: impl Into<String>) -> ParseError {
@@ -92,11 +140,94 @@ fn missing(loc: SourceSpan, token: &'static str, what: &'static str) -> ParseErr
     ParseError::Missing(Missing { loc, token, what })
 }
 
+////////////////////////////////////////////////
+// Syntax errors
+/// Report all the `ERROR' and `MISSING' nodes under `node'.
+pub fn syntax_errors(node: &Node, source: &str) -> Result<Vec<ParseError>> {
+    let mut errors = vec![];
+    collect_errors(node, source, &mut errors)?;
+    Ok(errors)
+}
+
+fn collect_errors(node: &Node, source: &str, errors: &mut Vec<ParseError>) -> Result<()> {
+    if node.is_missing() {
+        // tree sitter inserts any keyword that completes an expression
+        let kind = node.kind();
+        let token = if !node.is_named() && kind.chars().all(char::is_alphabetic) {
+            "expression"
+        } else {
+            kind
+        };
+        errors.push(ParseError::from(Incomplete {
+            loc: location(node),
+            token: token.to_string(),
+        }));
+    } else if node.is_error() {
+        // a `λ' that the grammar couldn't match ends up as an error holding
+        // its head, and maybe the parenthesis opening its arguments
+        let count = node.child_count();
+        let last = node.child(count.saturating_sub(1));
+        let open = last.filter(|last| last.kind() == "(");
+        let head = node.child(count.saturating_sub(1 + open.iter().len()));
+        match (head.filter(is_lambda_head), open, node.next_sibling()) {
+            (Some(_), Some(_), _) => {
+                let mut siblings = vec![];
+                let mut next = node.next_sibling();
+                while let Some(sibling) = next {
+                    siblings.push(sibling);
+                    next = sibling.next_sibling();
+                }
+                binders(&siblings, source, errors)?;
+            }
+            (Some(_), None, Some(next)) => {
+                let loc = location(&next);
+                errors.push(mismatch(
+                    loc,
+                    slice(loc, source),
+                    "a parenthesized list of variable names",
+                ));
+            }
+            _ => {
+                // point at the first token, the rest is most likely fine
+                let mut first = *node;
+                while let Some(child) = first.child(0) {
+                    first = child;
+                }
+                let loc = location(&first);
+                errors.push(ParseError::from(Unexpected {
+                    loc,
+                    token: slice(loc, source).to_string(),
+                }));
+            }
+        }
+    } else if node.kind() == "lambda" && node.has_error() {
+        let mut cursor = node.walk();
+        let children: Vec<_> = node.children(&mut cursor).collect();
+        // `(', the head, then the parenthesis opening the arguments
+        let rest = match children.get(2) {
+            Some(open) if open.kind() == "(" => {
+                let taken = binders(&children[3..], source, errors)?;
+                &children[3 + taken..]
+            }
+            _ => &children[..],
+        };
+        for child in rest {
+            collect_errors(child, source, errors)?;
+        }
+    } else if node.has_error() {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect_errors(&child, source, errors)?;
+        }
+    }
+    Ok(())
+}
+
 ////////////////////////////////////////////////
 // Trait
 pub trait Parser {
     const KIND: &'static str;
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Self>
+    fn new(node: &Node, source: &str) -> Result<Self>
     where
         Self: Sized;
 }
@@ -108,32 +239,112 @@ fn location(node: &Node) -> SourceSpan {
     SourceSpan::new(SourceOffset::from(start), SourceOffset::from(end - start))
 }
 
-// extracting data from source
-// perhaps use File as cursor, then `read_exact_at' is available.
-fn read(loc: SourceSpan, cursor: &mut (impl Read + Seek)) -> Result<String> {
-    let loc_ = loc.offset().try_into().map_err(|err: TryFromIntError| {
-        ParseError::Reading(Reading {
-            loc,
-            help: err.to_string(),
-        })
-    })?;
+// text of `node', sliced straight from the source
+fn text<'a>(node: &Node, source: &'a str) -> Result<&'a str> {
+    node.utf8_text(source.as_bytes())
+        .map_err(ParseError::Encoding)
+}
 
-    cursor.seek(SeekFrom::Start(loc_)).map_err(|err| {
-        ParseError::Reading(Reading {
-            loc,
-            help: err.to_string(),
-        })
-    })?;
+// text under `loc', which has to lie on character boundaries
+fn slice(loc: SourceSpan, source: &str) -> &str {
+    &source[loc.offset()..loc.offset() + loc.len()]
+}
 
-    let mut buf: Vec<u8> = vec![0; loc.len()];
-    cursor.read_exact(&mut buf).map_err(|err| {
-        ParseError::Reading(Reading {
-            loc,
-            help: err.to_string(),
-        })
-    })?;
+// argument of a `λ' spanning `from' to `to'
+fn not_a_binder(from: &Node, to: &Node, source: &str) -> ParseError {
+    let loc = SourceSpan::new(
+        from.start_byte().into(),
+        (to.end_byte() - from.start_byte()).into(),
+    );
+    mismatch(
+        loc,
+        slice(loc, source),
+        "a variable name (`λ' binds only variables)",
+    )
+}
+
+fn is_lambda_head(node: &Node) -> bool {
+    matches!(node.kind(), "\\" | "λ" | "lambda")
+}
+
+// report the arguments of a `λ' that aren't variables, given the `nodes'
+// after the parenthesis opening them, and return how many of them the
+// arguments span; error recovery splits parenthesized arguments across
+// `error' nodes, so these are flattened into their tokens first
+fn binders(nodes: &[Node], source: &str, errors: &mut Vec<ParseError>) -> Result<usize> {
+    fn tokens<'a>(node: Node<'a>, index: usize, acc: &mut Vec<(usize, Node<'a>)>) {
+        if node.is_error() && node.child_count() > 0 {
+            let mut cursor = node.walk();
+            for child in node.children(&mut cursor) {
+                tokens(child, index, acc);
+            }
+        } else {
+            // variables are wrapped in expressions outside a `λ'
+            let ident = node
+                .named_child(0)
+                .filter(|ident| node.kind() == "expression" && ident.kind() == "identifier");
+            acc.push((index, ident.unwrap_or(node)));
+        }
+    }
+    let mut acc = vec![];
+    for (index, node) in nodes.iter().enumerate() {
+        tokens(*node, index, &mut acc);
+    }
+
+    // start of the parenthesized argument or quoted symbol in progress
+    let mut open: Option<Node> = None;
+    let mut depth = 0;
+    for (index, token) in acc {
+        if token.is_missing() {
+            collect_errors(&token, source, errors)?;
+        }
+        match token.kind() {
+            ")" if depth == 0 => return Ok(index + 1),
+            "(" => {
+                open = open.or(Some(token));
+                depth += 1;
+            }
+            ")" => {
+                depth -= 1;
+                if depth == 0 {
+                    errors.push(not_a_binder(&open.take().unwrap_or(token), &token, source));
+                }
+            }
+            _ if depth > 0 => (),
+            "comment" => (),
+            "'" => open = Some(token),
+            "identifier" => {
+                if let Some(quote) = open.take() {
+                    errors.push(not_a_binder(&quote, &token, source));
+                }
+            }
+            _ => errors.push(not_a_binder(&open.take().unwrap_or(token), &token, source)),
+        }
+    }
+    Ok(nodes.len())
+}
 
-    String::from_utf8(buf).map_err(|err| ParseError::Encoding(err.utf8_error()))
+// parse mandatory `field' of `node'
+fn child<T: Parser>(node: &Node, field: &'static str, source: &str) -> Result<Box<T>> {
+    node.child_by_field_name(field)
+        .ok_or(missing(location(node), node.kind(), field))
+        .and_then(|child| Parser::new(&child, source))
+        .map(Box::new)
+}
+
+// numbers are a shorthand for `add1's, kept as one node however big
+fn number(node: &Node, source: &str) -> Result<Expression<SourceSpan>> {
+    let ann = location(node);
+    let n: usize = text(node, source)?
+        .parse()
+        .map_err(|err: std::num::ParseIntError| {
+            ParseError::Number(Number {
+                loc: ann,
+                help: err.to_string(),
+            })
+        })?;
+
+    Ok(Expression::Numeral(Numeral { ann, n }))
 }
 
 ////////////////////////////////////////////////
@@ -141,13 +352,13 @@ fn read(loc: SourceSpan, cursor: &mut (impl Read + Seek)) -> Result<String> {
 impl Parser for Atom<SourceSpan> {
     const KIND: &'static str = "atom";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Atom<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Atom<SourceSpan>> {
         let ann = location(node);
 
         let ident = node
             .child_by_field_name("identifier")
             .ok_or(missing(ann, node.kind(), "identifier"))
-            .and_then(|ident_node| read(location(&ident_node), source).map(Identifier))?;
+            .and_then(|ident_node| text(&ident_node, source).map(Identifier::new))?;
 
         Ok(Atom { ann, ident })
     }
@@ -156,33 +367,65 @@ impl Parser for Atom<SourceSpan> {
 impl Parser for Variable<SourceSpan> {
     const KIND: &'static str = "identifier";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Variable<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Variable<SourceSpan>> {
         let ann = location(node);
-        let ident = read(location(node), source).map(Identifier)?;
+        let ident = text(node, source).map(Identifier::new)?;
         Ok(Variable { ann, ident })
     }
 }
 
+impl Parser for Binder<SourceSpan> {
+    const KIND: &'static str = "identifier";
+
+    fn new(node: &Node, source: &str) -> Result<Binder<SourceSpan>> {
+        let ann = location(node);
+        let ident = text(node, source).map(Identifier::new)?;
+        Ok(Binder { ann, ident })
+    }
+}
+
 impl Parser for Type<SourceSpan> {
-    const KIND: &'static str = "type_identifier";
+    const KIND: &'static str = "base_type";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Type<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Type<SourceSpan>> {
         let ann = location(node);
-        let ident = read(location(node), source).map(Identifier)?;
-        Ok(Type { ann, ident })
+        let ty = match text(node, source)? {
+            "Atom" => Base::Atom,
+            "Nat" => Base::Nat,
+            "Trivial" => Base::Trivial,
+            "Absurd" => Base::Absurd,
+            name => {
+                return Err(mismatch(
+                    ann,
+                    name,
+                    one_of_msg(["`Atom'", "`Nat'", "`Trivial'", "`Absurd'"].iter()),
+                ))
+            }
+        };
+        Ok(Type { ann, ty })
+    }
+}
+
+impl Parser for Universe<SourceSpan> {
+    const KIND: &'static str = "universe";
+
+    fn new(node: &Node, _source: &str) -> Result<Universe<SourceSpan>> {
+        Ok(Universe {
+            ann: location(node),
+        })
     }
 }
 
 impl Parser for Lambda<SourceSpan> {
     const KIND: &'static str = "lambda";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Lambda<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Lambda<SourceSpan>> {
         let ann = location(node);
         let mut cursor = node.walk();
 
         let args = node
             .children_by_field_name("arguments", &mut cursor)
-            .map(|child| Parser::new(&child, source).map(Box::new))
+            .map(|child| Parser::new(&child, source))
             .collect::<Result<Vec<_>>>()?;
 
         let body = node
@@ -198,7 +441,7 @@ impl Parser for Lambda<SourceSpan> {
 impl Parser for Apply<SourceSpan> {
     const KIND: &'static str = "application";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Apply<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Apply<SourceSpan>> {
         let ann = location(node);
         let mut cursor = node.walk();
 
@@ -221,10 +464,563 @@ impl Parser for Apply<SourceSpan> {
     }
 }
 
+impl Parser for Arrow<SourceSpan> {
+    const KIND: &'static str = "function_type";
+
+    fn new(node: &Node, source: &str) -> Result<Arrow<SourceSpan>> {
+        let ann = location(node);
+        let mut cursor = node.walk();
+
+        let doms = node
+            .children_by_field_name("domain", &mut cursor)
+            .map(|child| Parser::new(&child, source).map(Box::new))
+            .collect::<Result<Vec<_>>>()?;
+
+        let cod = node
+            .child_by_field_name("codomain")
+            .ok_or(missing(ann, "function type", "codomain `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Arrow { ann, doms, cod })
+    }
+}
+
+impl Parser for TypedBinder<SourceSpan> {
+    const KIND: &'static str = "typed_binder";
+
+    fn new(node: &Node, source: &str) -> Result<TypedBinder<SourceSpan>> {
+        let ann = location(node);
+
+        let ident = node
+            .child_by_field_name("identifier")
+            .ok_or(missing(ann, node.kind(), "identifier"))
+            .and_then(|ident_node| text(&ident_node, source).map(Identifier::new))?;
+
+        let ty = node
+            .child_by_field_name("type")
+            .ok_or(missing(ann, "typed binder", "type `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(TypedBinder { ann, ident, ty })
+    }
+}
+
+impl Parser for Pi<SourceSpan> {
+    const KIND: &'static str = "pi_type";
+
+    fn new(node: &Node, source: &str) -> Result<Pi<SourceSpan>> {
+        let ann = location(node);
+        let mut cursor = node.walk();
+
+        let binders = node
+            .children_by_field_name("binders", &mut cursor)
+            .map(|child| Parser::new(&child, source))
+            .collect::<Result<Vec<_>>>()?;
+
+        let cod = node
+            .child_by_field_name("codomain")
+            .ok_or(missing(ann, "`Π' type", "codomain `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Pi { ann, binders, cod })
+    }
+}
+
+impl Parser for Sigma<SourceSpan> {
+    const KIND: &'static str = "sigma_type";
+
+    fn new(node: &Node, source: &str) -> Result<Sigma<SourceSpan>> {
+        let ann = location(node);
+        let mut cursor = node.walk();
+
+        let binders = node
+            .children_by_field_name("binders", &mut cursor)
+            .map(|child| Parser::new(&child, source))
+            .collect::<Result<Vec<_>>>()?;
+
+        let cdr_ty = node
+            .child_by_field_name("cdr_type")
+            .ok_or(missing(ann, "`Σ' type", "`cdr' type `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Sigma {
+            ann,
+            binders,
+            cdr_ty,
+        })
+    }
+}
+
+impl Parser for Pair<SourceSpan> {
+    const KIND: &'static str = "pair_type";
+
+    fn new(node: &Node, source: &str) -> Result<Pair<SourceSpan>> {
+        let ann = location(node);
+
+        let car_ty = node
+            .child_by_field_name("car_type")
+            .ok_or(missing(ann, "`Pair' type", "`car' type `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        let cdr_ty = node
+            .child_by_field_name("cdr_type")
+            .ok_or(missing(ann, "`Pair' type", "`cdr' type `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Pair {
+            ann,
+            car_ty,
+            cdr_ty,
+        })
+    }
+}
+
+impl Parser for Cons<SourceSpan> {
+    const KIND: &'static str = "cons";
+
+    fn new(node: &Node, source: &str) -> Result<Cons<SourceSpan>> {
+        let ann = location(node);
+
+        let car = node
+            .child_by_field_name("car")
+            .ok_or(missing(ann, "`cons'", "`car' `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        let cdr = node
+            .child_by_field_name("cdr")
+            .ok_or(missing(ann, "`cons'", "`cdr' `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Cons { ann, car, cdr })
+    }
+}
+
+impl Parser for Car<SourceSpan> {
+    const KIND: &'static str = "car";
+
+    fn new(node: &Node, source: &str) -> Result<Car<SourceSpan>> {
+        let ann = location(node);
+
+        let pair = node
+            .child_by_field_name("pair")
+            .ok_or(missing(ann, "`car'", "pair `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Car { ann, pair })
+    }
+}
+
+impl Parser for Cdr<SourceSpan> {
+    const KIND: &'static str = "cdr";
+
+    fn new(node: &Node, source: &str) -> Result<Cdr<SourceSpan>> {
+        let ann = location(node);
+
+        let pair = node
+            .child_by_field_name("pair")
+            .ok_or(missing(ann, "`cdr'", "pair `expression'"))
+            .and_then(|child| Parser::new(&child, source))
+            .map(Box::new)?;
+
+        Ok(Cdr { ann, pair })
+    }
+}
+
+impl Parser for Zero<SourceSpan> {
+    const KIND: &'static str = "zero";
+
+    fn new(node: &Node, _source: &str) -> Result<Zero<SourceSpan>> {
+        Ok(Zero {
+            ann: location(node),
+        })
+    }
+}
+
+impl Parser for Add1<SourceSpan> {
+    const KIND: &'static str = "add1";
+
+    fn new(node: &Node, source: &str) -> Result<Add1<SourceSpan>> {
+        Ok(Add1 {
+            ann: location(node),
+            n: child(node, "n", source)?,
+        })
+    }
+}
+
+impl Parser for WhichNat<SourceSpan> {
+    const KIND: &'static str = "which_nat";
+
+    fn new(node: &Node, source: &str) -> Result<WhichNat<SourceSpan>> {
+        Ok(WhichNat {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for IterNat<SourceSpan> {
+    const KIND: &'static str = "iter_nat";
+
+    fn new(node: &Node, source: &str) -> Result<IterNat<SourceSpan>> {
+        Ok(IterNat {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for RecNat<SourceSpan> {
+    const KIND: &'static str = "rec_nat";
+
+    fn new(node: &Node, source: &str) -> Result<RecNat<SourceSpan>> {
+        Ok(RecNat {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for IndNat<SourceSpan> {
+    const KIND: &'static str = "ind_nat";
+
+    fn new(node: &Node, source: &str) -> Result<IndNat<SourceSpan>> {
+        Ok(IndNat {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for List<SourceSpan> {
+    const KIND: &'static str = "list_type";
+
+    fn new(node: &Node, source: &str) -> Result<List<SourceSpan>> {
+        Ok(List {
+            ann: location(node),
+            elem: child(node, "element_type", source)?,
+        })
+    }
+}
+
+impl Parser for Nil<SourceSpan> {
+    const KIND: &'static str = "nil";
+
+    fn new(node: &Node, _source: &str) -> Result<Nil<SourceSpan>> {
+        Ok(Nil {
+            ann: location(node),
+        })
+    }
+}
+
+impl Parser for ListCons<SourceSpan> {
+    const KIND: &'static str = "list_cons";
+
+    fn new(node: &Node, source: &str) -> Result<ListCons<SourceSpan>> {
+        Ok(ListCons {
+            ann: location(node),
+            head: child(node, "head", source)?,
+            tail: child(node, "tail", source)?,
+        })
+    }
+}
+
+impl Parser for RecList<SourceSpan> {
+    const KIND: &'static str = "rec_list";
+
+    fn new(node: &Node, source: &str) -> Result<RecList<SourceSpan>> {
+        Ok(RecList {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for IndList<SourceSpan> {
+    const KIND: &'static str = "ind_list";
+
+    fn new(node: &Node, source: &str) -> Result<IndList<SourceSpan>> {
+        Ok(IndList {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for Vector<SourceSpan> {
+    const KIND: &'static str = "vec_type";
+
+    fn new(node: &Node, source: &str) -> Result<Vector<SourceSpan>> {
+        Ok(Vector {
+            ann: location(node),
+            elem: child(node, "element_type", source)?,
+            len: child(node, "length", source)?,
+        })
+    }
+}
+
+impl Parser for VecNil<SourceSpan> {
+    const KIND: &'static str = "vecnil";
+
+    fn new(node: &Node, _source: &str) -> Result<VecNil<SourceSpan>> {
+        Ok(VecNil {
+            ann: location(node),
+        })
+    }
+}
+
+impl Parser for VecCons<SourceSpan> {
+    const KIND: &'static str = "vec_cons";
+
+    fn new(node: &Node, source: &str) -> Result<VecCons<SourceSpan>> {
+        Ok(VecCons {
+            ann: location(node),
+            head: child(node, "head", source)?,
+            tail: child(node, "tail", source)?,
+        })
+    }
+}
+
+impl Parser for Head<SourceSpan> {
+    const KIND: &'static str = "head";
+
+    fn new(node: &Node, source: &str) -> Result<Head<SourceSpan>> {
+        Ok(Head {
+            ann: location(node),
+            vec: child(node, "vec", source)?,
+        })
+    }
+}
+
+impl Parser for Tail<SourceSpan> {
+    const KIND: &'static str = "tail";
+
+    fn new(node: &Node, source: &str) -> Result<Tail<SourceSpan>> {
+        Ok(Tail {
+            ann: location(node),
+            vec: child(node, "vec", source)?,
+        })
+    }
+}
+
+impl Parser for IndVec<SourceSpan> {
+    const KIND: &'static str = "ind_vec";
+
+    fn new(node: &Node, source: &str) -> Result<IndVec<SourceSpan>> {
+        Ok(IndVec {
+            ann: location(node),
+            len: child(node, "length", source)?,
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base: child(node, "base", source)?,
+            step: child(node, "step", source)?,
+        })
+    }
+}
+
+impl Parser for Equal<SourceSpan> {
+    const KIND: &'static str = "equal_type";
+
+    fn new(node: &Node, source: &str) -> Result<Equal<SourceSpan>> {
+        Ok(Equal {
+            ann: location(node),
+            ty: child(node, "type", source)?,
+            from: child(node, "from", source)?,
+            to: child(node, "to", source)?,
+        })
+    }
+}
+
+impl Parser for Same<SourceSpan> {
+    const KIND: &'static str = "same";
+
+    fn new(node: &Node, source: &str) -> Result<Same<SourceSpan>> {
+        Ok(Same {
+            ann: location(node),
+            expr: child(node, "expr", source)?,
+        })
+    }
+}
+
+impl Parser for Cong<SourceSpan> {
+    const KIND: &'static str = "cong";
+
+    fn new(node: &Node, source: &str) -> Result<Cong<SourceSpan>> {
+        Ok(Cong {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            fun: child(node, "function", source)?,
+        })
+    }
+}
+
+impl Parser for Replace<SourceSpan> {
+    const KIND: &'static str = "replace";
+
+    fn new(node: &Node, source: &str) -> Result<Replace<SourceSpan>> {
+        Ok(Replace {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base: child(node, "base", source)?,
+        })
+    }
+}
+
+impl Parser for Symm<SourceSpan> {
+    const KIND: &'static str = "symm";
+
+    fn new(node: &Node, source: &str) -> Result<Symm<SourceSpan>> {
+        Ok(Symm {
+            ann: location(node),
+            target: child(node, "target", source)?,
+        })
+    }
+}
+
+impl Parser for Trans<SourceSpan> {
+    const KIND: &'static str = "trans";
+
+    fn new(node: &Node, source: &str) -> Result<Trans<SourceSpan>> {
+        Ok(Trans {
+            ann: location(node),
+            left: child(node, "left", source)?,
+            right: child(node, "right", source)?,
+        })
+    }
+}
+
+impl Parser for IndEq<SourceSpan> {
+    const KIND: &'static str = "ind_eq";
+
+    fn new(node: &Node, source: &str) -> Result<IndEq<SourceSpan>> {
+        Ok(IndEq {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base: child(node, "base", source)?,
+        })
+    }
+}
+
+impl Parser for Either<SourceSpan> {
+    const KIND: &'static str = "either_type";
+
+    fn new(node: &Node, source: &str) -> Result<Either<SourceSpan>> {
+        Ok(Either {
+            ann: location(node),
+            left: child(node, "left_type", source)?,
+            right: child(node, "right_type", source)?,
+        })
+    }
+}
+
+impl Parser for Left<SourceSpan> {
+    const KIND: &'static str = "left";
+
+    fn new(node: &Node, source: &str) -> Result<Left<SourceSpan>> {
+        Ok(Left {
+            ann: location(node),
+            expr: child(node, "expr", source)?,
+        })
+    }
+}
+
+impl Parser for Right<SourceSpan> {
+    const KIND: &'static str = "right";
+
+    fn new(node: &Node, source: &str) -> Result<Right<SourceSpan>> {
+        Ok(Right {
+            ann: location(node),
+            expr: child(node, "expr", source)?,
+        })
+    }
+}
+
+impl Parser for IndEither<SourceSpan> {
+    const KIND: &'static str = "ind_either";
+
+    fn new(node: &Node, source: &str) -> Result<IndEither<SourceSpan>> {
+        Ok(IndEither {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+            base_left: child(node, "base_left", source)?,
+            base_right: child(node, "base_right", source)?,
+        })
+    }
+}
+
+impl Parser for Sole<SourceSpan> {
+    const KIND: &'static str = "sole";
+
+    fn new(node: &Node, _source: &str) -> Result<Sole<SourceSpan>> {
+        Ok(Sole {
+            ann: location(node),
+        })
+    }
+}
+
+impl Parser for The<SourceSpan> {
+    const KIND: &'static str = "the";
+
+    fn new(node: &Node, source: &str) -> Result<The<SourceSpan>> {
+        Ok(The {
+            ann: location(node),
+            ty: child(node, "type", source)?,
+            expr: child(node, "expr", source)?,
+        })
+    }
+}
+
+impl Parser for Todo<SourceSpan> {
+    const KIND: &'static str = "todo";
+
+    fn new(node: &Node, _source: &str) -> Result<Todo<SourceSpan>> {
+        Ok(Todo {
+            ann: location(node),
+        })
+    }
+}
+
+impl Parser for IndAbsurd<SourceSpan> {
+    const KIND: &'static str = "ind_absurd";
+
+    fn new(node: &Node, source: &str) -> Result<IndAbsurd<SourceSpan>> {
+        Ok(IndAbsurd {
+            ann: location(node),
+            target: child(node, "target", source)?,
+            motive: child(node, "motive", source)?,
+        })
+    }
+}
+
 impl Parser for Expression<SourceSpan> {
     const KIND: &'static str = "expression";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Expression<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Expression<SourceSpan>> {
         let ann = location(node);
 
         let actual = node.kind();
@@ -241,9 +1037,50 @@ impl Parser for Expression<SourceSpan> {
         match kind {
             "atom" => Parser::new(&expr, source).map(Expression::Atom),
             "identifier" => Parser::new(&expr, source).map(Expression::Ref),
-            "type_identifier" => Parser::new(&expr, source).map(Expression::Ty),
+            "base_type" => Parser::new(&expr, source).map(Expression::Ty),
+            "universe" => Parser::new(&expr, source).map(Expression::Universe),
             "lambda" => Parser::new(&expr, source).map(Expression::Abs),
             "application" => Parser::new(&expr, source).map(Expression::App),
+            "function_type" => Parser::new(&expr, source).map(Expression::Arrow),
+            "pi_type" => Parser::new(&expr, source).map(Expression::Pi),
+            "sigma_type" => Parser::new(&expr, source).map(Expression::Sigma),
+            "pair_type" => Parser::new(&expr, source).map(Expression::Pair),
+            "cons" => Parser::new(&expr, source).map(Expression::Cons),
+            "car" => Parser::new(&expr, source).map(Expression::Car),
+            "cdr" => Parser::new(&expr, source).map(Expression::Cdr),
+            "zero" => Parser::new(&expr, source).map(Expression::Zero),
+            "add1" => Parser::new(&expr, source).map(Expression::Add1),
+            "number" => number(&expr, source),
+            "which_nat" => Parser::new(&expr, source).map(Expression::WhichNat),
+            "iter_nat" => Parser::new(&expr, source).map(Expression::IterNat),
+            "rec_nat" => Parser::new(&expr, source).map(Expression::RecNat),
+            "ind_nat" => Parser::new(&expr, source).map(Expression::IndNat),
+            "list_type" => Parser::new(&expr, source).map(Expression::List),
+            "nil" => Parser::new(&expr, source).map(Expression::Nil),
+            "list_cons" => Parser::new(&expr, source).map(Expression::ListCons),
+            "rec_list" => Parser::new(&expr, source).map(Expression::RecList),
+            "ind_list" => Parser::new(&expr, source).map(Expression::IndList),
+            "vec_type" => Parser::new(&expr, source).map(Expression::Vector),
+            "vecnil" => Parser::new(&expr, source).map(Expression::VecNil),
+            "vec_cons" => Parser::new(&expr, source).map(Expression::VecCons),
+            "head" => Parser::new(&expr, source).map(Expression::Head),
+            "tail" => Parser::new(&expr, source).map(Expression::Tail),
+            "ind_vec" => Parser::new(&expr, source).map(Expression::IndVec),
+            "equal_type" => Parser::new(&expr, source).map(Expression::Equal),
+            "same" => Parser::new(&expr, source).map(Expression::Same),
+            "cong" => Parser::new(&expr, source).map(Expression::Cong),
+            "replace" => Parser::new(&expr, source).map(Expression::Replace),
+            "symm" => Parser::new(&expr, source).map(Expression::Symm),
+            "trans" => Parser::new(&expr, source).map(Expression::Trans),
+            "ind_eq" => Parser::new(&expr, source).map(Expression::IndEq),
+            "either_type" => Parser::new(&expr, source).map(Expression::Either),
+            "left" => Parser::new(&expr, source).map(Expression::Left),
+            "right" => Parser::new(&expr, source).map(Expression::Right),
+            "ind_either" => Parser::new(&expr, source).map(Expression::IndEither),
+            "sole" => Parser::new(&expr, source).map(Expression::Sole),
+            "ind_absurd" => Parser::new(&expr, source).map(Expression::IndAbsurd),
+            "the" => Parser::new(&expr, source).map(Expression::The),
+            "todo" => Parser::new(&expr, source).map(Expression::Todo),
             kind => Err(mismatch(
                 ann,
                 kind,
@@ -251,9 +1088,50 @@ impl Parser for Expression<SourceSpan> {
                     [
                         "atom",
                         "identifier",
-                        "type identifier",
+                        "base type",
+                        "`U'",
                         "lambda",
                         "application",
+                        "function type",
+                        "`Π' type",
+                        "`Σ' type",
+                        "`Pair' type",
+                        "`cons'",
+                        "`car'",
+                        "`cdr'",
+                        "`zero'",
+                        "`add1'",
+                        "number",
+                        "`which-Nat'",
+                        "`iter-Nat'",
+                        "`rec-Nat'",
+                        "`ind-Nat'",
+                        "`List' type",
+                        "`nil'",
+                        "`::'",
+                        "`rec-List'",
+                        "`ind-List'",
+                        "`Vec' type",
+                        "`vecnil'",
+                        "`vec::'",
+                        "`head'",
+                        "`tail'",
+                        "`ind-Vec'",
+                        "`=' type",
+                        "`same'",
+                        "`cong'",
+                        "`replace'",
+                        "`symm'",
+                        "`trans'",
+                        "`ind-Eq'",
+                        "`Either' type",
+                        "`left'",
+                        "`right'",
+                        "`ind-Either'",
+                        "`sole'",
+                        "`ind-Absurd'",
+                        "`the'",
+                        "`TODO'",
                     ]
                     .iter(),
                 ),
@@ -265,13 +1143,13 @@ impl Parser for Expression<SourceSpan> {
 impl Parser for Claim<SourceSpan> {
     const KIND: &'static str = "claim";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Claim<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Claim<SourceSpan>> {
         let ann = location(node);
 
         let ident = node
             .child_by_field_name("identifier")
             .ok_or(missing(ann, node.kind(), "identifier"))
-            .and_then(|ident_node| read(location(&ident_node), source).map(Identifier))?;
+            .and_then(|ident_node| text(&ident_node, source).map(Identifier::new))?;
 
         let expr = node
             .child_by_field_name("type")
@@ -286,13 +1164,13 @@ impl Parser for Claim<SourceSpan> {
 impl Parser for Define<SourceSpan> {
     const KIND: &'static str = "define";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Define<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Define<SourceSpan>> {
         let ann = location(node);
 
         let ident = node
             .child_by_field_name("identifier")
             .ok_or(missing(ann, node.kind(), "identifier"))
-            .and_then(|ident_node| read(location(&ident_node), source).map(Identifier))?;
+            .and_then(|ident_node| text(&ident_node, source).map(Identifier::new))?;
 
         let body = node
             .child_by_field_name("body")
@@ -304,51 +1182,150 @@ impl Parser for Define<SourceSpan> {
     }
 }
 
+impl Parser for CheckSame<SourceSpan> {
+    const KIND: &'static str = "check_same";
+
+    fn new(node: &Node, source: &str) -> Result<CheckSame<SourceSpan>> {
+        Ok(CheckSame {
+            ann: location(node),
+            ty: child(node, "type", source)?,
+            left: child(node, "left", source)?,
+            right: child(node, "right", source)?,
+        })
+    }
+}
+
 impl Parser for Statement<SourceSpan> {
     const KIND: &'static str = "statement";
 
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Statement<SourceSpan>> {
+    fn new(node: &Node, source: &str) -> Result<Statement<SourceSpan>> {
         let ann = location(node);
 
         let kind = node.kind();
         match kind {
             "claim" => Parser::new(node, source).map(Statement::Claim),
             "define" => Parser::new(node, source).map(Statement::Def),
+            "check_same" => Parser::new(node, source).map(Statement::CheckSame),
             "expression" => Parser::new(node, source).map(Statement::Expr),
             kind => Err(mismatch(
                 ann,
                 kind,
-                one_of_msg(["clam", "define", "expression"].iter()),
+                one_of_msg(["claim", "define", "check-same", "expression"].iter()),
             )),
         }
     }
 }
 
-impl Parser for Source<SourceSpan> {
-    const KIND: &'static str = "source";
-
-    fn new(node: &Node, source: &mut (impl Read + Seek)) -> Result<Source<SourceSpan>> {
-        let ann = location(node);
+/// Parse all the statements of `node' that are fine, together with the
+/// errors in the others.
+pub fn source(node: &Node, source: &str) -> (Source<SourceSpan>, Vec<ParseError>) {
+    let ann = location(node);
+    let mut statements: Vec<Statement<SourceSpan>> = vec![];
+    let mut errors = vec![];
 
-        let kind = node.kind();
-        match kind {
-            "source" => {
-                let mut cursor = node.walk();
-                let mut statements: Vec<Statement<SourceSpan>> = vec![];
-                for child in node.named_children(&mut cursor) {
-                    if child.kind() == "comment" {
-                        ()
-                    } else {
-                        statements.push(Parser::new(&child, source)?)
+    match node.kind() {
+        "source" => {
+            let mut cursor = node.walk();
+            // broken statements are reported as syntax errors
+            for child in node.named_children(&mut cursor) {
+                if child.kind() != "comment" && !child.has_error() {
+                    match Parser::new(&child, source) {
+                        Ok(stmt) => statements.push(stmt),
+                        Err(err) => errors.push(err),
                     }
                 }
-                Ok(Source {
-                    ann,
-                    // source: std::str::from_utf8(source.to_mut())?.to_string(),
-                    statements,
-                })
             }
-            kind => Err(mismatch(ann, kind, "source")),
         }
+        kind => errors.push(mismatch(ann, kind, "source")),
+    }
+
+    (
+        Source {
+            ann,
+            // source: std::str::from_utf8(source.to_mut())?.to_string(),
+            statements,
+        },
+        errors,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    // text and expectation of every syntax error in `text'
+    fn errors(text: &str) -> Vec<(&str, String)> {
+        let (_, errors) = parse(text).unwrap();
+        errors
+            .into_iter()
+            .map(|err| match err {
+                ParseError::Mismatch(Mismatch { loc, expected, .. }) => {
+                    (slice(loc, text), expected)
+                }
+                err => panic!("unexpected error: {err:?}"),
+            })
+            .collect()
+    }
+
+    const NOT_A_VARIABLE: &str = "a variable name (`λ' binds only variables)";
+
+    #[test]
+    fn test_binder_number() {
+        assert_eq!(errors("(λ (x 1) x)"), [("1", NOT_A_VARIABLE.into())]);
+        assert_eq!(
+            errors("(λ (1 2) x)"),
+            [("1", NOT_A_VARIABLE.into()), ("2", NOT_A_VARIABLE.into())]
+        );
+    }
+
+    #[test]
+    fn test_binder_parenthesized() {
+        assert_eq!(errors("(λ ((f x)) x)"), [("(f x)", NOT_A_VARIABLE.into())]);
+        assert_eq!(
+            errors("(λ (x (f (g y))) x)"),
+            [("(f (g y))", NOT_A_VARIABLE.into())]
+        );
+    }
+
+    #[test]
+    fn test_binder_atom() {
+        assert_eq!(errors("(λ ('a y) x)"), [("'a", NOT_A_VARIABLE.into())]);
+    }
+
+    #[test]
+    fn test_binder_after_comment() {
+        assert_eq!(errors("(λ (x ; y\n 1) x)"), [("1", NOT_A_VARIABLE.into())]);
+    }
+
+    #[test]
+    fn test_binder_in_lambda_lambda() {
+        assert_eq!(errors("(lambda (y 2) x)"), [("2", NOT_A_VARIABLE.into())]);
+    }
+
+    #[test]
+    fn test_no_arguments() {
+        assert_eq!(
+            errors("(λ x x)"),
+            [("x", "a parenthesized list of variable names".into())]
+        );
+    }
+
+    #[test]
+    fn test_numeral() {
+        let (source, errors) = parse("99999999999").unwrap();
+        assert!(errors.is_empty());
+        assert!(matches!(
+            source.statements[..],
+            [Statement::Expr(Expression::Numeral(Numeral {
+                n: 99999999999,
+                ..
+            }))]
+        ));
+    }
+
+    #[test]
+    fn test_good_binders() {
+        assert!(errors("(λ (x y) (λ (z) x))").is_empty());
     }
 }
@@ -1,3 +1,12 @@
+pub mod check;
+pub mod conv;
+pub mod desugar;
+pub mod elab;
 pub mod error;
+pub mod eval;
+pub mod format;
+pub mod lsp;
 pub mod parser;
+pub mod resolve;
 pub mod syntax;
+pub mod term;
@@ -0,0 +1,477 @@
+use crate::syntax::{
+    Add1, Apply, Arrow, Binder, Car, Cdr, CheckSame, Claim, Cong, Cons, Define, Either, Equal,
+    Expression, Head, Identifier, IndAbsurd, IndEither, IndEq, IndList, IndNat, IndVec, IterNat,
+    Lambda, Left, List, ListCons, Pair, Pi, RecList, RecNat, Replace, Right, Same, Sigma, Source,
+    Statement, Symm, Tail, The, Trans, TypedBinder, Variable, VecCons, Vector, WhichNat,
+};
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+////////////////////////////////////////////////
+// Errors
+#[derive(Debug, Diagnostic, Error)]
+#[error("Unknown variable")]
+#[diagnostic(help("{}", self.help))]
+pub struct Unbound {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub ident: Identifier,
+    // name in scope that is spelled similarly
+    pub suggestion: Option<Identifier>,
+    pub help: String,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Shadowed variable")]
+#[diagnostic(
+    severity(Warning),
+    help("`{}' hides an earlier variable with the same name", self.ident)
+)]
+pub struct Shadowed {
+    #[label("this")]
+    pub loc: SourceSpan,
+    #[label("shadows this one")]
+    pub previous: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+#[error("Unused argument")]
+#[diagnostic(
+    severity(Warning),
+    help("`{}' is never used, prefix it with `_' if that's intended", self.ident)
+)]
+pub struct Unused {
+    #[label("this")]
+    pub loc: SourceSpan,
+    pub ident: Identifier,
+}
+
+#[derive(Debug, Diagnostic, Error)]
+pub enum ScopeError {
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Unbound(#[from] Unbound),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Shadowed(#[from] Shadowed),
+    #[error(transparent)]
+    #[diagnostic(transparent)]
+    Unused(#[from] Unused),
+}
+
+impl ScopeError {
+    pub fn loc(&self) -> SourceSpan {
+        match self {
+            ScopeError::Unbound(Unbound { loc, .. })
+            | ScopeError::Shadowed(Shadowed { loc, .. })
+            | ScopeError::Unused(Unused { loc, .. }) => *loc,
+        }
+    }
+}
+
+////////////////////////////////////////////////
+// Scopes
+// variable bound by a lambda or a type former
+struct Local {
+    ident: Identifier,
+    loc: SourceSpan,
+    used: bool,
+    // only lambda arguments are expected to be used
+    argument: bool,
+}
+
+#[derive(Default)]
+struct Scope {
+    globals: Vec<(Identifier, SourceSpan)>,
+    locals: Vec<Local>,
+    problems: Vec<ScopeError>,
+}
+
+/// Make sure that every variable in `source' refers to a lambda
+/// argument, a binder of a type or an earlier definition.
+pub fn resolve(source: &Source<SourceSpan>) -> Vec<ScopeError> {
+    let mut scope = Scope::default();
+    for stmt in source.statements.iter() {
+        match stmt {
+            Statement::Claim(Claim { expr, .. }) => scope.expression(expr),
+            Statement::Def(Define { ann, ident, body }) => {
+                scope.expression(body);
+                scope.globals.push((*ident, *ann));
+            }
+            Statement::CheckSame(CheckSame {
+                ty, left, right, ..
+            }) => {
+                scope.expression(ty);
+                scope.expression(left);
+                scope.expression(right);
+            }
+            Statement::Expr(expr) => scope.expression(expr),
+        }
+    }
+    // unused arguments are found when leaving their scope, innermost
+    // first; report everything in the order it appears in the source
+    scope.problems.sort_by_key(|problem| problem.loc().offset());
+    scope.problems
+}
+
+// closest name to `ident' among `candidates', if it's close enough to
+// be a typo
+fn suggest<'a>(
+    ident: &Identifier,
+    candidates: impl Iterator<Item = &'a Identifier>,
+) -> Option<Identifier> {
+    let max = (ident.as_str().chars().count() / 3).max(1);
+    candidates
+        .map(|candidate| {
+            (
+                strsim::levenshtein(ident.as_str(), candidate.as_str()),
+                candidate,
+            )
+        })
+        .filter(|(distance, _)| *distance <= max)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| *candidate)
+}
+
+impl Scope {
+    fn bind(&mut self, ident: &Identifier, loc: SourceSpan, argument: bool) {
+        let previous = self
+            .locals
+            .iter()
+            .rev()
+            .find(|local| &local.ident == ident)
+            .map(|local| local.loc)
+            .or_else(|| {
+                self.globals
+                    .iter()
+                    .find(|(name, _)| name == ident)
+                    .map(|(_, loc)| *loc)
+            });
+        if let Some(previous) = previous {
+            self.problems.push(ScopeError::from(Shadowed {
+                loc,
+                previous,
+                ident: *ident,
+            }));
+        }
+        self.locals.push(Local {
+            ident: *ident,
+            loc,
+            used: false,
+            argument,
+        });
+    }
+
+    // forget locals bound since there were `depth' of them
+    fn unbind(&mut self, depth: usize) {
+        for local in self.locals.drain(depth..) {
+            if local.argument && !local.used && !local.ident.as_str().starts_with('_') {
+                self.problems.push(ScopeError::from(Unused {
+                    loc: local.loc,
+                    ident: local.ident,
+                }));
+            }
+        }
+    }
+
+    fn lookup(&mut self, loc: SourceSpan, ident: &Identifier) {
+        if let Some(local) = self
+            .locals
+            .iter_mut()
+            .rev()
+            .find(|local| &local.ident == ident)
+        {
+            local.used = true;
+            return;
+        }
+        if self.globals.iter().any(|(name, _)| name == ident) {
+            return;
+        }
+        let suggestion = suggest(
+            ident,
+            self.locals
+                .iter()
+                .map(|local| &local.ident)
+                .chain(self.globals.iter().map(|(name, _)| name)),
+        );
+        let help = match &suggestion {
+            Some(name) => format!("`{ident}' is not defined, did you mean `{name}'?"),
+            None => format!("`{ident}' is not defined"),
+        };
+        self.problems.push(ScopeError::from(Unbound {
+            loc,
+            ident: *ident,
+            suggestion,
+            help,
+        }));
+    }
+
+    fn telescope(&mut self, binders: &[TypedBinder<SourceSpan>], body: &Expression<SourceSpan>) {
+        let depth = self.locals.len();
+        for TypedBinder { ann, ident, ty } in binders.iter() {
+            self.expression(ty);
+            self.bind(ident, *ann, false);
+        }
+        self.expression(body);
+        self.unbind(depth);
+    }
+
+    fn expression(&mut self, expr: &Expression<SourceSpan>) {
+        match expr {
+            Expression::Atom(_)
+            | Expression::Ty(_)
+            | Expression::Universe(_)
+            | Expression::Zero(_)
+            | Expression::Numeral(_)
+            | Expression::Nil(_)
+            | Expression::VecNil(_)
+            | Expression::Sole(_)
+            | Expression::Todo(_) => (),
+            Expression::Ref(Variable { ann, ident }) => self.lookup(*ann, ident),
+            Expression::Abs(Lambda { args, body, .. }) => {
+                let depth = self.locals.len();
+                for Binder { ann, ident } in args.iter() {
+                    self.bind(ident, *ann, true);
+                }
+                self.expression(body);
+                self.unbind(depth);
+            }
+            Expression::App(Apply { fun, args, .. }) => {
+                self.expression(fun);
+                args.iter().for_each(|arg| self.expression(arg));
+            }
+            Expression::Arrow(Arrow { doms, cod, .. }) => {
+                doms.iter().for_each(|dom| self.expression(dom));
+                self.expression(cod);
+            }
+            Expression::Pi(Pi { binders, cod, .. }) => self.telescope(binders, cod),
+            Expression::Sigma(Sigma {
+                binders, cdr_ty, ..
+            }) => self.telescope(binders, cdr_ty),
+            Expression::Add1(Add1 { n: expr, .. })
+            | Expression::Car(Car { pair: expr, .. })
+            | Expression::Cdr(Cdr { pair: expr, .. })
+            | Expression::List(List { elem: expr, .. })
+            | Expression::Head(Head { vec: expr, .. })
+            | Expression::Tail(Tail { vec: expr, .. })
+            | Expression::Same(Same { expr, .. })
+            | Expression::Symm(Symm { target: expr, .. })
+            | Expression::Left(Left { expr, .. })
+            | Expression::Right(Right { expr, .. }) => self.expression(expr),
+            Expression::Pair(Pair {
+                car_ty: left,
+                cdr_ty: right,
+                ..
+            })
+            | Expression::Cons(Cons {
+                car: left,
+                cdr: right,
+                ..
+            })
+            | Expression::The(The {
+                ty: left,
+                expr: right,
+                ..
+            })
+            | Expression::ListCons(ListCons {
+                head: left,
+                tail: right,
+                ..
+            })
+            | Expression::VecCons(VecCons {
+                head: left,
+                tail: right,
+                ..
+            })
+            | Expression::Vector(Vector {
+                elem: left,
+                len: right,
+                ..
+            })
+            | Expression::Cong(Cong {
+                target: left,
+                fun: right,
+                ..
+            })
+            | Expression::Trans(Trans { left, right, .. })
+            | Expression::Either(Either { left, right, .. })
+            | Expression::IndAbsurd(IndAbsurd {
+                target: left,
+                motive: right,
+                ..
+            }) => {
+                self.expression(left);
+                self.expression(right);
+            }
+            Expression::WhichNat(WhichNat {
+                target, base, step, ..
+            })
+            | Expression::IterNat(IterNat {
+                target, base, step, ..
+            })
+            | Expression::RecNat(RecNat {
+                target, base, step, ..
+            })
+            | Expression::RecList(RecList {
+                target, base, step, ..
+            })
+            | Expression::Replace(Replace {
+                target,
+                motive: base,
+                base: step,
+                ..
+            })
+            | Expression::IndEq(IndEq {
+                target,
+                motive: base,
+                base: step,
+                ..
+            })
+            | Expression::Equal(Equal {
+                ty: target,
+                from: base,
+                to: step,
+                ..
+            }) => {
+                self.expression(target);
+                self.expression(base);
+                self.expression(step);
+            }
+            Expression::IndNat(IndNat {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            })
+            | Expression::IndList(IndList {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            })
+            | Expression::IndEither(IndEither {
+                target,
+                motive,
+                base_left: base,
+                base_right: step,
+                ..
+            }) => {
+                self.expression(target);
+                self.expression(motive);
+                self.expression(base);
+                self.expression(step);
+            }
+            Expression::IndVec(IndVec {
+                len,
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => {
+                self.expression(len);
+                self.expression(target);
+                self.expression(motive);
+                self.expression(base);
+                self.expression(step);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+
+    // problems in `text', as kind, source text and the name suggested
+    fn problems(text: &str) -> Vec<(&'static str, String, Option<String>)> {
+        let (source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        let at = |loc: SourceSpan| text[loc.offset()..loc.offset() + loc.len()].to_string();
+        resolve(&source)
+            .into_iter()
+            .map(|problem| match problem {
+                ScopeError::Unbound(Unbound {
+                    loc, suggestion, ..
+                }) => ("unbound", at(loc), suggestion.map(|s| s.to_string())),
+                ScopeError::Shadowed(Shadowed { loc, previous, .. }) => {
+                    ("shadowed", at(loc), Some(format!("{}", previous.offset())))
+                }
+                ScopeError::Unused(Unused { loc, .. }) => ("unused", at(loc), None),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_bound() {
+        assert!(problems("(λ (x) x)").is_empty());
+        assert!(problems("(Π ((n Nat)) (Vec Atom n))").is_empty());
+        assert!(problems("(define one 1)\n(add1 one)").is_empty());
+        // a definition isn't in scope in its own body
+        assert_eq!(
+            problems("(define loop loop)"),
+            [("unbound", "loop".to_string(), None)]
+        );
+    }
+
+    #[test]
+    fn test_suggestion() {
+        assert_eq!(
+            problems("(define length 0)\n(add1 lenght)"),
+            [("unbound", "lenght".to_string(), Some("length".to_string()))]
+        );
+        assert_eq!(
+            problems("(λ (apple) (add1 aple))"),
+            [
+                ("unused", "apple".to_string(), None),
+                ("unbound", "aple".to_string(), Some("apple".to_string())),
+            ]
+        );
+        // nothing is close enough
+        assert_eq!(
+            problems("(λ (x) (cons x banana))"),
+            [("unbound", "banana".to_string(), None)]
+        );
+    }
+
+    #[test]
+    fn test_shadowed() {
+        assert_eq!(
+            problems("(λ (x) (λ (x) x))"),
+            [
+                ("unused", "x".to_string(), None),
+                ("shadowed", "x".to_string(), Some("5".to_string())),
+            ]
+        );
+        assert_eq!(
+            problems("(define n 1)\n(Π ((n Nat)) (Vec Atom n))"),
+            [("shadowed", "(n Nat)".to_string(), Some("0".to_string()))]
+        );
+    }
+
+    #[test]
+    fn test_unused() {
+        assert_eq!(problems("(λ (x y) x)"), [("unused", "y".to_string(), None)]);
+        assert!(problems("(λ (x _y) x)").is_empty());
+        assert!(problems("(λ (_) 'a)").is_empty());
+        // binders of types don't have to be used
+        assert!(problems("(Π ((n Nat)) Atom)").is_empty());
+    }
+
+    #[test]
+    fn test_source_order() {
+        assert_eq!(
+            problems("(λ (a) (λ (b) (λ (c) zzz)))"),
+            [
+                ("unused", "a".to_string(), None),
+                ("unused", "b".to_string(), None),
+                ("unused", "c".to_string(), None),
+                ("unbound", "zzz".to_string(), None),
+            ]
+        );
+    }
+}
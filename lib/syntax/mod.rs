@@ -1,11 +1,77 @@
+use std::collections::HashSet;
 use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::sync::{Mutex, OnceLock};
 
-#[derive(Clone, Debug, Eq, Hash, PartialEq)]
-pub struct Identifier(pub String);
+mod pretty;
+
+////////////////////////////////////////////////
+// Interning
+// names seen in this session, stored once each and never freed; only
+// making new identifiers takes the lock, reading them doesn't
+#[derive(Default)]
+struct Interner {
+    names: HashSet<&'static str>,
+}
+
+fn interner() -> &'static Mutex<Interner> {
+    static INTERNER: OnceLock<Mutex<Interner>> = OnceLock::new();
+    INTERNER.get_or_init(Default::default)
+}
+
+/// Interned name, cheap to copy and compare.  Names are never freed: a
+/// process keeps every distinct name it has made until it exits, a few
+/// dozen bytes on top of the name each.
+#[derive(Clone, Copy)]
+pub struct Identifier(&'static str);
+
+impl Identifier {
+    pub fn new(name: &str) -> Identifier {
+        let mut interner = interner().lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(name) = interner.names.get(name) {
+            return Identifier(name);
+        }
+        // names live as long as the session
+        let name: &'static str = Box::leak(name.to_owned().into_boxed_str());
+        interner.names.insert(name);
+        Identifier(name)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+// there is only one copy of each name, so its address stands for it
+impl PartialEq for Identifier {
+    fn eq(&self, other: &Identifier) -> bool {
+        std::ptr::eq(self.0, other.0)
+    }
+}
+
+impl Eq for Identifier {}
+
+impl Hash for Identifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.as_ptr().hash(state)
+    }
+}
+
+impl From<&str> for Identifier {
+    fn from(name: &str) -> Identifier {
+        Identifier::new(name)
+    }
+}
 
 impl fmt::Display for Identifier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        return write!(f, "{}", self.0);
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl fmt::Debug for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Identifier({:?})", self.as_str())
     }
 }
 
@@ -23,8 +89,446 @@ pub enum Expression<Ann = ()> {
     Atom(Atom<Ann>),
     Ref(Variable<Ann>),
     Ty(Type<Ann>),
+    Universe(Universe<Ann>),
     Abs(Lambda<Ann>),
     App(Apply<Ann>),
+    Arrow(Arrow<Ann>),
+    Pi(Pi<Ann>),
+    Sigma(Sigma<Ann>),
+    Pair(Pair<Ann>),
+    Cons(Cons<Ann>),
+    Car(Car<Ann>),
+    Cdr(Cdr<Ann>),
+    The(The<Ann>),
+    Zero(Zero<Ann>),
+    Add1(Add1<Ann>),
+    Numeral(Numeral<Ann>),
+    WhichNat(WhichNat<Ann>),
+    IterNat(IterNat<Ann>),
+    RecNat(RecNat<Ann>),
+    IndNat(IndNat<Ann>),
+    List(List<Ann>),
+    Nil(Nil<Ann>),
+    ListCons(ListCons<Ann>),
+    RecList(RecList<Ann>),
+    IndList(IndList<Ann>),
+    Vector(Vector<Ann>),
+    VecNil(VecNil<Ann>),
+    VecCons(VecCons<Ann>),
+    Head(Head<Ann>),
+    Tail(Tail<Ann>),
+    IndVec(IndVec<Ann>),
+    Equal(Equal<Ann>),
+    Same(Same<Ann>),
+    Cong(Cong<Ann>),
+    Replace(Replace<Ann>),
+    Symm(Symm<Ann>),
+    Trans(Trans<Ann>),
+    IndEq(IndEq<Ann>),
+    Either(Either<Ann>),
+    Left(Left<Ann>),
+    Right(Right<Ann>),
+    IndEither(IndEither<Ann>),
+    Sole(Sole<Ann>),
+    IndAbsurd(IndAbsurd<Ann>),
+    Todo(Todo<Ann>),
+}
+
+impl<Ann> Expression<Ann> {
+    pub fn ann(&self) -> &Ann {
+        match self {
+            Expression::Atom(Atom { ann, .. })
+            | Expression::Ref(Variable { ann, .. })
+            | Expression::Ty(Type { ann, .. })
+            | Expression::Universe(Universe { ann })
+            | Expression::Abs(Lambda { ann, .. })
+            | Expression::App(Apply { ann, .. })
+            | Expression::Arrow(Arrow { ann, .. })
+            | Expression::Pi(Pi { ann, .. })
+            | Expression::Sigma(Sigma { ann, .. })
+            | Expression::Pair(Pair { ann, .. })
+            | Expression::Cons(Cons { ann, .. })
+            | Expression::Car(Car { ann, .. })
+            | Expression::Cdr(Cdr { ann, .. })
+            | Expression::The(The { ann, .. })
+            | Expression::Zero(Zero { ann })
+            | Expression::Add1(Add1 { ann, .. })
+            | Expression::Numeral(Numeral { ann, .. })
+            | Expression::WhichNat(WhichNat { ann, .. })
+            | Expression::IterNat(IterNat { ann, .. })
+            | Expression::RecNat(RecNat { ann, .. })
+            | Expression::IndNat(IndNat { ann, .. })
+            | Expression::List(List { ann, .. })
+            | Expression::Nil(Nil { ann })
+            | Expression::ListCons(ListCons { ann, .. })
+            | Expression::RecList(RecList { ann, .. })
+            | Expression::IndList(IndList { ann, .. })
+            | Expression::Vector(Vector { ann, .. })
+            | Expression::VecNil(VecNil { ann })
+            | Expression::VecCons(VecCons { ann, .. })
+            | Expression::Head(Head { ann, .. })
+            | Expression::Tail(Tail { ann, .. })
+            | Expression::IndVec(IndVec { ann, .. })
+            | Expression::Equal(Equal { ann, .. })
+            | Expression::Same(Same { ann, .. })
+            | Expression::Cong(Cong { ann, .. })
+            | Expression::Replace(Replace { ann, .. })
+            | Expression::Symm(Symm { ann, .. })
+            | Expression::Trans(Trans { ann, .. })
+            | Expression::IndEq(IndEq { ann, .. })
+            | Expression::Either(Either { ann, .. })
+            | Expression::Left(Left { ann, .. })
+            | Expression::Right(Right { ann, .. })
+            | Expression::IndEither(IndEither { ann, .. })
+            | Expression::Sole(Sole { ann })
+            | Expression::IndAbsurd(IndAbsurd { ann, .. })
+            | Expression::Todo(Todo { ann }) => ann,
+        }
+    }
+
+    /// Call `f' on the annotation of every part of the expression.
+    pub fn for_each_ann(&mut self, f: &mut impl FnMut(&mut Ann)) {
+        match self {
+            Expression::Atom(Atom { ann, .. })
+            | Expression::Ref(Variable { ann, .. })
+            | Expression::Ty(Type { ann, .. })
+            | Expression::Universe(Universe { ann })
+            | Expression::Zero(Zero { ann })
+            | Expression::Numeral(Numeral { ann, .. })
+            | Expression::Nil(Nil { ann })
+            | Expression::VecNil(VecNil { ann })
+            | Expression::Sole(Sole { ann })
+            | Expression::Todo(Todo { ann }) => f(ann),
+            Expression::Abs(Lambda { ann, args, body }) => {
+                f(ann);
+                args.iter_mut().for_each(|arg| f(&mut arg.ann));
+                body.for_each_ann(f);
+            }
+            Expression::App(Apply { ann, fun, args }) => {
+                f(ann);
+                fun.for_each_ann(f);
+                args.iter_mut().for_each(|arg| arg.for_each_ann(f));
+            }
+            Expression::Arrow(Arrow { ann, doms, cod }) => {
+                f(ann);
+                doms.iter_mut().for_each(|dom| dom.for_each_ann(f));
+                cod.for_each_ann(f);
+            }
+            Expression::Pi(Pi {
+                ann,
+                binders,
+                cod: body,
+            })
+            | Expression::Sigma(Sigma {
+                ann,
+                binders,
+                cdr_ty: body,
+            }) => {
+                f(ann);
+                for TypedBinder { ann, ty, .. } in binders.iter_mut() {
+                    f(ann);
+                    ty.for_each_ann(f);
+                }
+                body.for_each_ann(f);
+            }
+            Expression::Add1(Add1 { ann, n: expr })
+            | Expression::Car(Car { ann, pair: expr })
+            | Expression::Cdr(Cdr { ann, pair: expr })
+            | Expression::List(List { ann, elem: expr })
+            | Expression::Head(Head { ann, vec: expr })
+            | Expression::Tail(Tail { ann, vec: expr })
+            | Expression::Same(Same { ann, expr })
+            | Expression::Symm(Symm { ann, target: expr })
+            | Expression::Left(Left { ann, expr })
+            | Expression::Right(Right { ann, expr }) => {
+                f(ann);
+                expr.for_each_ann(f);
+            }
+            Expression::Pair(Pair {
+                ann,
+                car_ty: left,
+                cdr_ty: right,
+            })
+            | Expression::Cons(Cons {
+                ann,
+                car: left,
+                cdr: right,
+            })
+            | Expression::The(The {
+                ann,
+                ty: left,
+                expr: right,
+            })
+            | Expression::ListCons(ListCons {
+                ann,
+                head: left,
+                tail: right,
+            })
+            | Expression::VecCons(VecCons {
+                ann,
+                head: left,
+                tail: right,
+            })
+            | Expression::Vector(Vector {
+                ann,
+                elem: left,
+                len: right,
+            })
+            | Expression::Cong(Cong {
+                ann,
+                target: left,
+                fun: right,
+            })
+            | Expression::Trans(Trans { ann, left, right })
+            | Expression::Either(Either { ann, left, right })
+            | Expression::IndAbsurd(IndAbsurd {
+                ann,
+                target: left,
+                motive: right,
+            }) => {
+                f(ann);
+                left.for_each_ann(f);
+                right.for_each_ann(f);
+            }
+            Expression::WhichNat(WhichNat {
+                ann,
+                target,
+                base,
+                step,
+            })
+            | Expression::IterNat(IterNat {
+                ann,
+                target,
+                base,
+                step,
+            })
+            | Expression::RecNat(RecNat {
+                ann,
+                target,
+                base,
+                step,
+            })
+            | Expression::RecList(RecList {
+                ann,
+                target,
+                base,
+                step,
+            })
+            | Expression::Replace(Replace {
+                ann,
+                target,
+                motive: base,
+                base: step,
+            })
+            | Expression::IndEq(IndEq {
+                ann,
+                target,
+                motive: base,
+                base: step,
+            })
+            | Expression::Equal(Equal {
+                ann,
+                ty: target,
+                from: base,
+                to: step,
+            }) => {
+                f(ann);
+                target.for_each_ann(f);
+                base.for_each_ann(f);
+                step.for_each_ann(f);
+            }
+            Expression::IndNat(IndNat {
+                ann,
+                target,
+                motive,
+                base,
+                step,
+            })
+            | Expression::IndList(IndList {
+                ann,
+                target,
+                motive,
+                base,
+                step,
+            })
+            | Expression::IndEither(IndEither {
+                ann,
+                target,
+                motive,
+                base_left: base,
+                base_right: step,
+            }) => {
+                f(ann);
+                target.for_each_ann(f);
+                motive.for_each_ann(f);
+                base.for_each_ann(f);
+                step.for_each_ann(f);
+            }
+            Expression::IndVec(IndVec {
+                ann,
+                len,
+                target,
+                motive,
+                base,
+                step,
+            }) => {
+                f(ann);
+                len.for_each_ann(f);
+                target.for_each_ann(f);
+                motive.for_each_ann(f);
+                base.for_each_ann(f);
+                step.for_each_ann(f);
+            }
+        }
+    }
+
+    /// Expressions right inside this one, in the order they are written.
+    pub fn children(&self) -> Vec<&Expression<Ann>> {
+        match self {
+            Expression::Atom(_)
+            | Expression::Ref(_)
+            | Expression::Ty(_)
+            | Expression::Universe(_)
+            | Expression::Zero(_)
+            | Expression::Numeral(_)
+            | Expression::Nil(_)
+            | Expression::VecNil(_)
+            | Expression::Sole(_)
+            | Expression::Todo(_) => vec![],
+            Expression::Abs(Lambda { body, .. }) => vec![&**body],
+            Expression::App(Apply { fun, args, .. }) => std::iter::once(&**fun)
+                .chain(args.iter().map(|arg| &**arg))
+                .collect(),
+            Expression::Arrow(Arrow { doms, cod, .. }) => doms
+                .iter()
+                .map(|dom| &**dom)
+                .chain(std::iter::once(&**cod))
+                .collect(),
+            Expression::Pi(Pi {
+                binders, cod: body, ..
+            })
+            | Expression::Sigma(Sigma {
+                binders,
+                cdr_ty: body,
+                ..
+            }) => binders
+                .iter()
+                .map(|binder| &*binder.ty)
+                .chain(std::iter::once(&**body))
+                .collect(),
+            Expression::Add1(Add1 { n: expr, .. })
+            | Expression::Car(Car { pair: expr, .. })
+            | Expression::Cdr(Cdr { pair: expr, .. })
+            | Expression::List(List { elem: expr, .. })
+            | Expression::Head(Head { vec: expr, .. })
+            | Expression::Tail(Tail { vec: expr, .. })
+            | Expression::Same(Same { expr, .. })
+            | Expression::Symm(Symm { target: expr, .. })
+            | Expression::Left(Left { expr, .. })
+            | Expression::Right(Right { expr, .. }) => vec![&**expr],
+            Expression::Pair(Pair {
+                car_ty: left,
+                cdr_ty: right,
+                ..
+            })
+            | Expression::Cons(Cons {
+                car: left,
+                cdr: right,
+                ..
+            })
+            | Expression::The(The {
+                ty: left,
+                expr: right,
+                ..
+            })
+            | Expression::ListCons(ListCons {
+                head: left,
+                tail: right,
+                ..
+            })
+            | Expression::VecCons(VecCons {
+                head: left,
+                tail: right,
+                ..
+            })
+            | Expression::Vector(Vector {
+                elem: left,
+                len: right,
+                ..
+            })
+            | Expression::Cong(Cong {
+                target: left,
+                fun: right,
+                ..
+            })
+            | Expression::Trans(Trans { left, right, .. })
+            | Expression::Either(Either { left, right, .. })
+            | Expression::IndAbsurd(IndAbsurd {
+                target: left,
+                motive: right,
+                ..
+            }) => vec![&**left, &**right],
+            Expression::WhichNat(WhichNat {
+                target, base, step, ..
+            })
+            | Expression::IterNat(IterNat {
+                target, base, step, ..
+            })
+            | Expression::RecNat(RecNat {
+                target, base, step, ..
+            })
+            | Expression::RecList(RecList {
+                target, base, step, ..
+            })
+            | Expression::Replace(Replace {
+                target,
+                motive: base,
+                base: step,
+                ..
+            })
+            | Expression::IndEq(IndEq {
+                target,
+                motive: base,
+                base: step,
+                ..
+            })
+            | Expression::Equal(Equal {
+                ty: target,
+                from: base,
+                to: step,
+                ..
+            }) => vec![&**target, &**base, &**step],
+            Expression::IndNat(IndNat {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            })
+            | Expression::IndList(IndList {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            })
+            | Expression::IndEither(IndEither {
+                target,
+                motive,
+                base_left: base,
+                base_right: step,
+                ..
+            }) => vec![&**target, &**motive, &**base, &**step],
+            Expression::IndVec(IndVec {
+                len,
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => vec![&**len, &**target, &**motive, &**base, &**step],
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -39,16 +543,42 @@ pub struct Variable<Ann = ()> {
     pub ident: Identifier,
 }
 
+/// Types that don't take any arguments.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Base {
+    Atom,
+    Nat,
+    Trivial,
+    Absurd,
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Base::Atom => write!(f, "Atom"),
+            Base::Nat => write!(f, "Nat"),
+            Base::Trivial => write!(f, "Trivial"),
+            Base::Absurd => write!(f, "Absurd"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Type<Ann = ()> {
     pub ann: Ann,
-    pub ident: Identifier,
+    pub ty: Base,
+}
+
+// `U', the type of (almost) all types
+#[derive(Clone, Debug)]
+pub struct Universe<Ann = ()> {
+    pub ann: Ann,
 }
 
 #[derive(Clone, Debug)]
 pub struct Lambda<Ann = ()> {
     pub ann: Ann,
-    pub args: Vec<Box<Expression<Ann>>>,
+    pub args: Vec<Binder<Ann>>,
     pub body: Box<Expression<Ann>>,
 }
 
@@ -59,13 +589,335 @@ pub struct Apply<Ann = ()> {
     pub args: Vec<Box<Expression<Ann>>>,
 }
 
+#[derive(Clone, Debug)]
+pub struct Arrow<Ann = ()> {
+    pub ann: Ann,
+    pub doms: Vec<Box<Expression<Ann>>>,
+    pub cod: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pi<Ann = ()> {
+    pub ann: Ann,
+    pub binders: Vec<TypedBinder<Ann>>,
+    pub cod: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Sigma<Ann = ()> {
+    pub ann: Ann,
+    pub binders: Vec<TypedBinder<Ann>>,
+    pub cdr_ty: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Pair<Ann = ()> {
+    pub ann: Ann,
+    pub car_ty: Box<Expression<Ann>>,
+    pub cdr_ty: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cons<Ann = ()> {
+    pub ann: Ann,
+    pub car: Box<Expression<Ann>>,
+    pub cdr: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Car<Ann = ()> {
+    pub ann: Ann,
+    pub pair: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cdr<Ann = ()> {
+    pub ann: Ann,
+    pub pair: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct The<Ann = ()> {
+    pub ann: Ann,
+    pub ty: Box<Expression<Ann>>,
+    pub expr: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Zero<Ann = ()> {
+    pub ann: Ann,
+}
+
+#[derive(Clone, Debug)]
+pub struct Add1<Ann = ()> {
+    pub ann: Ann,
+    pub n: Box<Expression<Ann>>,
+}
+
+// decimal literal, standing for that many `add1's around `zero'
+#[derive(Clone, Debug)]
+pub struct Numeral<Ann = ()> {
+    pub ann: Ann,
+    pub n: usize,
+}
+
+#[derive(Clone, Debug)]
+pub struct WhichNat<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IterNat<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecNat<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndNat<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct List<Ann = ()> {
+    pub ann: Ann,
+    pub elem: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Nil<Ann = ()> {
+    pub ann: Ann,
+}
+
+#[derive(Clone, Debug)]
+pub struct ListCons<Ann = ()> {
+    pub ann: Ann,
+    pub head: Box<Expression<Ann>>,
+    pub tail: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct RecList<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndList<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+// `(Vec E len)', named so as not to shadow the standard `Vec'
+#[derive(Clone, Debug)]
+pub struct Vector<Ann = ()> {
+    pub ann: Ann,
+    pub elem: Box<Expression<Ann>>,
+    pub len: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct VecNil<Ann = ()> {
+    pub ann: Ann,
+}
+
+#[derive(Clone, Debug)]
+pub struct VecCons<Ann = ()> {
+    pub ann: Ann,
+    pub head: Box<Expression<Ann>>,
+    pub tail: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Head<Ann = ()> {
+    pub ann: Ann,
+    pub vec: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Tail<Ann = ()> {
+    pub ann: Ann,
+    pub vec: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndVec<Ann = ()> {
+    pub ann: Ann,
+    pub len: Box<Expression<Ann>>,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+    pub step: Box<Expression<Ann>>,
+}
+
+// `(= X from to)'
+#[derive(Clone, Debug)]
+pub struct Equal<Ann = ()> {
+    pub ann: Ann,
+    pub ty: Box<Expression<Ann>>,
+    pub from: Box<Expression<Ann>>,
+    pub to: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Same<Ann = ()> {
+    pub ann: Ann,
+    pub expr: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Cong<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub fun: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Replace<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Symm<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Trans<Ann = ()> {
+    pub ann: Ann,
+    pub left: Box<Expression<Ann>>,
+    pub right: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndEq<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Either<Ann = ()> {
+    pub ann: Ann,
+    pub left: Box<Expression<Ann>>,
+    pub right: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Left<Ann = ()> {
+    pub ann: Ann,
+    pub expr: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Right<Ann = ()> {
+    pub ann: Ann,
+    pub expr: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndEither<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+    pub base_left: Box<Expression<Ann>>,
+    pub base_right: Box<Expression<Ann>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Sole<Ann = ()> {
+    pub ann: Ann,
+}
+
+#[derive(Clone, Debug)]
+pub struct IndAbsurd<Ann = ()> {
+    pub ann: Ann,
+    pub target: Box<Expression<Ann>>,
+    pub motive: Box<Expression<Ann>>,
+}
+
+// hole in a program, to be filled in later
+#[derive(Clone, Debug)]
+pub struct Todo<Ann = ()> {
+    pub ann: Ann,
+}
+
+// argument of a `λ'
+#[derive(Clone, Debug)]
+pub struct Binder<Ann = ()> {
+    pub ann: Ann,
+    pub ident: Identifier,
+}
+
+#[derive(Clone, Debug)]
+pub struct TypedBinder<Ann = ()> {
+    pub ann: Ann,
+    pub ident: Identifier,
+    pub ty: Box<Expression<Ann>>,
+}
+
 #[derive(Clone, Debug)]
 pub enum Statement<Ann = ()> {
     Claim(Claim<Ann>),
     Def(Define<Ann>),
+    CheckSame(CheckSame<Ann>),
     Expr(Expression<Ann>),
 }
 
+impl<Ann> Statement<Ann> {
+    /// Call `f' on the annotation of every part of the statement.
+    pub fn for_each_ann(&mut self, f: &mut impl FnMut(&mut Ann)) {
+        match self {
+            Statement::Claim(Claim { ann, expr, .. }) => {
+                f(ann);
+                expr.for_each_ann(f);
+            }
+            Statement::Def(Define { ann, body, .. }) => {
+                f(ann);
+                body.for_each_ann(f);
+            }
+            Statement::CheckSame(CheckSame {
+                ann,
+                ty,
+                left,
+                right,
+            }) => {
+                f(ann);
+                ty.for_each_ann(f);
+                left.for_each_ann(f);
+                right.for_each_ann(f);
+            }
+            Statement::Expr(expr) => expr.for_each_ann(f),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct Claim<Ann = ()> {
     pub ann: Ann,
@@ -80,6 +932,15 @@ pub struct Define<Ann = ()> {
     pub body: Box<Expression<Ann>>,
 }
 
+// `(check-same T left right)', asserting that both are the same `T'
+#[derive(Clone, Debug)]
+pub struct CheckSame<Ann = ()> {
+    pub ann: Ann,
+    pub ty: Box<Expression<Ann>>,
+    pub left: Box<Expression<Ann>>,
+    pub right: Box<Expression<Ann>>,
+}
+
 #[derive(Clone, Debug)]
 pub struct Source<Ann = ()> {
     pub ann: Ann,
@@ -95,3 +956,23 @@ pub struct Definition<Ann = ()> {
     pub claim: (Ann, Box<Expression<Ann>>),
     pub body: (Ann, Box<Expression<Ann>>),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+
+    #[test]
+    fn test_identifier() {
+        let x = Identifier::new("x");
+        assert_eq!(x, Identifier::new(&String::from("x")));
+        assert_ne!(x, Identifier::new("y"));
+        assert_eq!(x.as_str(), "x");
+        assert_eq!(format!("{x} {x:?}"), "x Identifier(\"x\")");
+        // the same on every thread
+        let other = thread::spawn(|| Identifier::new("x")).join().unwrap();
+        assert_eq!(x, other);
+        let set: HashSet<Identifier> = [x, other, Identifier::new("y")].into();
+        assert_eq!(set.len(), 2);
+    }
+}
@@ -0,0 +1,277 @@
+use crate::syntax::{
+    Add1, Apply, Arrow, Atom, Binder, Car, Cdr, CheckSame, Claim, Cong, Cons, Define, Either,
+    Equal, Expression, Head, IndAbsurd, IndEither, IndEq, IndList, IndNat, IndVec, IterNat, Lambda,
+    Left, List, ListCons, Numeral, Pair, Pi, RecList, RecNat, Replace, Right, Same, Sigma, Source,
+    Statement, Symm, Tail, The, Trans, Type, TypedBinder, Variable, VecCons, Vector, WhichNat,
+};
+use pretty::{DocAllocator, RcAllocator, RcDoc};
+use std::fmt;
+
+// used when the formatter isn't given one, as in `{expr:60}'
+const WIDTH: usize = 80;
+
+type Doc = RcDoc<'static>;
+
+////////////////////////////////////////////////
+// Layout
+// the rest of the document is indented up to the current column
+fn align(doc: Doc) -> Doc {
+    RcAllocator.nil().append(doc).align().into_doc()
+}
+
+// `(head first rest ...)', either on one line or with each of `rest' on
+// its own line, indented under `head'
+fn form(head: Doc, args: Vec<Doc>) -> Doc {
+    let mut args = args.into_iter();
+    let Some(first) = args.next() else {
+        return Doc::text("(").append(head).append(")");
+    };
+    let rest = Doc::concat(args.map(|arg| Doc::line().append(arg)));
+    Doc::text("(")
+        .append(head)
+        .append(" ")
+        .append(align(first))
+        .append(rest.nest(2))
+        .append(")")
+        .group()
+}
+
+fn keyword<Ann>(name: &'static str, args: &[&Expression<Ann>]) -> Doc {
+    form(
+        Doc::text(name),
+        args.iter().map(|arg| expression(arg)).collect(),
+    )
+}
+
+// `((x A) (y B) ...)', aligned when it doesn't fit on one line
+fn telescope<Ann>(binders: &[TypedBinder<Ann>]) -> Doc {
+    let binders = binders
+        .iter()
+        .map(|TypedBinder { ident, ty, .. }| form(Doc::text(ident.as_str()), vec![expression(ty)]));
+    Doc::text("(")
+        .append(align(Doc::intersperse(binders, Doc::line()).group()))
+        .append(")")
+}
+
+// `(head binders' body)', with the body on the next line if need be
+fn binding(head: &'static str, binders: Doc, body: Doc) -> Doc {
+    Doc::text("(")
+        .append(head)
+        .append(" ")
+        .append(binders)
+        .append(Doc::line().append(body).nest(2))
+        .append(")")
+        .group()
+}
+
+fn expression<Ann>(expr: &Expression<Ann>) -> Doc {
+    match expr {
+        Expression::Atom(Atom { ident, .. }) => Doc::text("'").append(ident.as_str()),
+        Expression::Ref(Variable { ident, .. }) => Doc::text(ident.as_str()),
+        Expression::Ty(Type { ty, .. }) => Doc::as_string(ty),
+        Expression::Universe(_) => Doc::text("U"),
+        Expression::Abs(Lambda { args, body, .. }) => {
+            let args = args
+                .iter()
+                .map(|Binder { ident, .. }| Doc::text(ident.as_str()));
+            let args = Doc::text("(")
+                .append(Doc::intersperse(args, " "))
+                .append(")");
+            binding("λ", args, expression(body))
+        }
+        Expression::App(Apply { fun, args, .. }) => form(
+            expression(fun),
+            args.iter().map(|arg| expression(arg)).collect(),
+        ),
+        Expression::Arrow(Arrow { doms, cod, .. }) => {
+            let args = doms.iter().chain([cod]).map(|arg| expression(arg));
+            form(Doc::text("→"), args.collect())
+        }
+        Expression::Pi(Pi { binders, cod, .. }) => {
+            binding("Π", telescope(binders), expression(cod))
+        }
+        Expression::Sigma(Sigma {
+            binders, cdr_ty, ..
+        }) => binding("Σ", telescope(binders), expression(cdr_ty)),
+        Expression::Pair(Pair { car_ty, cdr_ty, .. }) => keyword("Pair", &[car_ty, cdr_ty]),
+        Expression::Cons(Cons { car, cdr, .. }) => keyword("cons", &[car, cdr]),
+        Expression::Car(Car { pair, .. }) => keyword("car", &[pair]),
+        Expression::Cdr(Cdr { pair, .. }) => keyword("cdr", &[pair]),
+        Expression::The(The { ty, expr, .. }) => keyword("the", &[ty, expr]),
+        // written as they are, so that they parse back the same
+        Expression::Zero(_) => Doc::text("zero"),
+        Expression::Add1(Add1 { n, .. }) => keyword("add1", &[n]),
+        Expression::Numeral(Numeral { n, .. }) => Doc::as_string(n),
+        Expression::WhichNat(WhichNat {
+            target, base, step, ..
+        }) => keyword("which-Nat", &[target, base, step]),
+        Expression::IterNat(IterNat {
+            target, base, step, ..
+        }) => keyword("iter-Nat", &[target, base, step]),
+        Expression::RecNat(RecNat {
+            target, base, step, ..
+        }) => keyword("rec-Nat", &[target, base, step]),
+        Expression::IndNat(IndNat {
+            target,
+            motive,
+            base,
+            step,
+            ..
+        }) => keyword("ind-Nat", &[target, motive, base, step]),
+        Expression::List(List { elem, .. }) => keyword("List", &[elem]),
+        Expression::Nil(_) => Doc::text("nil"),
+        Expression::ListCons(ListCons { head, tail, .. }) => keyword("::", &[head, tail]),
+        Expression::RecList(RecList {
+            target, base, step, ..
+        }) => keyword("rec-List", &[target, base, step]),
+        Expression::IndList(IndList {
+            target,
+            motive,
+            base,
+            step,
+            ..
+        }) => keyword("ind-List", &[target, motive, base, step]),
+        Expression::Vector(Vector { elem, len, .. }) => keyword("Vec", &[elem, len]),
+        Expression::VecNil(_) => Doc::text("vecnil"),
+        Expression::VecCons(VecCons { head, tail, .. }) => keyword("vec::", &[head, tail]),
+        Expression::Head(Head { vec, .. }) => keyword("head", &[vec]),
+        Expression::Tail(Tail { vec, .. }) => keyword("tail", &[vec]),
+        Expression::IndVec(IndVec {
+            len,
+            target,
+            motive,
+            base,
+            step,
+            ..
+        }) => keyword("ind-Vec", &[len, target, motive, base, step]),
+        Expression::Equal(Equal { ty, from, to, .. }) => keyword("=", &[ty, from, to]),
+        Expression::Same(Same { expr, .. }) => keyword("same", &[expr]),
+        Expression::Cong(Cong { target, fun, .. }) => keyword("cong", &[target, fun]),
+        Expression::Replace(Replace {
+            target,
+            motive,
+            base,
+            ..
+        }) => keyword("replace", &[target, motive, base]),
+        Expression::Symm(Symm { target, .. }) => keyword("symm", &[target]),
+        Expression::Trans(Trans { left, right, .. }) => keyword("trans", &[left, right]),
+        Expression::IndEq(IndEq {
+            target,
+            motive,
+            base,
+            ..
+        }) => keyword("ind-Eq", &[target, motive, base]),
+        Expression::Either(Either { left, right, .. }) => keyword("Either", &[left, right]),
+        Expression::Left(Left { expr, .. }) => keyword("left", &[expr]),
+        Expression::Right(Right { expr, .. }) => keyword("right", &[expr]),
+        Expression::IndEither(IndEither {
+            target,
+            motive,
+            base_left,
+            base_right,
+            ..
+        }) => keyword("ind-Either", &[target, motive, base_left, base_right]),
+        Expression::Sole(_) => Doc::text("sole"),
+        Expression::Todo(_) => Doc::text("TODO"),
+        Expression::IndAbsurd(IndAbsurd { target, motive, .. }) => {
+            keyword("ind-Absurd", &[target, motive])
+        }
+    }
+}
+
+fn statement<Ann>(stmt: &Statement<Ann>) -> Doc {
+    match stmt {
+        Statement::Claim(Claim { ident, expr, .. }) => form(
+            Doc::text("claim"),
+            vec![Doc::text(ident.as_str()), expression(expr)],
+        ),
+        Statement::Def(Define { ident, body, .. }) => form(
+            Doc::text("define"),
+            vec![Doc::text(ident.as_str()), expression(body)],
+        ),
+        Statement::CheckSame(CheckSame {
+            ty, left, right, ..
+        }) => keyword("check-same", &[ty, left, right]),
+        Statement::Expr(expr) => expression(expr),
+    }
+}
+
+// top-level statements are separated by an empty line
+fn source<Ann>(Source { statements, .. }: &Source<Ann>) -> Doc {
+    let separator = Doc::hardline().append(Doc::hardline());
+    Doc::intersperse(statements.iter().map(statement), separator)
+}
+
+////////////////////////////////////////////////
+// Printing
+/// Prints canonical Pie, as wide as the formatter's width or 80 columns.
+impl<Ann> fmt::Display for Expression<Ann> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        expression(self).render_fmt(f.width().unwrap_or(WIDTH), f)
+    }
+}
+
+impl<Ann> fmt::Display for Statement<Ann> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        statement(self).render_fmt(f.width().unwrap_or(WIDTH), f)
+    }
+}
+
+impl<Ann> fmt::Display for Source<Ann> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        source(self).render_fmt(f.width().unwrap_or(WIDTH), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parser::parse;
+    use std::fs;
+
+    // the statements of `text', without their spans
+    fn statements(text: &str) -> Vec<String> {
+        let (source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        source
+            .statements
+            .iter()
+            .map(|stmt| {
+                let debug = format!("{stmt:?}");
+                let mut parts = debug.split("SourceSpan {");
+                let mut stripped = parts.next().unwrap_or_default().to_string();
+                for part in parts {
+                    stripped.push_str(part.split_once('}').map_or(part, |(_, rest)| rest));
+                }
+                stripped
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_round_trip_examples() {
+        let mut checked = 0;
+        for entry in fs::read_dir("examples").unwrap() {
+            let path = entry.unwrap().path();
+            let text = fs::read_to_string(&path).unwrap();
+            // some examples have syntax errors on purpose
+            let Ok((source, errors)) = parse(&text) else {
+                continue;
+            };
+            if !errors.is_empty() {
+                continue;
+            }
+            let expected = statements(&text);
+            for width in [80, 20] {
+                let printed = format!("{source:width$}");
+                assert_eq!(
+                    statements(&printed),
+                    expected,
+                    "{} printed at width {width}:\n{printed}",
+                    path.display()
+                );
+            }
+            checked += 1;
+        }
+        assert!(checked > 0, "no examples to print");
+    }
+}
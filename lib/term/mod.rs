@@ -0,0 +1,955 @@
+use crate::syntax::{
+    Add1, Apply, Arrow, Atom, Base, Binder, Car, Cdr, Cong, Cons, Either, Equal, Expression, Head,
+    Identifier, IndAbsurd, IndEither, IndEq, IndList, IndNat, IndVec, IterNat, Lambda, Left, List,
+    ListCons, Nil, Numeral, Pair, Pi, RecList, RecNat, Replace, Right, Same, Sigma, Sole, Symm,
+    Tail, The, Todo, Trans, Type, TypedBinder, Universe, Variable, VecCons, VecNil, Vector,
+    WhichNat,
+};
+use std::rc::Rc;
+
+////////////////////////////////////////////////
+// Core terms
+/// Name a binder had in the source, only used when turning terms back
+/// into syntax.  All hints are the same, so that terms equal up to
+/// renaming of bound variables are equal.
+#[derive(Clone, Debug)]
+pub struct Hint(pub Identifier);
+
+impl PartialEq for Hint {
+    fn eq(&self, _other: &Hint) -> bool {
+        true
+    }
+}
+
+impl Eq for Hint {}
+
+/// Locally nameless representation of expressions: bound variables are
+/// de Bruijn indices, counting binders from the innermost one, and
+/// free variables keep their names.  All functions take one argument.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Term {
+    Bound(usize),
+    Free(Identifier),
+    Atom(Identifier),
+    Universe,
+    Base(Base),
+    // bodies under binders are shared with the closures they evaluate to
+    Lam(Hint, Rc<Term>),
+    App(Box<Term>, Box<Term>),
+    // domain, and codomain under the binder
+    Pi(Hint, Box<Term>, Rc<Term>),
+    Sigma(Hint, Box<Term>, Rc<Term>),
+    Cons(Box<Term>, Box<Term>),
+    Car(Box<Term>),
+    Cdr(Box<Term>),
+    The(Box<Term>, Box<Term>),
+    Zero,
+    // that many `add1's around a term that isn't one, so that big
+    // numbers stay small
+    Add1(usize, Box<Term>),
+    // target, base and step
+    WhichNat(Box<Term>, Box<Term>, Box<Term>),
+    IterNat(Box<Term>, Box<Term>, Box<Term>),
+    RecNat(Box<Term>, Box<Term>, Box<Term>),
+    // target, motive, base and step
+    IndNat(Box<Term>, Box<Term>, Box<Term>, Box<Term>),
+    List(Box<Term>),
+    Nil,
+    ListCons(Box<Term>, Box<Term>),
+    RecList(Box<Term>, Box<Term>, Box<Term>),
+    IndList(Box<Term>, Box<Term>, Box<Term>, Box<Term>),
+    // element type and length
+    Vec(Box<Term>, Box<Term>),
+    VecNil,
+    VecCons(Box<Term>, Box<Term>),
+    Head(Box<Term>),
+    Tail(Box<Term>),
+    // length, target, motive, base and step
+    IndVec(Box<Term>, Box<Term>, Box<Term>, Box<Term>, Box<Term>),
+    // type, from and to
+    Equal(Box<Term>, Box<Term>, Box<Term>),
+    Same(Box<Term>),
+    // target and function
+    Cong(Box<Term>, Box<Term>),
+    // target, motive and base
+    Replace(Box<Term>, Box<Term>, Box<Term>),
+    Symm(Box<Term>),
+    Trans(Box<Term>, Box<Term>),
+    IndEq(Box<Term>, Box<Term>, Box<Term>),
+    Either(Box<Term>, Box<Term>),
+    Left(Box<Term>),
+    Right(Box<Term>),
+    // target, motive and both bases
+    IndEither(Box<Term>, Box<Term>, Box<Term>, Box<Term>),
+    Sole,
+    // target and motive
+    IndAbsurd(Box<Term>, Box<Term>),
+    Todo,
+}
+
+////////////////////////////////////////////////
+// Utils
+/// Pick a variant of `name' that doesn't clash with any of the `used'
+/// names, by numbering it with subscripts.  A name that is already
+/// numbered counts on from its number, so `x₁' becomes `x₂'.
+pub fn fresh(used: &[Identifier], name: &Identifier) -> Identifier {
+    const SUBSCRIPTS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+    let text = name.as_str();
+    let base = text.trim_end_matches(SUBSCRIPTS);
+    let number = text[base.len()..].chars().try_fold(0usize, |n, digit| {
+        let digit = SUBSCRIPTS.iter().position(|sub| *sub == digit)?;
+        n.checked_mul(10)?.checked_add(digit)
+    });
+    // a name made of subscripts alone has nothing to number
+    let (base, mut n) = match number {
+        Some(n) if !base.is_empty() => (base, n),
+        _ => (text, 0),
+    };
+    let mut candidate = *name;
+    while used.contains(&candidate) {
+        n += 1;
+        let suffix: String = n
+            .to_string()
+            .chars()
+            .map(|digit| SUBSCRIPTS[digit.to_digit(10).unwrap_or(0) as usize])
+            .collect();
+        candidate = Identifier::new(&format!("{base}{suffix}"));
+    }
+    candidate
+}
+
+fn boxed(term: Term) -> Box<Term> {
+    Box::new(term)
+}
+
+/// `count' more `add1's around `n'.
+pub fn add1s(count: usize, n: Term) -> Term {
+    match n {
+        n if count == 0 => n,
+        Term::Add1(more, n) => Term::Add1(count + more, n),
+        n => Term::Add1(count, boxed(n)),
+    }
+}
+
+impl Term {
+    // immediate sub-terms, with the number of binders they are under
+    fn subterms(&self) -> Vec<(&Term, usize)> {
+        match self {
+            Term::Bound(_)
+            | Term::Free(_)
+            | Term::Atom(_)
+            | Term::Universe
+            | Term::Base(_)
+            | Term::Zero
+            | Term::Nil
+            | Term::VecNil
+            | Term::Sole
+            | Term::Todo => vec![],
+            Term::Lam(_, body) => vec![(body, 1)],
+            Term::Pi(_, dom, cod) | Term::Sigma(_, dom, cod) => vec![(dom, 0), (cod, 1)],
+            Term::Car(term)
+            | Term::Cdr(term)
+            | Term::Add1(_, term)
+            | Term::List(term)
+            | Term::Head(term)
+            | Term::Tail(term)
+            | Term::Same(term)
+            | Term::Symm(term)
+            | Term::Left(term)
+            | Term::Right(term) => vec![(term, 0)],
+            Term::App(fst, snd)
+            | Term::Cons(fst, snd)
+            | Term::The(fst, snd)
+            | Term::ListCons(fst, snd)
+            | Term::Vec(fst, snd)
+            | Term::VecCons(fst, snd)
+            | Term::Cong(fst, snd)
+            | Term::Trans(fst, snd)
+            | Term::Either(fst, snd)
+            | Term::IndAbsurd(fst, snd) => vec![(fst, 0), (snd, 0)],
+            Term::WhichNat(fst, snd, thd)
+            | Term::IterNat(fst, snd, thd)
+            | Term::RecNat(fst, snd, thd)
+            | Term::RecList(fst, snd, thd)
+            | Term::Equal(fst, snd, thd)
+            | Term::Replace(fst, snd, thd)
+            | Term::IndEq(fst, snd, thd) => vec![(fst, 0), (snd, 0), (thd, 0)],
+            Term::IndNat(fst, snd, thd, fth)
+            | Term::IndList(fst, snd, thd, fth)
+            | Term::IndEither(fst, snd, thd, fth) => {
+                vec![(fst, 0), (snd, 0), (thd, 0), (fth, 0)]
+            }
+            Term::IndVec(len, target, motive, base, step) => {
+                vec![(len, 0), (target, 0), (motive, 0), (base, 0), (step, 0)]
+            }
+        }
+    }
+
+    fn subterms_mut(&mut self) -> Vec<(&mut Term, usize)> {
+        match self {
+            Term::Bound(_)
+            | Term::Free(_)
+            | Term::Atom(_)
+            | Term::Universe
+            | Term::Base(_)
+            | Term::Zero
+            | Term::Nil
+            | Term::VecNil
+            | Term::Sole
+            | Term::Todo => vec![],
+            Term::Lam(_, body) => vec![(Rc::make_mut(body), 1)],
+            Term::Pi(_, dom, cod) | Term::Sigma(_, dom, cod) => {
+                vec![(dom, 0), (Rc::make_mut(cod), 1)]
+            }
+            Term::Car(term)
+            | Term::Cdr(term)
+            | Term::Add1(_, term)
+            | Term::List(term)
+            | Term::Head(term)
+            | Term::Tail(term)
+            | Term::Same(term)
+            | Term::Symm(term)
+            | Term::Left(term)
+            | Term::Right(term) => vec![(term, 0)],
+            Term::App(fst, snd)
+            | Term::Cons(fst, snd)
+            | Term::The(fst, snd)
+            | Term::ListCons(fst, snd)
+            | Term::Vec(fst, snd)
+            | Term::VecCons(fst, snd)
+            | Term::Cong(fst, snd)
+            | Term::Trans(fst, snd)
+            | Term::Either(fst, snd)
+            | Term::IndAbsurd(fst, snd) => vec![(fst, 0), (snd, 0)],
+            Term::WhichNat(fst, snd, thd)
+            | Term::IterNat(fst, snd, thd)
+            | Term::RecNat(fst, snd, thd)
+            | Term::RecList(fst, snd, thd)
+            | Term::Equal(fst, snd, thd)
+            | Term::Replace(fst, snd, thd)
+            | Term::IndEq(fst, snd, thd) => vec![(fst, 0), (snd, 0), (thd, 0)],
+            Term::IndNat(fst, snd, thd, fth)
+            | Term::IndList(fst, snd, thd, fth)
+            | Term::IndEither(fst, snd, thd, fth) => {
+                vec![(fst, 0), (snd, 0), (thd, 0), (fth, 0)]
+            }
+            Term::IndVec(len, target, motive, base, step) => {
+                vec![(len, 0), (target, 0), (motive, 0), (base, 0), (step, 0)]
+            }
+        }
+    }
+
+    /// Does the variable bound `index' binders up occur in the term?
+    pub fn mentions(&self, index: usize) -> bool {
+        match self {
+            Term::Bound(var) => *var == index,
+            term => term
+                .subterms()
+                .into_iter()
+                .any(|(term, binders)| term.mentions(index + binders)),
+        }
+    }
+
+    /// Turn free variable `name' into a bound one, for a binder placed
+    /// right around the term.
+    pub fn close(mut self, name: &Identifier) -> Term {
+        self.close_(name, 0);
+        self
+    }
+
+    fn close_(&mut self, name: &Identifier, index: usize) {
+        match self {
+            Term::Free(var) if var == name => *self = Term::Bound(index),
+            term => {
+                for (term, binders) in term.subterms_mut() {
+                    term.close_(name, index + binders);
+                }
+            }
+        }
+    }
+
+    fn free_vars(&self, vars: &mut Vec<Identifier>) {
+        match self {
+            Term::Free(var) if !vars.contains(var) => vars.push(*var),
+            term => term
+                .subterms()
+                .into_iter()
+                .for_each(|(term, _)| term.free_vars(vars)),
+        }
+    }
+}
+
+////////////////////////////////////////////////
+// Lowering
+/// Translate `expr' to a term, leaving variables that it doesn't bind
+/// free.
+pub fn lower<Ann>(expr: &Expression<Ann>) -> Term {
+    Lower(vec![]).expr(expr)
+}
+
+/// Are `lhs' and `rhs' the same up to names of bound variables?
+pub fn alpha_equivalent<L, R>(lhs: &Expression<L>, rhs: &Expression<R>) -> bool {
+    lower(lhs) == lower(rhs)
+}
+
+// variables in scope, innermost last; binders of arrows and pairs can't
+// be referred to
+struct Lower(Vec<Option<Identifier>>);
+
+impl Lower {
+    fn boxed<Ann>(&mut self, expr: &Expression<Ann>) -> Box<Term> {
+        boxed(self.expr(expr))
+    }
+
+    fn var(&self, ident: &Identifier) -> Term {
+        match self
+            .0
+            .iter()
+            .rev()
+            .position(|name| name.as_ref() == Some(ident))
+        {
+            Some(index) => Term::Bound(index),
+            None => Term::Free(*ident),
+        }
+    }
+
+    // lower `body' under binders, one at a time
+    fn under<Ann>(
+        &mut self,
+        binders: &[(Option<Identifier>, &Expression<Ann>)],
+        body: &Expression<Ann>,
+        former: impl Fn(Hint, Box<Term>, Rc<Term>) -> Term,
+    ) -> Term {
+        let depth = self.0.len();
+        let doms: Vec<(Hint, Term)> = binders
+            .iter()
+            .map(|(name, dom)| {
+                let dom = self.expr(dom);
+                self.0.push(*name);
+                let hint = (*name).unwrap_or(Identifier::new("x"));
+                (Hint(hint), dom)
+            })
+            .collect();
+        let body = self.expr(body);
+        self.0.truncate(depth);
+        doms.into_iter().rev().fold(body, |body, (hint, dom)| {
+            former(hint, boxed(dom), Rc::new(body))
+        })
+    }
+
+    fn expr<Ann>(&mut self, expr: &Expression<Ann>) -> Term {
+        match expr {
+            Expression::Atom(Atom { ident, .. }) => Term::Atom(*ident),
+            Expression::Ref(Variable { ident, .. }) => self.var(ident),
+            Expression::Ty(Type { ty, .. }) => Term::Base(*ty),
+            Expression::Universe(_) => Term::Universe,
+            Expression::Abs(Lambda { args, body, .. }) => {
+                let depth = self.0.len();
+                let hints: Vec<Identifier> = args.iter().map(|arg| arg.ident).collect();
+                self.0.extend(hints.iter().copied().map(Some));
+                let body = self.expr(body);
+                self.0.truncate(depth);
+                hints
+                    .into_iter()
+                    .rev()
+                    .fold(body, |body, hint| Term::Lam(Hint(hint), Rc::new(body)))
+            }
+            Expression::App(Apply { fun, args, .. }) => {
+                args.iter().fold(self.expr(fun), |fun, arg| {
+                    Term::App(boxed(fun), self.boxed(arg))
+                })
+            }
+            Expression::Arrow(Arrow { doms, cod, .. }) => {
+                let binders: Vec<_> = doms.iter().map(|dom| (None, dom.as_ref())).collect();
+                self.under(&binders, cod, Term::Pi)
+            }
+            Expression::Pi(Pi { binders, cod, .. }) => {
+                let binders: Vec<_> = binders
+                    .iter()
+                    .map(|TypedBinder { ident, ty, .. }| (Some(*ident), ty.as_ref()))
+                    .collect();
+                self.under(&binders, cod, Term::Pi)
+            }
+            Expression::Sigma(Sigma {
+                binders, cdr_ty, ..
+            }) => {
+                let binders: Vec<_> = binders
+                    .iter()
+                    .map(|TypedBinder { ident, ty, .. }| (Some(*ident), ty.as_ref()))
+                    .collect();
+                self.under(&binders, cdr_ty, Term::Sigma)
+            }
+            Expression::Pair(Pair { car_ty, cdr_ty, .. }) => {
+                self.under(&[(None, car_ty.as_ref())], cdr_ty, Term::Sigma)
+            }
+            Expression::Cons(Cons { car, cdr, .. }) => Term::Cons(self.boxed(car), self.boxed(cdr)),
+            Expression::Car(Car { pair, .. }) => Term::Car(self.boxed(pair)),
+            Expression::Cdr(Cdr { pair, .. }) => Term::Cdr(self.boxed(pair)),
+            Expression::The(The { ty, expr, .. }) => Term::The(self.boxed(ty), self.boxed(expr)),
+            Expression::Zero(_) => Term::Zero,
+            Expression::Add1(Add1 { n, .. }) => add1s(1, self.expr(n)),
+            Expression::Numeral(Numeral { n, .. }) => add1s(*n, Term::Zero),
+            Expression::WhichNat(WhichNat {
+                target, base, step, ..
+            }) => Term::WhichNat(self.boxed(target), self.boxed(base), self.boxed(step)),
+            Expression::IterNat(IterNat {
+                target, base, step, ..
+            }) => Term::IterNat(self.boxed(target), self.boxed(base), self.boxed(step)),
+            Expression::RecNat(RecNat {
+                target, base, step, ..
+            }) => Term::RecNat(self.boxed(target), self.boxed(base), self.boxed(step)),
+            Expression::IndNat(IndNat {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => Term::IndNat(
+                self.boxed(target),
+                self.boxed(motive),
+                self.boxed(base),
+                self.boxed(step),
+            ),
+            Expression::List(List { elem, .. }) => Term::List(self.boxed(elem)),
+            Expression::Nil(_) => Term::Nil,
+            Expression::ListCons(ListCons { head, tail, .. }) => {
+                Term::ListCons(self.boxed(head), self.boxed(tail))
+            }
+            Expression::RecList(RecList {
+                target, base, step, ..
+            }) => Term::RecList(self.boxed(target), self.boxed(base), self.boxed(step)),
+            Expression::IndList(IndList {
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => Term::IndList(
+                self.boxed(target),
+                self.boxed(motive),
+                self.boxed(base),
+                self.boxed(step),
+            ),
+            Expression::Vector(Vector { elem, len, .. }) => {
+                Term::Vec(self.boxed(elem), self.boxed(len))
+            }
+            Expression::VecNil(_) => Term::VecNil,
+            Expression::VecCons(VecCons { head, tail, .. }) => {
+                Term::VecCons(self.boxed(head), self.boxed(tail))
+            }
+            Expression::Head(Head { vec, .. }) => Term::Head(self.boxed(vec)),
+            Expression::Tail(Tail { vec, .. }) => Term::Tail(self.boxed(vec)),
+            Expression::IndVec(IndVec {
+                len,
+                target,
+                motive,
+                base,
+                step,
+                ..
+            }) => Term::IndVec(
+                self.boxed(len),
+                self.boxed(target),
+                self.boxed(motive),
+                self.boxed(base),
+                self.boxed(step),
+            ),
+            Expression::Equal(Equal { ty, from, to, .. }) => {
+                Term::Equal(self.boxed(ty), self.boxed(from), self.boxed(to))
+            }
+            Expression::Same(Same { expr, .. }) => Term::Same(self.boxed(expr)),
+            Expression::Cong(Cong { target, fun, .. }) => {
+                Term::Cong(self.boxed(target), self.boxed(fun))
+            }
+            Expression::Replace(Replace {
+                target,
+                motive,
+                base,
+                ..
+            }) => Term::Replace(self.boxed(target), self.boxed(motive), self.boxed(base)),
+            Expression::Symm(Symm { target, .. }) => Term::Symm(self.boxed(target)),
+            Expression::Trans(Trans { left, right, .. }) => {
+                Term::Trans(self.boxed(left), self.boxed(right))
+            }
+            Expression::IndEq(IndEq {
+                target,
+                motive,
+                base,
+                ..
+            }) => Term::IndEq(self.boxed(target), self.boxed(motive), self.boxed(base)),
+            Expression::Either(Either { left, right, .. }) => {
+                Term::Either(self.boxed(left), self.boxed(right))
+            }
+            Expression::Left(Left { expr, .. }) => Term::Left(self.boxed(expr)),
+            Expression::Right(Right { expr, .. }) => Term::Right(self.boxed(expr)),
+            Expression::IndEither(IndEither {
+                target,
+                motive,
+                base_left,
+                base_right,
+                ..
+            }) => Term::IndEither(
+                self.boxed(target),
+                self.boxed(motive),
+                self.boxed(base_left),
+                self.boxed(base_right),
+            ),
+            Expression::Sole(_) => Term::Sole,
+            Expression::IndAbsurd(IndAbsurd { target, motive, .. }) => {
+                Term::IndAbsurd(self.boxed(target), self.boxed(motive))
+            }
+            Expression::Todo(_) => Term::Todo,
+        }
+    }
+}
+
+////////////////////////////////////////////////
+// Raising
+/// Translate `term' back to an expression, naming bound variables so
+/// that they don't capture free ones, nor any of the `names' in scope.
+pub fn raise(term: &Term, names: &[Identifier]) -> Expression {
+    let mut used = names.to_vec();
+    term.free_vars(&mut used);
+    Raise {
+        used,
+        scope: vec![],
+    }
+    .term(term)
+}
+
+struct Raise {
+    // names that new binders have to avoid
+    used: Vec<Identifier>,
+    // names of bound variables, innermost last
+    scope: Vec<Identifier>,
+}
+
+fn ref_(ident: Identifier) -> Expression {
+    Expression::Ref(Variable { ann: (), ident })
+}
+
+impl Raise {
+    fn boxed(&mut self, term: &Term) -> Box<Expression> {
+        Box::new(self.term(term))
+    }
+
+    // raise `body' under a fresh name for binder `hint'
+    fn under(&mut self, hint: &Hint, body: &Term) -> (Identifier, Expression) {
+        let name = fresh(&self.used, &hint.0);
+        self.used.push(name);
+        self.scope.push(name);
+        let body = self.term(body);
+        self.scope.pop();
+        self.used.pop();
+        (name, body)
+    }
+
+    fn term(&mut self, term: &Term) -> Expression {
+        match term {
+            Term::Bound(index) => ref_(self.scope[self.scope.len() - 1 - index]),
+            Term::Free(ident) => ref_(*ident),
+            Term::Atom(ident) => Expression::Atom(Atom {
+                ann: (),
+                ident: *ident,
+            }),
+            Term::Universe => Expression::Universe(Universe { ann: () }),
+            Term::Base(ty) => Expression::Ty(Type { ann: (), ty: *ty }),
+            // nested lambdas are written as one
+            Term::Lam(hint, body) => {
+                let (name, body) = self.under(hint, body);
+                let mut args = vec![Binder {
+                    ann: (),
+                    ident: name,
+                }];
+                match body {
+                    Expression::Abs(Lambda {
+                        args: rest, body, ..
+                    }) => {
+                        args.extend(rest);
+                        Expression::Abs(Lambda {
+                            ann: (),
+                            args,
+                            body,
+                        })
+                    }
+                    body => Expression::Abs(Lambda {
+                        ann: (),
+                        args,
+                        body: Box::new(body),
+                    }),
+                }
+            }
+            // so are applications
+            Term::App(fun, arg) => {
+                let arg = self.boxed(arg);
+                match self.term(fun) {
+                    Expression::App(Apply { fun, mut args, .. }) => {
+                        args.push(arg);
+                        Expression::App(Apply { ann: (), fun, args })
+                    }
+                    fun => Expression::App(Apply {
+                        ann: (),
+                        fun: Box::new(fun),
+                        args: vec![arg],
+                    }),
+                }
+            }
+            Term::Pi(hint, dom, cod) => {
+                let dom = self.term(dom);
+                let (name, cod_) = self.under(hint, cod);
+
+                // non-dependent ones are written as arrows
+                if !cod.mentions(0) {
+                    let mut doms = vec![Box::new(dom)];
+                    return match cod_ {
+                        Expression::Arrow(Arrow {
+                            doms: rest, cod, ..
+                        }) => {
+                            doms.extend(rest);
+                            Expression::Arrow(Arrow { ann: (), doms, cod })
+                        }
+                        cod => Expression::Arrow(Arrow {
+                            ann: (),
+                            doms,
+                            cod: Box::new(cod),
+                        }),
+                    };
+                }
+
+                let mut binders = vec![TypedBinder {
+                    ann: (),
+                    ident: name,
+                    ty: Box::new(dom),
+                }];
+                match cod_ {
+                    Expression::Pi(Pi {
+                        binders: rest, cod, ..
+                    }) => {
+                        binders.extend(rest);
+                        Expression::Pi(Pi {
+                            ann: (),
+                            binders,
+                            cod,
+                        })
+                    }
+                    cod => Expression::Pi(Pi {
+                        ann: (),
+                        binders,
+                        cod: Box::new(cod),
+                    }),
+                }
+            }
+            Term::Sigma(hint, car_ty, cdr_ty) => {
+                let car_ty = self.term(car_ty);
+                let (name, cdr_ty_) = self.under(hint, cdr_ty);
+
+                // non-dependent ones are written as pairs
+                if !cdr_ty.mentions(0) {
+                    return Expression::Pair(Pair {
+                        ann: (),
+                        car_ty: Box::new(car_ty),
+                        cdr_ty: Box::new(cdr_ty_),
+                    });
+                }
+
+                let mut binders = vec![TypedBinder {
+                    ann: (),
+                    ident: name,
+                    ty: Box::new(car_ty),
+                }];
+                match cdr_ty_ {
+                    Expression::Sigma(Sigma {
+                        binders: rest,
+                        cdr_ty,
+                        ..
+                    }) => {
+                        binders.extend(rest);
+                        Expression::Sigma(Sigma {
+                            ann: (),
+                            binders,
+                            cdr_ty,
+                        })
+                    }
+                    cdr_ty => Expression::Sigma(Sigma {
+                        ann: (),
+                        binders,
+                        cdr_ty: Box::new(cdr_ty),
+                    }),
+                }
+            }
+            Term::Cons(car, cdr) => Expression::Cons(Cons {
+                ann: (),
+                car: self.boxed(car),
+                cdr: self.boxed(cdr),
+            }),
+            Term::Car(pair) => Expression::Car(Car {
+                ann: (),
+                pair: self.boxed(pair),
+            }),
+            Term::Cdr(pair) => Expression::Cdr(Cdr {
+                ann: (),
+                pair: self.boxed(pair),
+            }),
+            Term::The(ty, expr) => Expression::The(The {
+                ann: (),
+                ty: self.boxed(ty),
+                expr: self.boxed(expr),
+            }),
+            Term::Zero => Expression::Numeral(Numeral { ann: (), n: 0 }),
+            // numbers are written as numerals
+            Term::Add1(count, n) if **n == Term::Zero => {
+                Expression::Numeral(Numeral { ann: (), n: *count })
+            }
+            Term::Add1(count, n) => (0..*count).fold(self.term(n), |n, _| {
+                Expression::Add1(Add1 {
+                    ann: (),
+                    n: Box::new(n),
+                })
+            }),
+            Term::WhichNat(target, base, step) => Expression::WhichNat(WhichNat {
+                ann: (),
+                target: self.boxed(target),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::IterNat(target, base, step) => Expression::IterNat(IterNat {
+                ann: (),
+                target: self.boxed(target),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::RecNat(target, base, step) => Expression::RecNat(RecNat {
+                ann: (),
+                target: self.boxed(target),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::IndNat(target, motive, base, step) => Expression::IndNat(IndNat {
+                ann: (),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::List(elem) => Expression::List(List {
+                ann: (),
+                elem: self.boxed(elem),
+            }),
+            Term::Nil => Expression::Nil(Nil { ann: () }),
+            Term::ListCons(head, tail) => Expression::ListCons(ListCons {
+                ann: (),
+                head: self.boxed(head),
+                tail: self.boxed(tail),
+            }),
+            Term::RecList(target, base, step) => Expression::RecList(RecList {
+                ann: (),
+                target: self.boxed(target),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::IndList(target, motive, base, step) => Expression::IndList(IndList {
+                ann: (),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::Vec(elem, len) => Expression::Vector(Vector {
+                ann: (),
+                elem: self.boxed(elem),
+                len: self.boxed(len),
+            }),
+            Term::VecNil => Expression::VecNil(VecNil { ann: () }),
+            Term::VecCons(head, tail) => Expression::VecCons(VecCons {
+                ann: (),
+                head: self.boxed(head),
+                tail: self.boxed(tail),
+            }),
+            Term::Head(vec) => Expression::Head(Head {
+                ann: (),
+                vec: self.boxed(vec),
+            }),
+            Term::Tail(vec) => Expression::Tail(Tail {
+                ann: (),
+                vec: self.boxed(vec),
+            }),
+            Term::IndVec(len, target, motive, base, step) => Expression::IndVec(IndVec {
+                ann: (),
+                len: self.boxed(len),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+                base: self.boxed(base),
+                step: self.boxed(step),
+            }),
+            Term::Equal(ty, from, to) => Expression::Equal(Equal {
+                ann: (),
+                ty: self.boxed(ty),
+                from: self.boxed(from),
+                to: self.boxed(to),
+            }),
+            Term::Same(expr) => Expression::Same(Same {
+                ann: (),
+                expr: self.boxed(expr),
+            }),
+            Term::Cong(target, fun) => Expression::Cong(Cong {
+                ann: (),
+                target: self.boxed(target),
+                fun: self.boxed(fun),
+            }),
+            Term::Replace(target, motive, base) => Expression::Replace(Replace {
+                ann: (),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+                base: self.boxed(base),
+            }),
+            Term::Symm(target) => Expression::Symm(Symm {
+                ann: (),
+                target: self.boxed(target),
+            }),
+            Term::Trans(left, right) => Expression::Trans(Trans {
+                ann: (),
+                left: self.boxed(left),
+                right: self.boxed(right),
+            }),
+            Term::IndEq(target, motive, base) => Expression::IndEq(IndEq {
+                ann: (),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+                base: self.boxed(base),
+            }),
+            Term::Either(left, right) => Expression::Either(Either {
+                ann: (),
+                left: self.boxed(left),
+                right: self.boxed(right),
+            }),
+            Term::Left(expr) => Expression::Left(Left {
+                ann: (),
+                expr: self.boxed(expr),
+            }),
+            Term::Right(expr) => Expression::Right(Right {
+                ann: (),
+                expr: self.boxed(expr),
+            }),
+            Term::IndEither(target, motive, base_left, base_right) => {
+                Expression::IndEither(IndEither {
+                    ann: (),
+                    target: self.boxed(target),
+                    motive: self.boxed(motive),
+                    base_left: self.boxed(base_left),
+                    base_right: self.boxed(base_right),
+                })
+            }
+            Term::Sole => Expression::Sole(Sole { ann: () }),
+            Term::IndAbsurd(target, motive) => Expression::IndAbsurd(IndAbsurd {
+                ann: (),
+                target: self.boxed(target),
+                motive: self.boxed(motive),
+            }),
+            Term::Todo => Expression::Todo(Todo { ann: () }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse;
+    use crate::syntax::Statement;
+    use miette::SourceSpan;
+
+    fn expr(text: &str) -> Expression<SourceSpan> {
+        let (source, errors) = parse(text).unwrap();
+        assert!(errors.is_empty(), "syntax errors: {errors:?}");
+        match source.statements.into_iter().next() {
+            Some(Statement::Expr(expr)) => expr,
+            _ => panic!("expected a single expression"),
+        }
+    }
+
+    fn names(names: &[&str]) -> Vec<Identifier> {
+        names.iter().map(|name| Identifier::new(name)).collect()
+    }
+
+    // `text' lowered and raised again among the `names' in scope
+    fn round_trip(text: &str, scope: &[&str]) -> String {
+        raise(&lower(&expr(text)), &names(scope)).to_string()
+    }
+
+    #[test]
+    fn test_fresh() {
+        let x = Identifier::new("x");
+        assert_eq!(fresh(&[], &x), x);
+        assert_eq!(fresh(&names(&["y"]), &x).as_str(), "x");
+        assert_eq!(fresh(&names(&["x"]), &x).as_str(), "x₁");
+        assert_eq!(fresh(&names(&["x", "x₁", "x₂"]), &x).as_str(), "x₃");
+        let mut used = vec![x];
+        for _ in 1..10 {
+            used.push(fresh(&used, &x));
+        }
+        assert_eq!(fresh(&used, &x).as_str(), "x₁₀");
+        // numbered names count on instead of growing more subscripts
+        let x1 = Identifier::new("x₁");
+        assert_eq!(fresh(&names(&["x₁"]), &x1).as_str(), "x₂");
+        assert_eq!(fresh(&names(&["x₁", "x₂"]), &x1).as_str(), "x₃");
+        let x9 = Identifier::new("x₉");
+        assert_eq!(fresh(&names(&["x₉"]), &x9).as_str(), "x₁₀");
+        let sub = Identifier::new("₁");
+        assert_eq!(fresh(&names(&["₁"]), &sub).as_str(), "₁₁");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for text in [
+            "(λ (x) x)",
+            "(λ (f x) (f (f x)))",
+            "(Π ((n Nat) (v (Vec Atom n))) (= (Vec Atom n) v v))",
+            "(Π ((n Nat)) (→ (Vec Atom n) (Vec Atom (add1 n))))",
+            "(Σ ((a Atom)) (= Atom a 'b))",
+            "(→ Nat Atom Nat)",
+            "(Pair Nat (Either Atom Trivial))",
+            "(which-Nat 3 'z (λ (n) 's))",
+            "(ind-Nat n (λ (k) Nat) 0 (λ (k acc) (add1 acc)))",
+            "(the (→ Nat Nat) (λ (x) x))",
+            "(cons 10000 (vec:: 'a vecnil))",
+        ] {
+            assert_eq!(round_trip(text, &[]), text);
+            assert!(alpha_equivalent(
+                &expr(text),
+                &raise(&lower(&expr(text)), &[])
+            ));
+        }
+    }
+
+    #[test]
+    fn test_raise_avoids_capture() {
+        // binders don't capture the names in scope
+        let (x, x1) = (Identifier::new("x"), Identifier::new("x₁"));
+        let term = Term::Lam(
+            Hint(x),
+            Rc::new(Term::Lam(Hint(x1), Rc::new(Term::Bound(1)))),
+        );
+        assert_eq!(raise(&term, &[x]).to_string(), "(λ (x₁ x₂) x₁)");
+        assert_eq!(round_trip("(λ (x) x)", &["x", "x₁"]), "(λ (x₂) x₂)");
+        // nor the free variables they are wrapped around
+        assert_eq!(round_trip("(λ (y) (λ (x) y))", &[]), "(λ (y x) y)");
+        let y = Identifier::new("y");
+        let term = Term::Lam(Hint(y), Rc::new(Term::Free(y)));
+        assert_eq!(raise(&term, &[]).to_string(), "(λ (y₁) y)");
+        // shadowed binders are renamed apart
+        assert_eq!(round_trip("(λ (x) (λ (x) x))", &[]), "(λ (x x₁) x₁)");
+    }
+
+    #[test]
+    fn test_alpha_equivalent() {
+        let same = |lhs, rhs| alpha_equivalent(&expr(lhs), &expr(rhs));
+        assert!(same("(λ (x) x)", "(λ (y) y)"));
+        assert!(same("(λ (x y) x)", "(λ (a b) a)"));
+        assert!(same(
+            "(Π ((n Nat)) (Vec Atom n))",
+            "(Π ((k Nat)) (Vec Atom k))"
+        ));
+        assert!(same("(add1 (add1 zero))", "2"));
+        assert!(!same("(λ (x y) x)", "(λ (x y) y)"));
+        assert!(!same("(λ (x) y)", "(λ (x) z)"));
+        assert!(!same("(λ (x) x)", "(λ (y) x)"));
+    }
+}
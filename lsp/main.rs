@@ -0,0 +1,17 @@
+extern crate pie;
+use lsp_server::Connection;
+use miette::{IntoDiagnostic, Result};
+use pie::lsp;
+
+// every distinct name the server sees stays interned until it exits,
+// including the unfinished ones typed on the way to a name, at a few
+// dozen bytes on top of the name each: a hundred thousand different
+// names cost some megabytes, so restart the server if a very long
+// session grows too big
+fn main() -> Result<()> {
+    let (connection, io_threads) = Connection::stdio();
+    lsp::serve(&connection)?;
+    drop(connection);
+    io_threads.join().into_diagnostic()?;
+    Ok(())
+}
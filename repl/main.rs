@@ -1,6 +1,6 @@
 extern crate pie;
 
-use miette::Report;
+use miette::{Diagnostic, Report, Severity};
 use rustyline::{
     completion::{Completer, FilenameCompleter, Pair},
     config::Config,
@@ -12,16 +12,12 @@ use rustyline::{
 };
 use rustyline_derive::Helper;
 use std::{
-    borrow::{
-        Cow,
-        Cow::{Borrowed, Owned},
-    },
+    borrow::Cow::{Borrowed, Owned},
     format, println,
 };
-use xdg;
 
 use pie::error::Result;
-use pie::parser;
+use pie::{check, desugar, elab, parser, resolve};
 
 #[derive(Helper)]
 struct PieHelper {
@@ -92,6 +88,89 @@ impl Validator for PieHelper {
     }
 }
 
+// everything entered so far, which checks as a whole; the names in it,
+// and the ones the checker makes up to rename variables apart, stay
+// interned until the REPL exits, which is fine for the length of a
+// session at a prompt
+#[derive(Default)]
+struct Session {
+    ctx: check::Context,
+    history: String,
+    statements: usize,
+    definitions: usize,
+}
+
+// print the `problems' found in the last line of `source', which starts
+// at `start', returning how many of them are errors
+fn report<E>(source: &str, start: usize, problems: Vec<E>) -> usize
+where
+    E: Diagnostic + Send + Sync + 'static,
+{
+    let mut fatal = 0;
+    for problem in problems {
+        let offset = problem
+            .labels()
+            .and_then(|mut labels| labels.next())
+            .map_or(start, |label| label.offset());
+        if offset < start {
+            continue;
+        }
+        if problem.severity() != Some(Severity::Warning) {
+            fatal += 1;
+        }
+        let report = Report::new(problem).with_source_code(source.to_string());
+        println!("{:?}", report);
+    }
+    fatal
+}
+
+impl Session {
+    // check `line' after the previous ones, keeping it if it is fine
+    fn line(&mut self, line: &str) {
+        let start = self.history.len();
+        let source = format!("{}{line}\n", self.history);
+        let (mut module, errors) = match parser::parse(&source) {
+            Ok(parsed) => parsed,
+            Err(err) => {
+                let report = Report::new(err).with_source_code(source);
+                println!("{:?}", report);
+                return;
+            }
+        };
+        if report(&source, start, errors) > 0
+            || report(&source, start, desugar::desugar(&mut module)) > 0
+        {
+            return;
+        }
+        let (defs, mut problems) = elab::definitions(&module);
+        // definitions come on later lines
+        problems.retain(|problem| !matches!(problem, elab::DefinitionError::Undefined(_)));
+        if report(&source, start, problems) > 0
+            || report(&source, start, resolve::resolve(&module)) > 0
+        {
+            return;
+        }
+
+        let mut ctx = self.ctx.clone();
+        let statements = &module.statements[self.statements..];
+        for checked in check::check(&mut ctx, statements, &defs[self.definitions..]) {
+            report(&source, start, checked.holes);
+            match checked.normal {
+                Ok(Some(normal)) => println!("{normal}"),
+                Ok(None) => (),
+                Err(err) => {
+                    report(&source, start, vec![err]);
+                    return;
+                }
+            }
+        }
+        self.ctx = ctx;
+        self.history = source;
+        self.statements = module.statements.len();
+        self.definitions = defs.len();
+    }
+}
+
 fn main() -> Result<()> {
     let xdg_dirs = xdg::BaseDirectories::with_prefix("pie")?;
     let history_file = xdg_dirs.place_state_file("history")?;
@@ -124,29 +203,19 @@ fn main() -> Result<()> {
     println!("Press Ctrl-D or enter \"quit\" to exit.");
     println!();
 
-    let prompt = format!("ΛΠ ≫ ");
+    let prompt = "ΛΠ ≫ ".to_string();
     rl.helper_mut().expect("No helper").colored_prompt = format!("\x1b[1;32m{}\x1b[0m", prompt);
 
+    // claims and definitions persist between lines
+    let mut session = Session::default();
+
     for readline in rl.iter(&prompt) {
         match readline {
             Ok(line) => {
                 if "quit" == line {
                     break;
                 }
-                let mut source = Cow::from(line);
-
-                match parser::parse(&mut source) {
-                    Ok(module) => {
-                        for stmt in module.statements.iter() {
-                            println!("{stmt:#?}");
-                        }
-                    }
-
-                    Err(err) => {
-                        let report = Report::new(err).with_source_code(source);
-                        println!("{:?}", report);
-                    }
-                }
+                session.line(&line);
             }
 
             Err(err) => {
@@ -1,8 +1,9 @@
 extern crate pie;
-use clap::Parser;
-use miette::{IntoDiagnostic, Report, Result};
-use pie::parser;
-use std::{borrow::Cow, fs, path::PathBuf, println};
+use clap::{Parser, Subcommand};
+use miette::{miette, Diagnostic, IntoDiagnostic, NamedSource, Report, Result, Severity};
+use pie::parser::ts::ParseErrors;
+use pie::{check, desugar, elab, format, parser, resolve};
+use std::{eprintln, fs, path::PathBuf, print, println};
 
 #[derive(Parser)]
 #[command(name = "pie lang")]
@@ -10,25 +11,151 @@ use std::{borrow::Cow, fs, path::PathBuf, println};
 #[command(version = "0.0.1")]
 #[command(about = "The Little Typer")]
 #[command(long_about = "Learning dependent types by building a language")]
+#[command(args_conflicts_with_subcommands = true)]
+#[command(subcommand_negates_reqs = true)]
 struct Opts {
+    #[command(subcommand)]
+    command: Option<Command>,
     /// FILE to typecheck
     // #[arg(last = true)]
-    file: PathBuf,
+    #[arg(required = true)]
+    file: Option<PathBuf>,
     /// TODO: look for imports in DIR
     #[arg(short, long, value_name = "DIR")]
     include: Vec<PathBuf>,
 }
 
+#[derive(Subcommand)]
+enum Command {
+    /// Lay out FILES in the canonical way, keeping their comments
+    Fmt {
+        /// FILES to format, printed unless `--check' or `--write' is given
+        #[arg(required = true)]
+        files: Vec<PathBuf>,
+        /// Fail if any of the FILES isn't formatted, without changing them
+        #[arg(long)]
+        check: bool,
+        /// Rewrite FILES in place
+        #[arg(short, long, conflicts_with = "check")]
+        write: bool,
+        /// Break lines longer than WIDTH
+        #[arg(long, value_name = "WIDTH", default_value_t = 80)]
+        width: usize,
+    },
+}
+
 fn main() -> Result<()> {
     let opts = Opts::parse();
+    match opts.command {
+        Some(Command::Fmt {
+            files,
+            check,
+            write,
+            width,
+        }) => fmt(files, check, write, width),
+        None => typecheck(opts.file.expect("FILE is required")),
+    }
+}
+
+fn fmt(files: Vec<PathBuf>, check: bool, write: bool, width: usize) -> Result<()> {
+    let (mut unformatted, mut failed) = (0, 0);
+    for file in files {
+        let source = fs::read_to_string(&file).into_diagnostic()?;
+        let (formatted, errors) = format::format(&source, width)
+            .map_err(|err| Report::new(err).with_source_code(source.to_string()))?;
+        // report the file and leave it alone, the others may still be
+        // formatted
+        if !errors.is_empty() {
+            let source = NamedSource::new(file.display().to_string(), source);
+            eprintln!(
+                "{:?}",
+                Report::new(ParseErrors { errors }).with_source_code(source)
+            );
+            failed += 1;
+            continue;
+        }
+        if check {
+            if formatted != source {
+                eprintln!("{} isn't formatted", file.display());
+                unformatted += 1;
+            }
+        } else if write {
+            if formatted != source {
+                fs::write(&file, formatted).into_diagnostic()?;
+            }
+        } else {
+            print!("{formatted}");
+        }
+    }
+    match (failed, unformatted) {
+        (0, 0) => Ok(()),
+        (0, _) => Err(miette!("found {unformatted} files that aren't formatted")),
+        (_, 0) => Err(miette!("couldn't format {failed} files with syntax errors")),
+        _ => Err(miette!(
+            "couldn't format {failed} files with syntax errors and found {unformatted} files that aren't formatted"
+        )),
+    }
+}
+
+fn typecheck(file: PathBuf) -> Result<()> {
+    let source = fs::read_to_string(file).into_diagnostic()?;
 
-    let mut source = Cow::from(fs::read_to_string(opts.file).into_diagnostic()?);
+    let (mut prog, errors) = parser::parse(&source)
+        .map_err(|err| Report::new(err).with_source_code(source.to_string()))?;
 
-    let prog =
-        parser::parse(&mut source).map_err(|err| Report::new(err).with_source_code(source))?;
+    if !errors.is_empty() {
+        return Err(Report::new(ParseErrors { errors }).with_source_code(source.to_string()));
+    }
+
+    let fatal = report(&source, desugar::desugar(&mut prog));
+    if fatal > 0 {
+        return Err(miette!("found {fatal} functions without arguments"));
+    }
 
-    for stmt in prog.statements {
-        println!("{stmt:#?}");
+    let (defs, problems) = elab::definitions(&prog);
+    let fatal = report(&source, problems);
+    if fatal > 0 {
+        return Err(miette!(
+            "found {fatal} problems with claims and definitions"
+        ));
+    }
+    let fatal = report(&source, resolve::resolve(&prog));
+    if fatal > 0 {
+        return Err(miette!("found {fatal} unknown variables"));
+    }
+
+    let mut ctx = check::Context::default();
+    for checked in check::check(&mut ctx, &prog.statements, &defs) {
+        for hole in checked.holes {
+            eprintln!(
+                "{:?}",
+                Report::new(hole).with_source_code(source.to_string())
+            );
+        }
+        let normal = checked
+            .normal
+            .map_err(|err| Report::new(err).with_source_code(source.to_string()))?;
+        if let Some(normal) = normal {
+            println!("{normal}");
+        }
     }
     Ok(())
 }
+
+// print all of the `problems', returning how many of them are errors
+fn report<E>(source: &str, problems: Vec<E>) -> usize
+where
+    E: Diagnostic + Send + Sync + 'static,
+{
+    let mut fatal = 0;
+    for problem in problems {
+        if problem.severity() != Some(Severity::Warning) {
+            fatal += 1;
+        }
+        eprintln!(
+            "{:?}",
+            Report::new(problem).with_source_code(source.to_string())
+        );
+    }
+    fatal
+}
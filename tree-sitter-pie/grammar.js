@@ -8,6 +8,9 @@ const LOWERCASE =
 const DIGIT =
     /\p{Decimal_Number}/;
 
+const NUMBER =
+    /[0-9]+/;
+
 const COMMENT =
     /;.*/;
 
@@ -21,20 +24,14 @@ const IDENTIFIER_BODY =
 const VARIABLE_HEAD =
     choice(
 	LOWERCASE,
-	SYMBOL,
-	DIGIT
+	UPPERCASE,
+	SYMBOL
     );
 const VARIABLE =
     token(seq(
 	VARIABLE_HEAD,
 	repeat(IDENTIFIER_BODY)));
 
-const TYPE_HEAD = UPPERCASE;
-const TYPE =
-    token(seq(
-	TYPE_HEAD,
-	repeat(IDENTIFIER_BODY)));
-
 const LAMBDA_HEAD =
     choice(
 	"\\",
@@ -48,6 +45,18 @@ const FUNCTION_TYPE =
         "→"
     );
 
+const PI_HEAD =
+    choice(
+	"Π",
+	token("Pi"),
+    );
+
+const SIGMA_HEAD =
+    choice(
+	"Σ",
+	token("Sigma"),
+    );
+
 module.exports = grammar({
   name: "pie",
 
@@ -60,6 +69,7 @@ module.exports = grammar({
     source: $ => repeat(choice(
 	$.claim,
 	$.define,
+	$.check_same,
 	$.expression
     )),
 
@@ -81,13 +91,63 @@ module.exports = grammar({
 	    ")"
 	),
 
+    check_same: $ =>
+        seq(
+	    "(",
+	    token("check-same"),
+	    field("type", $.expression),
+	    field("left", $.expression),
+	    field("right", $.expression),
+	    ")"
+	),
+
     expression: $ =>
         choice(
 	  $.atom,
 	  $.lambda,
-	  // $.function_type,
+	  $.function_type,
+	  $.pi_type,
+	  $.sigma_type,
+	  $.pair_type,
+	  $.cons,
+	  $.car,
+	  $.cdr,
+	  $.zero,
+	  $.add1,
+	  $.number,
+	  $.which_nat,
+	  $.iter_nat,
+	  $.rec_nat,
+	  $.ind_nat,
+	  $.list_type,
+	  $.nil,
+	  $.list_cons,
+	  $.rec_list,
+	  $.ind_list,
+	  $.vec_type,
+	  $.vecnil,
+	  $.vec_cons,
+	  $.head,
+	  $.tail,
+	  $.ind_vec,
+	  $.equal_type,
+	  $.same,
+	  $.cong,
+	  $.replace,
+	  $.symm,
+	  $.trans,
+	  $.ind_eq,
+	  $.either_type,
+	  $.left,
+	  $.right,
+	  $.ind_either,
+	  $.sole,
+	  $.ind_absurd,
+	  $.the,
+	  $.todo,
 	  $.application,
-	  $.type_identifier,
+	  $.base_type,
+	  $.universe,
 	  $.identifier,
         ),
 
@@ -102,21 +162,350 @@ module.exports = grammar({
 	      LAMBDA_HEAD,
 	      seq(
 		  "(",
-		  field("arguments", repeat($.expression)),
+		  field("arguments", repeat($.identifier)),
 		  ")"
 	      ),
 	      field("body", $.expression),
 	      ")"
 	  ),
 
-      // function_type: $ =>
-      // 	  seq(
-      // 	      "(",
-      // 	      FUNCTION_TYPE,
-      // 	      field("domain", $.expression),
-      // 	      field("codomain", repeat1($.expression)),
-      // 	      ")",
-      // 	  ),
+      function_type: $ =>
+	  seq(
+	      "(",
+	      FUNCTION_TYPE,
+	      field("domain", repeat1($.expression)),
+	      field("codomain", $.expression),
+	      ")",
+	  ),
+
+      pi_type: $ =>
+	  seq(
+	      "(",
+	      PI_HEAD,
+	      seq(
+		  "(",
+		  field("binders", repeat1($.typed_binder)),
+		  ")"
+	      ),
+	      field("codomain", $.expression),
+	      ")",
+	  ),
+
+      sigma_type: $ =>
+	  seq(
+	      "(",
+	      SIGMA_HEAD,
+	      seq(
+		  "(",
+		  field("binders", repeat1($.typed_binder)),
+		  ")"
+	      ),
+	      field("cdr_type", $.expression),
+	      ")",
+	  ),
+
+      pair_type: $ =>
+	  seq(
+	      "(",
+	      token("Pair"),
+	      field("car_type", $.expression),
+	      field("cdr_type", $.expression),
+	      ")",
+	  ),
+
+      cons: $ =>
+	  seq(
+	      "(",
+	      token("cons"),
+	      field("car", $.expression),
+	      field("cdr", $.expression),
+	      ")",
+	  ),
+
+      car: $ =>
+	  seq(
+	      "(",
+	      token("car"),
+	      field("pair", $.expression),
+	      ")",
+	  ),
+
+      cdr: $ =>
+	  seq(
+	      "(",
+	      token("cdr"),
+	      field("pair", $.expression),
+	      ")",
+	  ),
+
+      zero: _ => token("zero"),
+
+      add1: $ =>
+	  seq(
+	      "(",
+	      token("add1"),
+	      field("n", $.expression),
+	      ")",
+	  ),
+
+      which_nat: $ =>
+	  seq(
+	      "(",
+	      token("which-Nat"),
+	      field("target", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      iter_nat: $ =>
+	  seq(
+	      "(",
+	      token("iter-Nat"),
+	      field("target", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      rec_nat: $ =>
+	  seq(
+	      "(",
+	      token("rec-Nat"),
+	      field("target", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      ind_nat: $ =>
+	  seq(
+	      "(",
+	      token("ind-Nat"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      list_type: $ =>
+	  seq(
+	      "(",
+	      token("List"),
+	      field("element_type", $.expression),
+	      ")",
+	  ),
+
+      nil: _ => token("nil"),
+
+      list_cons: $ =>
+	  seq(
+	      "(",
+	      token("::"),
+	      field("head", $.expression),
+	      field("tail", $.expression),
+	      ")",
+	  ),
+
+      rec_list: $ =>
+	  seq(
+	      "(",
+	      token("rec-List"),
+	      field("target", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      ind_list: $ =>
+	  seq(
+	      "(",
+	      token("ind-List"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      vec_type: $ =>
+	  seq(
+	      "(",
+	      token("Vec"),
+	      field("element_type", $.expression),
+	      field("length", $.expression),
+	      ")",
+	  ),
+
+      vecnil: _ => token("vecnil"),
+
+      vec_cons: $ =>
+	  seq(
+	      "(",
+	      token("vec::"),
+	      field("head", $.expression),
+	      field("tail", $.expression),
+	      ")",
+	  ),
+
+      head: $ =>
+	  seq(
+	      "(",
+	      token("head"),
+	      field("vec", $.expression),
+	      ")",
+	  ),
+
+      tail: $ =>
+	  seq(
+	      "(",
+	      token("tail"),
+	      field("vec", $.expression),
+	      ")",
+	  ),
+
+      ind_vec: $ =>
+	  seq(
+	      "(",
+	      token("ind-Vec"),
+	      field("length", $.expression),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base", $.expression),
+	      field("step", $.expression),
+	      ")",
+	  ),
+
+      equal_type: $ =>
+	  seq(
+	      "(",
+	      token("="),
+	      field("type", $.expression),
+	      field("from", $.expression),
+	      field("to", $.expression),
+	      ")",
+	  ),
+
+      same: $ =>
+	  seq(
+	      "(",
+	      token("same"),
+	      field("expr", $.expression),
+	      ")",
+	  ),
+
+      cong: $ =>
+	  seq(
+	      "(",
+	      token("cong"),
+	      field("target", $.expression),
+	      field("function", $.expression),
+	      ")",
+	  ),
+
+      replace: $ =>
+	  seq(
+	      "(",
+	      token("replace"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base", $.expression),
+	      ")",
+	  ),
+
+      symm: $ =>
+	  seq(
+	      "(",
+	      token("symm"),
+	      field("target", $.expression),
+	      ")",
+	  ),
+
+      trans: $ =>
+	  seq(
+	      "(",
+	      token("trans"),
+	      field("left", $.expression),
+	      field("right", $.expression),
+	      ")",
+	  ),
+
+      ind_eq: $ =>
+	  seq(
+	      "(",
+	      token("ind-Eq"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base", $.expression),
+	      ")",
+	  ),
+
+      either_type: $ =>
+	  seq(
+	      "(",
+	      token("Either"),
+	      field("left_type", $.expression),
+	      field("right_type", $.expression),
+	      ")",
+	  ),
+
+      left: $ =>
+	  seq(
+	      "(",
+	      token("left"),
+	      field("expr", $.expression),
+	      ")",
+	  ),
+
+      right: $ =>
+	  seq(
+	      "(",
+	      token("right"),
+	      field("expr", $.expression),
+	      ")",
+	  ),
+
+      ind_either: $ =>
+	  seq(
+	      "(",
+	      token("ind-Either"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      field("base_left", $.expression),
+	      field("base_right", $.expression),
+	      ")",
+	  ),
+
+      sole: _ => token("sole"),
+
+      ind_absurd: $ =>
+	  seq(
+	      "(",
+	      token("ind-Absurd"),
+	      field("target", $.expression),
+	      field("motive", $.expression),
+	      ")",
+	  ),
+
+      the: $ =>
+	  seq(
+	      "(",
+	      token("the"),
+	      field("type", $.expression),
+	      field("expr", $.expression),
+	      ")",
+	  ),
+
+      todo: _ => token("TODO"),
+
+      typed_binder: $ =>
+	  seq(
+	      "(",
+	      field("identifier", $.identifier),
+	      field("type", $.expression),
+	      ")",
+	  ),
 
       application: $ =>
 	  seq(
@@ -127,7 +516,14 @@ module.exports = grammar({
 	  ),
 	  
       identifier: _ => token(VARIABLE),
-      type_identifier: _ => token(TYPE),
+      base_type: _ => choice(
+	  token("Atom"),
+	  token("Nat"),
+	  token("Trivial"),
+	  token("Absurd"),
+      ),
+      universe: _ => token("U"),
+      number: _ => token(NUMBER),
       comment: _ => token(COMMENT),
    }
 });
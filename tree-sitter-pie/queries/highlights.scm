@@ -0,0 +1,73 @@
+; Earlier patterns take precedence over later ones.
+
+(comment) @comment
+
+["(" ")"] @punctuation.bracket
+
+; Statements
+
+["claim" "define" "check-same"] @keyword
+
+; one pattern per type, the highlighter mistakes a partial match of
+; an alternation for a whole one
+(claim
+  identifier: (identifier) @function
+  type: (expression (function_type)))
+(claim
+  identifier: (identifier) @function
+  type: (expression (pi_type)))
+
+(define
+  identifier: (identifier) @function
+  body: (expression (lambda)))
+
+(claim identifier: (identifier) @constant)
+(define identifier: (identifier) @constant)
+
+; Binders
+
+["λ" "\\" "lambda" "Π" "Pi" "Σ" "Sigma"] @keyword.function
+
+(lambda (identifier) @variable.parameter)
+(typed_binder identifier: (identifier) @variable.parameter)
+
+; Types
+
+[
+  "→" "->" "Pair" "List" "Vec" "=" "Either"
+] @type
+
+(base_type) @type.builtin
+(universe) @type.builtin
+
+; Values
+
+(atom
+  "'" @string.special.symbol
+  identifier: (identifier) @string.special.symbol)
+
+(number) @number
+
+[
+  (zero)
+  (nil)
+  (vecnil)
+  (sole)
+] @constant.builtin
+
+(todo) @keyword
+
+[
+  "the" "cons" "car" "cdr" "add1"
+  "which-Nat" "iter-Nat" "rec-Nat" "ind-Nat"
+  "::" "rec-List" "ind-List"
+  "vec::" "head" "tail" "ind-Vec"
+  "same" "cong" "replace" "symm" "trans" "ind-Eq"
+  "left" "right" "ind-Either"
+  "ind-Absurd"
+] @function.builtin
+
+(application
+  function: (expression (identifier) @function.call))
+
+(identifier) @variable
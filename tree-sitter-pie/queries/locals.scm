@@ -0,0 +1,17 @@
+; `λ', `Π' and `Σ' bind variables for the rest of the form.
+
+[
+  (lambda)
+  (pi_type)
+  (sigma_type)
+] @local.scope
+
+(lambda (identifier) @local.definition)
+(typed_binder identifier: (identifier) @local.definition)
+
+; top-level names are visible in the statements after them
+
+(claim identifier: (identifier) @local.definition)
+(define identifier: (identifier) @local.definition)
+
+(expression (identifier) @local.reference)
@@ -0,0 +1,33 @@
+; A `claim' gives both the name and the type of a definition.
+
+(
+  (comment)* @doc
+  .
+  (claim
+    identifier: (identifier) @name
+    type: (expression (function_type))) @definition.function
+  (#strip! @doc "^;+\\s*")
+  (#select-adjacent! @doc @definition.function)
+)
+
+(
+  (comment)* @doc
+  .
+  (claim
+    identifier: (identifier) @name
+    type: (expression (pi_type))) @definition.function
+  (#strip! @doc "^;+\\s*")
+  (#select-adjacent! @doc @definition.function)
+)
+
+; the first pattern wins, so the rest of claims are constants
+(
+  (comment)* @doc
+  .
+  (claim identifier: (identifier) @name) @definition.constant
+  (#strip! @doc "^;+\\s*")
+  (#select-adjacent! @doc @definition.constant)
+)
+
+(application
+  function: (expression (identifier) @name)) @reference.call
@@ -6,37 +6,124 @@
 #endif
 
 #define LANGUAGE_VERSION 14
-#define STATE_COUNT 32
-#define LARGE_STATE_COUNT 11
-#define SYMBOL_COUNT 21
+#define STATE_COUNT 279
+#define LARGE_STATE_COUNT 193
+#define SYMBOL_COUNT 108
 #define ALIAS_COUNT 0
-#define TOKEN_COUNT 12
+#define TOKEN_COUNT 60
 #define EXTERNAL_TOKEN_COUNT 0
-#define FIELD_COUNT 5
-#define MAX_ALIAS_SEQUENCE_LENGTH 7
-#define PRODUCTION_ID_COUNT 8
+#define FIELD_COUNT 32
+#define MAX_ALIAS_SEQUENCE_LENGTH 8
+#define PRODUCTION_ID_COUNT 34
 
 enum {
   anon_sym_LPAREN = 1,
   anon_sym_claim = 2,
   anon_sym_RPAREN = 3,
   anon_sym_define = 4,
-  anon_sym_SQUOTE = 5,
-  anon_sym_BSLASH = 6,
-  anon_sym_ = 7,
-  anon_sym_lambda = 8,
-  sym_identifier = 9,
-  sym_type_identifier = 10,
-  sym_comment = 11,
-  sym_source = 12,
-  sym_claim = 13,
-  sym_define = 14,
-  sym_expression = 15,
-  sym_atom = 16,
-  sym_lambda = 17,
-  sym_application = 18,
-  aux_sym_source_repeat1 = 19,
-  aux_sym_lambda_repeat1 = 20,
+  anon_sym_check_DASHsame = 5,
+  anon_sym_SQUOTE = 6,
+  anon_sym_BSLASH = 7,
+  anon_sym_ = 8,
+  anon_sym_lambda = 9,
+  anon_sym_DASH_GT = 10,
+  anon_sym_2 = 11,
+  anon_sym_3 = 12,
+  anon_sym_Pi = 13,
+  anon_sym_4 = 14,
+  anon_sym_Sigma = 15,
+  anon_sym_Pair = 16,
+  anon_sym_cons = 17,
+  anon_sym_car = 18,
+  anon_sym_cdr = 19,
+  anon_sym_add1 = 20,
+  anon_sym_which_DASHNat = 21,
+  anon_sym_iter_DASHNat = 22,
+  anon_sym_rec_DASHNat = 23,
+  anon_sym_ind_DASHNat = 24,
+  anon_sym_List = 25,
+  anon_sym_COLON_COLON = 26,
+  anon_sym_rec_DASHList = 27,
+  anon_sym_ind_DASHList = 28,
+  anon_sym_Vec = 29,
+  anon_sym_vec_COLON_COLON = 30,
+  anon_sym_head = 31,
+  anon_sym_tail = 32,
+  anon_sym_ind_DASHVec = 33,
+  anon_sym_EQ = 34,
+  anon_sym_same = 35,
+  anon_sym_cong = 36,
+  anon_sym_replace = 37,
+  anon_sym_symm = 38,
+  anon_sym_trans = 39,
+  anon_sym_ind_DASHEq = 40,
+  anon_sym_Either = 41,
+  anon_sym_left = 42,
+  anon_sym_right = 43,
+  anon_sym_ind_DASHEither = 44,
+  anon_sym_ind_DASHAbsurd = 45,
+  anon_sym_the = 46,
+  anon_sym_Atom = 47,
+  anon_sym_Nat = 48,
+  anon_sym_Trivial = 49,
+  anon_sym_Absurd = 50,
+  sym_zero = 51,
+  sym_nil = 52,
+  sym_vecnil = 53,
+  sym_sole = 54,
+  sym_todo = 55,
+  sym_identifier = 56,
+  sym_universe = 57,
+  sym_number = 58,
+  sym_comment = 59,
+  sym_source = 60,
+  sym_claim = 61,
+  sym_define = 62,
+  sym_check_same = 63,
+  sym_expression = 64,
+  sym_atom = 65,
+  sym_lambda = 66,
+  sym_function_type = 67,
+  sym_pi_type = 68,
+  sym_sigma_type = 69,
+  sym_pair_type = 70,
+  sym_cons = 71,
+  sym_car = 72,
+  sym_cdr = 73,
+  sym_add1 = 74,
+  sym_which_nat = 75,
+  sym_iter_nat = 76,
+  sym_rec_nat = 77,
+  sym_ind_nat = 78,
+  sym_list_type = 79,
+  sym_list_cons = 80,
+  sym_rec_list = 81,
+  sym_ind_list = 82,
+  sym_vec_type = 83,
+  sym_vec_cons = 84,
+  sym_head = 85,
+  sym_tail = 86,
+  sym_ind_vec = 87,
+  sym_equal_type = 88,
+  sym_same = 89,
+  sym_cong = 90,
+  sym_replace = 91,
+  sym_symm = 92,
+  sym_trans = 93,
+  sym_ind_eq = 94,
+  sym_either_type = 95,
+  sym_left = 96,
+  sym_right = 97,
+  sym_ind_either = 98,
+  sym_ind_absurd = 99,
+  sym_the = 100,
+  sym_typed_binder = 101,
+  sym_application = 102,
+  sym_base_type = 103,
+  aux_sym_source_repeat1 = 104,
+  aux_sym_lambda_repeat1 = 105,
+  aux_sym_function_type_repeat1 = 106,
+  aux_sym_pi_type_repeat1 = 107,
 };
 
 static const char * const ts_symbol_names[] = {
@@ -45,22 +132,109 @@ static const char * const ts_symbol_names[] = {
   [anon_sym_claim] = "claim",
   [anon_sym_RPAREN] = ")",
   [anon_sym_define] = "define",
+  [anon_sym_check_DASHsame] = "check-same",
   [anon_sym_SQUOTE] = "'",
   [anon_sym_BSLASH] = "\\",
   [anon_sym_] = "λ",
   [anon_sym_lambda] = "lambda",
+  [anon_sym_DASH_GT] = "->",
+  [anon_sym_2] = "→",
+  [anon_sym_3] = "Π",
+  [anon_sym_Pi] = "Pi",
+  [anon_sym_4] = "Σ",
+  [anon_sym_Sigma] = "Sigma",
+  [anon_sym_Pair] = "Pair",
+  [anon_sym_cons] = "cons",
+  [anon_sym_car] = "car",
+  [anon_sym_cdr] = "cdr",
+  [anon_sym_add1] = "add1",
+  [anon_sym_which_DASHNat] = "which-Nat",
+  [anon_sym_iter_DASHNat] = "iter-Nat",
+  [anon_sym_rec_DASHNat] = "rec-Nat",
+  [anon_sym_ind_DASHNat] = "ind-Nat",
+  [anon_sym_List] = "List",
+  [anon_sym_COLON_COLON] = "::",
+  [anon_sym_rec_DASHList] = "rec-List",
+  [anon_sym_ind_DASHList] = "ind-List",
+  [anon_sym_Vec] = "Vec",
+  [anon_sym_vec_COLON_COLON] = "vec::",
+  [anon_sym_head] = "head",
+  [anon_sym_tail] = "tail",
+  [anon_sym_ind_DASHVec] = "ind-Vec",
+  [anon_sym_EQ] = "=",
+  [anon_sym_same] = "same",
+  [anon_sym_cong] = "cong",
+  [anon_sym_replace] = "replace",
+  [anon_sym_symm] = "symm",
+  [anon_sym_trans] = "trans",
+  [anon_sym_ind_DASHEq] = "ind-Eq",
+  [anon_sym_Either] = "Either",
+  [anon_sym_left] = "left",
+  [anon_sym_right] = "right",
+  [anon_sym_ind_DASHEither] = "ind-Either",
+  [anon_sym_ind_DASHAbsurd] = "ind-Absurd",
+  [anon_sym_the] = "the",
+  [anon_sym_Atom] = "Atom",
+  [anon_sym_Nat] = "Nat",
+  [anon_sym_Trivial] = "Trivial",
+  [anon_sym_Absurd] = "Absurd",
+  [sym_zero] = "zero",
+  [sym_nil] = "nil",
+  [sym_vecnil] = "vecnil",
+  [sym_sole] = "sole",
+  [sym_todo] = "todo",
   [sym_identifier] = "identifier",
-  [sym_type_identifier] = "type_identifier",
+  [sym_universe] = "universe",
+  [sym_number] = "number",
   [sym_comment] = "comment",
   [sym_source] = "source",
   [sym_claim] = "claim",
   [sym_define] = "define",
+  [sym_check_same] = "check_same",
   [sym_expression] = "expression",
   [sym_atom] = "atom",
   [sym_lambda] = "lambda",
+  [sym_function_type] = "function_type",
+  [sym_pi_type] = "pi_type",
+  [sym_sigma_type] = "sigma_type",
+  [sym_pair_type] = "pair_type",
+  [sym_cons] = "cons",
+  [sym_car] = "car",
+  [sym_cdr] = "cdr",
+  [sym_add1] = "add1",
+  [sym_which_nat] = "which_nat",
+  [sym_iter_nat] = "iter_nat",
+  [sym_rec_nat] = "rec_nat",
+  [sym_ind_nat] = "ind_nat",
+  [sym_list_type] = "list_type",
+  [sym_list_cons] = "list_cons",
+  [sym_rec_list] = "rec_list",
+  [sym_ind_list] = "ind_list",
+  [sym_vec_type] = "vec_type",
+  [sym_vec_cons] = "vec_cons",
+  [sym_head] = "head",
+  [sym_tail] = "tail",
+  [sym_ind_vec] = "ind_vec",
+  [sym_equal_type] = "equal_type",
+  [sym_same] = "same",
+  [sym_cong] = "cong",
+  [sym_replace] = "replace",
+  [sym_symm] = "symm",
+  [sym_trans] = "trans",
+  [sym_ind_eq] = "ind_eq",
+  [sym_either_type] = "either_type",
+  [sym_left] = "left",
+  [sym_right] = "right",
+  [sym_ind_either] = "ind_either",
+  [sym_ind_absurd] = "ind_absurd",
+  [sym_the] = "the",
+  [sym_typed_binder] = "typed_binder",
   [sym_application] = "application",
+  [sym_base_type] = "base_type",
   [aux_sym_source_repeat1] = "source_repeat1",
   [aux_sym_lambda_repeat1] = "lambda_repeat1",
+  [aux_sym_function_type_repeat1] = "function_type_repeat1",
+  [aux_sym_pi_type_repeat1] = "pi_type_repeat1",
 };
 
 static const TSSymbol ts_symbol_map[] = {
@@ -69,22 +243,109 @@ static const TSSymbol ts_symbol_map[] = {
   [anon_sym_claim] = anon_sym_claim,
   [anon_sym_RPAREN] = anon_sym_RPAREN,
   [anon_sym_define] = anon_sym_define,
+  [anon_sym_check_DASHsame] = anon_sym_check_DASHsame,
   [anon_sym_SQUOTE] = anon_sym_SQUOTE,
   [anon_sym_BSLASH] = anon_sym_BSLASH,
   [anon_sym_] = anon_sym_,
   [anon_sym_lambda] = anon_sym_lambda,
+  [anon_sym_DASH_GT] = anon_sym_DASH_GT,
+  [anon_sym_2] = anon_sym_2,
+  [anon_sym_3] = anon_sym_3,
+  [anon_sym_Pi] = anon_sym_Pi,
+  [anon_sym_4] = anon_sym_4,
+  [anon_sym_Sigma] = anon_sym_Sigma,
+  [anon_sym_Pair] = anon_sym_Pair,
+  [anon_sym_cons] = anon_sym_cons,
+  [anon_sym_car] = anon_sym_car,
+  [anon_sym_cdr] = anon_sym_cdr,
+  [anon_sym_add1] = anon_sym_add1,
+  [anon_sym_which_DASHNat] = anon_sym_which_DASHNat,
+  [anon_sym_iter_DASHNat] = anon_sym_iter_DASHNat,
+  [anon_sym_rec_DASHNat] = anon_sym_rec_DASHNat,
+  [anon_sym_ind_DASHNat] = anon_sym_ind_DASHNat,
+  [anon_sym_List] = anon_sym_List,
+  [anon_sym_COLON_COLON] = anon_sym_COLON_COLON,
+  [anon_sym_rec_DASHList] = anon_sym_rec_DASHList,
+  [anon_sym_ind_DASHList] = anon_sym_ind_DASHList,
+  [anon_sym_Vec] = anon_sym_Vec,
+  [anon_sym_vec_COLON_COLON] = anon_sym_vec_COLON_COLON,
+  [anon_sym_head] = anon_sym_head,
+  [anon_sym_tail] = anon_sym_tail,
+  [anon_sym_ind_DASHVec] = anon_sym_ind_DASHVec,
+  [anon_sym_EQ] = anon_sym_EQ,
+  [anon_sym_same] = anon_sym_same,
+  [anon_sym_cong] = anon_sym_cong,
+  [anon_sym_replace] = anon_sym_replace,
+  [anon_sym_symm] = anon_sym_symm,
+  [anon_sym_trans] = anon_sym_trans,
+  [anon_sym_ind_DASHEq] = anon_sym_ind_DASHEq,
+  [anon_sym_Either] = anon_sym_Either,
+  [anon_sym_left] = anon_sym_left,
+  [anon_sym_right] = anon_sym_right,
+  [anon_sym_ind_DASHEither] = anon_sym_ind_DASHEither,
+  [anon_sym_ind_DASHAbsurd] = anon_sym_ind_DASHAbsurd,
+  [anon_sym_the] = anon_sym_the,
+  [anon_sym_Atom] = anon_sym_Atom,
+  [anon_sym_Nat] = anon_sym_Nat,
+  [anon_sym_Trivial] = anon_sym_Trivial,
+  [anon_sym_Absurd] = anon_sym_Absurd,
+  [sym_zero] = sym_zero,
+  [sym_nil] = sym_nil,
+  [sym_vecnil] = sym_vecnil,
+  [sym_sole] = sym_sole,
+  [sym_todo] = sym_todo,
   [sym_identifier] = sym_identifier,
-  [sym_type_identifier] = sym_type_identifier,
+  [sym_universe] = sym_universe,
+  [sym_number] = sym_number,
   [sym_comment] = sym_comment,
   [sym_source] = sym_source,
   [sym_claim] = sym_claim,
   [sym_define] = sym_define,
+  [sym_check_same] = sym_check_same,
   [sym_expression] = sym_expression,
   [sym_atom] = sym_atom,
   [sym_lambda] = sym_lambda,
+  [sym_function_type] = sym_function_type,
+  [sym_pi_type] = sym_pi_type,
+  [sym_sigma_type] = sym_sigma_type,
+  [sym_pair_type] = sym_pair_type,
+  [sym_cons] = sym_cons,
+  [sym_car] = sym_car,
+  [sym_cdr] = sym_cdr,
+  [sym_add1] = sym_add1,
+  [sym_which_nat] = sym_which_nat,
+  [sym_iter_nat] = sym_iter_nat,
+  [sym_rec_nat] = sym_rec_nat,
+  [sym_ind_nat] = sym_ind_nat,
+  [sym_list_type] = sym_list_type,
+  [sym_list_cons] = sym_list_cons,
+  [sym_rec_list] = sym_rec_list,
+  [sym_ind_list] = sym_ind_list,
+  [sym_vec_type] = sym_vec_type,
+  [sym_vec_cons] = sym_vec_cons,
+  [sym_head] = sym_head,
+  [sym_tail] = sym_tail,
+  [sym_ind_vec] = sym_ind_vec,
+  [sym_equal_type] = sym_equal_type,
+  [sym_same] = sym_same,
+  [sym_cong] = sym_cong,
+  [sym_replace] = sym_replace,
+  [sym_symm] = sym_symm,
+  [sym_trans] = sym_trans,
+  [sym_ind_eq] = sym_ind_eq,
+  [sym_either_type] = sym_either_type,
+  [sym_left] = sym_left,
+  [sym_right] = sym_right,
+  [sym_ind_either] = sym_ind_either,
+  [sym_ind_absurd] = sym_ind_absurd,
+  [sym_the] = sym_the,
+  [sym_typed_binder] = sym_typed_binder,
   [sym_application] = sym_application,
+  [sym_base_type] = sym_base_type,
   [aux_sym_source_repeat1] = aux_sym_source_repeat1,
   [aux_sym_lambda_repeat1] = aux_sym_lambda_repeat1,
+  [aux_sym_function_type_repeat1] = aux_sym_function_type_repeat1,
+  [aux_sym_pi_type_repeat1] = aux_sym_pi_type_repeat1,
 };
 
 static const TSSymbolMetadata ts_symbol_metadata[] = {
@@ -108,6 +369,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_check_DASHsame] = {
+    .visible = true,
+    .named = false,
+  },
   [anon_sym_SQUOTE] = {
     .visible = true,
     .named = false,
@@ -124,11 +389,199 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = false,
   },
+  [anon_sym_DASH_GT] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_2] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_3] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Pi] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_4] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Sigma] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Pair] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_cons] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_car] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_cdr] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_add1] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_which_DASHNat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_iter_DASHNat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_rec_DASHNat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHNat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_List] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_COLON_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_rec_DASHList] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHList] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Vec] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_vec_COLON_COLON] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_head] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_tail] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHVec] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_EQ] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_same] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_cong] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_replace] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_symm] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_trans] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHEq] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Either] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_left] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_right] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHEither] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_ind_DASHAbsurd] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_the] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Atom] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Nat] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Trivial] = {
+    .visible = true,
+    .named = false,
+  },
+  [anon_sym_Absurd] = {
+    .visible = true,
+    .named = false,
+  },
+  [sym_zero] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_nil] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_vecnil] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sole] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_todo] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_identifier] = {
     .visible = true,
     .named = true,
   },
-  [sym_type_identifier] = {
+  [sym_universe] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_number] = {
     .visible = true,
     .named = true,
   },
@@ -148,6 +601,10 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_check_same] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_expression] = {
     .visible = true,
     .named = true,
@@ -160,10 +617,154 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = true,
     .named = true,
   },
+  [sym_function_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pi_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_sigma_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_pair_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cons] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_car] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cdr] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_add1] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_which_nat] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_iter_nat] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rec_nat] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_nat] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_list_cons] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_rec_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_list] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_vec_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_vec_cons] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_head] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_tail] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_vec] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_equal_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_same] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_cong] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_replace] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_symm] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_trans] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_eq] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_either_type] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_left] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_right] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_either] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_ind_absurd] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_the] = {
+    .visible = true,
+    .named = true,
+  },
+  [sym_typed_binder] = {
+    .visible = true,
+    .named = true,
+  },
   [sym_application] = {
     .visible = true,
     .named = true,
   },
+  [sym_base_type] = {
+    .visible = true,
+    .named = true,
+  },
   [aux_sym_source_repeat1] = {
     .visible = false,
     .named = false,
@@ -172,33 +773,121 @@ static const TSSymbolMetadata ts_symbol_metadata[] = {
     .visible = false,
     .named = false,
   },
-};
+  [aux_sym_function_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+  [aux_sym_pi_type_repeat1] = {
+    .visible = false,
+    .named = false,
+  },
+};
 
 enum {
   field_arguments = 1,
-  field_body = 2,
-  field_function = 3,
-  field_identifier = 4,
-  field_type = 5,
+  field_base = 2,
+  field_base_left = 3,
+  field_base_right = 4,
+  field_binders = 5,
+  field_body = 6,
+  field_car = 7,
+  field_car_type = 8,
+  field_cdr = 9,
+  field_cdr_type = 10,
+  field_codomain = 11,
+  field_domain = 12,
+  field_element_type = 13,
+  field_expr = 14,
+  field_from = 15,
+  field_function = 16,
+  field_head = 17,
+  field_identifier = 18,
+  field_left = 19,
+  field_left_type = 20,
+  field_length = 21,
+  field_motive = 22,
+  field_n = 23,
+  field_pair = 24,
+  field_right = 25,
+  field_right_type = 26,
+  field_step = 27,
+  field_tail = 28,
+  field_target = 29,
+  field_to = 30,
+  field_type = 31,
+  field_vec = 32,
 };
 
 static const char * const ts_field_names[] = {
   [0] = NULL,
   [field_arguments] = "arguments",
+  [field_base] = "base",
+  [field_base_left] = "base_left",
+  [field_base_right] = "base_right",
+  [field_binders] = "binders",
   [field_body] = "body",
+  [field_car] = "car",
+  [field_car_type] = "car_type",
+  [field_cdr] = "cdr",
+  [field_cdr_type] = "cdr_type",
+  [field_codomain] = "codomain",
+  [field_domain] = "domain",
+  [field_element_type] = "element_type",
+  [field_expr] = "expr",
+  [field_from] = "from",
   [field_function] = "function",
+  [field_head] = "head",
   [field_identifier] = "identifier",
+  [field_left] = "left",
+  [field_left_type] = "left_type",
+  [field_length] = "length",
+  [field_motive] = "motive",
+  [field_n] = "n",
+  [field_pair] = "pair",
+  [field_right] = "right",
+  [field_right_type] = "right_type",
+  [field_step] = "step",
+  [field_tail] = "tail",
+  [field_target] = "target",
+  [field_to] = "to",
   [field_type] = "type",
+  [field_vec] = "vec",
 };
 
 static const TSFieldMapSlice ts_field_map_slices[PRODUCTION_ID_COUNT] = {
   [1] = {.index = 0, .length = 1},
   [2] = {.index = 1, .length = 1},
-  [3] = {.index = 2, .length = 2},
-  [4] = {.index = 4, .length = 2},
-  [5] = {.index = 6, .length = 2},
-  [6] = {.index = 8, .length = 1},
-  [7] = {.index = 9, .length = 2},
+  [3] = {.index = 2, .length = 1},
+  [4] = {.index = 3, .length = 1},
+  [5] = {.index = 4, .length = 1},
+  [6] = {.index = 5, .length = 1},
+  [7] = {.index = 6, .length = 1},
+  [8] = {.index = 7, .length = 1},
+  [9] = {.index = 8, .length = 2},
+  [10] = {.index = 10, .length = 2},
+  [11] = {.index = 12, .length = 2},
+  [12] = {.index = 14, .length = 2},
+  [13] = {.index = 16, .length = 2},
+  [14] = {.index = 18, .length = 2},
+  [15] = {.index = 20, .length = 2},
+  [16] = {.index = 22, .length = 2},
+  [17] = {.index = 24, .length = 2},
+  [18] = {.index = 26, .length = 2},
+  [19] = {.index = 28, .length = 2},
+  [20] = {.index = 30, .length = 1},
+  [21] = {.index = 31, .length = 3},
+  [22] = {.index = 34, .length = 3},
+  [23] = {.index = 37, .length = 3},
+  [24] = {.index = 40, .length = 2},
+  [25] = {.index = 42, .length = 2},
+  [26] = {.index = 44, .length = 2},
+  [27] = {.index = 46, .length = 4},
+  [28] = {.index = 50, .length = 4},
+  [29] = {.index = 54, .length = 5},
+  [30] = {.index = 59, .length = 2},
+  [31] = {.index = 61, .length = 2},
+  [32] = {.index = 63, .length = 3},
+  [33] = {.index = 66, .length = 2},
 };
 
 static const TSFieldMapEntry ts_field_map_entries[] = {
@@ -207,19 +896,102 @@ static const TSFieldMapEntry ts_field_map_entries[] = {
   [1] =
     {field_function, 1},
   [2] =
-    {field_arguments, 2},
-    {field_function, 1},
+    {field_pair, 2},
+  [3] =
+    {field_n, 2},
   [4] =
-    {field_identifier, 2},
-    {field_type, 3},
+    {field_element_type, 2},
+  [5] =
+    {field_vec, 2},
   [6] =
-    {field_body, 3},
-    {field_identifier, 2},
+    {field_expr, 2},
+  [7] =
+    {field_target, 2},
   [8] =
+    {field_arguments, 2},
+    {field_function, 1},
+  [10] =
+    {field_codomain, 3},
+    {field_domain, 2},
+  [12] =
+    {field_car_type, 2},
+    {field_cdr_type, 3},
+  [14] =
+    {field_car, 2},
+    {field_cdr, 3},
+  [16] =
+    {field_head, 2},
+    {field_tail, 3},
+  [18] =
+    {field_element_type, 2},
+    {field_length, 3},
+  [20] =
+    {field_function, 3},
+    {field_target, 2},
+  [22] =
+    {field_left, 2},
+    {field_right, 3},
+  [24] =
+    {field_left_type, 2},
+    {field_right_type, 3},
+  [26] =
+    {field_motive, 3},
+    {field_target, 2},
+  [28] =
+    {field_expr, 3},
+    {field_type, 2},
+  [30] =
     {field_body, 4},
-  [9] =
+  [31] =
+    {field_base, 3},
+    {field_step, 4},
+    {field_target, 2},
+  [34] =
+    {field_from, 3},
+    {field_to, 4},
+    {field_type, 2},
+  [37] =
+    {field_base, 4},
+    {field_motive, 3},
+    {field_target, 2},
+  [40] =
     {field_arguments, 3},
     {field_body, 5},
+  [42] =
+    {field_binders, 3},
+    {field_codomain, 5},
+  [44] =
+    {field_binders, 3},
+    {field_cdr_type, 5},
+  [46] =
+    {field_base, 4},
+    {field_motive, 3},
+    {field_step, 5},
+    {field_target, 2},
+  [50] =
+    {field_base_left, 4},
+    {field_base_right, 5},
+    {field_motive, 3},
+    {field_target, 2},
+  [54] =
+    {field_base, 5},
+    {field_length, 2},
+    {field_motive, 4},
+    {field_step, 6},
+    {field_target, 3},
+  [59] =
+    {field_identifier, 2},
+    {field_type, 3},
+  [61] =
+    {field_body, 3},
+    {field_identifier, 2},
+  [63] =
+    {field_left, 3},
+    {field_right, 4},
+    {field_type, 2},
+  [66] =
+    {field_identifier, 1},
+    {field_type, 2},
 };
 
 static const TSSymbol ts_alias_sequences[PRODUCTION_ID_COUNT][MAX_ALIAS_SEQUENCE_LENGTH] = {
@@ -263,1638 +1035,2505 @@ static const TSStateId ts_primary_state_ids[STATE_COUNT] = {
   [29] = 29,
   [30] = 30,
   [31] = 31,
+  [32] = 32,
+  [33] = 33,
+  [34] = 34,
+  [35] = 35,
+  [36] = 36,
+  [37] = 37,
+  [38] = 38,
+  [39] = 39,
+  [40] = 40,
+  [41] = 41,
+  [42] = 42,
+  [43] = 43,
+  [44] = 44,
+  [45] = 45,
+  [46] = 46,
+  [47] = 47,
+  [48] = 48,
+  [49] = 49,
+  [50] = 50,
+  [51] = 51,
+  [52] = 52,
+  [53] = 53,
+  [54] = 54,
+  [55] = 55,
+  [56] = 56,
+  [57] = 57,
+  [58] = 58,
+  [59] = 59,
+  [60] = 60,
+  [61] = 61,
+  [62] = 62,
+  [63] = 63,
+  [64] = 64,
+  [65] = 65,
+  [66] = 66,
+  [67] = 67,
+  [68] = 68,
+  [69] = 69,
+  [70] = 70,
+  [71] = 71,
+  [72] = 72,
+  [73] = 73,
+  [74] = 74,
+  [75] = 75,
+  [76] = 76,
+  [77] = 77,
+  [78] = 78,
+  [79] = 79,
+  [80] = 80,
+  [81] = 81,
+  [82] = 82,
+  [83] = 83,
+  [84] = 84,
+  [85] = 85,
+  [86] = 86,
+  [87] = 87,
+  [88] = 88,
+  [89] = 89,
+  [90] = 90,
+  [91] = 91,
+  [92] = 92,
+  [93] = 93,
+  [94] = 94,
+  [95] = 95,
+  [96] = 96,
+  [97] = 97,
+  [98] = 98,
+  [99] = 99,
+  [100] = 100,
+  [101] = 101,
+  [102] = 102,
+  [103] = 103,
+  [104] = 104,
+  [105] = 105,
+  [106] = 106,
+  [107] = 107,
+  [108] = 108,
+  [109] = 109,
+  [110] = 110,
+  [111] = 111,
+  [112] = 112,
+  [113] = 113,
+  [114] = 114,
+  [115] = 115,
+  [116] = 116,
+  [117] = 117,
+  [118] = 118,
+  [119] = 119,
+  [120] = 120,
+  [121] = 121,
+  [122] = 122,
+  [123] = 123,
+  [124] = 124,
+  [125] = 125,
+  [126] = 126,
+  [127] = 127,
+  [128] = 128,
+  [129] = 129,
+  [130] = 130,
+  [131] = 131,
+  [132] = 132,
+  [133] = 133,
+  [134] = 134,
+  [135] = 135,
+  [136] = 136,
+  [137] = 137,
+  [138] = 138,
+  [139] = 139,
+  [140] = 140,
+  [141] = 141,
+  [142] = 142,
+  [143] = 143,
+  [144] = 144,
+  [145] = 145,
+  [146] = 146,
+  [147] = 147,
+  [148] = 148,
+  [149] = 149,
+  [150] = 150,
+  [151] = 151,
+  [152] = 152,
+  [153] = 153,
+  [154] = 154,
+  [155] = 155,
+  [156] = 156,
+  [157] = 157,
+  [158] = 158,
+  [159] = 159,
+  [160] = 160,
+  [161] = 161,
+  [162] = 162,
+  [163] = 163,
+  [164] = 164,
+  [165] = 165,
+  [166] = 166,
+  [167] = 167,
+  [168] = 168,
+  [169] = 169,
+  [170] = 170,
+  [171] = 171,
+  [172] = 172,
+  [173] = 173,
+  [174] = 174,
+  [175] = 175,
+  [176] = 176,
+  [177] = 177,
+  [178] = 178,
+  [179] = 179,
+  [180] = 180,
+  [181] = 181,
+  [182] = 182,
+  [183] = 183,
+  [184] = 184,
+  [185] = 185,
+  [186] = 186,
+  [187] = 187,
+  [188] = 188,
+  [189] = 189,
+  [190] = 190,
+  [191] = 191,
+  [192] = 192,
+  [193] = 193,
+  [194] = 194,
+  [195] = 195,
+  [196] = 196,
+  [197] = 197,
+  [198] = 198,
+  [199] = 199,
+  [200] = 200,
+  [201] = 201,
+  [202] = 202,
+  [203] = 203,
+  [204] = 204,
+  [205] = 205,
+  [206] = 206,
+  [207] = 207,
+  [208] = 208,
+  [209] = 209,
+  [210] = 210,
+  [211] = 211,
+  [212] = 212,
+  [213] = 213,
+  [214] = 214,
+  [215] = 215,
+  [216] = 216,
+  [217] = 217,
+  [218] = 218,
+  [219] = 219,
+  [220] = 220,
+  [221] = 221,
+  [222] = 222,
+  [223] = 223,
+  [224] = 224,
+  [225] = 225,
+  [226] = 226,
+  [227] = 227,
+  [228] = 228,
+  [229] = 229,
+  [230] = 230,
+  [231] = 231,
+  [232] = 232,
+  [233] = 233,
+  [234] = 234,
+  [235] = 235,
+  [236] = 236,
+  [237] = 237,
+  [238] = 238,
+  [239] = 239,
+  [240] = 240,
+  [241] = 241,
+  [242] = 242,
+  [243] = 243,
+  [244] = 244,
+  [245] = 245,
+  [246] = 246,
+  [247] = 247,
+  [248] = 248,
+  [249] = 249,
+  [250] = 250,
+  [251] = 251,
+  [252] = 252,
+  [253] = 253,
+  [254] = 254,
+  [255] = 255,
+  [256] = 256,
+  [257] = 257,
+  [258] = 258,
+  [259] = 259,
+  [260] = 260,
+  [261] = 261,
+  [262] = 262,
+  [263] = 263,
+  [264] = 264,
+  [265] = 265,
+  [266] = 266,
+  [267] = 267,
+  [268] = 268,
+  [269] = 269,
+  [270] = 270,
+  [271] = 271,
+  [272] = 272,
+  [273] = 273,
+  [274] = 274,
+  [275] = 275,
+  [276] = 276,
+  [277] = 277,
+  [278] = 278,
 };
 
-static inline bool sym_identifier_character_set_1(int32_t c) {
-  return (c < 7773
-    ? (c < 1151
-      ? (c < 464
-        ? (c < 326
-          ? (c < 273
+static inline bool sym_character_set_1(int32_t c) {
+  return (c < 12306
+    ? (c < 5741
+      ? (c < 1329
+        ? (c < 444
+          ? (c < '^'
+            ? (c < '+'
+              ? (c < ' '
+                ? ((c >= '\t' && c <= '\n') || (c >= '\f' && c <= '\r'))
+                : (c == ' ' || (c == '$' || (c >= '\'' && c <= ')'))))
+              : (c == '+' || (c < 'A'
+                ? (c == '-' || (c >= '0' && c <= '>'))
+                : (c <= 'Z' || c == '\\'))))
+            : (c <= 'z' || (c < 174
+              ? (c < 162
+                ? (c == '|' || c == '~')
+                : (c <= 166 || ((c >= 168 && c <= 169) || (c >= 171 && c <= 172))))
+              : (c <= 177 || (c < 187
+                ? ((c >= 180 && c <= 181) || c == 184)
+                : (c == 187 || (c >= 192 && c <= 442)))))))
+          : (c <= 447 || (c < 751
+            ? (c < 661
+              ? (c < 457
+                ? (c == 452 || (c >= 454 && c <= 455))
+                : (c <= 458 || ((c >= 460 && c <= 497) || (c >= 499 && c <= 659))))
+              : (c <= 687 || (c < 741
+                ? ((c >= 706 && c <= 709) || (c >= 722 && c <= 735))
+                : (c <= 747 || c == 749))))
+            : (c <= 767 || (c < 904
+              ? (c < 891
+                ? ((c >= 880 && c <= 883) || (c >= 885 && c <= 887))
+                : (c <= 893 || (c == 895 || (c >= 900 && c <= 902))))
+              : (c <= 906 || (c < 931
+                ? (c == 908 || (c >= 910 && c <= 929))
+                : (c <= 1154 || (c >= 1162 && c <= 1327)))))))))
+        : (c <= 1366 || (c < 3647
+          ? (c < 2038
+            ? (c < 1547
+              ? (c < 1421
+                ? ((c >= 1376 && c <= 1416) || c == 1418)
+                : (c <= 1423 || (c == 1470 || (c >= 1542 && c <= 1544))))
+              : (c == 1547 || (c < 1769
+                ? ((c >= 1550 && c <= 1551) || c == 1758)
+                : (c == 1769 || (c >= 1789 && c <= 1790)))))
+            : (c == 2038 || (c < 2928
+              ? (c < 2546
+                ? ((c >= 2046 && c <= 2047) || c == 2184)
+                : (c <= 2547 || ((c >= 2554 && c <= 2555) || c == 2801)))
+              : (c == 2928 || (c < 3407
+                ? ((c >= 3059 && c <= 3066) || c == 3199)
+                : (c == 3407 || c == 3449))))))
+          : (c == 3647 || (c < 4053
+            ? (c < 3894
+              ? (c < 3861
+                ? ((c >= 3841 && c <= 3843) || c == 3859)
+                : (c <= 3863 || ((c >= 3866 && c <= 3871) || c == 3892)))
+              : (c == 3894 || (c < 4039
+                ? (c == 3896 || (c >= 4030 && c <= 4037))
+                : (c <= 4044 || (c >= 4046 && c <= 4047)))))
+            : (c <= 4056 || (c < 4349
+              ? (c < 4301
+                ? ((c >= 4254 && c <= 4293) || c == 4295)
+                : (c == 4301 || (c >= 4304 && c <= 4346)))
+              : (c <= 4351 || (c < 5112
+                ? ((c >= 5008 && c <= 5017) || (c >= 5024 && c <= 5109))
+                : (c <= 5117 || c == 5120))))))))))
+      : (c == 5741 || (c < 8276
+        ? (c < 8064
+          ? (c < 7531
+            ? (c < 7028
+              ? (c < 6464
+                ? (c == 6107 || c == 6150)
+                : (c == 6464 || ((c >= 6622 && c <= 6655) || (c >= 7009 && c <= 7018))))
+              : (c <= 7036 || (c < 7357
+                ? ((c >= 7296 && c <= 7304) || (c >= 7312 && c <= 7354))
+                : (c <= 7359 || (c >= 7424 && c <= 7467)))))
+            : (c <= 7543 || (c < 8016
+              ? (c < 7960
+                ? ((c >= 7545 && c <= 7578) || (c >= 7680 && c <= 7957))
+                : (c <= 7965 || ((c >= 7968 && c <= 8005) || (c >= 8008 && c <= 8013))))
+              : (c <= 8023 || (c < 8029
+                ? (c == 8025 || c == 8027)
+                : (c == 8029 || (c >= 8031 && c <= 8061)))))))
+          : (c <= 8071 || (c < 8182
+            ? (c < 8134
+              ? (c < 8112
+                ? ((c >= 8080 && c <= 8087) || (c >= 8096 && c <= 8103))
+                : (c <= 8116 || ((c >= 8118 && c <= 8123) || (c >= 8125 && c <= 8132))))
+              : (c <= 8139 || (c < 8157
+                ? ((c >= 8141 && c <= 8147) || (c >= 8150 && c <= 8155))
+                : (c <= 8175 || (c >= 8178 && c <= 8180)))))
+            : (c <= 8187 || (c < 8223
+              ? (c < 8216
+                ? ((c >= 8189 && c <= 8190) || (c >= 8208 && c <= 8213))
+                : (c <= 8217 || (c >= 8219 && c <= 8221)))
+              : (c == 8223 || (c < 8260
+                ? ((c >= 8249 && c <= 8250) || (c >= 8255 && c <= 8256))
+                : (c == 8260 || c == 8274))))))))
+        : (c == 8276 || (c < 11390
+          ? (c < 9280
+            ? (c < 8579
+              ? (c < 8352
+                ? ((c >= 8314 && c <= 8316) || (c >= 8330 && c <= 8332))
+                : (c <= 8384 || ((c >= 8448 && c <= 8500) || (c >= 8505 && c <= 8527))))
+              : (c <= 8580 || (c < 8972
+                ? ((c >= 8586 && c <= 8587) || (c >= 8592 && c <= 8967))
+                : (c <= 9000 || (c >= 9003 && c <= 9254)))))
+            : (c <= 9290 || (c < 10649
+              ? (c < 10132
+                ? ((c >= 9372 && c <= 9449) || (c >= 9472 && c <= 10087))
+                : (c <= 10180 || ((c >= 10183 && c <= 10213) || (c >= 10224 && c <= 10626))))
+              : (c <= 10711 || (c < 11126
+                ? ((c >= 10716 && c <= 10747) || (c >= 10750 && c <= 11123))
+                : (c <= 11157 || (c >= 11159 && c <= 11387)))))))
+          : (c <= 11502 || (c < 11808
+            ? (c < 11785
+              ? (c < 11559
+                ? ((c >= 11506 && c <= 11507) || (c >= 11520 && c <= 11557))
+                : (c == 11559 || (c == 11565 || (c >= 11778 && c <= 11781))))
+              : (c <= 11786 || (c < 11802
+                ? ((c >= 11788 && c <= 11789) || c == 11799)
+                : (c == 11802 || (c >= 11804 && c <= 11805)))))
+            : (c <= 11809 || (c < 11904
+              ? (c < 11856
+                ? ((c >= 11834 && c <= 11835) || c == 11840)
+                : (c <= 11857 || c == 11869))
+              : (c <= 11929 || (c < 12272
+                ? ((c >= 11931 && c <= 12019) || (c >= 12032 && c <= 12245))
+                : (c <= 12283 || c == 12292))))))))))))
+    : (c <= 12307 || (c < 92997
+      ? (c < 65020
+        ? (c < 42784
+          ? (c < 12800
+            ? (c < 12443
+              ? (c < 12336
+                ? (c == 12316 || c == 12320)
+                : (c == 12336 || ((c >= 12342 && c <= 12343) || (c >= 12350 && c <= 12351))))
+              : (c <= 12444 || (c < 12694
+                ? (c == 12448 || (c >= 12688 && c <= 12689))
+                : (c <= 12703 || (c >= 12736 && c <= 12771)))))
+            : (c <= 12830 || (c < 19904
+              ? (c < 12896
+                ? ((c >= 12842 && c <= 12871) || c == 12880)
+                : (c <= 12927 || ((c >= 12938 && c <= 12976) || (c >= 12992 && c <= 13311))))
+              : (c <= 19967 || (c < 42624
+                ? ((c >= 42128 && c <= 42182) || (c >= 42560 && c <= 42605))
+                : (c <= 42651 || (c >= 42752 && c <= 42774)))))))
+          : (c <= 42863 || (c < 43639
+            ? (c < 42965
+              ? (c < 42896
+                ? ((c >= 42865 && c <= 42887) || (c >= 42889 && c <= 42894))
+                : (c <= 42954 || ((c >= 42960 && c <= 42961) || c == 42963)))
+              : (c <= 42969 || (c < 43048
+                ? ((c >= 42997 && c <= 42998) || c == 43002)
+                : (c <= 43051 || (c >= 43062 && c <= 43065)))))
+            : (c <= 43641 || (c < 64275
+              ? (c < 43882
+                ? ((c >= 43824 && c <= 43867) || (c >= 43872 && c <= 43880))
+                : (c <= 43883 || ((c >= 43888 && c <= 43967) || (c >= 64256 && c <= 64262))))
+              : (c <= 64279 || (c < 64832
+                ? (c == 64297 || (c >= 64434 && c <= 64450))
+                : (c <= 64847 || c == 64975))))))))
+        : (c <= 65023 || (c < 66000
+          ? (c < 65342
+            ? (c < 65284
+              ? (c < 65112
+                ? ((c >= 65073 && c <= 65076) || (c >= 65101 && c <= 65103))
+                : (c == 65112 || ((c >= 65122 && c <= 65126) || c == 65129)))
+              : (c == 65284 || (c < 65308
+                ? (c == 65291 || c == 65293)
+                : (c <= 65310 || (c >= 65313 && c <= 65338)))))
+            : (c <= 65370 || (c < 65847
+              ? (c < 65504
+                ? (c == 65372 || c == 65374)
+                : (c <= 65510 || ((c >= 65512 && c <= 65518) || (c >= 65532 && c <= 65533))))
+              : (c <= 65855 || (c < 65936
+                ? ((c >= 65913 && c <= 65929) || (c >= 65932 && c <= 65934))
+                : (c <= 65948 || c == 65952))))))
+          : (c <= 66044 || (c < 67003
+            ? (c < 66956
+              ? (c < 66776
+                ? ((c >= 66560 && c <= 66639) || (c >= 66736 && c <= 66771))
+                : (c <= 66811 || ((c >= 66928 && c <= 66938) || (c >= 66940 && c <= 66954))))
+              : (c <= 66962 || (c < 66979
+                ? ((c >= 66964 && c <= 66965) || (c >= 66967 && c <= 66977))
+                : (c <= 66993 || (c >= 66995 && c <= 67001)))))
+            : (c <= 67004 || (c < 69293
+              ? (c < 68736
+                ? ((c >= 67703 && c <= 67704) || c == 68296)
+                : (c <= 68786 || (c >= 68800 && c <= 68850)))
+              : (c == 69293 || (c < 73685
+                ? (c == 71487 || (c >= 71840 && c <= 71903))
+                : (c <= 73713 || (c >= 92988 && c <= 92991)))))))))))
+      : (c == 92997 || (c < 125184
+        ? (c < 119997
+          ? (c < 119296
+            ? (c < 119081
+              ? (c < 118608
+                ? ((c >= 93760 && c <= 93823) || c == 113820)
+                : (c <= 118723 || ((c >= 118784 && c <= 119029) || (c >= 119040 && c <= 119078))))
+              : (c <= 119140 || (c < 119180
+                ? ((c >= 119146 && c <= 119148) || (c >= 119171 && c <= 119172))
+                : (c <= 119209 || (c >= 119214 && c <= 119274)))))
+            : (c <= 119361 || (c < 119970
+              ? (c < 119808
+                ? (c == 119365 || (c >= 119552 && c <= 119638))
+                : (c <= 119892 || ((c >= 119894 && c <= 119964) || (c >= 119966 && c <= 119967))))
+              : (c == 119970 || (c < 119982
+                ? ((c >= 119973 && c <= 119974) || (c >= 119977 && c <= 119980))
+                : (c <= 119993 || c == 119995))))))
+          : (c <= 120003 || (c < 120488
+            ? (c < 120123
+              ? (c < 120077
+                ? ((c >= 120005 && c <= 120069) || (c >= 120071 && c <= 120074))
+                : (c <= 120084 || ((c >= 120086 && c <= 120092) || (c >= 120094 && c <= 120121))))
+              : (c <= 120126 || (c < 120138
+                ? ((c >= 120128 && c <= 120132) || c == 120134)
+                : (c <= 120144 || (c >= 120146 && c <= 120485)))))
+            : (c <= 120779 || (c < 121477
+              ? (c < 121453
+                ? ((c >= 120832 && c <= 121343) || (c >= 121399 && c <= 121402))
+                : (c <= 121460 || (c >= 121462 && c <= 121475)))
+              : (c <= 121478 || (c < 123215
+                ? ((c >= 122624 && c <= 122633) || (c >= 122635 && c <= 122654))
+                : (c == 123215 || c == 123647))))))))
+        : (c <= 125251 || (c < 128992
+          ? (c < 127245
+            ? (c < 127024
+              ? (c < 126254
+                ? (c == 126124 || c == 126128)
+                : (c == 126254 || ((c >= 126704 && c <= 126705) || (c >= 126976 && c <= 127019))))
+              : (c <= 127123 || (c < 127169
+                ? ((c >= 127136 && c <= 127150) || (c >= 127153 && c <= 127167))
+                : (c <= 127183 || (c >= 127185 && c <= 127221)))))
+            : (c <= 127405 || (c < 127744
+              ? (c < 127552
+                ? ((c >= 127462 && c <= 127490) || (c >= 127504 && c <= 127547))
+                : (c <= 127560 || ((c >= 127568 && c <= 127569) || (c >= 127584 && c <= 127589))))
+              : (c <= 128727 || (c < 128768
+                ? ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))
+                : (c <= 128883 || (c >= 128896 && c <= 128984)))))))
+          : (c <= 129003 || (c < 129656
+            ? (c < 129168
+              ? (c < 129040
+                ? (c == 129008 || (c >= 129024 && c <= 129035))
+                : (c <= 129095 || ((c >= 129104 && c <= 129113) || (c >= 129120 && c <= 129159))))
+              : (c <= 129197 || (c < 129632
+                ? ((c >= 129200 && c <= 129201) || (c >= 129280 && c <= 129619))
+                : (c <= 129645 || (c >= 129648 && c <= 129652)))))
+            : (c <= 129660 || (c < 129744
+              ? (c < 129712
+                ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                : (c <= 129722 || (c >= 129728 && c <= 129733)))
+              : (c <= 129753 || (c < 129792
+                ? ((c >= 129760 && c <= 129767) || (c >= 129776 && c <= 129782))
+                : (c <= 129938 || (c >= 129940 && c <= 129994)))))))))))))));
+}
+
+static inline bool sym_character_set_2(int32_t c) {
+  return (c < 12306
+    ? (c < 5741
+      ? (c < 1329
+        ? (c < 444
+          ? (c < 'w'
+            ? (c < 'O'
+              ? (c < '-'
+                ? (c == '$' || c == '+')
+                : (c == '-' || ((c >= '<' && c <= '>') || (c >= 'B' && c <= 'M'))))
+              : (c <= 'S' || (c < 'o'
+                ? ((c >= 'V' && c <= 'Z') || (c >= '^' && c <= 'm'))
+                : (c <= 'r' || (c >= 't' && c <= 'u')))))
+            : (c <= 'y' || (c < 174
+              ? (c < 162
+                ? (c == '|' || c == '~')
+                : (c <= 166 || ((c >= 168 && c <= 169) || (c >= 171 && c <= 172))))
+              : (c <= 177 || (c < 187
+                ? ((c >= 180 && c <= 181) || c == 184)
+                : (c == 187 || (c >= 192 && c <= 442)))))))
+          : (c <= 447 || (c < 751
+            ? (c < 661
+              ? (c < 457
+                ? (c == 452 || (c >= 454 && c <= 455))
+                : (c <= 458 || ((c >= 460 && c <= 497) || (c >= 499 && c <= 659))))
+              : (c <= 687 || (c < 741
+                ? ((c >= 706 && c <= 709) || (c >= 722 && c <= 735))
+                : (c <= 747 || c == 749))))
+            : (c <= 767 || (c < 904
+              ? (c < 891
+                ? ((c >= 880 && c <= 883) || (c >= 885 && c <= 887))
+                : (c <= 893 || (c == 895 || (c >= 900 && c <= 902))))
+              : (c <= 906 || (c < 931
+                ? (c == 908 || (c >= 910 && c <= 929))
+                : (c <= 1154 || (c >= 1162 && c <= 1327)))))))))
+        : (c <= 1366 || (c < 3647
+          ? (c < 2038
+            ? (c < 1547
+              ? (c < 1421
+                ? ((c >= 1376 && c <= 1416) || c == 1418)
+                : (c <= 1423 || (c == 1470 || (c >= 1542 && c <= 1544))))
+              : (c == 1547 || (c < 1769
+                ? ((c >= 1550 && c <= 1551) || c == 1758)
+                : (c == 1769 || (c >= 1789 && c <= 1790)))))
+            : (c == 2038 || (c < 2928
+              ? (c < 2546
+                ? ((c >= 2046 && c <= 2047) || c == 2184)
+                : (c <= 2547 || ((c >= 2554 && c <= 2555) || c == 2801)))
+              : (c == 2928 || (c < 3407
+                ? ((c >= 3059 && c <= 3066) || c == 3199)
+                : (c == 3407 || c == 3449))))))
+          : (c == 3647 || (c < 4053
+            ? (c < 3894
+              ? (c < 3861
+                ? ((c >= 3841 && c <= 3843) || c == 3859)
+                : (c <= 3863 || ((c >= 3866 && c <= 3871) || c == 3892)))
+              : (c == 3894 || (c < 4039
+                ? (c == 3896 || (c >= 4030 && c <= 4037))
+                : (c <= 4044 || (c >= 4046 && c <= 4047)))))
+            : (c <= 4056 || (c < 4349
+              ? (c < 4301
+                ? ((c >= 4254 && c <= 4293) || c == 4295)
+                : (c == 4301 || (c >= 4304 && c <= 4346)))
+              : (c <= 4351 || (c < 5112
+                ? ((c >= 5008 && c <= 5017) || (c >= 5024 && c <= 5109))
+                : (c <= 5117 || c == 5120))))))))))
+      : (c == 5741 || (c < 8276
+        ? (c < 8064
+          ? (c < 7531
+            ? (c < 7028
+              ? (c < 6464
+                ? (c == 6107 || c == 6150)
+                : (c == 6464 || ((c >= 6622 && c <= 6655) || (c >= 7009 && c <= 7018))))
+              : (c <= 7036 || (c < 7357
+                ? ((c >= 7296 && c <= 7304) || (c >= 7312 && c <= 7354))
+                : (c <= 7359 || (c >= 7424 && c <= 7467)))))
+            : (c <= 7543 || (c < 8016
+              ? (c < 7960
+                ? ((c >= 7545 && c <= 7578) || (c >= 7680 && c <= 7957))
+                : (c <= 7965 || ((c >= 7968 && c <= 8005) || (c >= 8008 && c <= 8013))))
+              : (c <= 8023 || (c < 8029
+                ? (c == 8025 || c == 8027)
+                : (c == 8029 || (c >= 8031 && c <= 8061)))))))
+          : (c <= 8071 || (c < 8182
+            ? (c < 8134
+              ? (c < 8112
+                ? ((c >= 8080 && c <= 8087) || (c >= 8096 && c <= 8103))
+                : (c <= 8116 || ((c >= 8118 && c <= 8123) || (c >= 8125 && c <= 8132))))
+              : (c <= 8139 || (c < 8157
+                ? ((c >= 8141 && c <= 8147) || (c >= 8150 && c <= 8155))
+                : (c <= 8175 || (c >= 8178 && c <= 8180)))))
+            : (c <= 8187 || (c < 8223
+              ? (c < 8216
+                ? ((c >= 8189 && c <= 8190) || (c >= 8208 && c <= 8213))
+                : (c <= 8217 || (c >= 8219 && c <= 8221)))
+              : (c == 8223 || (c < 8260
+                ? ((c >= 8249 && c <= 8250) || (c >= 8255 && c <= 8256))
+                : (c == 8260 || c == 8274))))))))
+        : (c == 8276 || (c < 11390
+          ? (c < 9280
+            ? (c < 8579
+              ? (c < 8352
+                ? ((c >= 8314 && c <= 8316) || (c >= 8330 && c <= 8332))
+                : (c <= 8384 || ((c >= 8448 && c <= 8500) || (c >= 8505 && c <= 8527))))
+              : (c <= 8580 || (c < 8972
+                ? ((c >= 8586 && c <= 8587) || (c >= 8592 && c <= 8967))
+                : (c <= 9000 || (c >= 9003 && c <= 9254)))))
+            : (c <= 9290 || (c < 10649
+              ? (c < 10132
+                ? ((c >= 9372 && c <= 9449) || (c >= 9472 && c <= 10087))
+                : (c <= 10180 || ((c >= 10183 && c <= 10213) || (c >= 10224 && c <= 10626))))
+              : (c <= 10711 || (c < 11126
+                ? ((c >= 10716 && c <= 10747) || (c >= 10750 && c <= 11123))
+                : (c <= 11157 || (c >= 11159 && c <= 11387)))))))
+          : (c <= 11502 || (c < 11808
+            ? (c < 11785
+              ? (c < 11559
+                ? ((c >= 11506 && c <= 11507) || (c >= 11520 && c <= 11557))
+                : (c == 11559 || (c == 11565 || (c >= 11778 && c <= 11781))))
+              : (c <= 11786 || (c < 11802
+                ? ((c >= 11788 && c <= 11789) || c == 11799)
+                : (c == 11802 || (c >= 11804 && c <= 11805)))))
+            : (c <= 11809 || (c < 11904
+              ? (c < 11856
+                ? ((c >= 11834 && c <= 11835) || c == 11840)
+                : (c <= 11857 || c == 11869))
+              : (c <= 11929 || (c < 12272
+                ? ((c >= 11931 && c <= 12019) || (c >= 12032 && c <= 12245))
+                : (c <= 12283 || c == 12292))))))))))))
+    : (c <= 12307 || (c < 92997
+      ? (c < 65020
+        ? (c < 42784
+          ? (c < 12800
+            ? (c < 12443
+              ? (c < 12336
+                ? (c == 12316 || c == 12320)
+                : (c == 12336 || ((c >= 12342 && c <= 12343) || (c >= 12350 && c <= 12351))))
+              : (c <= 12444 || (c < 12694
+                ? (c == 12448 || (c >= 12688 && c <= 12689))
+                : (c <= 12703 || (c >= 12736 && c <= 12771)))))
+            : (c <= 12830 || (c < 19904
+              ? (c < 12896
+                ? ((c >= 12842 && c <= 12871) || c == 12880)
+                : (c <= 12927 || ((c >= 12938 && c <= 12976) || (c >= 12992 && c <= 13311))))
+              : (c <= 19967 || (c < 42624
+                ? ((c >= 42128 && c <= 42182) || (c >= 42560 && c <= 42605))
+                : (c <= 42651 || (c >= 42752 && c <= 42774)))))))
+          : (c <= 42863 || (c < 43639
+            ? (c < 42965
+              ? (c < 42896
+                ? ((c >= 42865 && c <= 42887) || (c >= 42889 && c <= 42894))
+                : (c <= 42954 || ((c >= 42960 && c <= 42961) || c == 42963)))
+              : (c <= 42969 || (c < 43048
+                ? ((c >= 42997 && c <= 42998) || c == 43002)
+                : (c <= 43051 || (c >= 43062 && c <= 43065)))))
+            : (c <= 43641 || (c < 64275
+              ? (c < 43882
+                ? ((c >= 43824 && c <= 43867) || (c >= 43872 && c <= 43880))
+                : (c <= 43883 || ((c >= 43888 && c <= 43967) || (c >= 64256 && c <= 64262))))
+              : (c <= 64279 || (c < 64832
+                ? (c == 64297 || (c >= 64434 && c <= 64450))
+                : (c <= 64847 || c == 64975))))))))
+        : (c <= 65023 || (c < 66000
+          ? (c < 65342
+            ? (c < 65284
+              ? (c < 65112
+                ? ((c >= 65073 && c <= 65076) || (c >= 65101 && c <= 65103))
+                : (c == 65112 || ((c >= 65122 && c <= 65126) || c == 65129)))
+              : (c == 65284 || (c < 65308
+                ? (c == 65291 || c == 65293)
+                : (c <= 65310 || (c >= 65313 && c <= 65338)))))
+            : (c <= 65370 || (c < 65847
+              ? (c < 65504
+                ? (c == 65372 || c == 65374)
+                : (c <= 65510 || ((c >= 65512 && c <= 65518) || (c >= 65532 && c <= 65533))))
+              : (c <= 65855 || (c < 65936
+                ? ((c >= 65913 && c <= 65929) || (c >= 65932 && c <= 65934))
+                : (c <= 65948 || c == 65952))))))
+          : (c <= 66044 || (c < 67003
+            ? (c < 66956
+              ? (c < 66776
+                ? ((c >= 66560 && c <= 66639) || (c >= 66736 && c <= 66771))
+                : (c <= 66811 || ((c >= 66928 && c <= 66938) || (c >= 66940 && c <= 66954))))
+              : (c <= 66962 || (c < 66979
+                ? ((c >= 66964 && c <= 66965) || (c >= 66967 && c <= 66977))
+                : (c <= 66993 || (c >= 66995 && c <= 67001)))))
+            : (c <= 67004 || (c < 69293
+              ? (c < 68736
+                ? ((c >= 67703 && c <= 67704) || c == 68296)
+                : (c <= 68786 || (c >= 68800 && c <= 68850)))
+              : (c == 69293 || (c < 73685
+                ? (c == 71487 || (c >= 71840 && c <= 71903))
+                : (c <= 73713 || (c >= 92988 && c <= 92991)))))))))))
+      : (c == 92997 || (c < 125184
+        ? (c < 119997
+          ? (c < 119296
+            ? (c < 119081
+              ? (c < 118608
+                ? ((c >= 93760 && c <= 93823) || c == 113820)
+                : (c <= 118723 || ((c >= 118784 && c <= 119029) || (c >= 119040 && c <= 119078))))
+              : (c <= 119140 || (c < 119180
+                ? ((c >= 119146 && c <= 119148) || (c >= 119171 && c <= 119172))
+                : (c <= 119209 || (c >= 119214 && c <= 119274)))))
+            : (c <= 119361 || (c < 119970
+              ? (c < 119808
+                ? (c == 119365 || (c >= 119552 && c <= 119638))
+                : (c <= 119892 || ((c >= 119894 && c <= 119964) || (c >= 119966 && c <= 119967))))
+              : (c == 119970 || (c < 119982
+                ? ((c >= 119973 && c <= 119974) || (c >= 119977 && c <= 119980))
+                : (c <= 119993 || c == 119995))))))
+          : (c <= 120003 || (c < 120488
+            ? (c < 120123
+              ? (c < 120077
+                ? ((c >= 120005 && c <= 120069) || (c >= 120071 && c <= 120074))
+                : (c <= 120084 || ((c >= 120086 && c <= 120092) || (c >= 120094 && c <= 120121))))
+              : (c <= 120126 || (c < 120138
+                ? ((c >= 120128 && c <= 120132) || c == 120134)
+                : (c <= 120144 || (c >= 120146 && c <= 120485)))))
+            : (c <= 120779 || (c < 121477
+              ? (c < 121453
+                ? ((c >= 120832 && c <= 121343) || (c >= 121399 && c <= 121402))
+                : (c <= 121460 || (c >= 121462 && c <= 121475)))
+              : (c <= 121478 || (c < 123215
+                ? ((c >= 122624 && c <= 122633) || (c >= 122635 && c <= 122654))
+                : (c == 123215 || c == 123647))))))))
+        : (c <= 125251 || (c < 128992
+          ? (c < 127245
+            ? (c < 127024
+              ? (c < 126254
+                ? (c == 126124 || c == 126128)
+                : (c == 126254 || ((c >= 126704 && c <= 126705) || (c >= 126976 && c <= 127019))))
+              : (c <= 127123 || (c < 127169
+                ? ((c >= 127136 && c <= 127150) || (c >= 127153 && c <= 127167))
+                : (c <= 127183 || (c >= 127185 && c <= 127221)))))
+            : (c <= 127405 || (c < 127744
+              ? (c < 127552
+                ? ((c >= 127462 && c <= 127490) || (c >= 127504 && c <= 127547))
+                : (c <= 127560 || ((c >= 127568 && c <= 127569) || (c >= 127584 && c <= 127589))))
+              : (c <= 128727 || (c < 128768
+                ? ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))
+                : (c <= 128883 || (c >= 128896 && c <= 128984)))))))
+          : (c <= 129003 || (c < 129656
+            ? (c < 129168
+              ? (c < 129040
+                ? (c == 129008 || (c >= 129024 && c <= 129035))
+                : (c <= 129095 || ((c >= 129104 && c <= 129113) || (c >= 129120 && c <= 129159))))
+              : (c <= 129197 || (c < 129632
+                ? ((c >= 129200 && c <= 129201) || (c >= 129280 && c <= 129619))
+                : (c <= 129645 || (c >= 129648 && c <= 129652)))))
+            : (c <= 129660 || (c < 129744
+              ? (c < 129712
+                ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                : (c <= 129722 || (c >= 129728 && c <= 129733)))
+              : (c <= 129753 || (c < 129792
+                ? ((c >= 129760 && c <= 129767) || (c >= 129776 && c <= 129782))
+                : (c <= 129938 || (c >= 129940 && c <= 129994)))))))))))))));
+}
+
+static inline bool sym_character_set_3(int32_t c) {
+  return (c < 12306
+    ? (c < 5741
+      ? (c < 1329
+        ? (c < 444
+          ? (c < '^'
+            ? (c < '+'
+              ? (c < ' '
+                ? ((c >= '\t' && c <= '\n') || (c >= '\f' && c <= '\r'))
+                : (c == ' ' || (c == '$' || (c >= '\'' && c <= '('))))
+              : (c == '+' || (c < 'A'
+                ? (c == '-' || (c >= '0' && c <= '>'))
+                : (c <= 'Z' || c == '\\'))))
+            : (c <= 'z' || (c < 174
+              ? (c < 162
+                ? (c == '|' || c == '~')
+                : (c <= 166 || ((c >= 168 && c <= 169) || (c >= 171 && c <= 172))))
+              : (c <= 177 || (c < 187
+                ? ((c >= 180 && c <= 181) || c == 184)
+                : (c == 187 || (c >= 192 && c <= 442)))))))
+          : (c <= 447 || (c < 751
+            ? (c < 661
+              ? (c < 457
+                ? (c == 452 || (c >= 454 && c <= 455))
+                : (c <= 458 || ((c >= 460 && c <= 497) || (c >= 499 && c <= 659))))
+              : (c <= 687 || (c < 741
+                ? ((c >= 706 && c <= 709) || (c >= 722 && c <= 735))
+                : (c <= 747 || c == 749))))
+            : (c <= 767 || (c < 904
+              ? (c < 891
+                ? ((c >= 880 && c <= 883) || (c >= 885 && c <= 887))
+                : (c <= 893 || (c == 895 || (c >= 900 && c <= 902))))
+              : (c <= 906 || (c < 931
+                ? (c == 908 || (c >= 910 && c <= 929))
+                : (c <= 1154 || (c >= 1162 && c <= 1327)))))))))
+        : (c <= 1366 || (c < 3647
+          ? (c < 2038
+            ? (c < 1547
+              ? (c < 1421
+                ? ((c >= 1376 && c <= 1416) || c == 1418)
+                : (c <= 1423 || (c == 1470 || (c >= 1542 && c <= 1544))))
+              : (c == 1547 || (c < 1769
+                ? ((c >= 1550 && c <= 1551) || c == 1758)
+                : (c == 1769 || (c >= 1789 && c <= 1790)))))
+            : (c == 2038 || (c < 2928
+              ? (c < 2546
+                ? ((c >= 2046 && c <= 2047) || c == 2184)
+                : (c <= 2547 || ((c >= 2554 && c <= 2555) || c == 2801)))
+              : (c == 2928 || (c < 3407
+                ? ((c >= 3059 && c <= 3066) || c == 3199)
+                : (c == 3407 || c == 3449))))))
+          : (c == 3647 || (c < 4053
+            ? (c < 3894
+              ? (c < 3861
+                ? ((c >= 3841 && c <= 3843) || c == 3859)
+                : (c <= 3863 || ((c >= 3866 && c <= 3871) || c == 3892)))
+              : (c == 3894 || (c < 4039
+                ? (c == 3896 || (c >= 4030 && c <= 4037))
+                : (c <= 4044 || (c >= 4046 && c <= 4047)))))
+            : (c <= 4056 || (c < 4349
+              ? (c < 4301
+                ? ((c >= 4254 && c <= 4293) || c == 4295)
+                : (c == 4301 || (c >= 4304 && c <= 4346)))
+              : (c <= 4351 || (c < 5112
+                ? ((c >= 5008 && c <= 5017) || (c >= 5024 && c <= 5109))
+                : (c <= 5117 || c == 5120))))))))))
+      : (c == 5741 || (c < 8276
+        ? (c < 8064
+          ? (c < 7531
+            ? (c < 7028
+              ? (c < 6464
+                ? (c == 6107 || c == 6150)
+                : (c == 6464 || ((c >= 6622 && c <= 6655) || (c >= 7009 && c <= 7018))))
+              : (c <= 7036 || (c < 7357
+                ? ((c >= 7296 && c <= 7304) || (c >= 7312 && c <= 7354))
+                : (c <= 7359 || (c >= 7424 && c <= 7467)))))
+            : (c <= 7543 || (c < 8016
+              ? (c < 7960
+                ? ((c >= 7545 && c <= 7578) || (c >= 7680 && c <= 7957))
+                : (c <= 7965 || ((c >= 7968 && c <= 8005) || (c >= 8008 && c <= 8013))))
+              : (c <= 8023 || (c < 8029
+                ? (c == 8025 || c == 8027)
+                : (c == 8029 || (c >= 8031 && c <= 8061)))))))
+          : (c <= 8071 || (c < 8182
+            ? (c < 8134
+              ? (c < 8112
+                ? ((c >= 8080 && c <= 8087) || (c >= 8096 && c <= 8103))
+                : (c <= 8116 || ((c >= 8118 && c <= 8123) || (c >= 8125 && c <= 8132))))
+              : (c <= 8139 || (c < 8157
+                ? ((c >= 8141 && c <= 8147) || (c >= 8150 && c <= 8155))
+                : (c <= 8175 || (c >= 8178 && c <= 8180)))))
+            : (c <= 8187 || (c < 8223
+              ? (c < 8216
+                ? ((c >= 8189 && c <= 8190) || (c >= 8208 && c <= 8213))
+                : (c <= 8217 || (c >= 8219 && c <= 8221)))
+              : (c == 8223 || (c < 8260
+                ? ((c >= 8249 && c <= 8250) || (c >= 8255 && c <= 8256))
+                : (c == 8260 || c == 8274))))))))
+        : (c == 8276 || (c < 11390
+          ? (c < 9280
+            ? (c < 8579
+              ? (c < 8352
+                ? ((c >= 8314 && c <= 8316) || (c >= 8330 && c <= 8332))
+                : (c <= 8384 || ((c >= 8448 && c <= 8500) || (c >= 8505 && c <= 8527))))
+              : (c <= 8580 || (c < 8972
+                ? ((c >= 8586 && c <= 8587) || (c >= 8592 && c <= 8967))
+                : (c <= 9000 || (c >= 9003 && c <= 9254)))))
+            : (c <= 9290 || (c < 10649
+              ? (c < 10132
+                ? ((c >= 9372 && c <= 9449) || (c >= 9472 && c <= 10087))
+                : (c <= 10180 || ((c >= 10183 && c <= 10213) || (c >= 10224 && c <= 10626))))
+              : (c <= 10711 || (c < 11126
+                ? ((c >= 10716 && c <= 10747) || (c >= 10750 && c <= 11123))
+                : (c <= 11157 || (c >= 11159 && c <= 11387)))))))
+          : (c <= 11502 || (c < 11808
+            ? (c < 11785
+              ? (c < 11559
+                ? ((c >= 11506 && c <= 11507) || (c >= 11520 && c <= 11557))
+                : (c == 11559 || (c == 11565 || (c >= 11778 && c <= 11781))))
+              : (c <= 11786 || (c < 11802
+                ? ((c >= 11788 && c <= 11789) || c == 11799)
+                : (c == 11802 || (c >= 11804 && c <= 11805)))))
+            : (c <= 11809 || (c < 11904
+              ? (c < 11856
+                ? ((c >= 11834 && c <= 11835) || c == 11840)
+                : (c <= 11857 || c == 11869))
+              : (c <= 11929 || (c < 12272
+                ? ((c >= 11931 && c <= 12019) || (c >= 12032 && c <= 12245))
+                : (c <= 12283 || c == 12292))))))))))))
+    : (c <= 12307 || (c < 92997
+      ? (c < 65020
+        ? (c < 42784
+          ? (c < 12800
+            ? (c < 12443
+              ? (c < 12336
+                ? (c == 12316 || c == 12320)
+                : (c == 12336 || ((c >= 12342 && c <= 12343) || (c >= 12350 && c <= 12351))))
+              : (c <= 12444 || (c < 12694
+                ? (c == 12448 || (c >= 12688 && c <= 12689))
+                : (c <= 12703 || (c >= 12736 && c <= 12771)))))
+            : (c <= 12830 || (c < 19904
+              ? (c < 12896
+                ? ((c >= 12842 && c <= 12871) || c == 12880)
+                : (c <= 12927 || ((c >= 12938 && c <= 12976) || (c >= 12992 && c <= 13311))))
+              : (c <= 19967 || (c < 42624
+                ? ((c >= 42128 && c <= 42182) || (c >= 42560 && c <= 42605))
+                : (c <= 42651 || (c >= 42752 && c <= 42774)))))))
+          : (c <= 42863 || (c < 43639
+            ? (c < 42965
+              ? (c < 42896
+                ? ((c >= 42865 && c <= 42887) || (c >= 42889 && c <= 42894))
+                : (c <= 42954 || ((c >= 42960 && c <= 42961) || c == 42963)))
+              : (c <= 42969 || (c < 43048
+                ? ((c >= 42997 && c <= 42998) || c == 43002)
+                : (c <= 43051 || (c >= 43062 && c <= 43065)))))
+            : (c <= 43641 || (c < 64275
+              ? (c < 43882
+                ? ((c >= 43824 && c <= 43867) || (c >= 43872 && c <= 43880))
+                : (c <= 43883 || ((c >= 43888 && c <= 43967) || (c >= 64256 && c <= 64262))))
+              : (c <= 64279 || (c < 64832
+                ? (c == 64297 || (c >= 64434 && c <= 64450))
+                : (c <= 64847 || c == 64975))))))))
+        : (c <= 65023 || (c < 66000
+          ? (c < 65342
+            ? (c < 65284
+              ? (c < 65112
+                ? ((c >= 65073 && c <= 65076) || (c >= 65101 && c <= 65103))
+                : (c == 65112 || ((c >= 65122 && c <= 65126) || c == 65129)))
+              : (c == 65284 || (c < 65308
+                ? (c == 65291 || c == 65293)
+                : (c <= 65310 || (c >= 65313 && c <= 65338)))))
+            : (c <= 65370 || (c < 65847
+              ? (c < 65504
+                ? (c == 65372 || c == 65374)
+                : (c <= 65510 || ((c >= 65512 && c <= 65518) || (c >= 65532 && c <= 65533))))
+              : (c <= 65855 || (c < 65936
+                ? ((c >= 65913 && c <= 65929) || (c >= 65932 && c <= 65934))
+                : (c <= 65948 || c == 65952))))))
+          : (c <= 66044 || (c < 67003
+            ? (c < 66956
+              ? (c < 66776
+                ? ((c >= 66560 && c <= 66639) || (c >= 66736 && c <= 66771))
+                : (c <= 66811 || ((c >= 66928 && c <= 66938) || (c >= 66940 && c <= 66954))))
+              : (c <= 66962 || (c < 66979
+                ? ((c >= 66964 && c <= 66965) || (c >= 66967 && c <= 66977))
+                : (c <= 66993 || (c >= 66995 && c <= 67001)))))
+            : (c <= 67004 || (c < 69293
+              ? (c < 68736
+                ? ((c >= 67703 && c <= 67704) || c == 68296)
+                : (c <= 68786 || (c >= 68800 && c <= 68850)))
+              : (c == 69293 || (c < 73685
+                ? (c == 71487 || (c >= 71840 && c <= 71903))
+                : (c <= 73713 || (c >= 92988 && c <= 92991)))))))))))
+      : (c == 92997 || (c < 125184
+        ? (c < 119997
+          ? (c < 119296
+            ? (c < 119081
+              ? (c < 118608
+                ? ((c >= 93760 && c <= 93823) || c == 113820)
+                : (c <= 118723 || ((c >= 118784 && c <= 119029) || (c >= 119040 && c <= 119078))))
+              : (c <= 119140 || (c < 119180
+                ? ((c >= 119146 && c <= 119148) || (c >= 119171 && c <= 119172))
+                : (c <= 119209 || (c >= 119214 && c <= 119274)))))
+            : (c <= 119361 || (c < 119970
+              ? (c < 119808
+                ? (c == 119365 || (c >= 119552 && c <= 119638))
+                : (c <= 119892 || ((c >= 119894 && c <= 119964) || (c >= 119966 && c <= 119967))))
+              : (c == 119970 || (c < 119982
+                ? ((c >= 119973 && c <= 119974) || (c >= 119977 && c <= 119980))
+                : (c <= 119993 || c == 119995))))))
+          : (c <= 120003 || (c < 120488
+            ? (c < 120123
+              ? (c < 120077
+                ? ((c >= 120005 && c <= 120069) || (c >= 120071 && c <= 120074))
+                : (c <= 120084 || ((c >= 120086 && c <= 120092) || (c >= 120094 && c <= 120121))))
+              : (c <= 120126 || (c < 120138
+                ? ((c >= 120128 && c <= 120132) || c == 120134)
+                : (c <= 120144 || (c >= 120146 && c <= 120485)))))
+            : (c <= 120779 || (c < 121477
+              ? (c < 121453
+                ? ((c >= 120832 && c <= 121343) || (c >= 121399 && c <= 121402))
+                : (c <= 121460 || (c >= 121462 && c <= 121475)))
+              : (c <= 121478 || (c < 123215
+                ? ((c >= 122624 && c <= 122633) || (c >= 122635 && c <= 122654))
+                : (c == 123215 || c == 123647))))))))
+        : (c <= 125251 || (c < 128992
+          ? (c < 127245
+            ? (c < 127024
+              ? (c < 126254
+                ? (c == 126124 || c == 126128)
+                : (c == 126254 || ((c >= 126704 && c <= 126705) || (c >= 126976 && c <= 127019))))
+              : (c <= 127123 || (c < 127169
+                ? ((c >= 127136 && c <= 127150) || (c >= 127153 && c <= 127167))
+                : (c <= 127183 || (c >= 127185 && c <= 127221)))))
+            : (c <= 127405 || (c < 127744
+              ? (c < 127552
+                ? ((c >= 127462 && c <= 127490) || (c >= 127504 && c <= 127547))
+                : (c <= 127560 || ((c >= 127568 && c <= 127569) || (c >= 127584 && c <= 127589))))
+              : (c <= 128727 || (c < 128768
+                ? ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))
+                : (c <= 128883 || (c >= 128896 && c <= 128984)))))))
+          : (c <= 129003 || (c < 129656
+            ? (c < 129168
+              ? (c < 129040
+                ? (c == 129008 || (c >= 129024 && c <= 129035))
+                : (c <= 129095 || ((c >= 129104 && c <= 129113) || (c >= 129120 && c <= 129159))))
+              : (c <= 129197 || (c < 129632
+                ? ((c >= 129200 && c <= 129201) || (c >= 129280 && c <= 129619))
+                : (c <= 129645 || (c >= 129648 && c <= 129652)))))
+            : (c <= 129660 || (c < 129744
+              ? (c < 129712
+                ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                : (c <= 129722 || (c >= 129728 && c <= 129733)))
+              : (c <= 129753 || (c < 129792
+                ? ((c >= 129760 && c <= 129767) || (c >= 129776 && c <= 129782))
+                : (c <= 129938 || (c >= 129940 && c <= 129994)))))))))))))));
+}
+
+static inline bool sym_character_set_4(int32_t c) {
+  return (c < 12336
+    ? (c < 6622
+      ? (c < 1470
+        ? (c < 499
+          ? (c < 171
+            ? (c < '^'
+              ? (c < '-'
+                ? (c == '$' || c == '+')
+                : (c == '-' || ((c >= '<' && c <= '>') || (c >= 'A' && c <= 'Z'))))
+              : (c <= 'z' || (c < 162
+                ? (c == '|' || c == '~')
+                : (c <= 166 || (c >= 168 && c <= 169)))))
+            : (c <= 172 || (c < 444
+              ? (c < 184
+                ? ((c >= 174 && c <= 177) || (c >= 180 && c <= 181))
+                : (c == 184 || (c == 187 || (c >= 192 && c <= 442))))
+              : (c <= 447 || (c < 457
+                ? (c == 452 || (c >= 454 && c <= 455))
+                : (c <= 458 || (c >= 460 && c <= 497)))))))
+          : (c <= 659 || (c < 900
+            ? (c < 751
+              ? (c < 722
+                ? ((c >= 661 && c <= 687) || (c >= 706 && c <= 709))
+                : (c <= 735 || ((c >= 741 && c <= 747) || c == 749)))
+              : (c <= 767 || (c < 891
+                ? ((c >= 880 && c <= 883) || (c >= 885 && c <= 887))
+                : (c <= 893 || c == 895))))
+            : (c <= 902 || (c < 1162
+              ? (c < 910
+                ? ((c >= 904 && c <= 906) || c == 908)
+                : (c <= 929 || (c >= 931 && c <= 1154)))
+              : (c <= 1327 || (c < 1418
+                ? ((c >= 1329 && c <= 1366) || (c >= 1376 && c <= 1416))
+                : (c == 1418 || (c >= 1421 && c <= 1423)))))))))
+        : (c == 1470 || (c < 3866
+          ? (c < 2554
+            ? (c < 1789
+              ? (c < 1550
+                ? ((c >= 1542 && c <= 1544) || c == 1547)
+                : (c <= 1551 || (c == 1758 || c == 1769)))
+              : (c <= 1790 || (c < 2184
+                ? (c == 2038 || (c >= 2046 && c <= 2047))
+                : (c == 2184 || (c >= 2546 && c <= 2547)))))
+            : (c <= 2555 || (c < 3449
+              ? (c < 3059
+                ? (c == 2801 || c == 2928)
+                : (c <= 3066 || (c == 3199 || c == 3407)))
+              : (c == 3449 || (c < 3859
+                ? (c == 3647 || (c >= 3841 && c <= 3843))
+                : (c == 3859 || (c >= 3861 && c <= 3863)))))))
+          : (c <= 3871 || (c < 4304
+            ? (c < 4046
+              ? (c < 3896
+                ? (c == 3892 || c == 3894)
+                : (c == 3896 || ((c >= 4030 && c <= 4037) || (c >= 4039 && c <= 4044))))
+              : (c <= 4047 || (c < 4295
+                ? ((c >= 4053 && c <= 4056) || (c >= 4254 && c <= 4293))
+                : (c == 4295 || c == 4301))))
+            : (c <= 4346 || (c < 5120
+              ? (c < 5024
+                ? ((c >= 4349 && c <= 4351) || (c >= 5008 && c <= 5017))
+                : (c <= 5109 || (c >= 5112 && c <= 5117)))
+              : (c == 5120 || (c < 6150
+                ? (c == 5741 || c == 6107)
+                : (c == 6150 || c == 6464))))))))))
+      : (c <= 6655 || (c < 8448
+        ? (c < 8118
+          ? (c < 7968
+            ? (c < 7424
+              ? (c < 7296
+                ? ((c >= 7009 && c <= 7018) || (c >= 7028 && c <= 7036))
+                : (c <= 7304 || ((c >= 7312 && c <= 7354) || (c >= 7357 && c <= 7359))))
+              : (c <= 7467 || (c < 7680
+                ? ((c >= 7531 && c <= 7543) || (c >= 7545 && c <= 7578))
+                : (c <= 7957 || (c >= 7960 && c <= 7965)))))
+            : (c <= 8005 || (c < 8031
+              ? (c < 8025
+                ? ((c >= 8008 && c <= 8013) || (c >= 8016 && c <= 8023))
+                : (c == 8025 || (c == 8027 || c == 8029)))
+              : (c <= 8061 || (c < 8096
+                ? ((c >= 8064 && c <= 8071) || (c >= 8080 && c <= 8087))
+                : (c <= 8103 || (c >= 8112 && c <= 8116)))))))
+          : (c <= 8123 || (c < 8219
+            ? (c < 8178
+              ? (c < 8141
+                ? ((c >= 8125 && c <= 8132) || (c >= 8134 && c <= 8139))
+                : (c <= 8147 || ((c >= 8150 && c <= 8155) || (c >= 8157 && c <= 8175))))
+              : (c <= 8180 || (c < 8208
+                ? ((c >= 8182 && c <= 8187) || (c >= 8189 && c <= 8190))
+                : (c <= 8213 || (c >= 8216 && c <= 8217)))))
+            : (c <= 8221 || (c < 8274
+              ? (c < 8255
+                ? (c == 8223 || (c >= 8249 && c <= 8250))
+                : (c <= 8256 || c == 8260))
+              : (c == 8274 || (c < 8330
+                ? (c == 8276 || (c >= 8314 && c <= 8316))
+                : (c <= 8332 || (c >= 8352 && c <= 8384)))))))))
+        : (c <= 8500 || (c < 11559
+          ? (c < 10183
+            ? (c < 9003
+              ? (c < 8586
+                ? ((c >= 8505 && c <= 8527) || (c >= 8579 && c <= 8580))
+                : (c <= 8587 || ((c >= 8592 && c <= 8967) || (c >= 8972 && c <= 9000))))
+              : (c <= 9254 || (c < 9472
+                ? ((c >= 9280 && c <= 9290) || (c >= 9372 && c <= 9449))
+                : (c <= 10087 || (c >= 10132 && c <= 10180)))))
+            : (c <= 10213 || (c < 11126
+              ? (c < 10716
+                ? ((c >= 10224 && c <= 10626) || (c >= 10649 && c <= 10711))
+                : (c <= 10747 || (c >= 10750 && c <= 11123)))
+              : (c <= 11157 || (c < 11506
+                ? ((c >= 11159 && c <= 11387) || (c >= 11390 && c <= 11502))
+                : (c <= 11507 || (c >= 11520 && c <= 11557)))))))
+          : (c == 11559 || (c < 11856
+            ? (c < 11802
+              ? (c < 11785
+                ? (c == 11565 || (c >= 11778 && c <= 11781))
+                : (c <= 11786 || ((c >= 11788 && c <= 11789) || c == 11799)))
+              : (c == 11802 || (c < 11834
+                ? ((c >= 11804 && c <= 11805) || (c >= 11808 && c <= 11809))
+                : (c <= 11835 || c == 11840))))
+            : (c <= 11857 || (c < 12272
+              ? (c < 11931
+                ? (c == 11869 || (c >= 11904 && c <= 11929))
+                : (c <= 12019 || (c >= 12032 && c <= 12245)))
+              : (c <= 12283 || (c < 12316
+                ? (c == 12292 || (c >= 12306 && c <= 12307))
+                : (c == 12316 || c == 12320))))))))))))
+    : (c == 12336 || (c < 93760
+      ? (c < 65101
+        ? (c < 42896
+          ? (c < 12896
+            ? (c < 12694
+              ? (c < 12443
+                ? ((c >= 12342 && c <= 12343) || (c >= 12350 && c <= 12351))
+                : (c <= 12444 || (c == 12448 || (c >= 12688 && c <= 12689))))
+              : (c <= 12703 || (c < 12842
+                ? ((c >= 12736 && c <= 12771) || (c >= 12800 && c <= 12830))
+                : (c <= 12871 || c == 12880))))
+            : (c <= 12927 || (c < 42624
+              ? (c < 19904
+                ? ((c >= 12938 && c <= 12976) || (c >= 12992 && c <= 13311))
+                : (c <= 19967 || ((c >= 42128 && c <= 42182) || (c >= 42560 && c <= 42605))))
+              : (c <= 42651 || (c < 42865
+                ? ((c >= 42752 && c <= 42774) || (c >= 42784 && c <= 42863))
+                : (c <= 42887 || (c >= 42889 && c <= 42894)))))))
+          : (c <= 42954 || (c < 43882
+            ? (c < 43048
+              ? (c < 42965
+                ? ((c >= 42960 && c <= 42961) || c == 42963)
+                : (c <= 42969 || ((c >= 42997 && c <= 42998) || c == 43002)))
+              : (c <= 43051 || (c < 43824
+                ? ((c >= 43062 && c <= 43065) || (c >= 43639 && c <= 43641))
+                : (c <= 43867 || (c >= 43872 && c <= 43880)))))
+            : (c <= 43883 || (c < 64434
+              ? (c < 64275
+                ? ((c >= 43888 && c <= 43967) || (c >= 64256 && c <= 64262))
+                : (c <= 64279 || c == 64297))
+              : (c <= 64450 || (c < 65020
+                ? ((c >= 64832 && c <= 64847) || c == 64975)
+                : (c <= 65023 || (c >= 65073 && c <= 65076)))))))))
+        : (c <= 65103 || (c < 66560
+          ? (c < 65374
+            ? (c < 65293
+              ? (c < 65129
+                ? (c == 65112 || (c >= 65122 && c <= 65126))
+                : (c == 65129 || (c == 65284 || c == 65291)))
+              : (c == 65293 || (c < 65342
+                ? ((c >= 65308 && c <= 65310) || (c >= 65313 && c <= 65338))
+                : (c <= 65370 || c == 65372))))
+            : (c == 65374 || (c < 65913
+              ? (c < 65532
+                ? ((c >= 65504 && c <= 65510) || (c >= 65512 && c <= 65518))
+                : (c <= 65533 || (c >= 65847 && c <= 65855)))
+              : (c <= 65929 || (c < 65952
+                ? ((c >= 65932 && c <= 65934) || (c >= 65936 && c <= 65948))
+                : (c == 65952 || (c >= 66000 && c <= 66044)))))))
+          : (c <= 66639 || (c < 67703
+            ? (c < 66964
+              ? (c < 66928
+                ? ((c >= 66736 && c <= 66771) || (c >= 66776 && c <= 66811))
+                : (c <= 66938 || ((c >= 66940 && c <= 66954) || (c >= 66956 && c <= 66962))))
+              : (c <= 66965 || (c < 66995
+                ? ((c >= 66967 && c <= 66977) || (c >= 66979 && c <= 66993))
+                : (c <= 67001 || (c >= 67003 && c <= 67004)))))
+            : (c <= 67704 || (c < 71487
+              ? (c < 68800
+                ? (c == 68296 || (c >= 68736 && c <= 68786))
+                : (c <= 68850 || c == 69293))
+              : (c == 71487 || (c < 92988
+                ? ((c >= 71840 && c <= 71903) || (c >= 73685 && c <= 73713))
+                : (c <= 92991 || c == 92997))))))))))
+      : (c <= 93823 || (c < 126124
+        ? (c < 120005
+          ? (c < 119365
+            ? (c < 119146
+              ? (c < 118784
+                ? (c == 113820 || (c >= 118608 && c <= 118723))
+                : (c <= 119029 || ((c >= 119040 && c <= 119078) || (c >= 119081 && c <= 119140))))
+              : (c <= 119148 || (c < 119214
+                ? ((c >= 119171 && c <= 119172) || (c >= 119180 && c <= 119209))
+                : (c <= 119274 || (c >= 119296 && c <= 119361)))))
+            : (c == 119365 || (c < 119973
+              ? (c < 119894
+                ? ((c >= 119552 && c <= 119638) || (c >= 119808 && c <= 119892))
+                : (c <= 119964 || ((c >= 119966 && c <= 119967) || c == 119970)))
+              : (c <= 119974 || (c < 119995
+                ? ((c >= 119977 && c <= 119980) || (c >= 119982 && c <= 119993))
+                : (c == 119995 || (c >= 119997 && c <= 120003)))))))
+          : (c <= 120069 || (c < 120832
+            ? (c < 120128
+              ? (c < 120086
+                ? ((c >= 120071 && c <= 120074) || (c >= 120077 && c <= 120084))
+                : (c <= 120092 || ((c >= 120094 && c <= 120121) || (c >= 120123 && c <= 120126))))
+              : (c <= 120132 || (c < 120146
+                ? (c == 120134 || (c >= 120138 && c <= 120144))
+                : (c <= 120485 || (c >= 120488 && c <= 120779)))))
+            : (c <= 121343 || (c < 122624
+              ? (c < 121462
+                ? ((c >= 121399 && c <= 121402) || (c >= 121453 && c <= 121460))
+                : (c <= 121475 || (c >= 121477 && c <= 121478)))
+              : (c <= 122633 || (c < 123647
+                ? ((c >= 122635 && c <= 122654) || c == 123215)
+                : (c == 123647 || (c >= 125184 && c <= 125251)))))))))
+        : (c == 126124 || (c < 128992
+          ? (c < 127462
+            ? (c < 127136
+              ? (c < 126704
+                ? (c == 126128 || c == 126254)
+                : (c <= 126705 || ((c >= 126976 && c <= 127019) || (c >= 127024 && c <= 127123))))
+              : (c <= 127150 || (c < 127185
+                ? ((c >= 127153 && c <= 127167) || (c >= 127169 && c <= 127183))
+                : (c <= 127221 || (c >= 127245 && c <= 127405)))))
+            : (c <= 127490 || (c < 127744
+              ? (c < 127568
+                ? ((c >= 127504 && c <= 127547) || (c >= 127552 && c <= 127560))
+                : (c <= 127569 || (c >= 127584 && c <= 127589)))
+              : (c <= 128727 || (c < 128768
+                ? ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))
+                : (c <= 128883 || (c >= 128896 && c <= 128984)))))))
+          : (c <= 129003 || (c < 129656
+            ? (c < 129168
+              ? (c < 129040
+                ? (c == 129008 || (c >= 129024 && c <= 129035))
+                : (c <= 129095 || ((c >= 129104 && c <= 129113) || (c >= 129120 && c <= 129159))))
+              : (c <= 129197 || (c < 129632
+                ? ((c >= 129200 && c <= 129201) || (c >= 129280 && c <= 129619))
+                : (c <= 129645 || (c >= 129648 && c <= 129652)))))
+            : (c <= 129660 || (c < 129744
+              ? (c < 129712
+                ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                : (c <= 129722 || (c >= 129728 && c <= 129733)))
+              : (c <= 129753 || (c < 129792
+                ? ((c >= 129760 && c <= 129767) || (c >= 129776 && c <= 129782))
+                : (c <= 129938 || (c >= 129940 && c <= 129994)))))))))))))));
+}
+
+static inline bool sym_character_set_5(int32_t c) {
+  return (c < 43646
+    ? (c < 4348
+      ? (c < 2821
+        ? (c < 2036
+          ? (c < 1329
             ? (c < 180
-              ? (c < '|'
+              ? (c < '^'
                 ? (c < '0'
                   ? (c < '+'
                     ? c == '$'
-                    : (c <= '+' || c == '-'))
-                  : (c <= '9' || (c < '^'
-                    ? (c >= '<' && c <= '>')
-                    : c <= 'z')))
-                : (c <= '|' || (c < 168
-                  ? (c < 162
-                    ? c == '~'
-                    : c <= 166)
-                  : (c <= 169 || (c < 174
-                    ? (c >= 171 && c <= 172)
-                    : c <= 177)))))
-              : (c <= 181 || (c < 261
-                ? (c < 223
-                  ? (c < 187
+                    : (c == '+' || c == '-'))
+                  : (c <= '9' || ((c >= '<' && c <= '>') || (c >= 'A' && c <= 'Z'))))
+                : (c <= 'z' || (c < 162
+                  ? (c == '|' || c == '~')
+                  : (c <= 166 || ((c >= 168 && c <= 172) || (c >= 174 && c <= 177))))))
+              : (c <= 181 || (c < 900
+                ? (c < 880
+                  ? (c < 186
                     ? c == 184
-                    : (c <= 187 || c == 215))
-                  : (c <= 255 || (c < 259
-                    ? c == 257
-                    : c <= 259)))
-                : (c <= 261 || (c < 267
-                  ? (c < 265
-                    ? c == 263
-                    : c <= 265)
-                  : (c <= 267 || (c < 271
-                    ? c == 269
-                    : c <= 271)))))))
-            : (c <= 273 || (c < 299
-              ? (c < 287
-                ? (c < 281
-                  ? (c < 277
-                    ? c == 275
-                    : (c <= 277 || c == 279))
-                  : (c <= 281 || (c < 285
-                    ? c == 283
-                    : c <= 285)))
-                : (c <= 287 || (c < 293
-                  ? (c < 291
-                    ? c == 289
-                    : c <= 291)
-                  : (c <= 293 || (c < 297
-                    ? c == 295
-                    : c <= 297)))))
-              : (c <= 299 || (c < 314
-                ? (c < 307
-                  ? (c < 303
-                    ? c == 301
-                    : (c <= 303 || c == 305))
-                  : (c <= 307 || (c < 311
-                    ? c == 309
-                    : c <= 312)))
-                : (c <= 314 || (c < 320
-                  ? (c < 318
-                    ? c == 316
-                    : c <= 318)
-                  : (c <= 320 || (c < 324
-                    ? c == 322
-                    : c <= 324)))))))))
-          : (c <= 326 || (c < 380
-            ? (c < 353
-              ? (c < 341
-                ? (c < 335
-                  ? (c < 331
-                    ? (c >= 328 && c <= 329)
-                    : (c <= 331 || c == 333))
-                  : (c <= 335 || (c < 339
-                    ? c == 337
-                    : c <= 339)))
-                : (c <= 341 || (c < 347
-                  ? (c < 345
-                    ? c == 343
-                    : c <= 345)
-                  : (c <= 347 || (c < 351
-                    ? c == 349
-                    : c <= 351)))))
-              : (c <= 353 || (c < 367
-                ? (c < 361
-                  ? (c < 357
-                    ? c == 355
-                    : (c <= 357 || c == 359))
-                  : (c <= 361 || (c < 365
-                    ? c == 363
-                    : c <= 365)))
-                : (c <= 367 || (c < 373
-                  ? (c < 371
-                    ? c == 369
-                    : c <= 371)
-                  : (c <= 373 || (c < 378
-                    ? c == 375
-                    : c <= 378)))))))
-            : (c <= 380 || (c < 424
-              ? (c < 405
-                ? (c < 392
-                  ? (c < 387
-                    ? (c >= 382 && c <= 384)
-                    : (c <= 387 || c == 389))
-                  : (c <= 392 || (c < 402
-                    ? (c >= 396 && c <= 397)
-                    : c <= 402)))
-                : (c <= 405 || (c < 417
-                  ? (c < 414
-                    ? (c >= 409 && c <= 411)
-                    : c <= 414)
-                  : (c <= 417 || (c < 421
-                    ? c == 419
-                    : c <= 421)))))
-              : (c <= 424 || (c < 441
-                ? (c < 432
-                  ? (c < 429
-                    ? (c >= 426 && c <= 427)
-                    : c <= 429)
-                  : (c <= 432 || (c < 438
-                    ? c == 436
-                    : c <= 438)))
-                : (c <= 442 || (c < 457
-                  ? (c < 454
-                    ? (c >= 445 && c <= 447)
-                    : c <= 454)
-                  : (c <= 457 || (c < 462
-                    ? c == 460
-                    : c <= 462)))))))))))
-        : (c <= 464 || (c < 585
-          ? (c < 521
-            ? (c < 491
-              ? (c < 479
-                ? (c < 472
-                  ? (c < 468
-                    ? c == 466
-                    : (c <= 468 || c == 470))
-                  : (c <= 472 || (c < 476
-                    ? c == 474
-                    : c <= 477)))
-                : (c <= 479 || (c < 485
-                  ? (c < 483
-                    ? c == 481
-                    : c <= 483)
-                  : (c <= 485 || (c < 489
-                    ? c == 487
-                    : c <= 489)))))
-              : (c <= 491 || (c < 509
-                ? (c < 501
-                  ? (c < 495
-                    ? c == 493
-                    : (c <= 496 || c == 499))
-                  : (c <= 501 || (c < 507
-                    ? c == 505
-                    : c <= 507)))
-                : (c <= 509 || (c < 515
-                  ? (c < 513
-                    ? c == 511
-                    : c <= 513)
-                  : (c <= 515 || (c < 519
-                    ? c == 517
-                    : c <= 519)))))))
-            : (c <= 521 || (c < 547
-              ? (c < 535
-                ? (c < 529
-                  ? (c < 525
-                    ? c == 523
-                    : (c <= 525 || c == 527))
-                  : (c <= 529 || (c < 533
-                    ? c == 531
-                    : c <= 533)))
-                : (c <= 535 || (c < 541
-                  ? (c < 539
-                    ? c == 537
-                    : c <= 539)
-                  : (c <= 541 || (c < 545
-                    ? c == 543
-                    : c <= 545)))))
-              : (c <= 547 || (c < 561
-                ? (c < 555
-                  ? (c < 551
-                    ? c == 549
-                    : (c <= 551 || c == 553))
-                  : (c <= 555 || (c < 559
-                    ? c == 557
-                    : c <= 559)))
-                : (c <= 561 || (c < 575
-                  ? (c < 572
-                    ? (c >= 563 && c <= 569)
-                    : c <= 572)
-                  : (c <= 576 || (c < 583
-                    ? c == 578
-                    : c <= 583)))))))))
-          : (c <= 585 || (c < 997
-            ? (c < 887
-              ? (c < 741
-                ? (c < 661
-                  ? (c < 589
-                    ? c == 587
-                    : (c <= 589 || (c >= 591 && c <= 659)))
-                  : (c <= 687 || (c < 722
-                    ? (c >= 706 && c <= 709)
-                    : c <= 735)))
-                : (c <= 747 || (c < 881
-                  ? (c < 751
-                    ? c == 749
-                    : c <= 767)
-                  : (c <= 881 || (c < 885
-                    ? c == 883
-                    : c <= 885)))))
-              : (c <= 887 || (c < 985
-                ? (c < 940
-                  ? (c < 900
-                    ? (c >= 891 && c <= 893)
-                    : (c <= 901 || c == 912))
-                  : (c <= 974 || (c < 981
-                    ? (c >= 976 && c <= 977)
-                    : c <= 983)))
-                : (c <= 985 || (c < 991
-                  ? (c < 989
-                    ? c == 987
-                    : c <= 989)
-                  : (c <= 991 || (c < 995
-                    ? c == 993
-                    : c <= 995)))))))
-            : (c <= 997 || (c < 1127
-              ? (c < 1016
-                ? (c < 1005
-                  ? (c < 1001
-                    ? c == 999
-                    : (c <= 1001 || c == 1003))
-                  : (c <= 1005 || (c < 1013
-                    ? (c >= 1007 && c <= 1011)
-                    : c <= 1014)))
-                : (c <= 1016 || (c < 1121
-                  ? (c < 1072
-                    ? (c >= 1019 && c <= 1020)
-                    : c <= 1119)
-                  : (c <= 1121 || (c < 1125
-                    ? c == 1123
-                    : c <= 1125)))))
-              : (c <= 1127 || (c < 1139
-                ? (c < 1133
-                  ? (c < 1131
-                    ? c == 1129
-                    : c <= 1131)
-                  : (c <= 1133 || (c < 1137
-                    ? c == 1135
-                    : c <= 1137)))
-                : (c <= 1139 || (c < 1145
-                  ? (c < 1143
-                    ? c == 1141
-                    : c <= 1143)
-                  : (c <= 1145 || (c < 1149
-                    ? c == 1147
-                    : c <= 1149)))))))))))))
-      : (c <= 1151 || (c < 2554
-        ? (c < 1265
-          ? (c < 1211
-            ? (c < 1185
-              ? (c < 1173
-                ? (c < 1167
-                  ? (c < 1163
-                    ? (c >= 1153 && c <= 1154)
-                    : (c <= 1163 || c == 1165))
-                  : (c <= 1167 || (c < 1171
-                    ? c == 1169
-                    : c <= 1171)))
-                : (c <= 1173 || (c < 1179
-                  ? (c < 1177
-                    ? c == 1175
-                    : c <= 1177)
-                  : (c <= 1179 || (c < 1183
-                    ? c == 1181
-                    : c <= 1183)))))
-              : (c <= 1185 || (c < 1199
-                ? (c < 1193
-                  ? (c < 1189
-                    ? c == 1187
-                    : (c <= 1189 || c == 1191))
-                  : (c <= 1193 || (c < 1197
-                    ? c == 1195
-                    : c <= 1197)))
-                : (c <= 1199 || (c < 1205
-                  ? (c < 1203
-                    ? c == 1201
-                    : c <= 1203)
-                  : (c <= 1205 || (c < 1209
-                    ? c == 1207
-                    : c <= 1209)))))))
-            : (c <= 1211 || (c < 1239
-              ? (c < 1226
-                ? (c < 1220
-                  ? (c < 1215
-                    ? c == 1213
-                    : (c <= 1215 || c == 1218))
-                  : (c <= 1220 || (c < 1224
-                    ? c == 1222
-                    : c <= 1224)))
-                : (c <= 1226 || (c < 1233
-                  ? (c < 1230
-                    ? c == 1228
-                    : c <= 1231)
-                  : (c <= 1233 || (c < 1237
-                    ? c == 1235
-                    : c <= 1237)))))
-              : (c <= 1239 || (c < 1253
-                ? (c < 1247
-                  ? (c < 1243
-                    ? c == 1241
-                    : (c <= 1243 || c == 1245))
-                  : (c <= 1247 || (c < 1251
-                    ? c == 1249
-                    : c <= 1251)))
-                : (c <= 1253 || (c < 1259
-                  ? (c < 1257
-                    ? c == 1255
-                    : c <= 1257)
-                  : (c <= 1259 || (c < 1263
-                    ? c == 1261
-                    : c <= 1263)))))))))
-          : (c <= 1265 || (c < 1317
-            ? (c < 1291
-              ? (c < 1279
-                ? (c < 1273
-                  ? (c < 1269
-                    ? c == 1267
-                    : (c <= 1269 || c == 1271))
-                  : (c <= 1273 || (c < 1277
-                    ? c == 1275
-                    : c <= 1277)))
-                : (c <= 1279 || (c < 1285
-                  ? (c < 1283
-                    ? c == 1281
-                    : c <= 1283)
-                  : (c <= 1285 || (c < 1289
-                    ? c == 1287
-                    : c <= 1289)))))
-              : (c <= 1291 || (c < 1305
-                ? (c < 1299
-                  ? (c < 1295
-                    ? c == 1293
-                    : (c <= 1295 || c == 1297))
-                  : (c <= 1299 || (c < 1303
-                    ? c == 1301
-                    : c <= 1303)))
-                : (c <= 1305 || (c < 1311
-                  ? (c < 1309
-                    ? c == 1307
-                    : c <= 1309)
-                  : (c <= 1311 || (c < 1315
-                    ? c == 1313
-                    : c <= 1315)))))))
-            : (c <= 1317 || (c < 1632
-              ? (c < 1418
-                ? (c < 1325
-                  ? (c < 1321
-                    ? c == 1319
-                    : (c <= 1321 || c == 1323))
-                  : (c <= 1325 || (c < 1376
-                    ? c == 1327
-                    : c <= 1416)))
-                : (c <= 1418 || (c < 1542
-                  ? (c < 1470
-                    ? (c >= 1421 && c <= 1423)
-                    : c <= 1470)
-                  : (c <= 1544 || (c < 1550
-                    ? c == 1547
-                    : c <= 1551)))))
-              : (c <= 1641 || (c < 2038
-                ? (c < 1776
-                  ? (c < 1769
-                    ? c == 1758
-                    : c <= 1769)
-                  : (c <= 1785 || (c < 1984
-                    ? (c >= 1789 && c <= 1790)
-                    : c <= 1993)))
-                : (c <= 2038 || (c < 2406
-                  ? (c < 2184
-                    ? (c >= 2046 && c <= 2047)
-                    : c <= 2184)
-                  : (c <= 2415 || (c < 2546
-                    ? (c >= 2534 && c <= 2543)
-                    : c <= 2547)))))))))))
-        : (c <= 2555 || (c < 7248
-          ? (c < 4046
-            ? (c < 3558
-              ? (c < 3174
-                ? (c < 2918
-                  ? (c < 2790
-                    ? (c >= 2662 && c <= 2671)
-                    : (c <= 2799 || c == 2801))
-                  : (c <= 2928 || (c < 3059
-                    ? (c >= 3046 && c <= 3055)
-                    : c <= 3066)))
-                : (c <= 3183 || (c < 3407
-                  ? (c < 3302
-                    ? c == 3199
-                    : c <= 3311)
-                  : (c <= 3407 || (c < 3449
-                    ? (c >= 3430 && c <= 3439)
-                    : c <= 3449)))))
-              : (c <= 3567 || (c < 3866
-                ? (c < 3841
-                  ? (c < 3664
-                    ? c == 3647
-                    : (c <= 3673 || (c >= 3792 && c <= 3801)))
-                  : (c <= 3843 || (c < 3861
-                    ? c == 3859
-                    : c <= 3863)))
-                : (c <= 3881 || (c < 3896
+                    : (c <= 187 || (c >= 192 && c <= 767)))
+                  : (c <= 887 || ((c >= 890 && c <= 893) || c == 895)))
+                : (c <= 902 || (c < 910
+                  ? ((c >= 904 && c <= 906) || c == 908)
+                  : (c <= 929 || ((c >= 931 && c <= 1154) || (c >= 1162 && c <= 1327))))))))
+            : (c <= 1366 || (c < 1646
+              ? (c < 1519
+                ? (c < 1421
+                  ? (c < 1376
+                    ? c == 1369
+                    : (c <= 1416 || c == 1418))
+                  : (c <= 1423 || (c == 1470 || (c >= 1488 && c <= 1514))))
+                : (c <= 1522 || (c < 1550
+                  ? ((c >= 1542 && c <= 1544) || c == 1547)
+                  : (c <= 1551 || ((c >= 1568 && c <= 1610) || (c >= 1632 && c <= 1641))))))
+              : (c <= 1647 || (c < 1774
+                ? (c < 1758
+                  ? ((c >= 1649 && c <= 1747) || c == 1749)
+                  : (c == 1758 || ((c >= 1765 && c <= 1766) || c == 1769)))
+                : (c <= 1791 || (c < 1869
+                  ? (c == 1808 || (c >= 1810 && c <= 1839))
+                  : (c <= 1957 || (c == 1969 || (c >= 1984 && c <= 2026))))))))))
+          : (c <= 2038 || (c < 2534
+            ? (c < 2392
+              ? (c < 2144
+                ? (c < 2084
+                  ? (c < 2046
+                    ? c == 2042
+                    : (c <= 2069 || c == 2074))
+                  : (c == 2084 || (c == 2088 || (c >= 2112 && c <= 2136))))
+                : (c <= 2154 || (c < 2308
+                  ? ((c >= 2160 && c <= 2190) || (c >= 2208 && c <= 2249))
+                  : (c <= 2361 || (c == 2365 || c == 2384)))))
+              : (c <= 2401 || (c < 2482
+                ? (c < 2447
+                  ? (c < 2417
+                    ? (c >= 2406 && c <= 2415)
+                    : (c <= 2432 || (c >= 2437 && c <= 2444)))
+                  : (c <= 2448 || ((c >= 2451 && c <= 2472) || (c >= 2474 && c <= 2480))))
+                : (c == 2482 || (c < 2510
+                  ? ((c >= 2486 && c <= 2489) || c == 2493)
+                  : (c == 2510 || ((c >= 2524 && c <= 2525) || (c >= 2527 && c <= 2529))))))))
+            : (c <= 2547 || (c < 2693
+              ? (c < 2613
+                ? (c < 2579
+                  ? (c < 2565
+                    ? (c >= 2554 && c <= 2556)
+                    : (c <= 2570 || (c >= 2575 && c <= 2576)))
+                  : (c <= 2600 || ((c >= 2602 && c <= 2608) || (c >= 2610 && c <= 2611))))
+                : (c <= 2614 || (c < 2654
+                  ? ((c >= 2616 && c <= 2617) || (c >= 2649 && c <= 2652))
+                  : (c == 2654 || ((c >= 2662 && c <= 2671) || (c >= 2674 && c <= 2676))))))
+              : (c <= 2701 || (c < 2749
+                ? (c < 2730
+                  ? ((c >= 2703 && c <= 2705) || (c >= 2707 && c <= 2728))
+                  : (c <= 2736 || ((c >= 2738 && c <= 2739) || (c >= 2741 && c <= 2745))))
+                : (c == 2749 || (c < 2790
+                  ? (c == 2768 || (c >= 2784 && c <= 2785))
+                  : (c <= 2799 || (c == 2801 || c == 2809)))))))))))
+        : (c <= 2828 || (c < 3449
+          ? (c < 3114
+            ? (c < 2962
+              ? (c < 2908
+                ? (c < 2866
+                  ? (c < 2835
+                    ? (c >= 2831 && c <= 2832)
+                    : (c <= 2856 || (c >= 2858 && c <= 2864)))
+                  : (c <= 2867 || ((c >= 2869 && c <= 2873) || c == 2877)))
+                : (c <= 2909 || (c < 2947
+                  ? ((c >= 2911 && c <= 2913) || (c >= 2918 && c <= 2929))
+                  : (c == 2947 || ((c >= 2949 && c <= 2954) || (c >= 2958 && c <= 2960))))))
+              : (c <= 2965 || (c < 3024
+                ? (c < 2979
+                  ? (c < 2972
+                    ? (c >= 2969 && c <= 2970)
+                    : (c == 2972 || (c >= 2974 && c <= 2975)))
+                  : (c <= 2980 || ((c >= 2984 && c <= 2986) || (c >= 2990 && c <= 3001))))
+                : (c == 3024 || (c < 3077
+                  ? ((c >= 3046 && c <= 3055) || (c >= 3059 && c <= 3066))
+                  : (c <= 3084 || ((c >= 3086 && c <= 3088) || (c >= 3090 && c <= 3112))))))))
+            : (c <= 3129 || (c < 3293
+              ? (c < 3205
+                ? (c < 3168
+                  ? (c < 3160
+                    ? c == 3133
+                    : (c <= 3162 || c == 3165))
+                  : (c <= 3169 || ((c >= 3174 && c <= 3183) || (c >= 3199 && c <= 3200))))
+                : (c <= 3212 || (c < 3242
+                  ? ((c >= 3214 && c <= 3216) || (c >= 3218 && c <= 3240))
+                  : (c <= 3251 || ((c >= 3253 && c <= 3257) || c == 3261)))))
+              : (c <= 3294 || (c < 3346
+                ? (c < 3313
+                  ? ((c >= 3296 && c <= 3297) || (c >= 3302 && c <= 3311))
+                  : (c <= 3314 || ((c >= 3332 && c <= 3340) || (c >= 3342 && c <= 3344))))
+                : (c <= 3386 || (c < 3412
+                  ? (c == 3389 || (c >= 3406 && c <= 3407))
+                  : (c <= 3414 || ((c >= 3423 && c <= 3425) || (c >= 3430 && c <= 3439))))))))))
+          : (c <= 3455 || (c < 3866
+            ? (c < 3718
+              ? (c < 3585
+                ? (c < 3517
+                  ? (c < 3482
+                    ? (c >= 3461 && c <= 3478)
+                    : (c <= 3505 || (c >= 3507 && c <= 3515)))
+                  : (c == 3517 || ((c >= 3520 && c <= 3526) || (c >= 3558 && c <= 3567))))
+                : (c <= 3632 || (c < 3664
+                  ? ((c >= 3634 && c <= 3635) || (c >= 3647 && c <= 3654))
+                  : (c <= 3673 || ((c >= 3713 && c <= 3714) || c == 3716)))))
+              : (c <= 3722 || (c < 3782
+                ? (c < 3762
+                  ? (c < 3749
+                    ? (c >= 3724 && c <= 3747)
+                    : (c == 3749 || (c >= 3751 && c <= 3760)))
+                  : (c <= 3763 || (c == 3773 || (c >= 3776 && c <= 3780))))
+                : (c == 3782 || (c < 3840
+                  ? ((c >= 3792 && c <= 3801) || (c >= 3804 && c <= 3807))
+                  : (c <= 3843 || (c == 3859 || (c >= 3861 && c <= 3863))))))))
+            : (c <= 3881 || (c < 4176
+              ? (c < 4030
+                ? (c < 3904
                   ? (c < 3894
                     ? c == 3892
-                    : c <= 3894)
-                  : (c <= 3896 || (c < 4039
-                    ? (c >= 4030 && c <= 4037)
-                    : c <= 4044)))))))
-            : (c <= 4047 || (c < 6150
-              ? (c < 5008
-                ? (c < 4254
-                  ? (c < 4160
-                    ? (c >= 4053 && c <= 4056)
-                    : (c <= 4169 || (c >= 4240 && c <= 4249)))
-                  : (c <= 4255 || (c < 4349
-                    ? (c >= 4304 && c <= 4346)
-                    : c <= 4351)))
-                : (c <= 5017 || (c < 5741
-                  ? (c < 5120
-                    ? (c >= 5112 && c <= 5117)
-                    : c <= 5120)
-                  : (c <= 5741 || (c < 6112
-                    ? c == 6107
-                    : c <= 6121)))))
-              : (c <= 6150 || (c < 6800
-                ? (c < 6608
-                  ? (c < 6464
-                    ? (c >= 6160 && c <= 6169)
-                    : (c <= 6464 || (c >= 6470 && c <= 6479)))
-                  : (c <= 6617 || (c < 6784
-                    ? (c >= 6622 && c <= 6655)
-                    : c <= 6793)))
-                : (c <= 6809 || (c < 7028
-                  ? (c < 7009
-                    ? (c >= 6992 && c <= 7001)
-                    : c <= 7018)
-                  : (c <= 7036 || (c < 7232
-                    ? (c >= 7088 && c <= 7097)
-                    : c <= 7241)))))))))
-          : (c <= 7257 || (c < 7723
-            ? (c < 7697
-              ? (c < 7685
-                ? (c < 7545
-                  ? (c < 7424
-                    ? (c >= 7296 && c <= 7304)
-                    : (c <= 7467 || (c >= 7531 && c <= 7543)))
-                  : (c <= 7578 || (c < 7683
-                    ? c == 7681
-                    : c <= 7683)))
-                : (c <= 7685 || (c < 7691
-                  ? (c < 7689
-                    ? c == 7687
-                    : c <= 7689)
-                  : (c <= 7691 || (c < 7695
-                    ? c == 7693
-                    : c <= 7695)))))
-              : (c <= 7697 || (c < 7711
-                ? (c < 7705
-                  ? (c < 7701
-                    ? c == 7699
-                    : (c <= 7701 || c == 7703))
-                  : (c <= 7705 || (c < 7709
-                    ? c == 7707
-                    : c <= 7709)))
-                : (c <= 7711 || (c < 7717
-                  ? (c < 7715
-                    ? c == 7713
-                    : c <= 7715)
-                  : (c <= 7717 || (c < 7721
-                    ? c == 7719
-                    : c <= 7721)))))))
-            : (c <= 7723 || (c < 7749
-              ? (c < 7737
-                ? (c < 7731
-                  ? (c < 7727
-                    ? c == 7725
-                    : (c <= 7727 || c == 7729))
-                  : (c <= 7731 || (c < 7735
-                    ? c == 7733
-                    : c <= 7735)))
-                : (c <= 7737 || (c < 7743
-                  ? (c < 7741
-                    ? c == 7739
-                    : c <= 7741)
-                  : (c <= 7743 || (c < 7747
-                    ? c == 7745
-                    : c <= 7747)))))
-              : (c <= 7749 || (c < 7761
-                ? (c < 7755
-                  ? (c < 7753
-                    ? c == 7751
-                    : c <= 7753)
-                  : (c <= 7755 || (c < 7759
-                    ? c == 7757
-                    : c <= 7759)))
-                : (c <= 7761 || (c < 7767
-                  ? (c < 7765
-                    ? c == 7763
-                    : c <= 7765)
-                  : (c <= 7767 || (c < 7771
-                    ? c == 7769
-                    : c <= 7771)))))))))))))))
-    : (c <= 7773 || (c < 11565
-      ? (c < 8249
-        ? (c < 7885
-          ? (c < 7825
-            ? (c < 7799
-              ? (c < 7787
-                ? (c < 7781
-                  ? (c < 7777
-                    ? c == 7775
-                    : (c <= 7777 || c == 7779))
-                  : (c <= 7781 || (c < 7785
-                    ? c == 7783
-                    : c <= 7785)))
-                : (c <= 7787 || (c < 7793
-                  ? (c < 7791
-                    ? c == 7789
-                    : c <= 7791)
-                  : (c <= 7793 || (c < 7797
-                    ? c == 7795
-                    : c <= 7797)))))
-              : (c <= 7799 || (c < 7813
-                ? (c < 7807
-                  ? (c < 7803
-                    ? c == 7801
-                    : (c <= 7803 || c == 7805))
-                  : (c <= 7807 || (c < 7811
-                    ? c == 7809
-                    : c <= 7811)))
-                : (c <= 7813 || (c < 7819
-                  ? (c < 7817
-                    ? c == 7815
-                    : c <= 7817)
-                  : (c <= 7819 || (c < 7823
-                    ? c == 7821
-                    : c <= 7823)))))))
-            : (c <= 7825 || (c < 7859
-              ? (c < 7847
-                ? (c < 7841
-                  ? (c < 7829
-                    ? c == 7827
-                    : (c <= 7837 || c == 7839))
-                  : (c <= 7841 || (c < 7845
-                    ? c == 7843
-                    : c <= 7845)))
-                : (c <= 7847 || (c < 7853
-                  ? (c < 7851
-                    ? c == 7849
-                    : c <= 7851)
-                  : (c <= 7853 || (c < 7857
-                    ? c == 7855
-                    : c <= 7857)))))
-              : (c <= 7859 || (c < 7873
-                ? (c < 7867
-                  ? (c < 7863
-                    ? c == 7861
-                    : (c <= 7863 || c == 7865))
-                  : (c <= 7867 || (c < 7871
-                    ? c == 7869
-                    : c <= 7871)))
-                : (c <= 7873 || (c < 7879
-                  ? (c < 7877
-                    ? c == 7875
-                    : c <= 7877)
-                  : (c <= 7879 || (c < 7883
-                    ? c == 7881
-                    : c <= 7883)))))))))
-          : (c <= 7885 || (c < 7952
-            ? (c < 7911
-              ? (c < 7899
-                ? (c < 7893
-                  ? (c < 7889
-                    ? c == 7887
-                    : (c <= 7889 || c == 7891))
-                  : (c <= 7893 || (c < 7897
-                    ? c == 7895
-                    : c <= 7897)))
-                : (c <= 7899 || (c < 7905
-                  ? (c < 7903
-                    ? c == 7901
-                    : c <= 7903)
-                  : (c <= 7905 || (c < 7909
-                    ? c == 7907
-                    : c <= 7909)))))
-              : (c <= 7911 || (c < 7925
-                ? (c < 7919
-                  ? (c < 7915
-                    ? c == 7913
-                    : (c <= 7915 || c == 7917))
-                  : (c <= 7919 || (c < 7923
-                    ? c == 7921
-                    : c <= 7923)))
-                : (c <= 7925 || (c < 7931
-                  ? (c < 7929
-                    ? c == 7927
-                    : c <= 7929)
-                  : (c <= 7931 || (c < 7935
-                    ? c == 7933
-                    : c <= 7943)))))))
-            : (c <= 7957 || (c < 8134
-              ? (c < 8064
-                ? (c < 8016
-                  ? (c < 7984
-                    ? (c >= 7968 && c <= 7975)
-                    : (c <= 7991 || (c >= 8000 && c <= 8005)))
-                  : (c <= 8023 || (c < 8048
-                    ? (c >= 8032 && c <= 8039)
-                    : c <= 8061)))
-                : (c <= 8071 || (c < 8112
-                  ? (c < 8096
-                    ? (c >= 8080 && c <= 8087)
-                    : c <= 8103)
-                  : (c <= 8116 || (c < 8125
-                    ? (c >= 8118 && c <= 8119)
-                    : c <= 8132)))))
-              : (c <= 8135 || (c < 8182
-                ? (c < 8157
-                  ? (c < 8150
-                    ? (c >= 8141 && c <= 8147)
-                    : c <= 8151)
-                  : (c <= 8167 || (c < 8178
-                    ? (c >= 8173 && c <= 8175)
-                    : c <= 8180)))
-                : (c <= 8183 || (c < 8216
-                  ? (c < 8208
-                    ? (c >= 8189 && c <= 8190)
-                    : c <= 8213)
-                  : (c <= 8217 || (c < 8223
-                    ? (c >= 8219 && c <= 8221)
-                    : c <= 8223)))))))))))
-        : (c <= 8250 || (c < 11401
-          ? (c < 8972
-            ? (c < 8470
-              ? (c < 8352
-                ? (c < 8276
-                  ? (c < 8260
-                    ? (c >= 8255 && c <= 8256)
-                    : (c <= 8260 || c == 8274))
-                  : (c <= 8276 || (c < 8330
-                    ? (c >= 8314 && c <= 8316)
-                    : c <= 8332)))
-                : (c <= 8384 || (c < 8456
-                  ? (c < 8451
-                    ? (c >= 8448 && c <= 8449)
-                    : c <= 8454)
-                  : (c <= 8458 || (c < 8467
-                    ? (c >= 8462 && c <= 8463)
-                    : c <= 8468)))))
-              : (c <= 8472 || (c < 8505
-                ? (c < 8489
-                  ? (c < 8485
-                    ? (c >= 8478 && c <= 8483)
-                    : (c <= 8485 || c == 8487))
-                  : (c <= 8489 || (c < 8500
-                    ? (c >= 8494 && c <= 8495)
-                    : c <= 8500)))
-                : (c <= 8509 || (c < 8580
-                  ? (c < 8518
-                    ? (c >= 8512 && c <= 8516)
-                    : c <= 8527)
-                  : (c <= 8580 || (c < 8592
+                    : (c == 3894 || c == 3896))
+                  : (c <= 3911 || ((c >= 3913 && c <= 3948) || (c >= 3976 && c <= 3980))))
+                : (c <= 4037 || (c < 4053
+                  ? ((c >= 4039 && c <= 4044) || (c >= 4046 && c <= 4047))
+                  : (c <= 4056 || ((c >= 4096 && c <= 4138) || (c >= 4159 && c <= 4169))))))
+              : (c <= 4181 || (c < 4238
+                ? (c < 4197
+                  ? ((c >= 4186 && c <= 4189) || c == 4193)
+                  : (c <= 4198 || ((c >= 4206 && c <= 4208) || (c >= 4213 && c <= 4225))))
+                : (c == 4238 || (c < 4295
+                  ? ((c >= 4240 && c <= 4249) || (c >= 4254 && c <= 4293))
+                  : (c == 4295 || (c == 4301 || (c >= 4304 && c <= 4346))))))))))))))
+      : (c <= 4680 || (c < 8579
+        ? (c < 6823
+          ? (c < 5952
+            ? (c < 4824
+              ? (c < 4752
+                ? (c < 4698
+                  ? (c < 4688
+                    ? (c >= 4682 && c <= 4685)
+                    : (c <= 4694 || c == 4696))
+                  : (c <= 4701 || ((c >= 4704 && c <= 4744) || (c >= 4746 && c <= 4749))))
+                : (c <= 4784 || (c < 4800
+                  ? ((c >= 4786 && c <= 4789) || (c >= 4792 && c <= 4798))
+                  : (c == 4800 || ((c >= 4802 && c <= 4805) || (c >= 4808 && c <= 4822))))))
+              : (c <= 4880 || (c < 5743
+                ? (c < 5024
+                  ? (c < 4888
+                    ? (c >= 4882 && c <= 4885)
+                    : (c <= 4954 || (c >= 4992 && c <= 5017)))
+                  : (c <= 5109 || ((c >= 5112 && c <= 5117) || (c >= 5120 && c <= 5741))))
+                : (c <= 5759 || (c < 5873
+                  ? ((c >= 5761 && c <= 5786) || (c >= 5792 && c <= 5866))
+                  : (c <= 5880 || ((c >= 5888 && c <= 5905) || (c >= 5919 && c <= 5937))))))))
+            : (c <= 5969 || (c < 6320
+              ? (c < 6150
+                ? (c < 6103
+                  ? (c < 5998
+                    ? (c >= 5984 && c <= 5996)
+                    : (c <= 6000 || (c >= 6016 && c <= 6067)))
+                  : (c == 6103 || ((c >= 6107 && c <= 6108) || (c >= 6112 && c <= 6121))))
+                : (c == 6150 || (c < 6272
+                  ? ((c >= 6160 && c <= 6169) || (c >= 6176 && c <= 6264))
+                  : (c <= 6276 || ((c >= 6279 && c <= 6312) || c == 6314)))))
+              : (c <= 6389 || (c < 6576
+                ? (c < 6470
+                  ? ((c >= 6400 && c <= 6430) || c == 6464)
+                  : (c <= 6509 || ((c >= 6512 && c <= 6516) || (c >= 6528 && c <= 6571))))
+                : (c <= 6601 || (c < 6688
+                  ? ((c >= 6608 && c <= 6617) || (c >= 6622 && c <= 6678))
+                  : (c <= 6740 || ((c >= 6784 && c <= 6793) || (c >= 6800 && c <= 6809))))))))))
+          : (c == 6823 || (c < 8029
+            ? (c < 7357
+              ? (c < 7086
+                ? (c < 7009
+                  ? (c < 6981
+                    ? (c >= 6917 && c <= 6963)
+                    : (c <= 6988 || (c >= 6992 && c <= 7001)))
+                  : (c <= 7018 || ((c >= 7028 && c <= 7036) || (c >= 7043 && c <= 7072))))
+                : (c <= 7141 || (c < 7245
+                  ? ((c >= 7168 && c <= 7203) || (c >= 7232 && c <= 7241))
+                  : (c <= 7293 || ((c >= 7296 && c <= 7304) || (c >= 7312 && c <= 7354))))))
+              : (c <= 7359 || (c < 7960
+                ? (c < 7418
+                  ? (c < 7406
+                    ? (c >= 7401 && c <= 7404)
+                    : (c <= 7411 || (c >= 7413 && c <= 7414)))
+                  : (c == 7418 || ((c >= 7424 && c <= 7615) || (c >= 7680 && c <= 7957))))
+                : (c <= 7965 || (c < 8016
+                  ? ((c >= 7968 && c <= 8005) || (c >= 8008 && c <= 8013))
+                  : (c <= 8023 || (c == 8025 || c == 8027)))))))
+            : (c == 8029 || (c < 8249
+              ? (c < 8178
+                ? (c < 8134
+                  ? (c < 8064
+                    ? (c >= 8031 && c <= 8061)
+                    : (c <= 8116 || (c >= 8118 && c <= 8132)))
+                  : (c <= 8147 || ((c >= 8150 && c <= 8155) || (c >= 8157 && c <= 8175))))
+                : (c <= 8180 || (c < 8216
+                  ? ((c >= 8182 && c <= 8190) || (c >= 8208 && c <= 8213))
+                  : (c <= 8217 || ((c >= 8219 && c <= 8221) || c == 8223)))))
+              : (c <= 8250 || (c < 8314
+                ? (c < 8274
+                  ? ((c >= 8255 && c <= 8256) || c == 8260)
+                  : (c == 8274 || (c == 8276 || c == 8305)))
+                : (c <= 8316 || (c < 8336
+                  ? (c == 8319 || (c >= 8330 && c <= 8332))
+                  : (c <= 8348 || ((c >= 8352 && c <= 8384) || (c >= 8448 && c <= 8527))))))))))))
+        : (c <= 8580 || (c < 12336
+          ? (c < 11704
+            ? (c < 10750
+              ? (c < 9472
+                ? (c < 9003
+                  ? (c < 8592
                     ? (c >= 8586 && c <= 8587)
-                    : c <= 8967)))))))
-            : (c <= 9000 || (c < 11312
-              ? (c < 10224
-                ? (c < 9472
-                  ? (c < 9280
-                    ? (c >= 9003 && c <= 9254)
-                    : (c <= 9290 || (c >= 9372 && c <= 9449)))
-                  : (c <= 10087 || (c < 10183
-                    ? (c >= 10132 && c <= 10180)
-                    : c <= 10213)))
-                : (c <= 10626 || (c < 10750
-                  ? (c < 10716
-                    ? (c >= 10649 && c <= 10711)
-                    : c <= 10747)
-                  : (c <= 11123 || (c < 11159
+                    : (c <= 8967 || (c >= 8972 && c <= 9000)))
+                  : (c <= 9254 || ((c >= 9280 && c <= 9290) || (c >= 9372 && c <= 9449))))
+                : (c <= 10087 || (c < 10224
+                  ? ((c >= 10132 && c <= 10180) || (c >= 10183 && c <= 10213))
+                  : (c <= 10626 || ((c >= 10649 && c <= 10711) || (c >= 10716 && c <= 10747))))))
+              : (c <= 11123 || (c < 11568
+                ? (c < 11520
+                  ? (c < 11159
                     ? (c >= 11126 && c <= 11157)
-                    : c <= 11263)))))
-              : (c <= 11359 || (c < 11379
-                ? (c < 11370
-                  ? (c < 11365
-                    ? c == 11361
-                    : (c <= 11366 || c == 11368))
-                  : (c <= 11370 || (c < 11377
-                    ? c == 11372
-                    : c <= 11377)))
-                : (c <= 11380 || (c < 11395
-                  ? (c < 11393
-                    ? (c >= 11382 && c <= 11387)
-                    : c <= 11393)
-                  : (c <= 11395 || (c < 11399
-                    ? c == 11397
-                    : c <= 11399)))))))))
-          : (c <= 11401 || (c < 11453
-            ? (c < 11427
-              ? (c < 11415
-                ? (c < 11409
-                  ? (c < 11405
-                    ? c == 11403
-                    : (c <= 11405 || c == 11407))
-                  : (c <= 11409 || (c < 11413
-                    ? c == 11411
-                    : c <= 11413)))
-                : (c <= 11415 || (c < 11421
-                  ? (c < 11419
-                    ? c == 11417
-                    : c <= 11419)
-                  : (c <= 11421 || (c < 11425
-                    ? c == 11423
-                    : c <= 11425)))))
-              : (c <= 11427 || (c < 11441
-                ? (c < 11435
-                  ? (c < 11431
-                    ? c == 11429
-                    : (c <= 11431 || c == 11433))
-                  : (c <= 11435 || (c < 11439
-                    ? c == 11437
-                    : c <= 11439)))
-                : (c <= 11441 || (c < 11447
-                  ? (c < 11445
-                    ? c == 11443
-                    : c <= 11445)
-                  : (c <= 11447 || (c < 11451
-                    ? c == 11449
-                    : c <= 11451)))))))
-            : (c <= 11453 || (c < 11479
-              ? (c < 11467
-                ? (c < 11461
-                  ? (c < 11457
-                    ? c == 11455
-                    : (c <= 11457 || c == 11459))
-                  : (c <= 11461 || (c < 11465
-                    ? c == 11463
-                    : c <= 11465)))
-                : (c <= 11467 || (c < 11473
-                  ? (c < 11471
-                    ? c == 11469
-                    : c <= 11471)
-                  : (c <= 11473 || (c < 11477
-                    ? c == 11475
-                    : c <= 11477)))))
-              : (c <= 11479 || (c < 11491
-                ? (c < 11485
-                  ? (c < 11483
-                    ? c == 11481
-                    : c <= 11483)
-                  : (c <= 11485 || (c < 11489
-                    ? c == 11487
-                    : c <= 11489)))
-                : (c <= 11498 || (c < 11507
-                  ? (c < 11502
-                    ? c == 11500
-                    : c <= 11502)
-                  : (c <= 11507 || (c < 11559
-                    ? (c >= 11520 && c <= 11557)
-                    : c <= 11559)))))))))))))
-      : (c <= 11565 || (c < 42843
-        ? (c < 42591
-          ? (c < 12694
-            ? (c < 11931
-              ? (c < 11808
-                ? (c < 11799
-                  ? (c < 11785
-                    ? (c >= 11778 && c <= 11781)
-                    : (c <= 11786 || (c >= 11788 && c <= 11789)))
-                  : (c <= 11799 || (c < 11804
-                    ? c == 11802
-                    : c <= 11805)))
-                : (c <= 11809 || (c < 11856
-                  ? (c < 11840
-                    ? (c >= 11834 && c <= 11835)
-                    : c <= 11840)
-                  : (c <= 11857 || (c < 11904
-                    ? c == 11869
-                    : c <= 11929)))))
-              : (c <= 12019 || (c < 12336
-                ? (c < 12306
-                  ? (c < 12272
-                    ? (c >= 12032 && c <= 12245)
-                    : (c <= 12283 || c == 12292))
-                  : (c <= 12307 || (c < 12320
-                    ? c == 12316
-                    : c <= 12320)))
-                : (c <= 12336 || (c < 12443
+                    : (c <= 11502 || (c >= 11506 && c <= 11507)))
+                  : (c <= 11557 || (c == 11559 || c == 11565)))
+                : (c <= 11623 || (c < 11680
+                  ? (c == 11631 || (c >= 11648 && c <= 11670))
+                  : (c <= 11686 || ((c >= 11688 && c <= 11694) || (c >= 11696 && c <= 11702))))))))
+            : (c <= 11710 || (c < 11834
+              ? (c < 11788
+                ? (c < 11736
+                  ? (c < 11720
+                    ? (c >= 11712 && c <= 11718)
+                    : (c <= 11726 || (c >= 11728 && c <= 11734)))
+                  : (c <= 11742 || ((c >= 11778 && c <= 11781) || (c >= 11785 && c <= 11786))))
+                : (c <= 11789 || (c < 11804
+                  ? (c == 11799 || c == 11802)
+                  : (c <= 11805 || ((c >= 11808 && c <= 11809) || c == 11823)))))
+              : (c <= 11835 || (c < 12032
+                ? (c < 11869
+                  ? (c == 11840 || (c >= 11856 && c <= 11857))
+                  : (c == 11869 || ((c >= 11904 && c <= 11929) || (c >= 11931 && c <= 12019))))
+                : (c <= 12245 || (c < 12306
+                  ? ((c >= 12272 && c <= 12283) || (c >= 12292 && c <= 12294))
+                  : (c <= 12307 || (c == 12316 || c == 12320)))))))))
+          : (c <= 12343 || (c < 42965
+            ? (c < 12896
+              ? (c < 12593
+                ? (c < 12443
                   ? (c < 12350
-                    ? (c >= 12342 && c <= 12343)
-                    : c <= 12351)
-                  : (c <= 12444 || (c < 12688
-                    ? c == 12448
-                    : c <= 12689)))))))
-            : (c <= 12703 || (c < 42565
-              ? (c < 12992
-                ? (c < 12880
-                  ? (c < 12800
-                    ? (c >= 12736 && c <= 12771)
-                    : (c <= 12830 || (c >= 12842 && c <= 12871)))
-                  : (c <= 12880 || (c < 12938
-                    ? (c >= 12896 && c <= 12927)
-                    : c <= 12976)))
-                : (c <= 13311 || (c < 42528
-                  ? (c < 42128
-                    ? (c >= 19904 && c <= 19967)
-                    : c <= 42182)
-                  : (c <= 42537 || (c < 42563
-                    ? c == 42561
-                    : c <= 42563)))))
-              : (c <= 42565 || (c < 42579
-                ? (c < 42573
-                  ? (c < 42569
-                    ? c == 42567
-                    : (c <= 42569 || c == 42571))
-                  : (c <= 42573 || (c < 42577
-                    ? c == 42575
-                    : c <= 42577)))
-                : (c <= 42579 || (c < 42585
-                  ? (c < 42583
-                    ? c == 42581
-                    : c <= 42583)
-                  : (c <= 42585 || (c < 42589
-                    ? c == 42587
-                    : c <= 42589)))))))))
-          : (c <= 42591 || (c < 42791
-            ? (c < 42635
-              ? (c < 42605
-                ? (c < 42599
-                  ? (c < 42595
-                    ? c == 42593
-                    : (c <= 42595 || c == 42597))
-                  : (c <= 42599 || (c < 42603
-                    ? c == 42601
-                    : c <= 42603)))
-                : (c <= 42605 || (c < 42629
-                  ? (c < 42627
-                    ? c == 42625
-                    : c <= 42627)
-                  : (c <= 42629 || (c < 42633
-                    ? c == 42631
-                    : c <= 42633)))))
-              : (c <= 42635 || (c < 42649
-                ? (c < 42643
-                  ? (c < 42639
-                    ? c == 42637
-                    : (c <= 42639 || c == 42641))
-                  : (c <= 42643 || (c < 42647
-                    ? c == 42645
-                    : c <= 42647)))
-                : (c <= 42649 || (c < 42784
-                  ? (c < 42752
-                    ? c == 42651
-                    : c <= 42774)
-                  : (c <= 42785 || (c < 42789
-                    ? c == 42787
-                    : c <= 42789)))))))
-            : (c <= 42791 || (c < 42819
-              ? (c < 42807
-                ? (c < 42799
-                  ? (c < 42795
-                    ? c == 42793
-                    : (c <= 42795 || c == 42797))
-                  : (c <= 42801 || (c < 42805
-                    ? c == 42803
-                    : c <= 42805)))
-                : (c <= 42807 || (c < 42813
-                  ? (c < 42811
-                    ? c == 42809
-                    : c <= 42811)
-                  : (c <= 42813 || (c < 42817
-                    ? c == 42815
-                    : c <= 42817)))))
-              : (c <= 42819 || (c < 42831
-                ? (c < 42825
-                  ? (c < 42823
-                    ? c == 42821
-                    : c <= 42823)
-                  : (c <= 42825 || (c < 42829
-                    ? c == 42827
-                    : c <= 42829)))
-                : (c <= 42831 || (c < 42837
-                  ? (c < 42835
-                    ? c == 42833
-                    : c <= 42835)
-                  : (c <= 42837 || (c < 42841
-                    ? c == 42839
-                    : c <= 42841)))))))))))
-        : (c <= 42843 || (c < 43002
-          ? (c < 42907
-            ? (c < 42876
-              ? (c < 42857
-                ? (c < 42851
-                  ? (c < 42847
-                    ? c == 42845
-                    : (c <= 42847 || c == 42849))
-                  : (c <= 42851 || (c < 42855
-                    ? c == 42853
-                    : c <= 42855)))
-                : (c <= 42857 || (c < 42863
-                  ? (c < 42861
-                    ? c == 42859
-                    : c <= 42861)
-                  : (c <= 42863 || (c < 42874
-                    ? (c >= 42865 && c <= 42872)
-                    : c <= 42874)))))
-              : (c <= 42876 || (c < 42892
-                ? (c < 42885
-                  ? (c < 42881
-                    ? c == 42879
-                    : (c <= 42881 || c == 42883))
-                  : (c <= 42885 || (c < 42889
-                    ? c == 42887
-                    : c <= 42890)))
-                : (c <= 42892 || (c < 42899
-                  ? (c < 42897
-                    ? c == 42894
-                    : c <= 42897)
-                  : (c <= 42901 || (c < 42905
-                    ? c == 42903
-                    : c <= 42905)))))))
-            : (c <= 42907 || (c < 42941
-              ? (c < 42921
-                ? (c < 42915
-                  ? (c < 42911
-                    ? c == 42909
-                    : (c <= 42911 || c == 42913))
-                  : (c <= 42915 || (c < 42919
-                    ? c == 42917
-                    : c <= 42919)))
-                : (c <= 42921 || (c < 42935
-                  ? (c < 42933
-                    ? c == 42927
-                    : c <= 42933)
-                  : (c <= 42935 || (c < 42939
-                    ? c == 42937
-                    : c <= 42939)))))
-              : (c <= 42941 || (c < 42961
-                ? (c < 42947
-                  ? (c < 42945
-                    ? c == 42943
-                    : c <= 42945)
-                  : (c <= 42947 || (c < 42954
-                    ? c == 42952
-                    : c <= 42954)))
-                : (c <= 42961 || (c < 42967
-                  ? (c < 42965
-                    ? c == 42963
-                    : c <= 42965)
-                  : (c <= 42967 || (c < 42998
-                    ? c == 42969
-                    : c <= 42998)))))))))
-          : (c <= 43002 || (c < 65284
-            ? (c < 44016
-              ? (c < 43600
-                ? (c < 43264
-                  ? (c < 43062
-                    ? (c >= 43048 && c <= 43051)
-                    : (c <= 43065 || (c >= 43216 && c <= 43225)))
-                  : (c <= 43273 || (c < 43504
-                    ? (c >= 43472 && c <= 43481)
-                    : c <= 43513)))
-                : (c <= 43609 || (c < 43872
-                  ? (c < 43824
-                    ? (c >= 43639 && c <= 43641)
-                    : c <= 43867)
-                  : (c <= 43880 || (c < 43888
-                    ? (c >= 43882 && c <= 43883)
-                    : c <= 43967)))))
-              : (c <= 44025 || (c < 65020
-                ? (c < 64434
-                  ? (c < 64275
-                    ? (c >= 64256 && c <= 64262)
-                    : (c <= 64279 || c == 64297))
-                  : (c <= 64450 || (c < 64975
-                    ? (c >= 64832 && c <= 64847)
-                    : c <= 64975)))
-                : (c <= 65023 || (c < 65112
-                  ? (c < 65101
-                    ? (c >= 65073 && c <= 65076)
-                    : c <= 65103)
-                  : (c <= 65112 || (c < 65129
-                    ? (c >= 65122 && c <= 65126)
-                    : c <= 65129)))))))
-            : (c <= 65284 || (c < 65932
-              ? (c < 65374
-                ? (c < 65308
-                  ? (c < 65293
-                    ? c == 65291
-                    : (c <= 65293 || (c >= 65296 && c <= 65305)))
-                  : (c <= 65310 || (c < 65372
-                    ? (c >= 65342 && c <= 65370)
-                    : c <= 65372)))
-                : (c <= 65374 || (c < 65532
-                  ? (c < 65512
-                    ? (c >= 65504 && c <= 65510)
-                    : c <= 65518)
-                  : (c <= 65533 || (c < 65913
-                    ? (c >= 65847 && c <= 65855)
-                    : c <= 65929)))))
-              : (c <= 65934 || (c < 66776
-                ? (c < 66000
-                  ? (c < 65952
-                    ? (c >= 65936 && c <= 65948)
-                    : c <= 65952)
-                  : (c <= 66044 || (c < 66720
-                    ? (c >= 66600 && c <= 66639)
-                    : c <= 66729)))
-                : (c <= 66811 || (c < 66995
-                  ? (c < 66979
-                    ? (c >= 66967 && c <= 66977)
-                    : c <= 66993)
-                  : (c <= 67001 || (c < 67703
-                    ? (c >= 67003 && c <= 67004)
-                    : c <= 67704)))))))))))))))));
+                    ? (c >= 12347 && c <= 12348)
+                    : (c <= 12351 || (c >= 12353 && c <= 12438)))
+                  : (c <= 12538 || ((c >= 12540 && c <= 12543) || (c >= 12549 && c <= 12591))))
+                : (c <= 12686 || (c < 12784
+                  ? ((c >= 12688 && c <= 12689) || (c >= 12694 && c <= 12771))
+                  : (c <= 12830 || ((c >= 12842 && c <= 12871) || c == 12880)))))
+              : (c <= 12927 || (c < 42560
+                ? (c < 42192
+                  ? (c < 12992
+                    ? (c >= 12938 && c <= 12976)
+                    : (c <= 42124 || (c >= 42128 && c <= 42182)))
+                  : (c <= 42237 || ((c >= 42240 && c <= 42508) || (c >= 42512 && c <= 42539))))
+                : (c <= 42606 || (c < 42752
+                  ? ((c >= 42623 && c <= 42653) || (c >= 42656 && c <= 42725))
+                  : (c <= 42954 || ((c >= 42960 && c <= 42961) || c == 42963)))))))
+            : (c <= 42969 || (c < 43264
+              ? (c < 43072
+                ? (c < 43020
+                  ? (c < 43011
+                    ? (c >= 42994 && c <= 43009)
+                    : (c <= 43013 || (c >= 43015 && c <= 43018)))
+                  : (c <= 43042 || ((c >= 43048 && c <= 43051) || (c >= 43062 && c <= 43065))))
+                : (c <= 43123 || (c < 43250
+                  ? ((c >= 43138 && c <= 43187) || (c >= 43216 && c <= 43225))
+                  : (c <= 43255 || (c == 43259 || (c >= 43261 && c <= 43262))))))
+              : (c <= 43301 || (c < 43494
+                ? (c < 43396
+                  ? ((c >= 43312 && c <= 43334) || (c >= 43360 && c <= 43388))
+                  : (c <= 43442 || ((c >= 43471 && c <= 43481) || (c >= 43488 && c <= 43492))))
+                : (c <= 43518 || (c < 43588
+                  ? ((c >= 43520 && c <= 43560) || (c >= 43584 && c <= 43586))
+                  : (c <= 43595 || ((c >= 43600 && c <= 43609) || (c >= 43616 && c <= 43642))))))))))))))))
+    : (c <= 43695 || (c < 72016
+      ? (c < 67584
+        ? (c < 65374
+          ? (c < 64312
+            ? (c < 43816
+              ? (c < 43744
+                ? (c < 43712
+                  ? (c < 43701
+                    ? c == 43697
+                    : (c <= 43702 || (c >= 43705 && c <= 43709)))
+                  : (c == 43712 || (c == 43714 || (c >= 43739 && c <= 43741))))
+                : (c <= 43754 || (c < 43785
+                  ? ((c >= 43762 && c <= 43764) || (c >= 43777 && c <= 43782))
+                  : (c <= 43790 || ((c >= 43793 && c <= 43798) || (c >= 43808 && c <= 43814))))))
+              : (c <= 43822 || (c < 63744
+                ? (c < 44032
+                  ? (c < 43888
+                    ? (c >= 43824 && c <= 43883)
+                    : (c <= 44002 || (c >= 44016 && c <= 44025)))
+                  : (c <= 55203 || ((c >= 55216 && c <= 55238) || (c >= 55243 && c <= 55291))))
+                : (c <= 64109 || (c < 64275
+                  ? ((c >= 64112 && c <= 64217) || (c >= 64256 && c <= 64262))
+                  : (c <= 64279 || (c == 64285 || (c >= 64287 && c <= 64310))))))))
+            : (c <= 64316 || (c < 65122
+              ? (c < 64914
+                ? (c < 64326
+                  ? (c < 64320
+                    ? c == 64318
+                    : (c <= 64321 || (c >= 64323 && c <= 64324)))
+                  : (c <= 64450 || ((c >= 64467 && c <= 64829) || (c >= 64832 && c <= 64911))))
+                : (c <= 64967 || (c < 65073
+                  ? (c == 64975 || (c >= 65008 && c <= 65023))
+                  : (c <= 65076 || ((c >= 65101 && c <= 65103) || c == 65112)))))
+              : (c <= 65126 || (c < 65293
+                ? (c < 65142
+                  ? (c == 65129 || (c >= 65136 && c <= 65140))
+                  : (c <= 65276 || (c == 65284 || c == 65291)))
+                : (c == 65293 || (c < 65313
+                  ? ((c >= 65296 && c <= 65305) || (c >= 65308 && c <= 65310))
+                  : (c <= 65338 || ((c >= 65342 && c <= 65370) || c == 65372)))))))))
+          : (c == 65374 || (c < 66370
+            ? (c < 65599
+              ? (c < 65512
+                ? (c < 65490
+                  ? (c < 65474
+                    ? (c >= 65382 && c <= 65470)
+                    : (c <= 65479 || (c >= 65482 && c <= 65487)))
+                  : (c <= 65495 || ((c >= 65498 && c <= 65500) || (c >= 65504 && c <= 65510))))
+                : (c <= 65518 || (c < 65549
+                  ? ((c >= 65532 && c <= 65533) || (c >= 65536 && c <= 65547))
+                  : (c <= 65574 || ((c >= 65576 && c <= 65594) || (c >= 65596 && c <= 65597))))))
+              : (c <= 65613 || (c < 65952
+                ? (c < 65913
+                  ? (c < 65664
+                    ? (c >= 65616 && c <= 65629)
+                    : (c <= 65786 || (c >= 65847 && c <= 65855)))
+                  : (c <= 65929 || ((c >= 65932 && c <= 65934) || (c >= 65936 && c <= 65948))))
+                : (c == 65952 || (c < 66208
+                  ? ((c >= 66000 && c <= 66044) || (c >= 66176 && c <= 66204))
+                  : (c <= 66256 || ((c >= 66304 && c <= 66335) || (c >= 66349 && c <= 66368))))))))
+            : (c <= 66377 || (c < 66956
+              ? (c < 66736
+                ? (c < 66504
+                  ? (c < 66432
+                    ? (c >= 66384 && c <= 66421)
+                    : (c <= 66461 || (c >= 66464 && c <= 66499)))
+                  : (c <= 66511 || ((c >= 66560 && c <= 66717) || (c >= 66720 && c <= 66729))))
+                : (c <= 66771 || (c < 66864
+                  ? ((c >= 66776 && c <= 66811) || (c >= 66816 && c <= 66855))
+                  : (c <= 66915 || ((c >= 66928 && c <= 66938) || (c >= 66940 && c <= 66954))))))
+              : (c <= 66962 || (c < 67072
+                ? (c < 66979
+                  ? ((c >= 66964 && c <= 66965) || (c >= 66967 && c <= 66977))
+                  : (c <= 66993 || ((c >= 66995 && c <= 67001) || (c >= 67003 && c <= 67004))))
+                : (c <= 67382 || (c < 67456
+                  ? ((c >= 67392 && c <= 67413) || (c >= 67424 && c <= 67431))
+                  : (c <= 67461 || ((c >= 67463 && c <= 67504) || (c >= 67506 && c <= 67514))))))))))))
+        : (c <= 67589 || (c < 70006
+          ? (c < 68736
+            ? (c < 68030
+              ? (c < 67712
+                ? (c < 67644
+                  ? (c < 67594
+                    ? c == 67592
+                    : (c <= 67637 || (c >= 67639 && c <= 67640)))
+                  : (c == 67644 || ((c >= 67647 && c <= 67669) || (c >= 67680 && c <= 67704))))
+                : (c <= 67742 || (c < 67840
+                  ? ((c >= 67808 && c <= 67826) || (c >= 67828 && c <= 67829))
+                  : (c <= 67861 || ((c >= 67872 && c <= 67897) || (c >= 67968 && c <= 68023))))))
+              : (c <= 68031 || (c < 68288
+                ? (c < 68121
+                  ? (c < 68112
+                    ? c == 68096
+                    : (c <= 68115 || (c >= 68117 && c <= 68119)))
+                  : (c <= 68149 || ((c >= 68192 && c <= 68220) || (c >= 68224 && c <= 68252))))
+                : (c <= 68324 || (c < 68448
+                  ? ((c >= 68352 && c <= 68405) || (c >= 68416 && c <= 68437))
+                  : (c <= 68466 || ((c >= 68480 && c <= 68497) || (c >= 68608 && c <= 68680))))))))
+            : (c <= 68786 || (c < 69635
+              ? (c < 69376
+                ? (c < 69248
+                  ? (c < 68864
+                    ? (c >= 68800 && c <= 68850)
+                    : (c <= 68899 || (c >= 68912 && c <= 68921)))
+                  : (c <= 69289 || (c == 69293 || (c >= 69296 && c <= 69297))))
+                : (c <= 69404 || (c < 69488
+                  ? (c == 69415 || (c >= 69424 && c <= 69445))
+                  : (c <= 69505 || ((c >= 69552 && c <= 69572) || (c >= 69600 && c <= 69622))))))
+              : (c <= 69687 || (c < 69872
+                ? (c < 69749
+                  ? ((c >= 69734 && c <= 69743) || (c >= 69745 && c <= 69746))
+                  : (c == 69749 || ((c >= 69763 && c <= 69807) || (c >= 69840 && c <= 69864))))
+                : (c <= 69881 || (c < 69956
+                  ? ((c >= 69891 && c <= 69926) || (c >= 69942 && c <= 69951))
+                  : (c == 69956 || (c == 69959 || (c >= 69968 && c <= 70002))))))))))
+          : (c == 70006 || (c < 70751
+            ? (c < 70384
+              ? (c < 70272
+                ? (c < 70108
+                  ? (c < 70081
+                    ? (c >= 70019 && c <= 70066)
+                    : (c <= 70084 || (c >= 70096 && c <= 70106)))
+                  : (c == 70108 || ((c >= 70144 && c <= 70161) || (c >= 70163 && c <= 70187))))
+                : (c <= 70278 || (c < 70287
+                  ? (c == 70280 || (c >= 70282 && c <= 70285))
+                  : (c <= 70301 || ((c >= 70303 && c <= 70312) || (c >= 70320 && c <= 70366))))))
+              : (c <= 70393 || (c < 70461
+                ? (c < 70442
+                  ? (c < 70415
+                    ? (c >= 70405 && c <= 70412)
+                    : (c <= 70416 || (c >= 70419 && c <= 70440)))
+                  : (c <= 70448 || ((c >= 70450 && c <= 70451) || (c >= 70453 && c <= 70457))))
+                : (c == 70461 || (c < 70656
+                  ? (c == 70480 || (c >= 70493 && c <= 70497))
+                  : (c <= 70708 || ((c >= 70727 && c <= 70730) || (c >= 70736 && c <= 70745))))))))
+            : (c <= 70753 || (c < 71424
+              ? (c < 71168
+                ? (c < 70864
+                  ? (c < 70852
+                    ? (c >= 70784 && c <= 70831)
+                    : (c <= 70853 || c == 70855))
+                  : (c <= 70873 || ((c >= 71040 && c <= 71086) || (c >= 71128 && c <= 71131))))
+                : (c <= 71215 || (c < 71296
+                  ? (c == 71236 || (c >= 71248 && c <= 71257))
+                  : (c <= 71338 || (c == 71352 || (c >= 71360 && c <= 71369))))))
+              : (c <= 71450 || (c < 71945
+                ? (c < 71680
+                  ? ((c >= 71472 && c <= 71481) || (c >= 71487 && c <= 71494))
+                  : (c <= 71723 || ((c >= 71840 && c <= 71913) || (c >= 71935 && c <= 71942))))
+                : (c == 71945 || (c < 71960
+                  ? ((c >= 71948 && c <= 71955) || (c >= 71957 && c <= 71958))
+                  : (c <= 71983 || (c == 71999 || c == 72001)))))))))))))
+      : (c <= 72025 || (c < 121462
+        ? (c < 94176
+          ? (c < 73120
+            ? (c < 72714
+              ? (c < 72250
+                ? (c < 72163
+                  ? (c < 72106
+                    ? (c >= 72096 && c <= 72103)
+                    : (c <= 72144 || c == 72161))
+                  : (c == 72163 || (c == 72192 || (c >= 72203 && c <= 72242))))
+                : (c == 72250 || (c < 72349
+                  ? (c == 72272 || (c >= 72284 && c <= 72329))
+                  : (c == 72349 || ((c >= 72368 && c <= 72440) || (c >= 72704 && c <= 72712))))))
+              : (c <= 72750 || (c < 73030
+                ? (c < 72960
+                  ? (c < 72784
+                    ? c == 72768
+                    : (c <= 72793 || (c >= 72818 && c <= 72847)))
+                  : (c <= 72966 || ((c >= 72968 && c <= 72969) || (c >= 72971 && c <= 73008))))
+                : (c == 73030 || (c < 73063
+                  ? ((c >= 73040 && c <= 73049) || (c >= 73056 && c <= 73061))
+                  : (c <= 73064 || ((c >= 73066 && c <= 73097) || c == 73112)))))))
+            : (c <= 73129 || (c < 92864
+              ? (c < 77824
+                ? (c < 73728
+                  ? (c < 73648
+                    ? (c >= 73440 && c <= 73458)
+                    : (c == 73648 || (c >= 73685 && c <= 73713)))
+                  : (c <= 74649 || ((c >= 74880 && c <= 75075) || (c >= 77712 && c <= 77808))))
+                : (c <= 78894 || (c < 92736
+                  ? ((c >= 82944 && c <= 83526) || (c >= 92160 && c <= 92728))
+                  : (c <= 92766 || ((c >= 92768 && c <= 92777) || (c >= 92784 && c <= 92862))))))
+              : (c <= 92873 || (c < 93027
+                ? (c < 92988
+                  ? ((c >= 92880 && c <= 92909) || (c >= 92928 && c <= 92975))
+                  : (c <= 92995 || (c == 92997 || (c >= 93008 && c <= 93017))))
+                : (c <= 93047 || (c < 93952
+                  ? ((c >= 93053 && c <= 93071) || (c >= 93760 && c <= 93823))
+                  : (c <= 94026 || (c == 94032 || (c >= 94099 && c <= 94111))))))))))
+          : (c <= 94177 || (c < 119365
+            ? (c < 113776
+              ? (c < 110589
+                ? (c < 101632
+                  ? (c < 94208
+                    ? c == 94179
+                    : (c <= 100343 || (c >= 100352 && c <= 101589)))
+                  : (c <= 101640 || ((c >= 110576 && c <= 110579) || (c >= 110581 && c <= 110587))))
+                : (c <= 110590 || (c < 110948
+                  ? ((c >= 110592 && c <= 110882) || (c >= 110928 && c <= 110930))
+                  : (c <= 110951 || ((c >= 110960 && c <= 111355) || (c >= 113664 && c <= 113770))))))
+              : (c <= 113788 || (c < 119081
+                ? (c < 118608
+                  ? (c < 113808
+                    ? (c >= 113792 && c <= 113800)
+                    : (c <= 113817 || c == 113820))
+                  : (c <= 118723 || ((c >= 118784 && c <= 119029) || (c >= 119040 && c <= 119078))))
+                : (c <= 119140 || (c < 119180
+                  ? ((c >= 119146 && c <= 119148) || (c >= 119171 && c <= 119172))
+                  : (c <= 119209 || ((c >= 119214 && c <= 119274) || (c >= 119296 && c <= 119361))))))))
+            : (c == 119365 || (c < 120077
+              ? (c < 119977
+                ? (c < 119966
+                  ? (c < 119808
+                    ? (c >= 119552 && c <= 119638)
+                    : (c <= 119892 || (c >= 119894 && c <= 119964)))
+                  : (c <= 119967 || (c == 119970 || (c >= 119973 && c <= 119974))))
+                : (c <= 119980 || (c < 119997
+                  ? ((c >= 119982 && c <= 119993) || c == 119995)
+                  : (c <= 120003 || ((c >= 120005 && c <= 120069) || (c >= 120071 && c <= 120074))))))
+              : (c <= 120084 || (c < 120138
+                ? (c < 120123
+                  ? ((c >= 120086 && c <= 120092) || (c >= 120094 && c <= 120121))
+                  : (c <= 120126 || ((c >= 120128 && c <= 120132) || c == 120134)))
+                : (c <= 120144 || (c < 120782
+                  ? ((c >= 120146 && c <= 120485) || (c >= 120488 && c <= 120779))
+                  : (c <= 121343 || ((c >= 121399 && c <= 121402) || (c >= 121453 && c <= 121460))))))))))))
+        : (c <= 121475 || (c < 126603
+          ? (c < 126503
+            ? (c < 124909
+              ? (c < 123536
+                ? (c < 123191
+                  ? (c < 122624
+                    ? (c >= 121477 && c <= 121478)
+                    : (c <= 122654 || (c >= 123136 && c <= 123180)))
+                  : (c <= 123197 || ((c >= 123200 && c <= 123209) || (c >= 123214 && c <= 123215))))
+                : (c <= 123565 || (c < 123647
+                  ? ((c >= 123584 && c <= 123627) || (c >= 123632 && c <= 123641))
+                  : (c == 123647 || ((c >= 124896 && c <= 124902) || (c >= 124904 && c <= 124907))))))
+              : (c <= 124910 || (c < 126128
+                ? (c < 125259
+                  ? (c < 124928
+                    ? (c >= 124912 && c <= 124926)
+                    : (c <= 125124 || (c >= 125184 && c <= 125251)))
+                  : (c == 125259 || ((c >= 125264 && c <= 125273) || c == 126124)))
+                : (c == 126128 || (c < 126469
+                  ? (c == 126254 || (c >= 126464 && c <= 126467))
+                  : (c <= 126495 || ((c >= 126497 && c <= 126498) || c == 126500)))))))
+            : (c == 126503 || (c < 126553
+              ? (c < 126537
+                ? (c < 126523
+                  ? (c < 126516
+                    ? (c >= 126505 && c <= 126514)
+                    : (c <= 126519 || c == 126521))
+                  : (c == 126523 || (c == 126530 || c == 126535)))
+                : (c == 126537 || (c < 126545
+                  ? (c == 126539 || (c >= 126541 && c <= 126543))
+                  : (c <= 126546 || (c == 126548 || c == 126551)))))
+              : (c == 126553 || (c < 126567
+                ? (c < 126559
+                  ? (c == 126555 || c == 126557)
+                  : (c == 126559 || ((c >= 126561 && c <= 126562) || c == 126564)))
+                : (c <= 126570 || (c < 126585
+                  ? ((c >= 126572 && c <= 126578) || (c >= 126580 && c <= 126583))
+                  : (c <= 126588 || (c == 126590 || (c >= 126592 && c <= 126601))))))))))
+          : (c <= 126619 || (c < 129104
+            ? (c < 127504
+              ? (c < 127136
+                ? (c < 126704
+                  ? (c < 126629
+                    ? (c >= 126625 && c <= 126627)
+                    : (c <= 126633 || (c >= 126635 && c <= 126651)))
+                  : (c <= 126705 || ((c >= 126976 && c <= 127019) || (c >= 127024 && c <= 127123))))
+                : (c <= 127150 || (c < 127185
+                  ? ((c >= 127153 && c <= 127167) || (c >= 127169 && c <= 127183))
+                  : (c <= 127221 || ((c >= 127245 && c <= 127405) || (c >= 127462 && c <= 127490))))))
+              : (c <= 127547 || (c < 128768
+                ? (c < 127744
+                  ? (c < 127568
+                    ? (c >= 127552 && c <= 127560)
+                    : (c <= 127569 || (c >= 127584 && c <= 127589)))
+                  : (c <= 128727 || ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))))
+                : (c <= 128883 || (c < 129008
+                  ? ((c >= 128896 && c <= 128984) || (c >= 128992 && c <= 129003))
+                  : (c == 129008 || ((c >= 129024 && c <= 129035) || (c >= 129040 && c <= 129095))))))))
+            : (c <= 129113 || (c < 129760
+              ? (c < 129656
+                ? (c < 129280
+                  ? (c < 129168
+                    ? (c >= 129120 && c <= 129159)
+                    : (c <= 129197 || (c >= 129200 && c <= 129201)))
+                  : (c <= 129619 || ((c >= 129632 && c <= 129645) || (c >= 129648 && c <= 129652))))
+                : (c <= 129660 || (c < 129712
+                  ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                  : (c <= 129722 || ((c >= 129728 && c <= 129733) || (c >= 129744 && c <= 129753))))))
+              : (c <= 129767 || (c < 173824
+                ? (c < 129940
+                  ? ((c >= 129776 && c <= 129782) || (c >= 129792 && c <= 129938))
+                  : (c <= 129994 || ((c >= 130032 && c <= 130041) || (c >= 131072 && c <= 173791))))
+                : (c <= 177976 || (c < 183984
+                  ? ((c >= 177984 && c <= 178205) || (c >= 178208 && c <= 183969))
+                  : (c <= 191456 || ((c >= 194560 && c <= 195101) || (c >= 196608 && c <= 201546))))))))))))))))));
 }
 
-static inline bool sym_identifier_character_set_2(int32_t c) {
-  return (c < 7168
-    ? (c < 3168
-      ? (c < 2417
-        ? (c < 1519
-          ? (c < 880
-            ? (c < '~'
-              ? (c < '<'
-                ? (c < '-'
+static inline bool sym_character_set_6(int32_t c) {
+  return (c < 43646
+    ? (c < 4348
+      ? (c < 2821
+        ? (c < 2036
+          ? (c < 1329
+            ? (c < 180
+              ? (c < '^'
+                ? (c < '0'
                   ? (c < '+'
                     ? c == '$'
-                    : c <= '+')
-                  : (c <= '-' || (c >= '0' && c <= '9')))
-                : (c <= '>' || (c < '^'
-                  ? (c >= 'A' && c <= 'Z')
-                  : (c <= 'z' || c == '|'))))
-              : (c <= '~' || (c < 180
-                ? (c < 168
-                  ? (c >= 162 && c <= 166)
-                  : (c <= 172 || (c >= 174 && c <= 177)))
-                : (c <= 181 || (c < 186
-                  ? c == 184
-                  : (c <= 187 || (c >= 192 && c <= 767)))))))
-            : (c <= 887 || (c < 1162
-              ? (c < 904
-                ? (c < 895
-                  ? (c >= 890 && c <= 893)
-                  : (c <= 895 || (c >= 900 && c <= 902)))
-                : (c <= 906 || (c < 910
-                  ? c == 908
-                  : (c <= 929 || (c >= 931 && c <= 1154)))))
-              : (c <= 1327 || (c < 1418
-                ? (c < 1369
-                  ? (c >= 1329 && c <= 1366)
-                  : (c <= 1369 || (c >= 1376 && c <= 1416)))
-                : (c <= 1418 || (c < 1470
-                  ? (c >= 1421 && c <= 1423)
-                  : (c <= 1470 || (c >= 1488 && c <= 1514)))))))))
-          : (c <= 1522 || (c < 1984
-            ? (c < 1758
-              ? (c < 1632
-                ? (c < 1550
-                  ? (c < 1547
-                    ? (c >= 1542 && c <= 1544)
-                    : c <= 1547)
-                  : (c <= 1551 || (c >= 1568 && c <= 1610)))
-                : (c <= 1641 || (c < 1649
-                  ? (c >= 1646 && c <= 1647)
-                  : (c <= 1747 || c == 1749))))
-              : (c <= 1758 || (c < 1808
-                ? (c < 1769
-                  ? (c >= 1765 && c <= 1766)
-                  : (c <= 1769 || (c >= 1774 && c <= 1791)))
-                : (c <= 1808 || (c < 1869
-                  ? (c >= 1810 && c <= 1839)
-                  : (c <= 1957 || c == 1969))))))
-            : (c <= 2026 || (c < 2144
-              ? (c < 2074
-                ? (c < 2042
-                  ? (c >= 2036 && c <= 2038)
-                  : (c <= 2042 || (c >= 2046 && c <= 2069)))
-                : (c <= 2074 || (c < 2088
-                  ? c == 2084
-                  : (c <= 2088 || (c >= 2112 && c <= 2136)))))
-              : (c <= 2154 || (c < 2365
-                ? (c < 2208
-                  ? (c >= 2160 && c <= 2190)
-                  : (c <= 2249 || (c >= 2308 && c <= 2361)))
-                : (c <= 2365 || (c < 2392
-                  ? c == 2384
-                  : (c <= 2401 || (c >= 2406 && c <= 2415)))))))))))
-        : (c <= 2432 || (c < 2790
-          ? (c < 2610
-            ? (c < 2524
-              ? (c < 2482
-                ? (c < 2451
-                  ? (c < 2447
-                    ? (c >= 2437 && c <= 2444)
-                    : c <= 2448)
-                  : (c <= 2472 || (c >= 2474 && c <= 2480)))
-                : (c <= 2482 || (c < 2493
-                  ? (c >= 2486 && c <= 2489)
-                  : (c <= 2493 || c == 2510))))
-              : (c <= 2525 || (c < 2565
-                ? (c < 2534
-                  ? (c >= 2527 && c <= 2529)
-                  : (c <= 2547 || (c >= 2554 && c <= 2556)))
-                : (c <= 2570 || (c < 2579
-                  ? (c >= 2575 && c <= 2576)
-                  : (c <= 2600 || (c >= 2602 && c <= 2608)))))))
-            : (c <= 2611 || (c < 2703
-              ? (c < 2654
-                ? (c < 2616
-                  ? (c >= 2613 && c <= 2614)
-                  : (c <= 2617 || (c >= 2649 && c <= 2652)))
-                : (c <= 2654 || (c < 2674
-                  ? (c >= 2662 && c <= 2671)
-                  : (c <= 2676 || (c >= 2693 && c <= 2701)))))
-              : (c <= 2705 || (c < 2741
+                    : (c == '+' || c == '-'))
+                  : (c <= ':' || ((c >= '<' && c <= '>') || (c >= 'A' && c <= 'Z'))))
+                : (c <= 'z' || (c < 162
+                  ? (c == '|' || c == '~')
+                  : (c <= 166 || ((c >= 168 && c <= 172) || (c >= 174 && c <= 177))))))
+              : (c <= 181 || (c < 900
+                ? (c < 880
+                  ? (c < 186
+                    ? c == 184
+                    : (c <= 187 || (c >= 192 && c <= 767)))
+                  : (c <= 887 || ((c >= 890 && c <= 893) || c == 895)))
+                : (c <= 902 || (c < 910
+                  ? ((c >= 904 && c <= 906) || c == 908)
+                  : (c <= 929 || ((c >= 931 && c <= 1154) || (c >= 1162 && c <= 1327))))))))
+            : (c <= 1366 || (c < 1646
+              ? (c < 1519
+                ? (c < 1421
+                  ? (c < 1376
+                    ? c == 1369
+                    : (c <= 1416 || c == 1418))
+                  : (c <= 1423 || (c == 1470 || (c >= 1488 && c <= 1514))))
+                : (c <= 1522 || (c < 1550
+                  ? ((c >= 1542 && c <= 1544) || c == 1547)
+                  : (c <= 1551 || ((c >= 1568 && c <= 1610) || (c >= 1632 && c <= 1641))))))
+              : (c <= 1647 || (c < 1774
+                ? (c < 1758
+                  ? ((c >= 1649 && c <= 1747) || c == 1749)
+                  : (c == 1758 || ((c >= 1765 && c <= 1766) || c == 1769)))
+                : (c <= 1791 || (c < 1869
+                  ? (c == 1808 || (c >= 1810 && c <= 1839))
+                  : (c <= 1957 || (c == 1969 || (c >= 1984 && c <= 2026))))))))))
+          : (c <= 2038 || (c < 2534
+            ? (c < 2392
+              ? (c < 2144
+                ? (c < 2084
+                  ? (c < 2046
+                    ? c == 2042
+                    : (c <= 2069 || c == 2074))
+                  : (c == 2084 || (c == 2088 || (c >= 2112 && c <= 2136))))
+                : (c <= 2154 || (c < 2308
+                  ? ((c >= 2160 && c <= 2190) || (c >= 2208 && c <= 2249))
+                  : (c <= 2361 || (c == 2365 || c == 2384)))))
+              : (c <= 2401 || (c < 2482
+                ? (c < 2447
+                  ? (c < 2417
+                    ? (c >= 2406 && c <= 2415)
+                    : (c <= 2432 || (c >= 2437 && c <= 2444)))
+                  : (c <= 2448 || ((c >= 2451 && c <= 2472) || (c >= 2474 && c <= 2480))))
+                : (c == 2482 || (c < 2510
+                  ? ((c >= 2486 && c <= 2489) || c == 2493)
+                  : (c == 2510 || ((c >= 2524 && c <= 2525) || (c >= 2527 && c <= 2529))))))))
+            : (c <= 2547 || (c < 2693
+              ? (c < 2613
+                ? (c < 2579
+                  ? (c < 2565
+                    ? (c >= 2554 && c <= 2556)
+                    : (c <= 2570 || (c >= 2575 && c <= 2576)))
+                  : (c <= 2600 || ((c >= 2602 && c <= 2608) || (c >= 2610 && c <= 2611))))
+                : (c <= 2614 || (c < 2654
+                  ? ((c >= 2616 && c <= 2617) || (c >= 2649 && c <= 2652))
+                  : (c == 2654 || ((c >= 2662 && c <= 2671) || (c >= 2674 && c <= 2676))))))
+              : (c <= 2701 || (c < 2749
                 ? (c < 2730
-                  ? (c >= 2707 && c <= 2728)
-                  : (c <= 2736 || (c >= 2738 && c <= 2739)))
-                : (c <= 2745 || (c < 2768
-                  ? c == 2749
-                  : (c <= 2768 || (c >= 2784 && c <= 2785)))))))))
-          : (c <= 2799 || (c < 2969
-            ? (c < 2877
-              ? (c < 2835
-                ? (c < 2821
-                  ? (c < 2809
-                    ? c == 2801
-                    : c <= 2809)
-                  : (c <= 2828 || (c >= 2831 && c <= 2832)))
-                : (c <= 2856 || (c < 2866
-                  ? (c >= 2858 && c <= 2864)
-                  : (c <= 2867 || (c >= 2869 && c <= 2873)))))
-              : (c <= 2877 || (c < 2947
-                ? (c < 2911
-                  ? (c >= 2908 && c <= 2909)
-                  : (c <= 2913 || (c >= 2918 && c <= 2929)))
-                : (c <= 2947 || (c < 2958
-                  ? (c >= 2949 && c <= 2954)
-                  : (c <= 2960 || (c >= 2962 && c <= 2965)))))))
-            : (c <= 2970 || (c < 3059
-              ? (c < 2984
-                ? (c < 2974
-                  ? c == 2972
-                  : (c <= 2975 || (c >= 2979 && c <= 2980)))
-                : (c <= 2986 || (c < 3024
-                  ? (c >= 2990 && c <= 3001)
-                  : (c <= 3024 || (c >= 3046 && c <= 3055)))))
-              : (c <= 3066 || (c < 3114
-                ? (c < 3086
-                  ? (c >= 3077 && c <= 3084)
-                  : (c <= 3088 || (c >= 3090 && c <= 3112)))
-                : (c <= 3129 || (c < 3160
-                  ? c == 3133
-                  : (c <= 3162 || c == 3165))))))))))))
-      : (c <= 3169 || (c < 4238
-        ? (c < 3716
-          ? (c < 3406
-            ? (c < 3293
-              ? (c < 3218
-                ? (c < 3205
-                  ? (c < 3199
-                    ? (c >= 3174 && c <= 3183)
-                    : c <= 3200)
-                  : (c <= 3212 || (c >= 3214 && c <= 3216)))
-                : (c <= 3240 || (c < 3253
-                  ? (c >= 3242 && c <= 3251)
-                  : (c <= 3257 || c == 3261))))
-              : (c <= 3294 || (c < 3332
-                ? (c < 3302
-                  ? (c >= 3296 && c <= 3297)
-                  : (c <= 3311 || (c >= 3313 && c <= 3314)))
-                : (c <= 3340 || (c < 3346
-                  ? (c >= 3342 && c <= 3344)
-                  : (c <= 3386 || c == 3389))))))
-            : (c <= 3407 || (c < 3517
-              ? (c < 3449
-                ? (c < 3423
-                  ? (c >= 3412 && c <= 3414)
-                  : (c <= 3425 || (c >= 3430 && c <= 3439)))
-                : (c <= 3455 || (c < 3482
-                  ? (c >= 3461 && c <= 3478)
-                  : (c <= 3505 || (c >= 3507 && c <= 3515)))))
-              : (c <= 3517 || (c < 3634
-                ? (c < 3558
-                  ? (c >= 3520 && c <= 3526)
-                  : (c <= 3567 || (c >= 3585 && c <= 3632)))
-                : (c <= 3635 || (c < 3664
-                  ? (c >= 3647 && c <= 3654)
-                  : (c <= 3673 || (c >= 3713 && c <= 3714)))))))))
-          : (c <= 3716 || (c < 3896
-            ? (c < 3792
-              ? (c < 3762
-                ? (c < 3749
-                  ? (c < 3724
-                    ? (c >= 3718 && c <= 3722)
-                    : c <= 3747)
-                  : (c <= 3749 || (c >= 3751 && c <= 3760)))
-                : (c <= 3763 || (c < 3776
-                  ? c == 3773
-                  : (c <= 3780 || c == 3782))))
-              : (c <= 3801 || (c < 3861
-                ? (c < 3840
-                  ? (c >= 3804 && c <= 3807)
-                  : (c <= 3843 || c == 3859))
-                : (c <= 3863 || (c < 3892
-                  ? (c >= 3866 && c <= 3881)
-                  : (c <= 3892 || c == 3894))))))
-            : (c <= 3896 || (c < 4096
+                  ? ((c >= 2703 && c <= 2705) || (c >= 2707 && c <= 2728))
+                  : (c <= 2736 || ((c >= 2738 && c <= 2739) || (c >= 2741 && c <= 2745))))
+                : (c == 2749 || (c < 2790
+                  ? (c == 2768 || (c >= 2784 && c <= 2785))
+                  : (c <= 2799 || (c == 2801 || c == 2809)))))))))))
+        : (c <= 2828 || (c < 3449
+          ? (c < 3114
+            ? (c < 2962
+              ? (c < 2908
+                ? (c < 2866
+                  ? (c < 2835
+                    ? (c >= 2831 && c <= 2832)
+                    : (c <= 2856 || (c >= 2858 && c <= 2864)))
+                  : (c <= 2867 || ((c >= 2869 && c <= 2873) || c == 2877)))
+                : (c <= 2909 || (c < 2947
+                  ? ((c >= 2911 && c <= 2913) || (c >= 2918 && c <= 2929))
+                  : (c == 2947 || ((c >= 2949 && c <= 2954) || (c >= 2958 && c <= 2960))))))
+              : (c <= 2965 || (c < 3024
+                ? (c < 2979
+                  ? (c < 2972
+                    ? (c >= 2969 && c <= 2970)
+                    : (c == 2972 || (c >= 2974 && c <= 2975)))
+                  : (c <= 2980 || ((c >= 2984 && c <= 2986) || (c >= 2990 && c <= 3001))))
+                : (c == 3024 || (c < 3077
+                  ? ((c >= 3046 && c <= 3055) || (c >= 3059 && c <= 3066))
+                  : (c <= 3084 || ((c >= 3086 && c <= 3088) || (c >= 3090 && c <= 3112))))))))
+            : (c <= 3129 || (c < 3293
+              ? (c < 3205
+                ? (c < 3168
+                  ? (c < 3160
+                    ? c == 3133
+                    : (c <= 3162 || c == 3165))
+                  : (c <= 3169 || ((c >= 3174 && c <= 3183) || (c >= 3199 && c <= 3200))))
+                : (c <= 3212 || (c < 3242
+                  ? ((c >= 3214 && c <= 3216) || (c >= 3218 && c <= 3240))
+                  : (c <= 3251 || ((c >= 3253 && c <= 3257) || c == 3261)))))
+              : (c <= 3294 || (c < 3346
+                ? (c < 3313
+                  ? ((c >= 3296 && c <= 3297) || (c >= 3302 && c <= 3311))
+                  : (c <= 3314 || ((c >= 3332 && c <= 3340) || (c >= 3342 && c <= 3344))))
+                : (c <= 3386 || (c < 3412
+                  ? (c == 3389 || (c >= 3406 && c <= 3407))
+                  : (c <= 3414 || ((c >= 3423 && c <= 3425) || (c >= 3430 && c <= 3439))))))))))
+          : (c <= 3455 || (c < 3866
+            ? (c < 3718
+              ? (c < 3585
+                ? (c < 3517
+                  ? (c < 3482
+                    ? (c >= 3461 && c <= 3478)
+                    : (c <= 3505 || (c >= 3507 && c <= 3515)))
+                  : (c == 3517 || ((c >= 3520 && c <= 3526) || (c >= 3558 && c <= 3567))))
+                : (c <= 3632 || (c < 3664
+                  ? ((c >= 3634 && c <= 3635) || (c >= 3647 && c <= 3654))
+                  : (c <= 3673 || ((c >= 3713 && c <= 3714) || c == 3716)))))
+              : (c <= 3722 || (c < 3782
+                ? (c < 3762
+                  ? (c < 3749
+                    ? (c >= 3724 && c <= 3747)
+                    : (c == 3749 || (c >= 3751 && c <= 3760)))
+                  : (c <= 3763 || (c == 3773 || (c >= 3776 && c <= 3780))))
+                : (c == 3782 || (c < 3840
+                  ? ((c >= 3792 && c <= 3801) || (c >= 3804 && c <= 3807))
+                  : (c <= 3843 || (c == 3859 || (c >= 3861 && c <= 3863))))))))
+            : (c <= 3881 || (c < 4176
               ? (c < 4030
-                ? (c < 3913
-                  ? (c >= 3904 && c <= 3911)
-                  : (c <= 3948 || (c >= 3976 && c <= 3980)))
-                : (c <= 4037 || (c < 4046
-                  ? (c >= 4039 && c <= 4044)
-                  : (c <= 4047 || (c >= 4053 && c <= 4056)))))
-              : (c <= 4138 || (c < 4193
-                ? (c < 4176
-                  ? (c >= 4159 && c <= 4169)
-                  : (c <= 4181 || (c >= 4186 && c <= 4189)))
-                : (c <= 4193 || (c < 4206
-                  ? (c >= 4197 && c <= 4198)
-                  : (c <= 4208 || (c >= 4213 && c <= 4225)))))))))))
-        : (c <= 4238 || (c < 5984
-          ? (c < 4802
-            ? (c < 4696
-              ? (c < 4304
-                ? (c < 4295
-                  ? (c < 4254
-                    ? (c >= 4240 && c <= 4249)
-                    : c <= 4293)
-                  : (c <= 4295 || c == 4301))
-                : (c <= 4346 || (c < 4682
-                  ? (c >= 4348 && c <= 4680)
-                  : (c <= 4685 || (c >= 4688 && c <= 4694)))))
-              : (c <= 4696 || (c < 4752
-                ? (c < 4704
-                  ? (c >= 4698 && c <= 4701)
-                  : (c <= 4744 || (c >= 4746 && c <= 4749)))
-                : (c <= 4784 || (c < 4792
-                  ? (c >= 4786 && c <= 4789)
-                  : (c <= 4798 || c == 4800))))))
-            : (c <= 4805 || (c < 5120
-              ? (c < 4888
-                ? (c < 4824
-                  ? (c >= 4808 && c <= 4822)
-                  : (c <= 4880 || (c >= 4882 && c <= 4885)))
-                : (c <= 4954 || (c < 5024
-                  ? (c >= 4992 && c <= 5017)
-                  : (c <= 5109 || (c >= 5112 && c <= 5117)))))
-              : (c <= 5741 || (c < 5873
-                ? (c < 5761
-                  ? (c >= 5743 && c <= 5759)
-                  : (c <= 5786 || (c >= 5792 && c <= 5866)))
-                : (c <= 5880 || (c < 5919
-                  ? (c >= 5888 && c <= 5905)
-                  : (c <= 5937 || (c >= 5952 && c <= 5969)))))))))
-          : (c <= 5996 || (c < 6512
-            ? (c < 6176
-              ? (c < 6107
-                ? (c < 6016
-                  ? (c >= 5998 && c <= 6000)
-                  : (c <= 6067 || c == 6103))
-                : (c <= 6108 || (c < 6150
-                  ? (c >= 6112 && c <= 6121)
-                  : (c <= 6150 || (c >= 6160 && c <= 6169)))))
-              : (c <= 6264 || (c < 6320
-                ? (c < 6279
-                  ? (c >= 6272 && c <= 6276)
-                  : (c <= 6312 || c == 6314))
-                : (c <= 6389 || (c < 6464
-                  ? (c >= 6400 && c <= 6430)
-                  : (c <= 6464 || (c >= 6470 && c <= 6509)))))))
-            : (c <= 6516 || (c < 6823
-              ? (c < 6622
-                ? (c < 6576
-                  ? (c >= 6528 && c <= 6571)
-                  : (c <= 6601 || (c >= 6608 && c <= 6617)))
-                : (c <= 6678 || (c < 6784
-                  ? (c >= 6688 && c <= 6740)
-                  : (c <= 6793 || (c >= 6800 && c <= 6809)))))
-              : (c <= 6823 || (c < 7009
-                ? (c < 6981
-                  ? (c >= 6917 && c <= 6963)
-                  : (c <= 6988 || (c >= 6992 && c <= 7001)))
-                : (c <= 7018 || (c < 7043
-                  ? (c >= 7028 && c <= 7036)
-                  : (c <= 7072 || (c >= 7086 && c <= 7141)))))))))))))))
-    : (c <= 7203 || (c < 43216
-      ? (c < 11680
-        ? (c < 8260
-          ? (c < 8027
-            ? (c < 7418
-              ? (c < 7357
-                ? (c < 7296
-                  ? (c < 7245
-                    ? (c >= 7232 && c <= 7241)
-                    : c <= 7293)
-                  : (c <= 7304 || (c >= 7312 && c <= 7354)))
-                : (c <= 7359 || (c < 7406
-                  ? (c >= 7401 && c <= 7404)
-                  : (c <= 7411 || (c >= 7413 && c <= 7414)))))
-              : (c <= 7418 || (c < 7968
-                ? (c < 7680
-                  ? (c >= 7424 && c <= 7615)
-                  : (c <= 7957 || (c >= 7960 && c <= 7965)))
-                : (c <= 8005 || (c < 8016
-                  ? (c >= 8008 && c <= 8013)
-                  : (c <= 8023 || c == 8025))))))
-            : (c <= 8027 || (c < 8178
-              ? (c < 8118
-                ? (c < 8031
-                  ? c == 8029
-                  : (c <= 8061 || (c >= 8064 && c <= 8116)))
-                : (c <= 8132 || (c < 8150
-                  ? (c >= 8134 && c <= 8147)
-                  : (c <= 8155 || (c >= 8157 && c <= 8175)))))
-              : (c <= 8180 || (c < 8219
-                ? (c < 8208
-                  ? (c >= 8182 && c <= 8190)
-                  : (c <= 8213 || (c >= 8216 && c <= 8217)))
-                : (c <= 8221 || (c < 8249
-                  ? c == 8223
-                  : (c <= 8250 || (c >= 8255 && c <= 8256)))))))))
-          : (c <= 8260 || (c < 9472
-            ? (c < 8448
-              ? (c < 8319
-                ? (c < 8305
-                  ? (c < 8276
-                    ? c == 8274
-                    : c <= 8276)
-                  : (c <= 8305 || (c >= 8314 && c <= 8316)))
-                : (c <= 8319 || (c < 8336
-                  ? (c >= 8330 && c <= 8332)
-                  : (c <= 8348 || (c >= 8352 && c <= 8384)))))
-              : (c <= 8527 || (c < 8972
-                ? (c < 8586
-                  ? (c >= 8579 && c <= 8580)
-                  : (c <= 8587 || (c >= 8592 && c <= 8967)))
-                : (c <= 9000 || (c < 9280
-                  ? (c >= 9003 && c <= 9254)
-                  : (c <= 9290 || (c >= 9372 && c <= 9449)))))))
-            : (c <= 10087 || (c < 11159
-              ? (c < 10649
-                ? (c < 10183
-                  ? (c >= 10132 && c <= 10180)
-                  : (c <= 10213 || (c >= 10224 && c <= 10626)))
-                : (c <= 10711 || (c < 10750
-                  ? (c >= 10716 && c <= 10747)
-                  : (c <= 11123 || (c >= 11126 && c <= 11157)))))
-              : (c <= 11502 || (c < 11565
+                ? (c < 3904
+                  ? (c < 3894
+                    ? c == 3892
+                    : (c == 3894 || c == 3896))
+                  : (c <= 3911 || ((c >= 3913 && c <= 3948) || (c >= 3976 && c <= 3980))))
+                : (c <= 4037 || (c < 4053
+                  ? ((c >= 4039 && c <= 4044) || (c >= 4046 && c <= 4047))
+                  : (c <= 4056 || ((c >= 4096 && c <= 4138) || (c >= 4159 && c <= 4169))))))
+              : (c <= 4181 || (c < 4238
+                ? (c < 4197
+                  ? ((c >= 4186 && c <= 4189) || c == 4193)
+                  : (c <= 4198 || ((c >= 4206 && c <= 4208) || (c >= 4213 && c <= 4225))))
+                : (c == 4238 || (c < 4295
+                  ? ((c >= 4240 && c <= 4249) || (c >= 4254 && c <= 4293))
+                  : (c == 4295 || (c == 4301 || (c >= 4304 && c <= 4346))))))))))))))
+      : (c <= 4680 || (c < 8579
+        ? (c < 6823
+          ? (c < 5952
+            ? (c < 4824
+              ? (c < 4752
+                ? (c < 4698
+                  ? (c < 4688
+                    ? (c >= 4682 && c <= 4685)
+                    : (c <= 4694 || c == 4696))
+                  : (c <= 4701 || ((c >= 4704 && c <= 4744) || (c >= 4746 && c <= 4749))))
+                : (c <= 4784 || (c < 4800
+                  ? ((c >= 4786 && c <= 4789) || (c >= 4792 && c <= 4798))
+                  : (c == 4800 || ((c >= 4802 && c <= 4805) || (c >= 4808 && c <= 4822))))))
+              : (c <= 4880 || (c < 5743
+                ? (c < 5024
+                  ? (c < 4888
+                    ? (c >= 4882 && c <= 4885)
+                    : (c <= 4954 || (c >= 4992 && c <= 5017)))
+                  : (c <= 5109 || ((c >= 5112 && c <= 5117) || (c >= 5120 && c <= 5741))))
+                : (c <= 5759 || (c < 5873
+                  ? ((c >= 5761 && c <= 5786) || (c >= 5792 && c <= 5866))
+                  : (c <= 5880 || ((c >= 5888 && c <= 5905) || (c >= 5919 && c <= 5937))))))))
+            : (c <= 5969 || (c < 6320
+              ? (c < 6150
+                ? (c < 6103
+                  ? (c < 5998
+                    ? (c >= 5984 && c <= 5996)
+                    : (c <= 6000 || (c >= 6016 && c <= 6067)))
+                  : (c == 6103 || ((c >= 6107 && c <= 6108) || (c >= 6112 && c <= 6121))))
+                : (c == 6150 || (c < 6272
+                  ? ((c >= 6160 && c <= 6169) || (c >= 6176 && c <= 6264))
+                  : (c <= 6276 || ((c >= 6279 && c <= 6312) || c == 6314)))))
+              : (c <= 6389 || (c < 6576
+                ? (c < 6470
+                  ? ((c >= 6400 && c <= 6430) || c == 6464)
+                  : (c <= 6509 || ((c >= 6512 && c <= 6516) || (c >= 6528 && c <= 6571))))
+                : (c <= 6601 || (c < 6688
+                  ? ((c >= 6608 && c <= 6617) || (c >= 6622 && c <= 6678))
+                  : (c <= 6740 || ((c >= 6784 && c <= 6793) || (c >= 6800 && c <= 6809))))))))))
+          : (c == 6823 || (c < 8029
+            ? (c < 7357
+              ? (c < 7086
+                ? (c < 7009
+                  ? (c < 6981
+                    ? (c >= 6917 && c <= 6963)
+                    : (c <= 6988 || (c >= 6992 && c <= 7001)))
+                  : (c <= 7018 || ((c >= 7028 && c <= 7036) || (c >= 7043 && c <= 7072))))
+                : (c <= 7141 || (c < 7245
+                  ? ((c >= 7168 && c <= 7203) || (c >= 7232 && c <= 7241))
+                  : (c <= 7293 || ((c >= 7296 && c <= 7304) || (c >= 7312 && c <= 7354))))))
+              : (c <= 7359 || (c < 7960
+                ? (c < 7418
+                  ? (c < 7406
+                    ? (c >= 7401 && c <= 7404)
+                    : (c <= 7411 || (c >= 7413 && c <= 7414)))
+                  : (c == 7418 || ((c >= 7424 && c <= 7615) || (c >= 7680 && c <= 7957))))
+                : (c <= 7965 || (c < 8016
+                  ? ((c >= 7968 && c <= 8005) || (c >= 8008 && c <= 8013))
+                  : (c <= 8023 || (c == 8025 || c == 8027)))))))
+            : (c == 8029 || (c < 8249
+              ? (c < 8178
+                ? (c < 8134
+                  ? (c < 8064
+                    ? (c >= 8031 && c <= 8061)
+                    : (c <= 8116 || (c >= 8118 && c <= 8132)))
+                  : (c <= 8147 || ((c >= 8150 && c <= 8155) || (c >= 8157 && c <= 8175))))
+                : (c <= 8180 || (c < 8216
+                  ? ((c >= 8182 && c <= 8190) || (c >= 8208 && c <= 8213))
+                  : (c <= 8217 || ((c >= 8219 && c <= 8221) || c == 8223)))))
+              : (c <= 8250 || (c < 8314
+                ? (c < 8274
+                  ? ((c >= 8255 && c <= 8256) || c == 8260)
+                  : (c == 8274 || (c == 8276 || c == 8305)))
+                : (c <= 8316 || (c < 8336
+                  ? (c == 8319 || (c >= 8330 && c <= 8332))
+                  : (c <= 8348 || ((c >= 8352 && c <= 8384) || (c >= 8448 && c <= 8527))))))))))))
+        : (c <= 8580 || (c < 12336
+          ? (c < 11704
+            ? (c < 10750
+              ? (c < 9472
+                ? (c < 9003
+                  ? (c < 8592
+                    ? (c >= 8586 && c <= 8587)
+                    : (c <= 8967 || (c >= 8972 && c <= 9000)))
+                  : (c <= 9254 || ((c >= 9280 && c <= 9290) || (c >= 9372 && c <= 9449))))
+                : (c <= 10087 || (c < 10224
+                  ? ((c >= 10132 && c <= 10180) || (c >= 10183 && c <= 10213))
+                  : (c <= 10626 || ((c >= 10649 && c <= 10711) || (c >= 10716 && c <= 10747))))))
+              : (c <= 11123 || (c < 11568
                 ? (c < 11520
-                  ? (c >= 11506 && c <= 11507)
-                  : (c <= 11557 || c == 11559))
-                : (c <= 11565 || (c < 11631
-                  ? (c >= 11568 && c <= 11623)
-                  : (c <= 11631 || (c >= 11648 && c <= 11670)))))))))))
-        : (c <= 11686 || (c < 12540
-          ? (c < 11840
-            ? (c < 11785
-              ? (c < 11720
-                ? (c < 11704
-                  ? (c < 11696
-                    ? (c >= 11688 && c <= 11694)
-                    : c <= 11702)
-                  : (c <= 11710 || (c >= 11712 && c <= 11718)))
-                : (c <= 11726 || (c < 11736
-                  ? (c >= 11728 && c <= 11734)
-                  : (c <= 11742 || (c >= 11778 && c <= 11781)))))
-              : (c <= 11786 || (c < 11804
-                ? (c < 11799
-                  ? (c >= 11788 && c <= 11789)
-                  : (c <= 11799 || c == 11802))
-                : (c <= 11805 || (c < 11823
-                  ? (c >= 11808 && c <= 11809)
-                  : (c <= 11823 || (c >= 11834 && c <= 11835)))))))
-            : (c <= 11840 || (c < 12306
-              ? (c < 11931
+                  ? (c < 11159
+                    ? (c >= 11126 && c <= 11157)
+                    : (c <= 11502 || (c >= 11506 && c <= 11507)))
+                  : (c <= 11557 || (c == 11559 || c == 11565)))
+                : (c <= 11623 || (c < 11680
+                  ? (c == 11631 || (c >= 11648 && c <= 11670))
+                  : (c <= 11686 || ((c >= 11688 && c <= 11694) || (c >= 11696 && c <= 11702))))))))
+            : (c <= 11710 || (c < 11834
+              ? (c < 11788
+                ? (c < 11736
+                  ? (c < 11720
+                    ? (c >= 11712 && c <= 11718)
+                    : (c <= 11726 || (c >= 11728 && c <= 11734)))
+                  : (c <= 11742 || ((c >= 11778 && c <= 11781) || (c >= 11785 && c <= 11786))))
+                : (c <= 11789 || (c < 11804
+                  ? (c == 11799 || c == 11802)
+                  : (c <= 11805 || ((c >= 11808 && c <= 11809) || c == 11823)))))
+              : (c <= 11835 || (c < 12032
                 ? (c < 11869
-                  ? (c >= 11856 && c <= 11857)
-                  : (c <= 11869 || (c >= 11904 && c <= 11929)))
-                : (c <= 12019 || (c < 12272
-                  ? (c >= 12032 && c <= 12245)
-                  : (c <= 12283 || (c >= 12292 && c <= 12294)))))
-              : (c <= 12307 || (c < 12347
-                ? (c < 12320
-                  ? c == 12316
-                  : (c <= 12320 || (c >= 12336 && c <= 12343)))
-                : (c <= 12348 || (c < 12353
-                  ? (c >= 12350 && c <= 12351)
-                  : (c <= 12438 || (c >= 12443 && c <= 12538)))))))))
-          : (c <= 12543 || (c < 42512
+                  ? (c == 11840 || (c >= 11856 && c <= 11857))
+                  : (c == 11869 || ((c >= 11904 && c <= 11929) || (c >= 11931 && c <= 12019))))
+                : (c <= 12245 || (c < 12306
+                  ? ((c >= 12272 && c <= 12283) || (c >= 12292 && c <= 12294))
+                  : (c <= 12307 || (c == 12316 || c == 12320)))))))))
+          : (c <= 12343 || (c < 42965
             ? (c < 12896
-              ? (c < 12694
-                ? (c < 12593
-                  ? (c >= 12549 && c <= 12591)
-                  : (c <= 12686 || (c >= 12688 && c <= 12689)))
-                : (c <= 12771 || (c < 12842
-                  ? (c >= 12784 && c <= 12830)
-                  : (c <= 12871 || c == 12880))))
-              : (c <= 12927 || (c < 40959
-                ? (c < 12992
-                  ? (c >= 12938 && c <= 12976)
-                  : (c <= 13312 || (c >= 19903 && c <= 19968)))
-                : (c <= 42124 || (c < 42192
-                  ? (c >= 42128 && c <= 42182)
-                  : (c <= 42237 || (c >= 42240 && c <= 42508)))))))
-            : (c <= 42539 || (c < 42994
-              ? (c < 42752
-                ? (c < 42623
-                  ? (c >= 42560 && c <= 42606)
-                  : (c <= 42653 || (c >= 42656 && c <= 42725)))
-                : (c <= 42954 || (c < 42963
-                  ? (c >= 42960 && c <= 42961)
-                  : (c <= 42963 || (c >= 42965 && c <= 42969)))))
-              : (c <= 43009 || (c < 43048
-                ? (c < 43015
-                  ? (c >= 43011 && c <= 43013)
-                  : (c <= 43018 || (c >= 43020 && c <= 43042)))
-                : (c <= 43051 || (c < 43072
-                  ? (c >= 43062 && c <= 43065)
-                  : (c <= 43123 || (c >= 43138 && c <= 43187)))))))))))))
-      : (c <= 43225 || (c < 65342
-        ? (c < 44032
-          ? (c < 43697
-            ? (c < 43488
-              ? (c < 43312
-                ? (c < 43261
-                  ? (c < 43259
-                    ? (c >= 43250 && c <= 43255)
-                    : c <= 43259)
-                  : (c <= 43262 || (c >= 43264 && c <= 43301)))
-                : (c <= 43334 || (c < 43396
-                  ? (c >= 43360 && c <= 43388)
-                  : (c <= 43442 || (c >= 43471 && c <= 43481)))))
-              : (c <= 43492 || (c < 43588
-                ? (c < 43520
-                  ? (c >= 43494 && c <= 43518)
-                  : (c <= 43560 || (c >= 43584 && c <= 43586)))
-                : (c <= 43595 || (c < 43616
-                  ? (c >= 43600 && c <= 43609)
-                  : (c <= 43642 || (c >= 43646 && c <= 43695)))))))
-            : (c <= 43697 || (c < 43777
-              ? (c < 43714
-                ? (c < 43705
-                  ? (c >= 43701 && c <= 43702)
-                  : (c <= 43709 || c == 43712))
-                : (c <= 43714 || (c < 43744
-                  ? (c >= 43739 && c <= 43741)
-                  : (c <= 43754 || (c >= 43762 && c <= 43764)))))
-              : (c <= 43782 || (c < 43816
-                ? (c < 43793
-                  ? (c >= 43785 && c <= 43790)
-                  : (c <= 43798 || (c >= 43808 && c <= 43814)))
-                : (c <= 43822 || (c < 43888
-                  ? (c >= 43824 && c <= 43883)
-                  : (c <= 44002 || (c >= 44016 && c <= 44025)))))))))
-          : (c <= 44032 || (c < 64914
-            ? (c < 64287
-              ? (c < 64112
-                ? (c < 55243
-                  ? (c < 55216
-                    ? c == 55203
-                    : c <= 55238)
-                  : (c <= 55291 || (c >= 63744 && c <= 64109)))
-                : (c <= 64217 || (c < 64275
-                  ? (c >= 64256 && c <= 64262)
-                  : (c <= 64279 || c == 64285))))
-              : (c <= 64310 || (c < 64323
-                ? (c < 64318
-                  ? (c >= 64312 && c <= 64316)
-                  : (c <= 64318 || (c >= 64320 && c <= 64321)))
-                : (c <= 64324 || (c < 64467
-                  ? (c >= 64326 && c <= 64450)
-                  : (c <= 64829 || (c >= 64832 && c <= 64911)))))))
-            : (c <= 64967 || (c < 65136
-              ? (c < 65101
-                ? (c < 65008
-                  ? c == 64975
-                  : (c <= 65023 || (c >= 65073 && c <= 65076)))
-                : (c <= 65103 || (c < 65122
-                  ? c == 65112
-                  : (c <= 65126 || c == 65129))))
-              : (c <= 65140 || (c < 65293
-                ? (c < 65284
-                  ? (c >= 65142 && c <= 65276)
-                  : (c <= 65284 || c == 65291))
-                : (c <= 65293 || (c < 65308
-                  ? (c >= 65296 && c <= 65305)
-                  : (c <= 65310 || (c >= 65313 && c <= 65338)))))))))))
-        : (c <= 65370 || (c < 66560
-          ? (c < 65664
-            ? (c < 65512
-              ? (c < 65482
-                ? (c < 65382
-                  ? (c < 65374
-                    ? c == 65372
-                    : c <= 65374)
-                  : (c <= 65470 || (c >= 65474 && c <= 65479)))
-                : (c <= 65487 || (c < 65498
-                  ? (c >= 65490 && c <= 65495)
-                  : (c <= 65500 || (c >= 65504 && c <= 65510)))))
-              : (c <= 65518 || (c < 65576
-                ? (c < 65536
-                  ? (c >= 65532 && c <= 65533)
-                  : (c <= 65547 || (c >= 65549 && c <= 65574)))
-                : (c <= 65594 || (c < 65599
-                  ? (c >= 65596 && c <= 65597)
-                  : (c <= 65613 || (c >= 65616 && c <= 65629)))))))
-            : (c <= 65786 || (c < 66208
-              ? (c < 65936
+              ? (c < 12593
+                ? (c < 12443
+                  ? (c < 12350
+                    ? (c >= 12347 && c <= 12348)
+                    : (c <= 12351 || (c >= 12353 && c <= 12438)))
+                  : (c <= 12538 || ((c >= 12540 && c <= 12543) || (c >= 12549 && c <= 12591))))
+                : (c <= 12686 || (c < 12784
+                  ? ((c >= 12688 && c <= 12689) || (c >= 12694 && c <= 12771))
+                  : (c <= 12830 || ((c >= 12842 && c <= 12871) || c == 12880)))))
+              : (c <= 12927 || (c < 42560
+                ? (c < 42192
+                  ? (c < 12992
+                    ? (c >= 12938 && c <= 12976)
+                    : (c <= 42124 || (c >= 42128 && c <= 42182)))
+                  : (c <= 42237 || ((c >= 42240 && c <= 42508) || (c >= 42512 && c <= 42539))))
+                : (c <= 42606 || (c < 42752
+                  ? ((c >= 42623 && c <= 42653) || (c >= 42656 && c <= 42725))
+                  : (c <= 42954 || ((c >= 42960 && c <= 42961) || c == 42963)))))))
+            : (c <= 42969 || (c < 43264
+              ? (c < 43072
+                ? (c < 43020
+                  ? (c < 43011
+                    ? (c >= 42994 && c <= 43009)
+                    : (c <= 43013 || (c >= 43015 && c <= 43018)))
+                  : (c <= 43042 || ((c >= 43048 && c <= 43051) || (c >= 43062 && c <= 43065))))
+                : (c <= 43123 || (c < 43250
+                  ? ((c >= 43138 && c <= 43187) || (c >= 43216 && c <= 43225))
+                  : (c <= 43255 || (c == 43259 || (c >= 43261 && c <= 43262))))))
+              : (c <= 43301 || (c < 43494
+                ? (c < 43396
+                  ? ((c >= 43312 && c <= 43334) || (c >= 43360 && c <= 43388))
+                  : (c <= 43442 || ((c >= 43471 && c <= 43481) || (c >= 43488 && c <= 43492))))
+                : (c <= 43518 || (c < 43588
+                  ? ((c >= 43520 && c <= 43560) || (c >= 43584 && c <= 43586))
+                  : (c <= 43595 || ((c >= 43600 && c <= 43609) || (c >= 43616 && c <= 43642))))))))))))))))
+    : (c <= 43695 || (c < 72016
+      ? (c < 67584
+        ? (c < 65374
+          ? (c < 64312
+            ? (c < 43816
+              ? (c < 43744
+                ? (c < 43712
+                  ? (c < 43701
+                    ? c == 43697
+                    : (c <= 43702 || (c >= 43705 && c <= 43709)))
+                  : (c == 43712 || (c == 43714 || (c >= 43739 && c <= 43741))))
+                : (c <= 43754 || (c < 43785
+                  ? ((c >= 43762 && c <= 43764) || (c >= 43777 && c <= 43782))
+                  : (c <= 43790 || ((c >= 43793 && c <= 43798) || (c >= 43808 && c <= 43814))))))
+              : (c <= 43822 || (c < 63744
+                ? (c < 44032
+                  ? (c < 43888
+                    ? (c >= 43824 && c <= 43883)
+                    : (c <= 44002 || (c >= 44016 && c <= 44025)))
+                  : (c <= 55203 || ((c >= 55216 && c <= 55238) || (c >= 55243 && c <= 55291))))
+                : (c <= 64109 || (c < 64275
+                  ? ((c >= 64112 && c <= 64217) || (c >= 64256 && c <= 64262))
+                  : (c <= 64279 || (c == 64285 || (c >= 64287 && c <= 64310))))))))
+            : (c <= 64316 || (c < 65122
+              ? (c < 64914
+                ? (c < 64326
+                  ? (c < 64320
+                    ? c == 64318
+                    : (c <= 64321 || (c >= 64323 && c <= 64324)))
+                  : (c <= 64450 || ((c >= 64467 && c <= 64829) || (c >= 64832 && c <= 64911))))
+                : (c <= 64967 || (c < 65073
+                  ? (c == 64975 || (c >= 65008 && c <= 65023))
+                  : (c <= 65076 || ((c >= 65101 && c <= 65103) || c == 65112)))))
+              : (c <= 65126 || (c < 65293
+                ? (c < 65142
+                  ? (c == 65129 || (c >= 65136 && c <= 65140))
+                  : (c <= 65276 || (c == 65284 || c == 65291)))
+                : (c == 65293 || (c < 65313
+                  ? ((c >= 65296 && c <= 65305) || (c >= 65308 && c <= 65310))
+                  : (c <= 65338 || ((c >= 65342 && c <= 65370) || c == 65372)))))))))
+          : (c == 65374 || (c < 66370
+            ? (c < 65599
+              ? (c < 65512
+                ? (c < 65490
+                  ? (c < 65474
+                    ? (c >= 65382 && c <= 65470)
+                    : (c <= 65479 || (c >= 65482 && c <= 65487)))
+                  : (c <= 65495 || ((c >= 65498 && c <= 65500) || (c >= 65504 && c <= 65510))))
+                : (c <= 65518 || (c < 65549
+                  ? ((c >= 65532 && c <= 65533) || (c >= 65536 && c <= 65547))
+                  : (c <= 65574 || ((c >= 65576 && c <= 65594) || (c >= 65596 && c <= 65597))))))
+              : (c <= 65613 || (c < 65952
                 ? (c < 65913
-                  ? (c >= 65847 && c <= 65855)
-                  : (c <= 65929 || (c >= 65932 && c <= 65934)))
-                : (c <= 65948 || (c < 66000
-                  ? c == 65952
-                  : (c <= 66044 || (c >= 66176 && c <= 66204)))))
-              : (c <= 66256 || (c < 66384
-                ? (c < 66349
-                  ? (c >= 66304 && c <= 66335)
-                  : (c <= 66368 || (c >= 66370 && c <= 66377)))
-                : (c <= 66421 || (c < 66464
-                  ? (c >= 66432 && c <= 66461)
-                  : (c <= 66499 || (c >= 66504 && c <= 66511)))))))))
-          : (c <= 66717 || (c < 67424
-            ? (c < 66956
-              ? (c < 66816
-                ? (c < 66736
-                  ? (c >= 66720 && c <= 66729)
-                  : (c <= 66771 || (c >= 66776 && c <= 66811)))
-                : (c <= 66855 || (c < 66928
-                  ? (c >= 66864 && c <= 66915)
-                  : (c <= 66938 || (c >= 66940 && c <= 66954)))))
-              : (c <= 66962 || (c < 66995
-                ? (c < 66967
-                  ? (c >= 66964 && c <= 66965)
-                  : (c <= 66977 || (c >= 66979 && c <= 66993)))
-                : (c <= 67001 || (c < 67072
-                  ? (c >= 67003 && c <= 67004)
-                  : (c <= 67382 || (c >= 67392 && c <= 67413)))))))
-            : (c <= 67431 || (c < 67644
-              ? (c < 67584
-                ? (c < 67463
-                  ? (c >= 67456 && c <= 67461)
-                  : (c <= 67504 || (c >= 67506 && c <= 67514)))
-                : (c <= 67589 || (c < 67594
-                  ? c == 67592
-                  : (c <= 67637 || (c >= 67639 && c <= 67640)))))
-              : (c <= 67644 || (c < 67808
-                ? (c < 67680
-                  ? (c >= 67647 && c <= 67669)
-                  : (c <= 67704 || (c >= 67712 && c <= 67742)))
-                : (c <= 67826 || (c < 67840
-                  ? (c >= 67828 && c <= 67829)
-                  : (c <= 67861 || (c >= 67872 && c <= 67883)))))))))))))))));
-}
-
-static inline bool sym_type_identifier_character_set_1(int32_t c) {
-  return (c < 8027
-    ? (c < 910
-      ? (c < 500
-        ? (c < 452
-          ? (c < 192
-            ? (c >= 'A' && c <= 'Z')
-            : (c <= 440 || c == 444))
-          : (c <= 452 || (c < 458
-            ? c == 455
-            : (c <= 458 || (c >= 461 && c <= 497)))))
-        : (c <= 590 || (c < 902
-          ? (c < 886
-            ? (c >= 880 && c <= 882)
-            : (c <= 886 || c == 895))
-          : (c <= 902 || (c < 908
-            ? (c >= 904 && c <= 906)
-            : c <= 908)))))
-      : (c <= 929 || (c < 7312
-        ? (c < 4256
-          ? (c < 1162
-            ? (c >= 931 && c <= 1152)
-            : (c <= 1326 || (c >= 1329 && c <= 1366)))
-          : (c <= 4293 || (c < 4301
-            ? c == 4295
-            : (c <= 4301 || (c >= 5024 && c <= 5109)))))
-        : (c <= 7354 || (c < 7976
-          ? (c < 7680
-            ? (c >= 7357 && c <= 7359)
-            : (c <= 7951 || (c >= 7960 && c <= 7965)))
-          : (c <= 7999 || (c < 8025
-            ? (c >= 8008 && c <= 8013)
-            : c <= 8025)))))))
-    : (c <= 8027 || (c < 42624
-      ? (c < 8450
-        ? (c < 8136
-          ? (c < 8031
-            ? c == 8029
-            : (c <= 8047 || (c >= 8120 && c <= 8123)))
-          : (c <= 8139 || (c < 8168
-            ? (c >= 8152 && c <= 8155)
-            : (c <= 8172 || (c >= 8184 && c <= 8187)))))
-        : (c <= 8499 || (c < 11390
-          ? (c < 8579
-            ? (c >= 8510 && c <= 8517)
-            : (c <= 8579 || (c >= 11264 && c <= 11381)))
-          : (c <= 11501 || (c < 42560
-            ? c == 11506
-            : c <= 42604)))))
-      : (c <= 42650 || (c < 65313
-        ? (c < 42896
-          ? (c < 42873
-            ? (c >= 42786 && c <= 42862)
-            : (c <= 42886 || (c >= 42891 && c <= 42893)))
-          : (c <= 42953 || (c < 42966
-            ? c == 42960
-            : (c <= 42968 || c == 42997))))
-        : (c <= 65338 || (c < 66940
-          ? (c < 66736
-            ? (c >= 66560 && c <= 66599)
-            : (c <= 66771 || (c >= 66928 && c <= 66938)))
-          : (c <= 66954 || (c < 66964
-            ? (c >= 66956 && c <= 66962)
-            : c <= 66965)))))))));
+                  ? (c < 65664
+                    ? (c >= 65616 && c <= 65629)
+                    : (c <= 65786 || (c >= 65847 && c <= 65855)))
+                  : (c <= 65929 || ((c >= 65932 && c <= 65934) || (c >= 65936 && c <= 65948))))
+                : (c == 65952 || (c < 66208
+                  ? ((c >= 66000 && c <= 66044) || (c >= 66176 && c <= 66204))
+                  : (c <= 66256 || ((c >= 66304 && c <= 66335) || (c >= 66349 && c <= 66368))))))))
+            : (c <= 66377 || (c < 66956
+              ? (c < 66736
+                ? (c < 66504
+                  ? (c < 66432
+                    ? (c >= 66384 && c <= 66421)
+                    : (c <= 66461 || (c >= 66464 && c <= 66499)))
+                  : (c <= 66511 || ((c >= 66560 && c <= 66717) || (c >= 66720 && c <= 66729))))
+                : (c <= 66771 || (c < 66864
+                  ? ((c >= 66776 && c <= 66811) || (c >= 66816 && c <= 66855))
+                  : (c <= 66915 || ((c >= 66928 && c <= 66938) || (c >= 66940 && c <= 66954))))))
+              : (c <= 66962 || (c < 67072
+                ? (c < 66979
+                  ? ((c >= 66964 && c <= 66965) || (c >= 66967 && c <= 66977))
+                  : (c <= 66993 || ((c >= 66995 && c <= 67001) || (c >= 67003 && c <= 67004))))
+                : (c <= 67382 || (c < 67456
+                  ? ((c >= 67392 && c <= 67413) || (c >= 67424 && c <= 67431))
+                  : (c <= 67461 || ((c >= 67463 && c <= 67504) || (c >= 67506 && c <= 67514))))))))))))
+        : (c <= 67589 || (c < 70006
+          ? (c < 68736
+            ? (c < 68030
+              ? (c < 67712
+                ? (c < 67644
+                  ? (c < 67594
+                    ? c == 67592
+                    : (c <= 67637 || (c >= 67639 && c <= 67640)))
+                  : (c == 67644 || ((c >= 67647 && c <= 67669) || (c >= 67680 && c <= 67704))))
+                : (c <= 67742 || (c < 67840
+                  ? ((c >= 67808 && c <= 67826) || (c >= 67828 && c <= 67829))
+                  : (c <= 67861 || ((c >= 67872 && c <= 67897) || (c >= 67968 && c <= 68023))))))
+              : (c <= 68031 || (c < 68288
+                ? (c < 68121
+                  ? (c < 68112
+                    ? c == 68096
+                    : (c <= 68115 || (c >= 68117 && c <= 68119)))
+                  : (c <= 68149 || ((c >= 68192 && c <= 68220) || (c >= 68224 && c <= 68252))))
+                : (c <= 68324 || (c < 68448
+                  ? ((c >= 68352 && c <= 68405) || (c >= 68416 && c <= 68437))
+                  : (c <= 68466 || ((c >= 68480 && c <= 68497) || (c >= 68608 && c <= 68680))))))))
+            : (c <= 68786 || (c < 69635
+              ? (c < 69376
+                ? (c < 69248
+                  ? (c < 68864
+                    ? (c >= 68800 && c <= 68850)
+                    : (c <= 68899 || (c >= 68912 && c <= 68921)))
+                  : (c <= 69289 || (c == 69293 || (c >= 69296 && c <= 69297))))
+                : (c <= 69404 || (c < 69488
+                  ? (c == 69415 || (c >= 69424 && c <= 69445))
+                  : (c <= 69505 || ((c >= 69552 && c <= 69572) || (c >= 69600 && c <= 69622))))))
+              : (c <= 69687 || (c < 69872
+                ? (c < 69749
+                  ? ((c >= 69734 && c <= 69743) || (c >= 69745 && c <= 69746))
+                  : (c == 69749 || ((c >= 69763 && c <= 69807) || (c >= 69840 && c <= 69864))))
+                : (c <= 69881 || (c < 69956
+                  ? ((c >= 69891 && c <= 69926) || (c >= 69942 && c <= 69951))
+                  : (c == 69956 || (c == 69959 || (c >= 69968 && c <= 70002))))))))))
+          : (c == 70006 || (c < 70751
+            ? (c < 70384
+              ? (c < 70272
+                ? (c < 70108
+                  ? (c < 70081
+                    ? (c >= 70019 && c <= 70066)
+                    : (c <= 70084 || (c >= 70096 && c <= 70106)))
+                  : (c == 70108 || ((c >= 70144 && c <= 70161) || (c >= 70163 && c <= 70187))))
+                : (c <= 70278 || (c < 70287
+                  ? (c == 70280 || (c >= 70282 && c <= 70285))
+                  : (c <= 70301 || ((c >= 70303 && c <= 70312) || (c >= 70320 && c <= 70366))))))
+              : (c <= 70393 || (c < 70461
+                ? (c < 70442
+                  ? (c < 70415
+                    ? (c >= 70405 && c <= 70412)
+                    : (c <= 70416 || (c >= 70419 && c <= 70440)))
+                  : (c <= 70448 || ((c >= 70450 && c <= 70451) || (c >= 70453 && c <= 70457))))
+                : (c == 70461 || (c < 70656
+                  ? (c == 70480 || (c >= 70493 && c <= 70497))
+                  : (c <= 70708 || ((c >= 70727 && c <= 70730) || (c >= 70736 && c <= 70745))))))))
+            : (c <= 70753 || (c < 71424
+              ? (c < 71168
+                ? (c < 70864
+                  ? (c < 70852
+                    ? (c >= 70784 && c <= 70831)
+                    : (c <= 70853 || c == 70855))
+                  : (c <= 70873 || ((c >= 71040 && c <= 71086) || (c >= 71128 && c <= 71131))))
+                : (c <= 71215 || (c < 71296
+                  ? (c == 71236 || (c >= 71248 && c <= 71257))
+                  : (c <= 71338 || (c == 71352 || (c >= 71360 && c <= 71369))))))
+              : (c <= 71450 || (c < 71945
+                ? (c < 71680
+                  ? ((c >= 71472 && c <= 71481) || (c >= 71487 && c <= 71494))
+                  : (c <= 71723 || ((c >= 71840 && c <= 71913) || (c >= 71935 && c <= 71942))))
+                : (c == 71945 || (c < 71960
+                  ? ((c >= 71948 && c <= 71955) || (c >= 71957 && c <= 71958))
+                  : (c <= 71983 || (c == 71999 || c == 72001)))))))))))))
+      : (c <= 72025 || (c < 121462
+        ? (c < 94176
+          ? (c < 73120
+            ? (c < 72714
+              ? (c < 72250
+                ? (c < 72163
+                  ? (c < 72106
+                    ? (c >= 72096 && c <= 72103)
+                    : (c <= 72144 || c == 72161))
+                  : (c == 72163 || (c == 72192 || (c >= 72203 && c <= 72242))))
+                : (c == 72250 || (c < 72349
+                  ? (c == 72272 || (c >= 72284 && c <= 72329))
+                  : (c == 72349 || ((c >= 72368 && c <= 72440) || (c >= 72704 && c <= 72712))))))
+              : (c <= 72750 || (c < 73030
+                ? (c < 72960
+                  ? (c < 72784
+                    ? c == 72768
+                    : (c <= 72793 || (c >= 72818 && c <= 72847)))
+                  : (c <= 72966 || ((c >= 72968 && c <= 72969) || (c >= 72971 && c <= 73008))))
+                : (c == 73030 || (c < 73063
+                  ? ((c >= 73040 && c <= 73049) || (c >= 73056 && c <= 73061))
+                  : (c <= 73064 || ((c >= 73066 && c <= 73097) || c == 73112)))))))
+            : (c <= 73129 || (c < 92864
+              ? (c < 77824
+                ? (c < 73728
+                  ? (c < 73648
+                    ? (c >= 73440 && c <= 73458)
+                    : (c == 73648 || (c >= 73685 && c <= 73713)))
+                  : (c <= 74649 || ((c >= 74880 && c <= 75075) || (c >= 77712 && c <= 77808))))
+                : (c <= 78894 || (c < 92736
+                  ? ((c >= 82944 && c <= 83526) || (c >= 92160 && c <= 92728))
+                  : (c <= 92766 || ((c >= 92768 && c <= 92777) || (c >= 92784 && c <= 92862))))))
+              : (c <= 92873 || (c < 93027
+                ? (c < 92988
+                  ? ((c >= 92880 && c <= 92909) || (c >= 92928 && c <= 92975))
+                  : (c <= 92995 || (c == 92997 || (c >= 93008 && c <= 93017))))
+                : (c <= 93047 || (c < 93952
+                  ? ((c >= 93053 && c <= 93071) || (c >= 93760 && c <= 93823))
+                  : (c <= 94026 || (c == 94032 || (c >= 94099 && c <= 94111))))))))))
+          : (c <= 94177 || (c < 119365
+            ? (c < 113776
+              ? (c < 110589
+                ? (c < 101632
+                  ? (c < 94208
+                    ? c == 94179
+                    : (c <= 100343 || (c >= 100352 && c <= 101589)))
+                  : (c <= 101640 || ((c >= 110576 && c <= 110579) || (c >= 110581 && c <= 110587))))
+                : (c <= 110590 || (c < 110948
+                  ? ((c >= 110592 && c <= 110882) || (c >= 110928 && c <= 110930))
+                  : (c <= 110951 || ((c >= 110960 && c <= 111355) || (c >= 113664 && c <= 113770))))))
+              : (c <= 113788 || (c < 119081
+                ? (c < 118608
+                  ? (c < 113808
+                    ? (c >= 113792 && c <= 113800)
+                    : (c <= 113817 || c == 113820))
+                  : (c <= 118723 || ((c >= 118784 && c <= 119029) || (c >= 119040 && c <= 119078))))
+                : (c <= 119140 || (c < 119180
+                  ? ((c >= 119146 && c <= 119148) || (c >= 119171 && c <= 119172))
+                  : (c <= 119209 || ((c >= 119214 && c <= 119274) || (c >= 119296 && c <= 119361))))))))
+            : (c == 119365 || (c < 120077
+              ? (c < 119977
+                ? (c < 119966
+                  ? (c < 119808
+                    ? (c >= 119552 && c <= 119638)
+                    : (c <= 119892 || (c >= 119894 && c <= 119964)))
+                  : (c <= 119967 || (c == 119970 || (c >= 119973 && c <= 119974))))
+                : (c <= 119980 || (c < 119997
+                  ? ((c >= 119982 && c <= 119993) || c == 119995)
+                  : (c <= 120003 || ((c >= 120005 && c <= 120069) || (c >= 120071 && c <= 120074))))))
+              : (c <= 120084 || (c < 120138
+                ? (c < 120123
+                  ? ((c >= 120086 && c <= 120092) || (c >= 120094 && c <= 120121))
+                  : (c <= 120126 || ((c >= 120128 && c <= 120132) || c == 120134)))
+                : (c <= 120144 || (c < 120782
+                  ? ((c >= 120146 && c <= 120485) || (c >= 120488 && c <= 120779))
+                  : (c <= 121343 || ((c >= 121399 && c <= 121402) || (c >= 121453 && c <= 121460))))))))))))
+        : (c <= 121475 || (c < 126603
+          ? (c < 126503
+            ? (c < 124909
+              ? (c < 123536
+                ? (c < 123191
+                  ? (c < 122624
+                    ? (c >= 121477 && c <= 121478)
+                    : (c <= 122654 || (c >= 123136 && c <= 123180)))
+                  : (c <= 123197 || ((c >= 123200 && c <= 123209) || (c >= 123214 && c <= 123215))))
+                : (c <= 123565 || (c < 123647
+                  ? ((c >= 123584 && c <= 123627) || (c >= 123632 && c <= 123641))
+                  : (c == 123647 || ((c >= 124896 && c <= 124902) || (c >= 124904 && c <= 124907))))))
+              : (c <= 124910 || (c < 126128
+                ? (c < 125259
+                  ? (c < 124928
+                    ? (c >= 124912 && c <= 124926)
+                    : (c <= 125124 || (c >= 125184 && c <= 125251)))
+                  : (c == 125259 || ((c >= 125264 && c <= 125273) || c == 126124)))
+                : (c == 126128 || (c < 126469
+                  ? (c == 126254 || (c >= 126464 && c <= 126467))
+                  : (c <= 126495 || ((c >= 126497 && c <= 126498) || c == 126500)))))))
+            : (c == 126503 || (c < 126553
+              ? (c < 126537
+                ? (c < 126523
+                  ? (c < 126516
+                    ? (c >= 126505 && c <= 126514)
+                    : (c <= 126519 || c == 126521))
+                  : (c == 126523 || (c == 126530 || c == 126535)))
+                : (c == 126537 || (c < 126545
+                  ? (c == 126539 || (c >= 126541 && c <= 126543))
+                  : (c <= 126546 || (c == 126548 || c == 126551)))))
+              : (c == 126553 || (c < 126567
+                ? (c < 126559
+                  ? (c == 126555 || c == 126557)
+                  : (c == 126559 || ((c >= 126561 && c <= 126562) || c == 126564)))
+                : (c <= 126570 || (c < 126585
+                  ? ((c >= 126572 && c <= 126578) || (c >= 126580 && c <= 126583))
+                  : (c <= 126588 || (c == 126590 || (c >= 126592 && c <= 126601))))))))))
+          : (c <= 126619 || (c < 129104
+            ? (c < 127504
+              ? (c < 127136
+                ? (c < 126704
+                  ? (c < 126629
+                    ? (c >= 126625 && c <= 126627)
+                    : (c <= 126633 || (c >= 126635 && c <= 126651)))
+                  : (c <= 126705 || ((c >= 126976 && c <= 127019) || (c >= 127024 && c <= 127123))))
+                : (c <= 127150 || (c < 127185
+                  ? ((c >= 127153 && c <= 127167) || (c >= 127169 && c <= 127183))
+                  : (c <= 127221 || ((c >= 127245 && c <= 127405) || (c >= 127462 && c <= 127490))))))
+              : (c <= 127547 || (c < 128768
+                ? (c < 127744
+                  ? (c < 127568
+                    ? (c >= 127552 && c <= 127560)
+                    : (c <= 127569 || (c >= 127584 && c <= 127589)))
+                  : (c <= 128727 || ((c >= 128733 && c <= 128748) || (c >= 128752 && c <= 128764))))
+                : (c <= 128883 || (c < 129008
+                  ? ((c >= 128896 && c <= 128984) || (c >= 128992 && c <= 129003))
+                  : (c == 129008 || ((c >= 129024 && c <= 129035) || (c >= 129040 && c <= 129095))))))))
+            : (c <= 129113 || (c < 129760
+              ? (c < 129656
+                ? (c < 129280
+                  ? (c < 129168
+                    ? (c >= 129120 && c <= 129159)
+                    : (c <= 129197 || (c >= 129200 && c <= 129201)))
+                  : (c <= 129619 || ((c >= 129632 && c <= 129645) || (c >= 129648 && c <= 129652))))
+                : (c <= 129660 || (c < 129712
+                  ? ((c >= 129664 && c <= 129670) || (c >= 129680 && c <= 129708))
+                  : (c <= 129722 || ((c >= 129728 && c <= 129733) || (c >= 129744 && c <= 129753))))))
+              : (c <= 129767 || (c < 173824
+                ? (c < 129940
+                  ? ((c >= 129776 && c <= 129782) || (c >= 129792 && c <= 129938))
+                  : (c <= 129994 || ((c >= 130032 && c <= 130041) || (c >= 131072 && c <= 173791))))
+                : (c <= 177976 || (c < 183984
+                  ? ((c >= 177984 && c <= 178205) || (c >= 178208 && c <= 183969))
+                  : (c <= 191456 || ((c >= 194560 && c <= 195101) || (c >= 196608 && c <= 201546))))))))))))))))));
 }
 
 static bool ts_lex(TSLexer *lexer, TSStateId state) {
@@ -1902,577 +3541,9692 @@ static bool ts_lex(TSLexer *lexer, TSStateId state) {
   eof = lexer->eof(lexer);
   switch (state) {
     case 0:
-      if (eof) ADVANCE(3);
+      if (eof) ADVANCE(13);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\f' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(0)
-      if (lookahead == '\'') ADVANCE(8);
-      if (lookahead == '(') ADVANCE(4);
-      if (lookahead == ')') ADVANCE(6);
-      if (lookahead == ';') ADVANCE(28);
-      if (lookahead == '\\') ADVANCE(9);
-      if (lookahead == 'c') ADVANCE(22);
-      if (lookahead == 'd') ADVANCE(17);
-      if (lookahead == 'l') ADVANCE(12);
-      if (lookahead == 955) ADVANCE(10);
-      if (sym_identifier_character_set_1(lookahead)) ADVANCE(26);
-      if (sym_type_identifier_character_set_1(lookahead)) ADVANCE(27);
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(17);
+      if (lookahead == '-') ADVANCE(18);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ':') ADVANCE(20);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == '=') ADVANCE(22);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'E') ADVANCE(24);
+      if (lookahead == 'L') ADVANCE(25);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'P') ADVANCE(27);
+      if (lookahead == 'S') ADVANCE(28);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'V') ADVANCE(31);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(33);
+      if (lookahead == 'c') ADVANCE(34);
+      if (lookahead == 'd') ADVANCE(35);
+      if (lookahead == 'h') ADVANCE(36);
+      if (lookahead == 'i') ADVANCE(37);
+      if (lookahead == 'l') ADVANCE(38);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(40);
+      if (lookahead == 's') ADVANCE(41);
+      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'v') ADVANCE(43);
+      if (lookahead == 'w') ADVANCE(44);
+      if (lookahead == 'z') ADVANCE(45);
+      if (lookahead == 928) ADVANCE(46);
+      if (lookahead == 931) ADVANCE(47);
+      if (lookahead == 955) ADVANCE(48);
+      if (lookahead == 8594) ADVANCE(49);
+      if (sym_character_set_1(lookahead)) ADVANCE(14);
       END_STATE();
     case 1:
+      if (eof) ADVANCE(13);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\f' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(1)
-      if (lookahead == '\'') ADVANCE(8);
-      if (lookahead == '(') ADVANCE(4);
-      if (lookahead == ';') ADVANCE(28);
-      if (lookahead == '\\') ADVANCE(9);
-      if (lookahead == 'l') ADVANCE(12);
-      if (lookahead == 955) ADVANCE(10);
-      if (sym_identifier_character_set_1(lookahead)) ADVANCE(26);
-      if (sym_type_identifier_character_set_1(lookahead)) ADVANCE(27);
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 's') ADVANCE(50);
+      if (lookahead == 'v') ADVANCE(51);
+      if (lookahead == 'z') ADVANCE(45);
+      if (sym_character_set_2(lookahead)) ADVANCE(14);
       END_STATE();
     case 2:
-      if (eof) ADVANCE(3);
       if (lookahead == '\t' ||
           lookahead == '\n' ||
           lookahead == '\f' ||
           lookahead == '\r' ||
           lookahead == ' ') SKIP(2)
-      if (lookahead == '\'') ADVANCE(8);
-      if (lookahead == '(') ADVANCE(4);
-      if (lookahead == ')') ADVANCE(6);
-      if (lookahead == ';') ADVANCE(28);
-      if (sym_identifier_character_set_1(lookahead)) ADVANCE(26);
-      if (sym_type_identifier_character_set_1(lookahead)) ADVANCE(27);
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == '-') ADVANCE(18);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ':') ADVANCE(20);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == '=') ADVANCE(22);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'E') ADVANCE(24);
+      if (lookahead == 'L') ADVANCE(25);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'P') ADVANCE(27);
+      if (lookahead == 'S') ADVANCE(28);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'V') ADVANCE(31);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(33);
+      if (lookahead == 'c') ADVANCE(34);
+      if (lookahead == 'd') ADVANCE(35);
+      if (lookahead == 'h') ADVANCE(36);
+      if (lookahead == 'i') ADVANCE(37);
+      if (lookahead == 'l') ADVANCE(38);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(40);
+      if (lookahead == 's') ADVANCE(41);
+      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'v') ADVANCE(43);
+      if (lookahead == 'w') ADVANCE(44);
+      if (lookahead == 'z') ADVANCE(45);
+      if (lookahead == 928) ADVANCE(46);
+      if (lookahead == 931) ADVANCE(47);
+      if (lookahead == 955) ADVANCE(48);
+      if (lookahead == 8594) ADVANCE(49);
+      if (sym_character_set_3(lookahead)) ADVANCE(14);
       END_STATE();
     case 3:
-      ACCEPT_TOKEN(ts_builtin_sym_end);
+      if (eof) ADVANCE(13);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(3)
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(17);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 's') ADVANCE(50);
+      if (lookahead == 'v') ADVANCE(51);
+      if (lookahead == 'z') ADVANCE(45);
+      if (sym_character_set_2(lookahead)) ADVANCE(14);
       END_STATE();
     case 4:
-      ACCEPT_TOKEN(anon_sym_LPAREN);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(4)
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 's') ADVANCE(50);
+      if (lookahead == 'v') ADVANCE(51);
+      if (lookahead == 'z') ADVANCE(45);
+      if (sym_character_set_2(lookahead)) ADVANCE(14);
       END_STATE();
     case 5:
-      ACCEPT_TOKEN(anon_sym_claim);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(5)
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == '-') ADVANCE(18);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ':') ADVANCE(20);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == '=') ADVANCE(22);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'E') ADVANCE(24);
+      if (lookahead == 'L') ADVANCE(25);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'P') ADVANCE(27);
+      if (lookahead == 'S') ADVANCE(28);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'V') ADVANCE(31);
+      if (lookahead == '\\') ADVANCE(32);
+      if (lookahead == 'a') ADVANCE(33);
+      if (lookahead == 'c') ADVANCE(52);
+      if (lookahead == 'h') ADVANCE(36);
+      if (lookahead == 'i') ADVANCE(37);
+      if (lookahead == 'l') ADVANCE(38);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 'r') ADVANCE(40);
+      if (lookahead == 's') ADVANCE(41);
+      if (lookahead == 't') ADVANCE(42);
+      if (lookahead == 'v') ADVANCE(43);
+      if (lookahead == 'w') ADVANCE(44);
+      if (lookahead == 'z') ADVANCE(45);
+      if (lookahead == 928) ADVANCE(46);
+      if (lookahead == 931) ADVANCE(47);
+      if (lookahead == 955) ADVANCE(48);
+      if (lookahead == 8594) ADVANCE(49);
+      if (sym_character_set_3(lookahead)) ADVANCE(14);
       END_STATE();
     case 6:
-      ACCEPT_TOKEN(anon_sym_RPAREN);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(6)
+      if (lookahead == '\'') ADVANCE(15);
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(17);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
+      if (lookahead == ';') ADVANCE(21);
+      if (lookahead == 'A') ADVANCE(23);
+      if (lookahead == 'N') ADVANCE(26);
+      if (lookahead == 'T') ADVANCE(29);
+      if (lookahead == 'U') ADVANCE(30);
+      if (lookahead == 'n') ADVANCE(39);
+      if (lookahead == 's') ADVANCE(50);
+      if (lookahead == 'v') ADVANCE(51);
+      if (lookahead == 'z') ADVANCE(45);
+      if (sym_character_set_2(lookahead)) ADVANCE(14);
       END_STATE();
     case 7:
-      ACCEPT_TOKEN(anon_sym_define);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (eof) ADVANCE(13);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(7)
+      if (lookahead == ';') ADVANCE(21);
       END_STATE();
     case 8:
-      ACCEPT_TOKEN(anon_sym_SQUOTE);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(8)
+      if (lookahead == ';') ADVANCE(21);
+      if (sym_character_set_4(lookahead)) ADVANCE(14);
       END_STATE();
     case 9:
-      ACCEPT_TOKEN(anon_sym_BSLASH);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(9)
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == ';') ADVANCE(21);
       END_STATE();
     case 10:
-      ACCEPT_TOKEN(anon_sym_);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(10)
+      if (lookahead == ')') ADVANCE(17);
+      if (lookahead == ';') ADVANCE(21);
+      if (sym_character_set_4(lookahead)) ADVANCE(14);
       END_STATE();
     case 11:
-      ACCEPT_TOKEN(anon_sym_lambda);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(11)
+      if (lookahead == ')') ADVANCE(17);
+      if (lookahead == ';') ADVANCE(21);
       END_STATE();
     case 12:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'a') ADVANCE(23);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == '\t' ||
+          lookahead == '\n' ||
+          lookahead == '\f' ||
+          lookahead == '\r' ||
+          lookahead == ' ') SKIP(12)
+      if (lookahead == '(') ADVANCE(16);
+      if (lookahead == ')') ADVANCE(17);
+      if (lookahead == ';') ADVANCE(21);
       END_STATE();
     case 13:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'a') ADVANCE(21);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(ts_builtin_sym_end);
       END_STATE();
     case 14:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'a') ADVANCE(11);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 15:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'b') ADVANCE(16);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(anon_sym_SQUOTE);
       END_STATE();
     case 16:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'd') ADVANCE(14);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(anon_sym_LPAREN);
       END_STATE();
     case 17:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(19);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(anon_sym_RPAREN);
       END_STATE();
     case 18:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'e') ADVANCE(7);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == '>') ADVANCE(53);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 19:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'f') ADVANCE(20);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(sym_number);
+      if ((lookahead >= '0' && lookahead <= '9')) ADVANCE(19);
       END_STATE();
     case 20:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'i') ADVANCE(25);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == ':') ADVANCE(54);
       END_STATE();
     case 21:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'i') ADVANCE(24);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(sym_comment);
+      if ((lookahead >= 1 && lookahead <= '\t') ||
+          (lookahead >= '\v' && lookahead <= 1114111)) ADVANCE(21);
       END_STATE();
     case 22:
-      ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'l') ADVANCE(13);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      ACCEPT_TOKEN(anon_sym_EQ);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 23:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'm') ADVANCE(15);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == 'b') ADVANCE(55);
+      if (lookahead == 't') ADVANCE(56);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 24:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'm') ADVANCE(5);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == 'i') ADVANCE(57);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 25:
       ACCEPT_TOKEN(sym_identifier);
-      if (lookahead == 'n') ADVANCE(18);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == 'i') ADVANCE(58);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 26:
       ACCEPT_TOKEN(sym_identifier);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(26);
+      if (lookahead == 'a') ADVANCE(59);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 27:
-      ACCEPT_TOKEN(sym_type_identifier);
-      if (sym_identifier_character_set_2(lookahead)) ADVANCE(27);
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(60);
+      if (lookahead == 'i') ADVANCE(61);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
     case 28:
-      ACCEPT_TOKEN(sym_comment);
-      if (lookahead != 0 &&
-          lookahead != '\n') ADVANCE(28);
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(62);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
       END_STATE();
-    default:
-      return false;
-  }
-}
-
-static const TSLexMode ts_lex_modes[STATE_COUNT] = {
-  [0] = {.lex_state = 0},
-  [1] = {.lex_state = 2},
-  [2] = {.lex_state = 0},
-  [3] = {.lex_state = 2},
-  [4] = {.lex_state = 2},
-  [5] = {.lex_state = 1},
-  [6] = {.lex_state = 2},
-  [7] = {.lex_state = 2},
-  [8] = {.lex_state = 2},
-  [9] = {.lex_state = 2},
-  [10] = {.lex_state = 2},
-  [11] = {.lex_state = 2},
-  [12] = {.lex_state = 2},
-  [13] = {.lex_state = 2},
-  [14] = {.lex_state = 2},
-  [15] = {.lex_state = 2},
-  [16] = {.lex_state = 2},
-  [17] = {.lex_state = 2},
-  [18] = {.lex_state = 2},
-  [19] = {.lex_state = 2},
-  [20] = {.lex_state = 2},
-  [21] = {.lex_state = 2},
-  [22] = {.lex_state = 2},
-  [23] = {.lex_state = 0},
-  [24] = {.lex_state = 0},
-  [25] = {.lex_state = 2},
-  [26] = {.lex_state = 0},
-  [27] = {.lex_state = 0},
-  [28] = {.lex_state = 0},
-  [29] = {.lex_state = 2},
-  [30] = {.lex_state = 0},
-  [31] = {.lex_state = 2},
-};
-
-static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
-  [0] = {
-    [ts_builtin_sym_end] = ACTIONS(1),
-    [anon_sym_LPAREN] = ACTIONS(1),
-    [anon_sym_claim] = ACTIONS(1),
-    [anon_sym_RPAREN] = ACTIONS(1),
-    [anon_sym_define] = ACTIONS(1),
-    [anon_sym_SQUOTE] = ACTIONS(1),
-    [anon_sym_BSLASH] = ACTIONS(1),
-    [anon_sym_] = ACTIONS(1),
-    [anon_sym_lambda] = ACTIONS(1),
-    [sym_identifier] = ACTIONS(1),
-    [sym_type_identifier] = ACTIONS(1),
-    [sym_comment] = ACTIONS(3),
-  },
-  [1] = {
-    [sym_source] = STATE(24),
-    [sym_claim] = STATE(3),
-    [sym_define] = STATE(3),
-    [sym_expression] = STATE(3),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_source_repeat1] = STATE(3),
-    [ts_builtin_sym_end] = ACTIONS(5),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+    case 29:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'O') ADVANCE(63);
+      if (lookahead == 'r') ADVANCE(64);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 30:
+      ACCEPT_TOKEN(sym_universe);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 31:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(65);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 32:
+      ACCEPT_TOKEN(anon_sym_BSLASH);
+      END_STATE();
+    case 33:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(66);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 34:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(67);
+      if (lookahead == 'd') ADVANCE(68);
+      if (lookahead == 'h') ADVANCE(69);
+      if (lookahead == 'l') ADVANCE(70);
+      if (lookahead == 'o') ADVANCE(71);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 35:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(72);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 36:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(73);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 37:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(74);
+      if (lookahead == 't') ADVANCE(75);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 38:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(76);
+      if (lookahead == 'e') ADVANCE(77);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 39:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(78);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 40:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(79);
+      if (lookahead == 'i') ADVANCE(80);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 41:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(81);
+      if (lookahead == 'o') ADVANCE(82);
+      if (lookahead == 'y') ADVANCE(83);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 42:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(84);
+      if (lookahead == 'h') ADVANCE(85);
+      if (lookahead == 'r') ADVANCE(86);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 43:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(87);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 44:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(88);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 45:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(89);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 46:
+      ACCEPT_TOKEN(anon_sym_3);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 47:
+      ACCEPT_TOKEN(anon_sym_4);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 48:
+      ACCEPT_TOKEN(anon_sym_);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 49:
+      ACCEPT_TOKEN(anon_sym_2);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 50:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(82);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 51:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(90);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 52:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(67);
+      if (lookahead == 'd') ADVANCE(68);
+      if (lookahead == 'o') ADVANCE(71);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 53:
+      ACCEPT_TOKEN(anon_sym_DASH_GT);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 54:
+      ACCEPT_TOKEN(anon_sym_COLON_COLON);
+      END_STATE();
+    case 55:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(91);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 56:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(92);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 57:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(93);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 58:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(94);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 59:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(95);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 60:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(96);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 61:
+      ACCEPT_TOKEN(anon_sym_Pi);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 62:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'g') ADVANCE(97);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 63:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'D') ADVANCE(98);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 64:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(99);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 65:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(100);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 66:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(101);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 67:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(102);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 68:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(103);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 69:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(104);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 70:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(105);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 71:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(106);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 72:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'f') ADVANCE(107);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 73:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(108);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 74:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(109);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 75:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(110);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 76:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(111);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 77:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'f') ADVANCE(112);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 78:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(113);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 79:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(114);
+      if (lookahead == 'p') ADVANCE(115);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 80:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'g') ADVANCE(116);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 81:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(117);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 82:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(118);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 83:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(119);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 84:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(120);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 85:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(121);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 86:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(122);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 87:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(123);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 88:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(124);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 89:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(125);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 90:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(126);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 91:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(127);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 92:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(128);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 93:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(129);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 94:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(130);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 95:
+      ACCEPT_TOKEN(anon_sym_Nat);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 96:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(131);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 97:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(132);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 98:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'O') ADVANCE(133);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 99:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'v') ADVANCE(134);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 100:
+      ACCEPT_TOKEN(anon_sym_Vec);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 101:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '1') ADVANCE(135);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 102:
+      ACCEPT_TOKEN(anon_sym_car);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 103:
+      ACCEPT_TOKEN(anon_sym_cdr);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 104:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(136);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 105:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(137);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 106:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'g') ADVANCE(138);
+      if (lookahead == 's') ADVANCE(139);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 107:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(140);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 108:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(141);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 109:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(142);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 110:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(143);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 111:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'b') ADVANCE(144);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 112:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(145);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 113:
+      ACCEPT_TOKEN(sym_nil);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 114:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(146);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 115:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(147);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 116:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(148);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 117:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(149);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 118:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(150);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 119:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(151);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 120:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(152);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 121:
+      ACCEPT_TOKEN(anon_sym_the);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 122:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(153);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 123:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == ':') ADVANCE(154);
+      if (lookahead == 'n') ADVANCE(155);
+      if (sym_character_set_6(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 124:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(156);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 125:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'o') ADVANCE(157);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 126:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(155);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 127:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(158);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 128:
+      ACCEPT_TOKEN(anon_sym_Atom);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 129:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(159);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 130:
+      ACCEPT_TOKEN(anon_sym_List);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 131:
+      ACCEPT_TOKEN(anon_sym_Pair);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 132:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(160);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 133:
+      ACCEPT_TOKEN(sym_todo);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 134:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(161);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 135:
+      ACCEPT_TOKEN(anon_sym_add1);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 136:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'k') ADVANCE(162);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 137:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(163);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 138:
+      ACCEPT_TOKEN(anon_sym_cong);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 139:
+      ACCEPT_TOKEN(anon_sym_cons);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 140:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'n') ADVANCE(164);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 141:
+      ACCEPT_TOKEN(anon_sym_head);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 142:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'A') ADVANCE(165);
+      if (lookahead == 'E') ADVANCE(166);
+      if (lookahead == 'L') ADVANCE(167);
+      if (lookahead == 'N') ADVANCE(168);
+      if (lookahead == 'V') ADVANCE(169);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 143:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(170);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 144:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(171);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 145:
+      ACCEPT_TOKEN(anon_sym_left);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 146:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'L') ADVANCE(172);
+      if (lookahead == 'N') ADVANCE(173);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 147:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(174);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 148:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(175);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 149:
+      ACCEPT_TOKEN(anon_sym_same);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 150:
+      ACCEPT_TOKEN(sym_sole);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 151:
+      ACCEPT_TOKEN(anon_sym_symm);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 152:
+      ACCEPT_TOKEN(anon_sym_tail);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 153:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(176);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 154:
+      if (lookahead == ':') ADVANCE(177);
+      END_STATE();
+    case 155:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(178);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 156:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(179);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 157:
+      ACCEPT_TOKEN(sym_zero);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 158:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(180);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 159:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(181);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 160:
+      ACCEPT_TOKEN(anon_sym_Sigma);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 161:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(182);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 162:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(183);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 163:
+      ACCEPT_TOKEN(anon_sym_claim);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 164:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(184);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 165:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'b') ADVANCE(185);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 166:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(186);
+      if (lookahead == 'q') ADVANCE(187);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 167:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(188);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 168:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(189);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 169:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(190);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 170:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'N') ADVANCE(191);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 171:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(192);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 172:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'i') ADVANCE(193);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 173:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(194);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 174:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(195);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 175:
+      ACCEPT_TOKEN(anon_sym_right);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 176:
+      ACCEPT_TOKEN(anon_sym_trans);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 177:
+      ACCEPT_TOKEN(anon_sym_vec_COLON_COLON);
+      END_STATE();
+    case 178:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(196);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 179:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == '-') ADVANCE(197);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 180:
+      ACCEPT_TOKEN(anon_sym_Absurd);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 181:
+      ACCEPT_TOKEN(anon_sym_Either);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 182:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'l') ADVANCE(198);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 183:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(199);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 184:
+      ACCEPT_TOKEN(anon_sym_define);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 185:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(200);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 186:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(201);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 187:
+      ACCEPT_TOKEN(anon_sym_ind_DASHEq);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 188:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(202);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 189:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(203);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 190:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'c') ADVANCE(204);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 191:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(205);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 192:
+      ACCEPT_TOKEN(anon_sym_lambda);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 193:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 's') ADVANCE(206);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 194:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(207);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 195:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(208);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 196:
+      ACCEPT_TOKEN(sym_vecnil);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 197:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'N') ADVANCE(209);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 198:
+      ACCEPT_TOKEN(anon_sym_Trivial);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 199:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(210);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 200:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'u') ADVANCE(211);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 201:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'h') ADVANCE(212);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 202:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(213);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 203:
+      ACCEPT_TOKEN(anon_sym_ind_DASHNat);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 204:
+      ACCEPT_TOKEN(anon_sym_ind_DASHVec);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 205:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(214);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 206:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(215);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 207:
+      ACCEPT_TOKEN(anon_sym_rec_DASHNat);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 208:
+      ACCEPT_TOKEN(anon_sym_replace);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 209:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'a') ADVANCE(216);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 210:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'm') ADVANCE(217);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 211:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(218);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 212:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(219);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 213:
+      ACCEPT_TOKEN(anon_sym_ind_DASHList);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 214:
+      ACCEPT_TOKEN(anon_sym_iter_DASHNat);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 215:
+      ACCEPT_TOKEN(anon_sym_rec_DASHList);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 216:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 't') ADVANCE(220);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 217:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'e') ADVANCE(221);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 218:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'd') ADVANCE(222);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 219:
+      ACCEPT_TOKEN(sym_identifier);
+      if (lookahead == 'r') ADVANCE(223);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 220:
+      ACCEPT_TOKEN(anon_sym_which_DASHNat);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 221:
+      ACCEPT_TOKEN(anon_sym_check_DASHsame);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 222:
+      ACCEPT_TOKEN(anon_sym_ind_DASHAbsurd);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    case 223:
+      ACCEPT_TOKEN(anon_sym_ind_DASHEither);
+      if (sym_character_set_5(lookahead)) ADVANCE(14);
+      END_STATE();
+    default:
+      return false;
+  }
+}
+
+static const TSLexMode ts_lex_modes[STATE_COUNT] = {
+  [0] = {.lex_state = 0},
+  [1] = {.lex_state = 1},
+  [2] = {.lex_state = 1},
+  [3] = {.lex_state = 2},
+  [4] = {.lex_state = 3},
+  [5] = {.lex_state = 3},
+  [6] = {.lex_state = 3},
+  [7] = {.lex_state = 3},
+  [8] = {.lex_state = 3},
+  [9] = {.lex_state = 3},
+  [10] = {.lex_state = 3},
+  [11] = {.lex_state = 3},
+  [12] = {.lex_state = 3},
+  [13] = {.lex_state = 3},
+  [14] = {.lex_state = 3},
+  [15] = {.lex_state = 3},
+  [16] = {.lex_state = 3},
+  [17] = {.lex_state = 3},
+  [18] = {.lex_state = 3},
+  [19] = {.lex_state = 3},
+  [20] = {.lex_state = 3},
+  [21] = {.lex_state = 3},
+  [22] = {.lex_state = 3},
+  [23] = {.lex_state = 3},
+  [24] = {.lex_state = 3},
+  [25] = {.lex_state = 3},
+  [26] = {.lex_state = 3},
+  [27] = {.lex_state = 3},
+  [28] = {.lex_state = 3},
+  [29] = {.lex_state = 3},
+  [30] = {.lex_state = 3},
+  [31] = {.lex_state = 3},
+  [32] = {.lex_state = 3},
+  [33] = {.lex_state = 3},
+  [34] = {.lex_state = 3},
+  [35] = {.lex_state = 3},
+  [36] = {.lex_state = 3},
+  [37] = {.lex_state = 3},
+  [38] = {.lex_state = 3},
+  [39] = {.lex_state = 3},
+  [40] = {.lex_state = 3},
+  [41] = {.lex_state = 3},
+  [42] = {.lex_state = 3},
+  [43] = {.lex_state = 3},
+  [44] = {.lex_state = 3},
+  [45] = {.lex_state = 3},
+  [46] = {.lex_state = 3},
+  [47] = {.lex_state = 3},
+  [48] = {.lex_state = 3},
+  [49] = {.lex_state = 3},
+  [50] = {.lex_state = 3},
+  [51] = {.lex_state = 3},
+  [52] = {.lex_state = 3},
+  [53] = {.lex_state = 3},
+  [54] = {.lex_state = 1},
+  [55] = {.lex_state = 4},
+  [56] = {.lex_state = 5},
+  [57] = {.lex_state = 4},
+  [58] = {.lex_state = 4},
+  [59] = {.lex_state = 4},
+  [60] = {.lex_state = 4},
+  [61] = {.lex_state = 4},
+  [62] = {.lex_state = 4},
+  [63] = {.lex_state = 4},
+  [64] = {.lex_state = 4},
+  [65] = {.lex_state = 4},
+  [66] = {.lex_state = 4},
+  [67] = {.lex_state = 4},
+  [68] = {.lex_state = 4},
+  [69] = {.lex_state = 4},
+  [70] = {.lex_state = 4},
+  [71] = {.lex_state = 4},
+  [72] = {.lex_state = 4},
+  [73] = {.lex_state = 4},
+  [74] = {.lex_state = 4},
+  [75] = {.lex_state = 4},
+  [76] = {.lex_state = 4},
+  [77] = {.lex_state = 4},
+  [78] = {.lex_state = 4},
+  [79] = {.lex_state = 4},
+  [80] = {.lex_state = 4},
+  [81] = {.lex_state = 4},
+  [82] = {.lex_state = 4},
+  [83] = {.lex_state = 4},
+  [84] = {.lex_state = 4},
+  [85] = {.lex_state = 4},
+  [86] = {.lex_state = 4},
+  [87] = {.lex_state = 4},
+  [88] = {.lex_state = 4},
+  [89] = {.lex_state = 4},
+  [90] = {.lex_state = 6},
+  [91] = {.lex_state = 3},
+  [92] = {.lex_state = 4},
+  [93] = {.lex_state = 4},
+  [94] = {.lex_state = 4},
+  [95] = {.lex_state = 4},
+  [96] = {.lex_state = 4},
+  [97] = {.lex_state = 4},
+  [98] = {.lex_state = 4},
+  [99] = {.lex_state = 4},
+  [100] = {.lex_state = 4},
+  [101] = {.lex_state = 4},
+  [102] = {.lex_state = 4},
+  [103] = {.lex_state = 4},
+  [104] = {.lex_state = 4},
+  [105] = {.lex_state = 4},
+  [106] = {.lex_state = 4},
+  [107] = {.lex_state = 4},
+  [108] = {.lex_state = 4},
+  [109] = {.lex_state = 4},
+  [110] = {.lex_state = 4},
+  [111] = {.lex_state = 4},
+  [112] = {.lex_state = 4},
+  [113] = {.lex_state = 4},
+  [114] = {.lex_state = 4},
+  [115] = {.lex_state = 4},
+  [116] = {.lex_state = 4},
+  [117] = {.lex_state = 4},
+  [118] = {.lex_state = 6},
+  [119] = {.lex_state = 3},
+  [120] = {.lex_state = 4},
+  [121] = {.lex_state = 4},
+  [122] = {.lex_state = 4},
+  [123] = {.lex_state = 4},
+  [124] = {.lex_state = 6},
+  [125] = {.lex_state = 3},
+  [126] = {.lex_state = 3},
+  [127] = {.lex_state = 3},
+  [128] = {.lex_state = 4},
+  [129] = {.lex_state = 4},
+  [130] = {.lex_state = 4},
+  [131] = {.lex_state = 4},
+  [132] = {.lex_state = 3},
+  [133] = {.lex_state = 4},
+  [134] = {.lex_state = 4},
+  [135] = {.lex_state = 3},
+  [136] = {.lex_state = 3},
+  [137] = {.lex_state = 4},
+  [138] = {.lex_state = 4},
+  [139] = {.lex_state = 3},
+  [140] = {.lex_state = 4},
+  [141] = {.lex_state = 3},
+  [142] = {.lex_state = 4},
+  [143] = {.lex_state = 3},
+  [144] = {.lex_state = 3},
+  [145] = {.lex_state = 4},
+  [146] = {.lex_state = 3},
+  [147] = {.lex_state = 4},
+  [148] = {.lex_state = 4},
+  [149] = {.lex_state = 4},
+  [150] = {.lex_state = 3},
+  [151] = {.lex_state = 3},
+  [152] = {.lex_state = 4},
+  [153] = {.lex_state = 4},
+  [154] = {.lex_state = 4},
+  [155] = {.lex_state = 4},
+  [156] = {.lex_state = 4},
+  [157] = {.lex_state = 3},
+  [158] = {.lex_state = 3},
+  [159] = {.lex_state = 4},
+  [160] = {.lex_state = 3},
+  [161] = {.lex_state = 4},
+  [162] = {.lex_state = 3},
+  [163] = {.lex_state = 3},
+  [164] = {.lex_state = 4},
+  [165] = {.lex_state = 3},
+  [166] = {.lex_state = 3},
+  [167] = {.lex_state = 3},
+  [168] = {.lex_state = 4},
+  [169] = {.lex_state = 3},
+  [170] = {.lex_state = 3},
+  [171] = {.lex_state = 3},
+  [172] = {.lex_state = 3},
+  [173] = {.lex_state = 3},
+  [174] = {.lex_state = 3},
+  [175] = {.lex_state = 3},
+  [176] = {.lex_state = 3},
+  [177] = {.lex_state = 3},
+  [178] = {.lex_state = 4},
+  [179] = {.lex_state = 3},
+  [180] = {.lex_state = 3},
+  [181] = {.lex_state = 3},
+  [182] = {.lex_state = 3},
+  [183] = {.lex_state = 3},
+  [184] = {.lex_state = 3},
+  [185] = {.lex_state = 3},
+  [186] = {.lex_state = 3},
+  [187] = {.lex_state = 3},
+  [188] = {.lex_state = 3},
+  [189] = {.lex_state = 3},
+  [190] = {.lex_state = 3},
+  [191] = {.lex_state = 3},
+  [192] = {.lex_state = 3},
+  [193] = {.lex_state = 7},
+  [194] = {.lex_state = 8},
+  [195] = {.lex_state = 1},
+  [196] = {.lex_state = 1},
+  [197] = {.lex_state = 1},
+  [198] = {.lex_state = 1},
+  [199] = {.lex_state = 8},
+  [200] = {.lex_state = 8},
+  [201] = {.lex_state = 9},
+  [202] = {.lex_state = 9},
+  [203] = {.lex_state = 9},
+  [204] = {.lex_state = 9},
+  [205] = {.lex_state = 9},
+  [206] = {.lex_state = 9},
+  [207] = {.lex_state = 9},
+  [208] = {.lex_state = 10},
+  [209] = {.lex_state = 10},
+  [210] = {.lex_state = 10},
+  [211] = {.lex_state = 6},
+  [212] = {.lex_state = 9},
+  [213] = {.lex_state = 9},
+  [214] = {.lex_state = 9},
+  [215] = {.lex_state = 9},
+  [216] = {.lex_state = 11},
+  [217] = {.lex_state = 11},
+  [218] = {.lex_state = 11},
+  [219] = {.lex_state = 11},
+  [220] = {.lex_state = 11},
+  [221] = {.lex_state = 11},
+  [222] = {.lex_state = 11},
+  [223] = {.lex_state = 11},
+  [224] = {.lex_state = 11},
+  [225] = {.lex_state = 11},
+  [226] = {.lex_state = 11},
+  [227] = {.lex_state = 11},
+  [228] = {.lex_state = 10},
+  [229] = {.lex_state = 10},
+  [230] = {.lex_state = 10},
+  [231] = {.lex_state = 10},
+  [232] = {.lex_state = 6},
+  [233] = {.lex_state = 6},
+  [234] = {.lex_state = 12},
+  [235] = {.lex_state = 8},
+  [236] = {.lex_state = 12},
+  [237] = {.lex_state = 12},
+  [238] = {.lex_state = 12},
+  [239] = {.lex_state = 12},
+  [240] = {.lex_state = 11},
+  [241] = {.lex_state = 11},
+  [242] = {.lex_state = 11},
+  [243] = {.lex_state = 11},
+  [244] = {.lex_state = 11},
+  [245] = {.lex_state = 11},
+  [246] = {.lex_state = 11},
+  [247] = {.lex_state = 11},
+  [248] = {.lex_state = 11},
+  [249] = {.lex_state = 11},
+  [250] = {.lex_state = 1},
+  [251] = {.lex_state = 1},
+  [252] = {.lex_state = 11},
+  [253] = {.lex_state = 10},
+  [254] = {.lex_state = 11},
+  [255] = {.lex_state = 11},
+  [256] = {.lex_state = 11},
+  [257] = {.lex_state = 12},
+  [258] = {.lex_state = 11},
+  [259] = {.lex_state = 11},
+  [260] = {.lex_state = 11},
+  [261] = {.lex_state = 11},
+  [262] = {.lex_state = 11},
+  [263] = {.lex_state = 11},
+  [264] = {.lex_state = 11},
+  [265] = {.lex_state = 1},
+  [266] = {.lex_state = 11},
+  [267] = {.lex_state = 11},
+  [268] = {.lex_state = 11},
+  [269] = {.lex_state = 11},
+  [270] = {.lex_state = 11},
+  [271] = {.lex_state = 11},
+  [272] = {.lex_state = 11},
+  [273] = {.lex_state = 11},
+  [274] = {.lex_state = 11},
+  [275] = {.lex_state = 11},
+  [276] = {.lex_state = 11},
+  [277] = {.lex_state = 12},
+  [278] = {.lex_state = 11},
+};
+
+static const uint16_t ts_parse_table[LARGE_STATE_COUNT][SYMBOL_COUNT] = {
+  [0] = {
+    [ts_builtin_sym_end] = ACTIONS(1),
+    [anon_sym_LPAREN] = ACTIONS(1),
+    [anon_sym_claim] = ACTIONS(1),
+    [anon_sym_RPAREN] = ACTIONS(1),
+    [anon_sym_define] = ACTIONS(1),
+    [anon_sym_check_DASHsame] = ACTIONS(1),
+    [anon_sym_SQUOTE] = ACTIONS(1),
+    [anon_sym_BSLASH] = ACTIONS(1),
+    [anon_sym_] = ACTIONS(1),
+    [anon_sym_lambda] = ACTIONS(1),
+    [anon_sym_DASH_GT] = ACTIONS(1),
+    [anon_sym_2] = ACTIONS(1),
+    [anon_sym_3] = ACTIONS(1),
+    [anon_sym_Pi] = ACTIONS(1),
+    [anon_sym_4] = ACTIONS(1),
+    [anon_sym_Sigma] = ACTIONS(1),
+    [anon_sym_Pair] = ACTIONS(1),
+    [anon_sym_cons] = ACTIONS(1),
+    [anon_sym_car] = ACTIONS(1),
+    [anon_sym_cdr] = ACTIONS(1),
+    [anon_sym_add1] = ACTIONS(1),
+    [anon_sym_which_DASHNat] = ACTIONS(1),
+    [anon_sym_iter_DASHNat] = ACTIONS(1),
+    [anon_sym_rec_DASHNat] = ACTIONS(1),
+    [anon_sym_ind_DASHNat] = ACTIONS(1),
+    [anon_sym_List] = ACTIONS(1),
+    [anon_sym_COLON_COLON] = ACTIONS(1),
+    [anon_sym_rec_DASHList] = ACTIONS(1),
+    [anon_sym_ind_DASHList] = ACTIONS(1),
+    [anon_sym_Vec] = ACTIONS(1),
+    [anon_sym_vec_COLON_COLON] = ACTIONS(1),
+    [anon_sym_head] = ACTIONS(1),
+    [anon_sym_tail] = ACTIONS(1),
+    [anon_sym_ind_DASHVec] = ACTIONS(1),
+    [anon_sym_EQ] = ACTIONS(1),
+    [anon_sym_same] = ACTIONS(1),
+    [anon_sym_cong] = ACTIONS(1),
+    [anon_sym_replace] = ACTIONS(1),
+    [anon_sym_symm] = ACTIONS(1),
+    [anon_sym_trans] = ACTIONS(1),
+    [anon_sym_ind_DASHEq] = ACTIONS(1),
+    [anon_sym_Either] = ACTIONS(1),
+    [anon_sym_left] = ACTIONS(1),
+    [anon_sym_right] = ACTIONS(1),
+    [anon_sym_ind_DASHEither] = ACTIONS(1),
+    [anon_sym_ind_DASHAbsurd] = ACTIONS(1),
+    [anon_sym_the] = ACTIONS(1),
+    [anon_sym_Atom] = ACTIONS(1),
+    [anon_sym_Nat] = ACTIONS(1),
+    [anon_sym_Trivial] = ACTIONS(1),
+    [anon_sym_Absurd] = ACTIONS(1),
+    [sym_zero] = ACTIONS(1),
+    [sym_nil] = ACTIONS(1),
+    [sym_vecnil] = ACTIONS(1),
+    [sym_sole] = ACTIONS(1),
+    [sym_todo] = ACTIONS(1),
+    [sym_identifier] = ACTIONS(1),
+    [sym_universe] = ACTIONS(1),
+    [sym_number] = ACTIONS(1),
+    [sym_comment] = ACTIONS(3),
+  },
+  [1] = {
+    [sym_source] = STATE(193),
+    [sym_claim] = STATE(195),
+    [sym_define] = STATE(196),
+    [sym_check_same] = STATE(197),
+    [sym_expression] = STATE(198),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_source_repeat1] = STATE(2),
+    [ts_builtin_sym_end] = ACTIONS(5),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [2] = {
+    [sym_claim] = STATE(195),
+    [sym_define] = STATE(196),
+    [sym_check_same] = STATE(197),
+    [sym_expression] = STATE(198),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_source_repeat1] = STATE(54),
+    [ts_builtin_sym_end] = ACTIONS(35),
+    [anon_sym_LPAREN] = ACTIONS(7),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [3] = {
+    [sym_expression] = STATE(90),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_claim] = ACTIONS(39),
+    [anon_sym_define] = ACTIONS(41),
+    [anon_sym_check_DASHsame] = ACTIONS(43),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_BSLASH] = ACTIONS(45),
+    [anon_sym_] = ACTIONS(47),
+    [anon_sym_lambda] = ACTIONS(49),
+    [anon_sym_DASH_GT] = ACTIONS(51),
+    [anon_sym_2] = ACTIONS(53),
+    [anon_sym_3] = ACTIONS(55),
+    [anon_sym_Pi] = ACTIONS(57),
+    [anon_sym_4] = ACTIONS(59),
+    [anon_sym_Sigma] = ACTIONS(61),
+    [anon_sym_Pair] = ACTIONS(63),
+    [anon_sym_cons] = ACTIONS(65),
+    [anon_sym_car] = ACTIONS(67),
+    [anon_sym_cdr] = ACTIONS(69),
+    [anon_sym_add1] = ACTIONS(71),
+    [anon_sym_which_DASHNat] = ACTIONS(73),
+    [anon_sym_iter_DASHNat] = ACTIONS(75),
+    [anon_sym_rec_DASHNat] = ACTIONS(77),
+    [anon_sym_ind_DASHNat] = ACTIONS(79),
+    [anon_sym_List] = ACTIONS(81),
+    [anon_sym_COLON_COLON] = ACTIONS(83),
+    [anon_sym_rec_DASHList] = ACTIONS(85),
+    [anon_sym_ind_DASHList] = ACTIONS(87),
+    [anon_sym_Vec] = ACTIONS(89),
+    [anon_sym_vec_COLON_COLON] = ACTIONS(91),
+    [anon_sym_head] = ACTIONS(93),
+    [anon_sym_tail] = ACTIONS(95),
+    [anon_sym_ind_DASHVec] = ACTIONS(97),
+    [anon_sym_EQ] = ACTIONS(99),
+    [anon_sym_same] = ACTIONS(101),
+    [anon_sym_cong] = ACTIONS(103),
+    [anon_sym_replace] = ACTIONS(105),
+    [anon_sym_symm] = ACTIONS(107),
+    [anon_sym_trans] = ACTIONS(109),
+    [anon_sym_ind_DASHEq] = ACTIONS(111),
+    [anon_sym_Either] = ACTIONS(113),
+    [anon_sym_left] = ACTIONS(115),
+    [anon_sym_right] = ACTIONS(117),
+    [anon_sym_ind_DASHEither] = ACTIONS(119),
+    [anon_sym_ind_DASHAbsurd] = ACTIONS(121),
+    [anon_sym_the] = ACTIONS(123),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [4] = {
+    [ts_builtin_sym_end] = ACTIONS(125),
+    [anon_sym_LPAREN] = ACTIONS(125),
+    [anon_sym_RPAREN] = ACTIONS(125),
+    [anon_sym_SQUOTE] = ACTIONS(125),
+    [anon_sym_Atom] = ACTIONS(127),
+    [anon_sym_Nat] = ACTIONS(127),
+    [anon_sym_Trivial] = ACTIONS(127),
+    [anon_sym_Absurd] = ACTIONS(127),
+    [sym_zero] = ACTIONS(127),
+    [sym_nil] = ACTIONS(127),
+    [sym_vecnil] = ACTIONS(127),
+    [sym_sole] = ACTIONS(127),
+    [sym_todo] = ACTIONS(127),
+    [sym_identifier] = ACTIONS(127),
+    [sym_universe] = ACTIONS(127),
+    [sym_number] = ACTIONS(125),
+    [sym_comment] = ACTIONS(3),
+  },
+  [5] = {
+    [ts_builtin_sym_end] = ACTIONS(129),
+    [anon_sym_LPAREN] = ACTIONS(129),
+    [anon_sym_RPAREN] = ACTIONS(129),
+    [anon_sym_SQUOTE] = ACTIONS(129),
+    [anon_sym_Atom] = ACTIONS(131),
+    [anon_sym_Nat] = ACTIONS(131),
+    [anon_sym_Trivial] = ACTIONS(131),
+    [anon_sym_Absurd] = ACTIONS(131),
+    [sym_zero] = ACTIONS(131),
+    [sym_nil] = ACTIONS(131),
+    [sym_vecnil] = ACTIONS(131),
+    [sym_sole] = ACTIONS(131),
+    [sym_todo] = ACTIONS(131),
+    [sym_identifier] = ACTIONS(131),
+    [sym_universe] = ACTIONS(131),
+    [sym_number] = ACTIONS(129),
+    [sym_comment] = ACTIONS(3),
+  },
+  [6] = {
+    [ts_builtin_sym_end] = ACTIONS(133),
+    [anon_sym_LPAREN] = ACTIONS(133),
+    [anon_sym_RPAREN] = ACTIONS(133),
+    [anon_sym_SQUOTE] = ACTIONS(133),
+    [anon_sym_Atom] = ACTIONS(135),
+    [anon_sym_Nat] = ACTIONS(135),
+    [anon_sym_Trivial] = ACTIONS(135),
+    [anon_sym_Absurd] = ACTIONS(135),
+    [sym_zero] = ACTIONS(135),
+    [sym_nil] = ACTIONS(135),
+    [sym_vecnil] = ACTIONS(135),
+    [sym_sole] = ACTIONS(135),
+    [sym_todo] = ACTIONS(135),
+    [sym_identifier] = ACTIONS(135),
+    [sym_universe] = ACTIONS(135),
+    [sym_number] = ACTIONS(133),
+    [sym_comment] = ACTIONS(3),
+  },
+  [7] = {
+    [ts_builtin_sym_end] = ACTIONS(137),
+    [anon_sym_LPAREN] = ACTIONS(137),
+    [anon_sym_RPAREN] = ACTIONS(137),
+    [anon_sym_SQUOTE] = ACTIONS(137),
+    [anon_sym_Atom] = ACTIONS(139),
+    [anon_sym_Nat] = ACTIONS(139),
+    [anon_sym_Trivial] = ACTIONS(139),
+    [anon_sym_Absurd] = ACTIONS(139),
+    [sym_zero] = ACTIONS(139),
+    [sym_nil] = ACTIONS(139),
+    [sym_vecnil] = ACTIONS(139),
+    [sym_sole] = ACTIONS(139),
+    [sym_todo] = ACTIONS(139),
+    [sym_identifier] = ACTIONS(139),
+    [sym_universe] = ACTIONS(139),
+    [sym_number] = ACTIONS(137),
+    [sym_comment] = ACTIONS(3),
+  },
+  [8] = {
+    [ts_builtin_sym_end] = ACTIONS(141),
+    [anon_sym_LPAREN] = ACTIONS(141),
+    [anon_sym_RPAREN] = ACTIONS(141),
+    [anon_sym_SQUOTE] = ACTIONS(141),
+    [anon_sym_Atom] = ACTIONS(143),
+    [anon_sym_Nat] = ACTIONS(143),
+    [anon_sym_Trivial] = ACTIONS(143),
+    [anon_sym_Absurd] = ACTIONS(143),
+    [sym_zero] = ACTIONS(143),
+    [sym_nil] = ACTIONS(143),
+    [sym_vecnil] = ACTIONS(143),
+    [sym_sole] = ACTIONS(143),
+    [sym_todo] = ACTIONS(143),
+    [sym_identifier] = ACTIONS(143),
+    [sym_universe] = ACTIONS(143),
+    [sym_number] = ACTIONS(141),
+    [sym_comment] = ACTIONS(3),
+  },
+  [9] = {
+    [ts_builtin_sym_end] = ACTIONS(145),
+    [anon_sym_LPAREN] = ACTIONS(145),
+    [anon_sym_RPAREN] = ACTIONS(145),
+    [anon_sym_SQUOTE] = ACTIONS(145),
+    [anon_sym_Atom] = ACTIONS(147),
+    [anon_sym_Nat] = ACTIONS(147),
+    [anon_sym_Trivial] = ACTIONS(147),
+    [anon_sym_Absurd] = ACTIONS(147),
+    [sym_zero] = ACTIONS(147),
+    [sym_nil] = ACTIONS(147),
+    [sym_vecnil] = ACTIONS(147),
+    [sym_sole] = ACTIONS(147),
+    [sym_todo] = ACTIONS(147),
+    [sym_identifier] = ACTIONS(147),
+    [sym_universe] = ACTIONS(147),
+    [sym_number] = ACTIONS(145),
+    [sym_comment] = ACTIONS(3),
+  },
+  [10] = {
+    [ts_builtin_sym_end] = ACTIONS(149),
+    [anon_sym_LPAREN] = ACTIONS(149),
+    [anon_sym_RPAREN] = ACTIONS(149),
+    [anon_sym_SQUOTE] = ACTIONS(149),
+    [anon_sym_Atom] = ACTIONS(151),
+    [anon_sym_Nat] = ACTIONS(151),
+    [anon_sym_Trivial] = ACTIONS(151),
+    [anon_sym_Absurd] = ACTIONS(151),
+    [sym_zero] = ACTIONS(151),
+    [sym_nil] = ACTIONS(151),
+    [sym_vecnil] = ACTIONS(151),
+    [sym_sole] = ACTIONS(151),
+    [sym_todo] = ACTIONS(151),
+    [sym_identifier] = ACTIONS(151),
+    [sym_universe] = ACTIONS(151),
+    [sym_number] = ACTIONS(149),
+    [sym_comment] = ACTIONS(3),
+  },
+  [11] = {
+    [ts_builtin_sym_end] = ACTIONS(153),
+    [anon_sym_LPAREN] = ACTIONS(153),
+    [anon_sym_RPAREN] = ACTIONS(153),
+    [anon_sym_SQUOTE] = ACTIONS(153),
+    [anon_sym_Atom] = ACTIONS(155),
+    [anon_sym_Nat] = ACTIONS(155),
+    [anon_sym_Trivial] = ACTIONS(155),
+    [anon_sym_Absurd] = ACTIONS(155),
+    [sym_zero] = ACTIONS(155),
+    [sym_nil] = ACTIONS(155),
+    [sym_vecnil] = ACTIONS(155),
+    [sym_sole] = ACTIONS(155),
+    [sym_todo] = ACTIONS(155),
+    [sym_identifier] = ACTIONS(155),
+    [sym_universe] = ACTIONS(155),
+    [sym_number] = ACTIONS(153),
+    [sym_comment] = ACTIONS(3),
+  },
+  [12] = {
+    [ts_builtin_sym_end] = ACTIONS(157),
+    [anon_sym_LPAREN] = ACTIONS(157),
+    [anon_sym_RPAREN] = ACTIONS(157),
+    [anon_sym_SQUOTE] = ACTIONS(157),
+    [anon_sym_Atom] = ACTIONS(159),
+    [anon_sym_Nat] = ACTIONS(159),
+    [anon_sym_Trivial] = ACTIONS(159),
+    [anon_sym_Absurd] = ACTIONS(159),
+    [sym_zero] = ACTIONS(159),
+    [sym_nil] = ACTIONS(159),
+    [sym_vecnil] = ACTIONS(159),
+    [sym_sole] = ACTIONS(159),
+    [sym_todo] = ACTIONS(159),
+    [sym_identifier] = ACTIONS(159),
+    [sym_universe] = ACTIONS(159),
+    [sym_number] = ACTIONS(157),
+    [sym_comment] = ACTIONS(3),
+  },
+  [13] = {
+    [ts_builtin_sym_end] = ACTIONS(161),
+    [anon_sym_LPAREN] = ACTIONS(161),
+    [anon_sym_RPAREN] = ACTIONS(161),
+    [anon_sym_SQUOTE] = ACTIONS(161),
+    [anon_sym_Atom] = ACTIONS(163),
+    [anon_sym_Nat] = ACTIONS(163),
+    [anon_sym_Trivial] = ACTIONS(163),
+    [anon_sym_Absurd] = ACTIONS(163),
+    [sym_zero] = ACTIONS(163),
+    [sym_nil] = ACTIONS(163),
+    [sym_vecnil] = ACTIONS(163),
+    [sym_sole] = ACTIONS(163),
+    [sym_todo] = ACTIONS(163),
+    [sym_identifier] = ACTIONS(163),
+    [sym_universe] = ACTIONS(163),
+    [sym_number] = ACTIONS(161),
+    [sym_comment] = ACTIONS(3),
+  },
+  [14] = {
+    [ts_builtin_sym_end] = ACTIONS(165),
+    [anon_sym_LPAREN] = ACTIONS(165),
+    [anon_sym_RPAREN] = ACTIONS(165),
+    [anon_sym_SQUOTE] = ACTIONS(165),
+    [anon_sym_Atom] = ACTIONS(167),
+    [anon_sym_Nat] = ACTIONS(167),
+    [anon_sym_Trivial] = ACTIONS(167),
+    [anon_sym_Absurd] = ACTIONS(167),
+    [sym_zero] = ACTIONS(167),
+    [sym_nil] = ACTIONS(167),
+    [sym_vecnil] = ACTIONS(167),
+    [sym_sole] = ACTIONS(167),
+    [sym_todo] = ACTIONS(167),
+    [sym_identifier] = ACTIONS(167),
+    [sym_universe] = ACTIONS(167),
+    [sym_number] = ACTIONS(165),
+    [sym_comment] = ACTIONS(3),
+  },
+  [15] = {
+    [ts_builtin_sym_end] = ACTIONS(169),
+    [anon_sym_LPAREN] = ACTIONS(169),
+    [anon_sym_RPAREN] = ACTIONS(169),
+    [anon_sym_SQUOTE] = ACTIONS(169),
+    [anon_sym_Atom] = ACTIONS(171),
+    [anon_sym_Nat] = ACTIONS(171),
+    [anon_sym_Trivial] = ACTIONS(171),
+    [anon_sym_Absurd] = ACTIONS(171),
+    [sym_zero] = ACTIONS(171),
+    [sym_nil] = ACTIONS(171),
+    [sym_vecnil] = ACTIONS(171),
+    [sym_sole] = ACTIONS(171),
+    [sym_todo] = ACTIONS(171),
+    [sym_identifier] = ACTIONS(171),
+    [sym_universe] = ACTIONS(171),
+    [sym_number] = ACTIONS(169),
+    [sym_comment] = ACTIONS(3),
+  },
+  [16] = {
+    [ts_builtin_sym_end] = ACTIONS(173),
+    [anon_sym_LPAREN] = ACTIONS(173),
+    [anon_sym_RPAREN] = ACTIONS(173),
+    [anon_sym_SQUOTE] = ACTIONS(173),
+    [anon_sym_Atom] = ACTIONS(175),
+    [anon_sym_Nat] = ACTIONS(175),
+    [anon_sym_Trivial] = ACTIONS(175),
+    [anon_sym_Absurd] = ACTIONS(175),
+    [sym_zero] = ACTIONS(175),
+    [sym_nil] = ACTIONS(175),
+    [sym_vecnil] = ACTIONS(175),
+    [sym_sole] = ACTIONS(175),
+    [sym_todo] = ACTIONS(175),
+    [sym_identifier] = ACTIONS(175),
+    [sym_universe] = ACTIONS(175),
+    [sym_number] = ACTIONS(173),
+    [sym_comment] = ACTIONS(3),
+  },
+  [17] = {
+    [ts_builtin_sym_end] = ACTIONS(177),
+    [anon_sym_LPAREN] = ACTIONS(177),
+    [anon_sym_RPAREN] = ACTIONS(177),
+    [anon_sym_SQUOTE] = ACTIONS(177),
+    [anon_sym_Atom] = ACTIONS(179),
+    [anon_sym_Nat] = ACTIONS(179),
+    [anon_sym_Trivial] = ACTIONS(179),
+    [anon_sym_Absurd] = ACTIONS(179),
+    [sym_zero] = ACTIONS(179),
+    [sym_nil] = ACTIONS(179),
+    [sym_vecnil] = ACTIONS(179),
+    [sym_sole] = ACTIONS(179),
+    [sym_todo] = ACTIONS(179),
+    [sym_identifier] = ACTIONS(179),
+    [sym_universe] = ACTIONS(179),
+    [sym_number] = ACTIONS(177),
+    [sym_comment] = ACTIONS(3),
+  },
+  [18] = {
+    [ts_builtin_sym_end] = ACTIONS(181),
+    [anon_sym_LPAREN] = ACTIONS(181),
+    [anon_sym_RPAREN] = ACTIONS(181),
+    [anon_sym_SQUOTE] = ACTIONS(181),
+    [anon_sym_Atom] = ACTIONS(183),
+    [anon_sym_Nat] = ACTIONS(183),
+    [anon_sym_Trivial] = ACTIONS(183),
+    [anon_sym_Absurd] = ACTIONS(183),
+    [sym_zero] = ACTIONS(183),
+    [sym_nil] = ACTIONS(183),
+    [sym_vecnil] = ACTIONS(183),
+    [sym_sole] = ACTIONS(183),
+    [sym_todo] = ACTIONS(183),
+    [sym_identifier] = ACTIONS(183),
+    [sym_universe] = ACTIONS(183),
+    [sym_number] = ACTIONS(181),
+    [sym_comment] = ACTIONS(3),
+  },
+  [19] = {
+    [ts_builtin_sym_end] = ACTIONS(185),
+    [anon_sym_LPAREN] = ACTIONS(185),
+    [anon_sym_RPAREN] = ACTIONS(185),
+    [anon_sym_SQUOTE] = ACTIONS(185),
+    [anon_sym_Atom] = ACTIONS(187),
+    [anon_sym_Nat] = ACTIONS(187),
+    [anon_sym_Trivial] = ACTIONS(187),
+    [anon_sym_Absurd] = ACTIONS(187),
+    [sym_zero] = ACTIONS(187),
+    [sym_nil] = ACTIONS(187),
+    [sym_vecnil] = ACTIONS(187),
+    [sym_sole] = ACTIONS(187),
+    [sym_todo] = ACTIONS(187),
+    [sym_identifier] = ACTIONS(187),
+    [sym_universe] = ACTIONS(187),
+    [sym_number] = ACTIONS(185),
+    [sym_comment] = ACTIONS(3),
+  },
+  [20] = {
+    [ts_builtin_sym_end] = ACTIONS(189),
+    [anon_sym_LPAREN] = ACTIONS(189),
+    [anon_sym_RPAREN] = ACTIONS(189),
+    [anon_sym_SQUOTE] = ACTIONS(189),
+    [anon_sym_Atom] = ACTIONS(191),
+    [anon_sym_Nat] = ACTIONS(191),
+    [anon_sym_Trivial] = ACTIONS(191),
+    [anon_sym_Absurd] = ACTIONS(191),
+    [sym_zero] = ACTIONS(191),
+    [sym_nil] = ACTIONS(191),
+    [sym_vecnil] = ACTIONS(191),
+    [sym_sole] = ACTIONS(191),
+    [sym_todo] = ACTIONS(191),
+    [sym_identifier] = ACTIONS(191),
+    [sym_universe] = ACTIONS(191),
+    [sym_number] = ACTIONS(189),
+    [sym_comment] = ACTIONS(3),
+  },
+  [21] = {
+    [ts_builtin_sym_end] = ACTIONS(193),
+    [anon_sym_LPAREN] = ACTIONS(193),
+    [anon_sym_RPAREN] = ACTIONS(193),
+    [anon_sym_SQUOTE] = ACTIONS(193),
+    [anon_sym_Atom] = ACTIONS(195),
+    [anon_sym_Nat] = ACTIONS(195),
+    [anon_sym_Trivial] = ACTIONS(195),
+    [anon_sym_Absurd] = ACTIONS(195),
+    [sym_zero] = ACTIONS(195),
+    [sym_nil] = ACTIONS(195),
+    [sym_vecnil] = ACTIONS(195),
+    [sym_sole] = ACTIONS(195),
+    [sym_todo] = ACTIONS(195),
+    [sym_identifier] = ACTIONS(195),
+    [sym_universe] = ACTIONS(195),
+    [sym_number] = ACTIONS(193),
+    [sym_comment] = ACTIONS(3),
+  },
+  [22] = {
+    [ts_builtin_sym_end] = ACTIONS(197),
+    [anon_sym_LPAREN] = ACTIONS(197),
+    [anon_sym_RPAREN] = ACTIONS(197),
+    [anon_sym_SQUOTE] = ACTIONS(197),
+    [anon_sym_Atom] = ACTIONS(199),
+    [anon_sym_Nat] = ACTIONS(199),
+    [anon_sym_Trivial] = ACTIONS(199),
+    [anon_sym_Absurd] = ACTIONS(199),
+    [sym_zero] = ACTIONS(199),
+    [sym_nil] = ACTIONS(199),
+    [sym_vecnil] = ACTIONS(199),
+    [sym_sole] = ACTIONS(199),
+    [sym_todo] = ACTIONS(199),
+    [sym_identifier] = ACTIONS(199),
+    [sym_universe] = ACTIONS(199),
+    [sym_number] = ACTIONS(197),
+    [sym_comment] = ACTIONS(3),
+  },
+  [23] = {
+    [ts_builtin_sym_end] = ACTIONS(201),
+    [anon_sym_LPAREN] = ACTIONS(201),
+    [anon_sym_RPAREN] = ACTIONS(201),
+    [anon_sym_SQUOTE] = ACTIONS(201),
+    [anon_sym_Atom] = ACTIONS(203),
+    [anon_sym_Nat] = ACTIONS(203),
+    [anon_sym_Trivial] = ACTIONS(203),
+    [anon_sym_Absurd] = ACTIONS(203),
+    [sym_zero] = ACTIONS(203),
+    [sym_nil] = ACTIONS(203),
+    [sym_vecnil] = ACTIONS(203),
+    [sym_sole] = ACTIONS(203),
+    [sym_todo] = ACTIONS(203),
+    [sym_identifier] = ACTIONS(203),
+    [sym_universe] = ACTIONS(203),
+    [sym_number] = ACTIONS(201),
+    [sym_comment] = ACTIONS(3),
+  },
+  [24] = {
+    [ts_builtin_sym_end] = ACTIONS(205),
+    [anon_sym_LPAREN] = ACTIONS(205),
+    [anon_sym_RPAREN] = ACTIONS(205),
+    [anon_sym_SQUOTE] = ACTIONS(205),
+    [anon_sym_Atom] = ACTIONS(207),
+    [anon_sym_Nat] = ACTIONS(207),
+    [anon_sym_Trivial] = ACTIONS(207),
+    [anon_sym_Absurd] = ACTIONS(207),
+    [sym_zero] = ACTIONS(207),
+    [sym_nil] = ACTIONS(207),
+    [sym_vecnil] = ACTIONS(207),
+    [sym_sole] = ACTIONS(207),
+    [sym_todo] = ACTIONS(207),
+    [sym_identifier] = ACTIONS(207),
+    [sym_universe] = ACTIONS(207),
+    [sym_number] = ACTIONS(205),
+    [sym_comment] = ACTIONS(3),
+  },
+  [25] = {
+    [ts_builtin_sym_end] = ACTIONS(209),
+    [anon_sym_LPAREN] = ACTIONS(209),
+    [anon_sym_RPAREN] = ACTIONS(209),
+    [anon_sym_SQUOTE] = ACTIONS(209),
+    [anon_sym_Atom] = ACTIONS(211),
+    [anon_sym_Nat] = ACTIONS(211),
+    [anon_sym_Trivial] = ACTIONS(211),
+    [anon_sym_Absurd] = ACTIONS(211),
+    [sym_zero] = ACTIONS(211),
+    [sym_nil] = ACTIONS(211),
+    [sym_vecnil] = ACTIONS(211),
+    [sym_sole] = ACTIONS(211),
+    [sym_todo] = ACTIONS(211),
+    [sym_identifier] = ACTIONS(211),
+    [sym_universe] = ACTIONS(211),
+    [sym_number] = ACTIONS(209),
+    [sym_comment] = ACTIONS(3),
+  },
+  [26] = {
+    [ts_builtin_sym_end] = ACTIONS(213),
+    [anon_sym_LPAREN] = ACTIONS(213),
+    [anon_sym_RPAREN] = ACTIONS(213),
+    [anon_sym_SQUOTE] = ACTIONS(213),
+    [anon_sym_Atom] = ACTIONS(215),
+    [anon_sym_Nat] = ACTIONS(215),
+    [anon_sym_Trivial] = ACTIONS(215),
+    [anon_sym_Absurd] = ACTIONS(215),
+    [sym_zero] = ACTIONS(215),
+    [sym_nil] = ACTIONS(215),
+    [sym_vecnil] = ACTIONS(215),
+    [sym_sole] = ACTIONS(215),
+    [sym_todo] = ACTIONS(215),
+    [sym_identifier] = ACTIONS(215),
+    [sym_universe] = ACTIONS(215),
+    [sym_number] = ACTIONS(213),
+    [sym_comment] = ACTIONS(3),
+  },
+  [27] = {
+    [ts_builtin_sym_end] = ACTIONS(217),
+    [anon_sym_LPAREN] = ACTIONS(217),
+    [anon_sym_RPAREN] = ACTIONS(217),
+    [anon_sym_SQUOTE] = ACTIONS(217),
+    [anon_sym_Atom] = ACTIONS(219),
+    [anon_sym_Nat] = ACTIONS(219),
+    [anon_sym_Trivial] = ACTIONS(219),
+    [anon_sym_Absurd] = ACTIONS(219),
+    [sym_zero] = ACTIONS(219),
+    [sym_nil] = ACTIONS(219),
+    [sym_vecnil] = ACTIONS(219),
+    [sym_sole] = ACTIONS(219),
+    [sym_todo] = ACTIONS(219),
+    [sym_identifier] = ACTIONS(219),
+    [sym_universe] = ACTIONS(219),
+    [sym_number] = ACTIONS(217),
+    [sym_comment] = ACTIONS(3),
+  },
+  [28] = {
+    [ts_builtin_sym_end] = ACTIONS(221),
+    [anon_sym_LPAREN] = ACTIONS(221),
+    [anon_sym_RPAREN] = ACTIONS(221),
+    [anon_sym_SQUOTE] = ACTIONS(221),
+    [anon_sym_Atom] = ACTIONS(223),
+    [anon_sym_Nat] = ACTIONS(223),
+    [anon_sym_Trivial] = ACTIONS(223),
+    [anon_sym_Absurd] = ACTIONS(223),
+    [sym_zero] = ACTIONS(223),
+    [sym_nil] = ACTIONS(223),
+    [sym_vecnil] = ACTIONS(223),
+    [sym_sole] = ACTIONS(223),
+    [sym_todo] = ACTIONS(223),
+    [sym_identifier] = ACTIONS(223),
+    [sym_universe] = ACTIONS(223),
+    [sym_number] = ACTIONS(221),
+    [sym_comment] = ACTIONS(3),
+  },
+  [29] = {
+    [ts_builtin_sym_end] = ACTIONS(225),
+    [anon_sym_LPAREN] = ACTIONS(225),
+    [anon_sym_RPAREN] = ACTIONS(225),
+    [anon_sym_SQUOTE] = ACTIONS(225),
+    [anon_sym_Atom] = ACTIONS(227),
+    [anon_sym_Nat] = ACTIONS(227),
+    [anon_sym_Trivial] = ACTIONS(227),
+    [anon_sym_Absurd] = ACTIONS(227),
+    [sym_zero] = ACTIONS(227),
+    [sym_nil] = ACTIONS(227),
+    [sym_vecnil] = ACTIONS(227),
+    [sym_sole] = ACTIONS(227),
+    [sym_todo] = ACTIONS(227),
+    [sym_identifier] = ACTIONS(227),
+    [sym_universe] = ACTIONS(227),
+    [sym_number] = ACTIONS(225),
+    [sym_comment] = ACTIONS(3),
+  },
+  [30] = {
+    [ts_builtin_sym_end] = ACTIONS(229),
+    [anon_sym_LPAREN] = ACTIONS(229),
+    [anon_sym_RPAREN] = ACTIONS(229),
+    [anon_sym_SQUOTE] = ACTIONS(229),
+    [anon_sym_Atom] = ACTIONS(231),
+    [anon_sym_Nat] = ACTIONS(231),
+    [anon_sym_Trivial] = ACTIONS(231),
+    [anon_sym_Absurd] = ACTIONS(231),
+    [sym_zero] = ACTIONS(231),
+    [sym_nil] = ACTIONS(231),
+    [sym_vecnil] = ACTIONS(231),
+    [sym_sole] = ACTIONS(231),
+    [sym_todo] = ACTIONS(231),
+    [sym_identifier] = ACTIONS(231),
+    [sym_universe] = ACTIONS(231),
+    [sym_number] = ACTIONS(229),
+    [sym_comment] = ACTIONS(3),
+  },
+  [31] = {
+    [ts_builtin_sym_end] = ACTIONS(233),
+    [anon_sym_LPAREN] = ACTIONS(233),
+    [anon_sym_RPAREN] = ACTIONS(233),
+    [anon_sym_SQUOTE] = ACTIONS(233),
+    [anon_sym_Atom] = ACTIONS(235),
+    [anon_sym_Nat] = ACTIONS(235),
+    [anon_sym_Trivial] = ACTIONS(235),
+    [anon_sym_Absurd] = ACTIONS(235),
+    [sym_zero] = ACTIONS(235),
+    [sym_nil] = ACTIONS(235),
+    [sym_vecnil] = ACTIONS(235),
+    [sym_sole] = ACTIONS(235),
+    [sym_todo] = ACTIONS(235),
+    [sym_identifier] = ACTIONS(235),
+    [sym_universe] = ACTIONS(235),
+    [sym_number] = ACTIONS(233),
+    [sym_comment] = ACTIONS(3),
+  },
+  [32] = {
+    [ts_builtin_sym_end] = ACTIONS(237),
+    [anon_sym_LPAREN] = ACTIONS(237),
+    [anon_sym_RPAREN] = ACTIONS(237),
+    [anon_sym_SQUOTE] = ACTIONS(237),
+    [anon_sym_Atom] = ACTIONS(239),
+    [anon_sym_Nat] = ACTIONS(239),
+    [anon_sym_Trivial] = ACTIONS(239),
+    [anon_sym_Absurd] = ACTIONS(239),
+    [sym_zero] = ACTIONS(239),
+    [sym_nil] = ACTIONS(239),
+    [sym_vecnil] = ACTIONS(239),
+    [sym_sole] = ACTIONS(239),
+    [sym_todo] = ACTIONS(239),
+    [sym_identifier] = ACTIONS(239),
+    [sym_universe] = ACTIONS(239),
+    [sym_number] = ACTIONS(237),
+    [sym_comment] = ACTIONS(3),
+  },
+  [33] = {
+    [ts_builtin_sym_end] = ACTIONS(241),
+    [anon_sym_LPAREN] = ACTIONS(241),
+    [anon_sym_RPAREN] = ACTIONS(241),
+    [anon_sym_SQUOTE] = ACTIONS(241),
+    [anon_sym_Atom] = ACTIONS(243),
+    [anon_sym_Nat] = ACTIONS(243),
+    [anon_sym_Trivial] = ACTIONS(243),
+    [anon_sym_Absurd] = ACTIONS(243),
+    [sym_zero] = ACTIONS(243),
+    [sym_nil] = ACTIONS(243),
+    [sym_vecnil] = ACTIONS(243),
+    [sym_sole] = ACTIONS(243),
+    [sym_todo] = ACTIONS(243),
+    [sym_identifier] = ACTIONS(243),
+    [sym_universe] = ACTIONS(243),
+    [sym_number] = ACTIONS(241),
+    [sym_comment] = ACTIONS(3),
+  },
+  [34] = {
+    [ts_builtin_sym_end] = ACTIONS(245),
+    [anon_sym_LPAREN] = ACTIONS(245),
+    [anon_sym_RPAREN] = ACTIONS(245),
+    [anon_sym_SQUOTE] = ACTIONS(245),
+    [anon_sym_Atom] = ACTIONS(247),
+    [anon_sym_Nat] = ACTIONS(247),
+    [anon_sym_Trivial] = ACTIONS(247),
+    [anon_sym_Absurd] = ACTIONS(247),
+    [sym_zero] = ACTIONS(247),
+    [sym_nil] = ACTIONS(247),
+    [sym_vecnil] = ACTIONS(247),
+    [sym_sole] = ACTIONS(247),
+    [sym_todo] = ACTIONS(247),
+    [sym_identifier] = ACTIONS(247),
+    [sym_universe] = ACTIONS(247),
+    [sym_number] = ACTIONS(245),
+    [sym_comment] = ACTIONS(3),
+  },
+  [35] = {
+    [ts_builtin_sym_end] = ACTIONS(249),
+    [anon_sym_LPAREN] = ACTIONS(249),
+    [anon_sym_RPAREN] = ACTIONS(249),
+    [anon_sym_SQUOTE] = ACTIONS(249),
+    [anon_sym_Atom] = ACTIONS(251),
+    [anon_sym_Nat] = ACTIONS(251),
+    [anon_sym_Trivial] = ACTIONS(251),
+    [anon_sym_Absurd] = ACTIONS(251),
+    [sym_zero] = ACTIONS(251),
+    [sym_nil] = ACTIONS(251),
+    [sym_vecnil] = ACTIONS(251),
+    [sym_sole] = ACTIONS(251),
+    [sym_todo] = ACTIONS(251),
+    [sym_identifier] = ACTIONS(251),
+    [sym_universe] = ACTIONS(251),
+    [sym_number] = ACTIONS(249),
+    [sym_comment] = ACTIONS(3),
+  },
+  [36] = {
+    [ts_builtin_sym_end] = ACTIONS(253),
+    [anon_sym_LPAREN] = ACTIONS(253),
+    [anon_sym_RPAREN] = ACTIONS(253),
+    [anon_sym_SQUOTE] = ACTIONS(253),
+    [anon_sym_Atom] = ACTIONS(255),
+    [anon_sym_Nat] = ACTIONS(255),
+    [anon_sym_Trivial] = ACTIONS(255),
+    [anon_sym_Absurd] = ACTIONS(255),
+    [sym_zero] = ACTIONS(255),
+    [sym_nil] = ACTIONS(255),
+    [sym_vecnil] = ACTIONS(255),
+    [sym_sole] = ACTIONS(255),
+    [sym_todo] = ACTIONS(255),
+    [sym_identifier] = ACTIONS(255),
+    [sym_universe] = ACTIONS(255),
+    [sym_number] = ACTIONS(253),
+    [sym_comment] = ACTIONS(3),
+  },
+  [37] = {
+    [ts_builtin_sym_end] = ACTIONS(257),
+    [anon_sym_LPAREN] = ACTIONS(257),
+    [anon_sym_RPAREN] = ACTIONS(257),
+    [anon_sym_SQUOTE] = ACTIONS(257),
+    [anon_sym_Atom] = ACTIONS(259),
+    [anon_sym_Nat] = ACTIONS(259),
+    [anon_sym_Trivial] = ACTIONS(259),
+    [anon_sym_Absurd] = ACTIONS(259),
+    [sym_zero] = ACTIONS(259),
+    [sym_nil] = ACTIONS(259),
+    [sym_vecnil] = ACTIONS(259),
+    [sym_sole] = ACTIONS(259),
+    [sym_todo] = ACTIONS(259),
+    [sym_identifier] = ACTIONS(259),
+    [sym_universe] = ACTIONS(259),
+    [sym_number] = ACTIONS(257),
+    [sym_comment] = ACTIONS(3),
+  },
+  [38] = {
+    [ts_builtin_sym_end] = ACTIONS(261),
+    [anon_sym_LPAREN] = ACTIONS(261),
+    [anon_sym_RPAREN] = ACTIONS(261),
+    [anon_sym_SQUOTE] = ACTIONS(261),
+    [anon_sym_Atom] = ACTIONS(263),
+    [anon_sym_Nat] = ACTIONS(263),
+    [anon_sym_Trivial] = ACTIONS(263),
+    [anon_sym_Absurd] = ACTIONS(263),
+    [sym_zero] = ACTIONS(263),
+    [sym_nil] = ACTIONS(263),
+    [sym_vecnil] = ACTIONS(263),
+    [sym_sole] = ACTIONS(263),
+    [sym_todo] = ACTIONS(263),
+    [sym_identifier] = ACTIONS(263),
+    [sym_universe] = ACTIONS(263),
+    [sym_number] = ACTIONS(261),
+    [sym_comment] = ACTIONS(3),
+  },
+  [39] = {
+    [ts_builtin_sym_end] = ACTIONS(265),
+    [anon_sym_LPAREN] = ACTIONS(265),
+    [anon_sym_RPAREN] = ACTIONS(265),
+    [anon_sym_SQUOTE] = ACTIONS(265),
+    [anon_sym_Atom] = ACTIONS(267),
+    [anon_sym_Nat] = ACTIONS(267),
+    [anon_sym_Trivial] = ACTIONS(267),
+    [anon_sym_Absurd] = ACTIONS(267),
+    [sym_zero] = ACTIONS(267),
+    [sym_nil] = ACTIONS(267),
+    [sym_vecnil] = ACTIONS(267),
+    [sym_sole] = ACTIONS(267),
+    [sym_todo] = ACTIONS(267),
+    [sym_identifier] = ACTIONS(267),
+    [sym_universe] = ACTIONS(267),
+    [sym_number] = ACTIONS(265),
+    [sym_comment] = ACTIONS(3),
+  },
+  [40] = {
+    [ts_builtin_sym_end] = ACTIONS(269),
+    [anon_sym_LPAREN] = ACTIONS(269),
+    [anon_sym_RPAREN] = ACTIONS(269),
+    [anon_sym_SQUOTE] = ACTIONS(269),
+    [anon_sym_Atom] = ACTIONS(271),
+    [anon_sym_Nat] = ACTIONS(271),
+    [anon_sym_Trivial] = ACTIONS(271),
+    [anon_sym_Absurd] = ACTIONS(271),
+    [sym_zero] = ACTIONS(271),
+    [sym_nil] = ACTIONS(271),
+    [sym_vecnil] = ACTIONS(271),
+    [sym_sole] = ACTIONS(271),
+    [sym_todo] = ACTIONS(271),
+    [sym_identifier] = ACTIONS(271),
+    [sym_universe] = ACTIONS(271),
+    [sym_number] = ACTIONS(269),
+    [sym_comment] = ACTIONS(3),
+  },
+  [41] = {
+    [ts_builtin_sym_end] = ACTIONS(273),
+    [anon_sym_LPAREN] = ACTIONS(273),
+    [anon_sym_RPAREN] = ACTIONS(273),
+    [anon_sym_SQUOTE] = ACTIONS(273),
+    [anon_sym_Atom] = ACTIONS(275),
+    [anon_sym_Nat] = ACTIONS(275),
+    [anon_sym_Trivial] = ACTIONS(275),
+    [anon_sym_Absurd] = ACTIONS(275),
+    [sym_zero] = ACTIONS(275),
+    [sym_nil] = ACTIONS(275),
+    [sym_vecnil] = ACTIONS(275),
+    [sym_sole] = ACTIONS(275),
+    [sym_todo] = ACTIONS(275),
+    [sym_identifier] = ACTIONS(275),
+    [sym_universe] = ACTIONS(275),
+    [sym_number] = ACTIONS(273),
+    [sym_comment] = ACTIONS(3),
+  },
+  [42] = {
+    [ts_builtin_sym_end] = ACTIONS(277),
+    [anon_sym_LPAREN] = ACTIONS(277),
+    [anon_sym_RPAREN] = ACTIONS(277),
+    [anon_sym_SQUOTE] = ACTIONS(277),
+    [anon_sym_Atom] = ACTIONS(279),
+    [anon_sym_Nat] = ACTIONS(279),
+    [anon_sym_Trivial] = ACTIONS(279),
+    [anon_sym_Absurd] = ACTIONS(279),
+    [sym_zero] = ACTIONS(279),
+    [sym_nil] = ACTIONS(279),
+    [sym_vecnil] = ACTIONS(279),
+    [sym_sole] = ACTIONS(279),
+    [sym_todo] = ACTIONS(279),
+    [sym_identifier] = ACTIONS(279),
+    [sym_universe] = ACTIONS(279),
+    [sym_number] = ACTIONS(277),
+    [sym_comment] = ACTIONS(3),
+  },
+  [43] = {
+    [ts_builtin_sym_end] = ACTIONS(281),
+    [anon_sym_LPAREN] = ACTIONS(281),
+    [anon_sym_RPAREN] = ACTIONS(281),
+    [anon_sym_SQUOTE] = ACTIONS(281),
+    [anon_sym_Atom] = ACTIONS(283),
+    [anon_sym_Nat] = ACTIONS(283),
+    [anon_sym_Trivial] = ACTIONS(283),
+    [anon_sym_Absurd] = ACTIONS(283),
+    [sym_zero] = ACTIONS(283),
+    [sym_nil] = ACTIONS(283),
+    [sym_vecnil] = ACTIONS(283),
+    [sym_sole] = ACTIONS(283),
+    [sym_todo] = ACTIONS(283),
+    [sym_identifier] = ACTIONS(283),
+    [sym_universe] = ACTIONS(283),
+    [sym_number] = ACTIONS(281),
+    [sym_comment] = ACTIONS(3),
+  },
+  [44] = {
+    [ts_builtin_sym_end] = ACTIONS(285),
+    [anon_sym_LPAREN] = ACTIONS(285),
+    [anon_sym_RPAREN] = ACTIONS(285),
+    [anon_sym_SQUOTE] = ACTIONS(285),
+    [anon_sym_Atom] = ACTIONS(287),
+    [anon_sym_Nat] = ACTIONS(287),
+    [anon_sym_Trivial] = ACTIONS(287),
+    [anon_sym_Absurd] = ACTIONS(287),
+    [sym_zero] = ACTIONS(287),
+    [sym_nil] = ACTIONS(287),
+    [sym_vecnil] = ACTIONS(287),
+    [sym_sole] = ACTIONS(287),
+    [sym_todo] = ACTIONS(287),
+    [sym_identifier] = ACTIONS(287),
+    [sym_universe] = ACTIONS(287),
+    [sym_number] = ACTIONS(285),
+    [sym_comment] = ACTIONS(3),
+  },
+  [45] = {
+    [ts_builtin_sym_end] = ACTIONS(289),
+    [anon_sym_LPAREN] = ACTIONS(289),
+    [anon_sym_RPAREN] = ACTIONS(289),
+    [anon_sym_SQUOTE] = ACTIONS(289),
+    [anon_sym_Atom] = ACTIONS(291),
+    [anon_sym_Nat] = ACTIONS(291),
+    [anon_sym_Trivial] = ACTIONS(291),
+    [anon_sym_Absurd] = ACTIONS(291),
+    [sym_zero] = ACTIONS(291),
+    [sym_nil] = ACTIONS(291),
+    [sym_vecnil] = ACTIONS(291),
+    [sym_sole] = ACTIONS(291),
+    [sym_todo] = ACTIONS(291),
+    [sym_identifier] = ACTIONS(291),
+    [sym_universe] = ACTIONS(291),
+    [sym_number] = ACTIONS(289),
+    [sym_comment] = ACTIONS(3),
+  },
+  [46] = {
+    [ts_builtin_sym_end] = ACTIONS(293),
+    [anon_sym_LPAREN] = ACTIONS(293),
+    [anon_sym_RPAREN] = ACTIONS(293),
+    [anon_sym_SQUOTE] = ACTIONS(293),
+    [anon_sym_Atom] = ACTIONS(295),
+    [anon_sym_Nat] = ACTIONS(295),
+    [anon_sym_Trivial] = ACTIONS(295),
+    [anon_sym_Absurd] = ACTIONS(295),
+    [sym_zero] = ACTIONS(295),
+    [sym_nil] = ACTIONS(295),
+    [sym_vecnil] = ACTIONS(295),
+    [sym_sole] = ACTIONS(295),
+    [sym_todo] = ACTIONS(295),
+    [sym_identifier] = ACTIONS(295),
+    [sym_universe] = ACTIONS(295),
+    [sym_number] = ACTIONS(293),
+    [sym_comment] = ACTIONS(3),
+  },
+  [47] = {
+    [ts_builtin_sym_end] = ACTIONS(297),
+    [anon_sym_LPAREN] = ACTIONS(297),
+    [anon_sym_RPAREN] = ACTIONS(297),
+    [anon_sym_SQUOTE] = ACTIONS(297),
+    [anon_sym_Atom] = ACTIONS(299),
+    [anon_sym_Nat] = ACTIONS(299),
+    [anon_sym_Trivial] = ACTIONS(299),
+    [anon_sym_Absurd] = ACTIONS(299),
+    [sym_zero] = ACTIONS(299),
+    [sym_nil] = ACTIONS(299),
+    [sym_vecnil] = ACTIONS(299),
+    [sym_sole] = ACTIONS(299),
+    [sym_todo] = ACTIONS(299),
+    [sym_identifier] = ACTIONS(299),
+    [sym_universe] = ACTIONS(299),
+    [sym_number] = ACTIONS(297),
+    [sym_comment] = ACTIONS(3),
+  },
+  [48] = {
+    [ts_builtin_sym_end] = ACTIONS(301),
+    [anon_sym_LPAREN] = ACTIONS(301),
+    [anon_sym_RPAREN] = ACTIONS(301),
+    [anon_sym_SQUOTE] = ACTIONS(301),
+    [anon_sym_Atom] = ACTIONS(303),
+    [anon_sym_Nat] = ACTIONS(303),
+    [anon_sym_Trivial] = ACTIONS(303),
+    [anon_sym_Absurd] = ACTIONS(303),
+    [sym_zero] = ACTIONS(303),
+    [sym_nil] = ACTIONS(303),
+    [sym_vecnil] = ACTIONS(303),
+    [sym_sole] = ACTIONS(303),
+    [sym_todo] = ACTIONS(303),
+    [sym_identifier] = ACTIONS(303),
+    [sym_universe] = ACTIONS(303),
+    [sym_number] = ACTIONS(301),
+    [sym_comment] = ACTIONS(3),
+  },
+  [49] = {
+    [ts_builtin_sym_end] = ACTIONS(305),
+    [anon_sym_LPAREN] = ACTIONS(305),
+    [anon_sym_RPAREN] = ACTIONS(305),
+    [anon_sym_SQUOTE] = ACTIONS(305),
+    [anon_sym_Atom] = ACTIONS(307),
+    [anon_sym_Nat] = ACTIONS(307),
+    [anon_sym_Trivial] = ACTIONS(307),
+    [anon_sym_Absurd] = ACTIONS(307),
+    [sym_zero] = ACTIONS(307),
+    [sym_nil] = ACTIONS(307),
+    [sym_vecnil] = ACTIONS(307),
+    [sym_sole] = ACTIONS(307),
+    [sym_todo] = ACTIONS(307),
+    [sym_identifier] = ACTIONS(307),
+    [sym_universe] = ACTIONS(307),
+    [sym_number] = ACTIONS(305),
+    [sym_comment] = ACTIONS(3),
+  },
+  [50] = {
+    [ts_builtin_sym_end] = ACTIONS(309),
+    [anon_sym_LPAREN] = ACTIONS(309),
+    [anon_sym_RPAREN] = ACTIONS(309),
+    [anon_sym_SQUOTE] = ACTIONS(309),
+    [anon_sym_Atom] = ACTIONS(311),
+    [anon_sym_Nat] = ACTIONS(311),
+    [anon_sym_Trivial] = ACTIONS(311),
+    [anon_sym_Absurd] = ACTIONS(311),
+    [sym_zero] = ACTIONS(311),
+    [sym_nil] = ACTIONS(311),
+    [sym_vecnil] = ACTIONS(311),
+    [sym_sole] = ACTIONS(311),
+    [sym_todo] = ACTIONS(311),
+    [sym_identifier] = ACTIONS(311),
+    [sym_universe] = ACTIONS(311),
+    [sym_number] = ACTIONS(309),
+    [sym_comment] = ACTIONS(3),
+  },
+  [51] = {
+    [ts_builtin_sym_end] = ACTIONS(313),
+    [anon_sym_LPAREN] = ACTIONS(313),
+    [anon_sym_RPAREN] = ACTIONS(313),
+    [anon_sym_SQUOTE] = ACTIONS(313),
+    [anon_sym_Atom] = ACTIONS(315),
+    [anon_sym_Nat] = ACTIONS(315),
+    [anon_sym_Trivial] = ACTIONS(315),
+    [anon_sym_Absurd] = ACTIONS(315),
+    [sym_zero] = ACTIONS(315),
+    [sym_nil] = ACTIONS(315),
+    [sym_vecnil] = ACTIONS(315),
+    [sym_sole] = ACTIONS(315),
+    [sym_todo] = ACTIONS(315),
+    [sym_identifier] = ACTIONS(315),
+    [sym_universe] = ACTIONS(315),
+    [sym_number] = ACTIONS(313),
+    [sym_comment] = ACTIONS(3),
+  },
+  [52] = {
+    [ts_builtin_sym_end] = ACTIONS(317),
+    [anon_sym_LPAREN] = ACTIONS(317),
+    [anon_sym_RPAREN] = ACTIONS(317),
+    [anon_sym_SQUOTE] = ACTIONS(317),
+    [anon_sym_Atom] = ACTIONS(319),
+    [anon_sym_Nat] = ACTIONS(319),
+    [anon_sym_Trivial] = ACTIONS(319),
+    [anon_sym_Absurd] = ACTIONS(319),
+    [sym_zero] = ACTIONS(319),
+    [sym_nil] = ACTIONS(319),
+    [sym_vecnil] = ACTIONS(319),
+    [sym_sole] = ACTIONS(319),
+    [sym_todo] = ACTIONS(319),
+    [sym_identifier] = ACTIONS(319),
+    [sym_universe] = ACTIONS(319),
+    [sym_number] = ACTIONS(317),
+    [sym_comment] = ACTIONS(3),
+  },
+  [53] = {
+    [ts_builtin_sym_end] = ACTIONS(321),
+    [anon_sym_LPAREN] = ACTIONS(321),
+    [anon_sym_RPAREN] = ACTIONS(321),
+    [anon_sym_SQUOTE] = ACTIONS(321),
+    [anon_sym_Atom] = ACTIONS(323),
+    [anon_sym_Nat] = ACTIONS(323),
+    [anon_sym_Trivial] = ACTIONS(323),
+    [anon_sym_Absurd] = ACTIONS(323),
+    [sym_zero] = ACTIONS(323),
+    [sym_nil] = ACTIONS(323),
+    [sym_vecnil] = ACTIONS(323),
+    [sym_sole] = ACTIONS(323),
+    [sym_todo] = ACTIONS(323),
+    [sym_identifier] = ACTIONS(323),
+    [sym_universe] = ACTIONS(323),
+    [sym_number] = ACTIONS(321),
+    [sym_comment] = ACTIONS(3),
+  },
+  [54] = {
+    [sym_claim] = STATE(195),
+    [sym_define] = STATE(196),
+    [sym_check_same] = STATE(197),
+    [sym_expression] = STATE(198),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_source_repeat1] = STATE(54),
+    [ts_builtin_sym_end] = ACTIONS(325),
+    [anon_sym_LPAREN] = ACTIONS(327),
+    [anon_sym_SQUOTE] = ACTIONS(330),
+    [anon_sym_Atom] = ACTIONS(333),
+    [anon_sym_Nat] = ACTIONS(336),
+    [anon_sym_Trivial] = ACTIONS(339),
+    [anon_sym_Absurd] = ACTIONS(342),
+    [sym_zero] = ACTIONS(345),
+    [sym_nil] = ACTIONS(348),
+    [sym_vecnil] = ACTIONS(351),
+    [sym_sole] = ACTIONS(354),
+    [sym_todo] = ACTIONS(357),
+    [sym_identifier] = ACTIONS(360),
+    [sym_universe] = ACTIONS(363),
+    [sym_number] = ACTIONS(366),
+    [sym_comment] = ACTIONS(3),
+  },
+  [55] = {
+    [sym_expression] = STATE(94),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [56] = {
+    [sym_expression] = STATE(90),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_BSLASH] = ACTIONS(45),
+    [anon_sym_] = ACTIONS(47),
+    [anon_sym_lambda] = ACTIONS(49),
+    [anon_sym_DASH_GT] = ACTIONS(51),
+    [anon_sym_2] = ACTIONS(53),
+    [anon_sym_3] = ACTIONS(55),
+    [anon_sym_Pi] = ACTIONS(57),
+    [anon_sym_4] = ACTIONS(59),
+    [anon_sym_Sigma] = ACTIONS(61),
+    [anon_sym_Pair] = ACTIONS(63),
+    [anon_sym_cons] = ACTIONS(65),
+    [anon_sym_car] = ACTIONS(67),
+    [anon_sym_cdr] = ACTIONS(69),
+    [anon_sym_add1] = ACTIONS(71),
+    [anon_sym_which_DASHNat] = ACTIONS(73),
+    [anon_sym_iter_DASHNat] = ACTIONS(75),
+    [anon_sym_rec_DASHNat] = ACTIONS(77),
+    [anon_sym_ind_DASHNat] = ACTIONS(79),
+    [anon_sym_List] = ACTIONS(81),
+    [anon_sym_COLON_COLON] = ACTIONS(83),
+    [anon_sym_rec_DASHList] = ACTIONS(85),
+    [anon_sym_ind_DASHList] = ACTIONS(87),
+    [anon_sym_Vec] = ACTIONS(89),
+    [anon_sym_vec_COLON_COLON] = ACTIONS(91),
+    [anon_sym_head] = ACTIONS(93),
+    [anon_sym_tail] = ACTIONS(95),
+    [anon_sym_ind_DASHVec] = ACTIONS(97),
+    [anon_sym_EQ] = ACTIONS(99),
+    [anon_sym_same] = ACTIONS(101),
+    [anon_sym_cong] = ACTIONS(103),
+    [anon_sym_replace] = ACTIONS(105),
+    [anon_sym_symm] = ACTIONS(107),
+    [anon_sym_trans] = ACTIONS(109),
+    [anon_sym_ind_DASHEq] = ACTIONS(111),
+    [anon_sym_Either] = ACTIONS(113),
+    [anon_sym_left] = ACTIONS(115),
+    [anon_sym_right] = ACTIONS(117),
+    [anon_sym_ind_DASHEither] = ACTIONS(119),
+    [anon_sym_ind_DASHAbsurd] = ACTIONS(121),
+    [anon_sym_the] = ACTIONS(123),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [57] = {
+    [sym_expression] = STATE(211),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(95),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [58] = {
+    [sym_expression] = STATE(211),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(96),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [59] = {
+    [sym_expression] = STATE(97),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [60] = {
+    [sym_expression] = STATE(98),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [61] = {
+    [sym_expression] = STATE(216),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [62] = {
+    [sym_expression] = STATE(217),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [63] = {
+    [sym_expression] = STATE(218),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [64] = {
+    [sym_expression] = STATE(99),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [65] = {
+    [sym_expression] = STATE(100),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [66] = {
+    [sym_expression] = STATE(101),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [67] = {
+    [sym_expression] = STATE(102),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [68] = {
+    [sym_expression] = STATE(219),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [69] = {
+    [sym_expression] = STATE(103),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [70] = {
+    [sym_expression] = STATE(104),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [71] = {
+    [sym_expression] = STATE(105),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [72] = {
+    [sym_expression] = STATE(106),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [73] = {
+    [sym_expression] = STATE(107),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [74] = {
+    [sym_expression] = STATE(220),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [75] = {
+    [sym_expression] = STATE(221),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [76] = {
+    [sym_expression] = STATE(108),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [77] = {
+    [sym_expression] = STATE(109),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [78] = {
+    [sym_expression] = STATE(222),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [79] = {
+    [sym_expression] = STATE(110),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [80] = {
+    [sym_expression] = STATE(111),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [81] = {
+    [sym_expression] = STATE(223),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [82] = {
+    [sym_expression] = STATE(112),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [83] = {
+    [sym_expression] = STATE(113),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [84] = {
+    [sym_expression] = STATE(114),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [85] = {
+    [sym_expression] = STATE(224),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [86] = {
+    [sym_expression] = STATE(225),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [87] = {
+    [sym_expression] = STATE(115),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [88] = {
+    [sym_expression] = STATE(116),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [89] = {
+    [sym_expression] = STATE(117),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [90] = {
+    [sym_expression] = STATE(211),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(118),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_RPAREN] = ACTIONS(369),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [91] = {
+    [ts_builtin_sym_end] = ACTIONS(371),
+    [anon_sym_LPAREN] = ACTIONS(371),
+    [anon_sym_RPAREN] = ACTIONS(371),
+    [anon_sym_SQUOTE] = ACTIONS(371),
+    [anon_sym_Atom] = ACTIONS(373),
+    [anon_sym_Nat] = ACTIONS(373),
+    [anon_sym_Trivial] = ACTIONS(373),
+    [anon_sym_Absurd] = ACTIONS(373),
+    [sym_zero] = ACTIONS(373),
+    [sym_nil] = ACTIONS(373),
+    [sym_vecnil] = ACTIONS(373),
+    [sym_sole] = ACTIONS(373),
+    [sym_todo] = ACTIONS(373),
+    [sym_identifier] = ACTIONS(373),
+    [sym_universe] = ACTIONS(373),
+    [sym_number] = ACTIONS(371),
+    [sym_comment] = ACTIONS(3),
+  },
+  [92] = {
+    [sym_expression] = STATE(226),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [93] = {
+    [sym_expression] = STATE(227),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [94] = {
+    [sym_expression] = STATE(120),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [95] = {
+    [sym_expression] = STATE(232),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(124),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [96] = {
+    [sym_expression] = STATE(233),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(124),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [97] = {
+    [sym_expression] = STATE(240),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [98] = {
+    [sym_expression] = STATE(241),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [99] = {
+    [sym_expression] = STATE(128),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [100] = {
+    [sym_expression] = STATE(129),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [101] = {
+    [sym_expression] = STATE(130),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [102] = {
+    [sym_expression] = STATE(131),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [103] = {
+    [sym_expression] = STATE(242),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [104] = {
+    [sym_expression] = STATE(133),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [105] = {
+    [sym_expression] = STATE(134),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [106] = {
+    [sym_expression] = STATE(243),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [107] = {
+    [sym_expression] = STATE(244),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [108] = {
+    [sym_expression] = STATE(137),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [109] = {
+    [sym_expression] = STATE(138),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [110] = {
+    [sym_expression] = STATE(245),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [111] = {
+    [sym_expression] = STATE(140),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [112] = {
+    [sym_expression] = STATE(246),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [113] = {
+    [sym_expression] = STATE(142),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [114] = {
+    [sym_expression] = STATE(247),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [115] = {
+    [sym_expression] = STATE(145),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [116] = {
+    [sym_expression] = STATE(248),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [117] = {
+    [sym_expression] = STATE(249),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [118] = {
+    [sym_expression] = STATE(211),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(124),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_RPAREN] = ACTIONS(375),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [119] = {
+    [ts_builtin_sym_end] = ACTIONS(377),
+    [anon_sym_LPAREN] = ACTIONS(377),
+    [anon_sym_RPAREN] = ACTIONS(377),
+    [anon_sym_SQUOTE] = ACTIONS(377),
+    [anon_sym_Atom] = ACTIONS(379),
+    [anon_sym_Nat] = ACTIONS(379),
+    [anon_sym_Trivial] = ACTIONS(379),
+    [anon_sym_Absurd] = ACTIONS(379),
+    [sym_zero] = ACTIONS(379),
+    [sym_nil] = ACTIONS(379),
+    [sym_vecnil] = ACTIONS(379),
+    [sym_sole] = ACTIONS(379),
+    [sym_todo] = ACTIONS(379),
+    [sym_identifier] = ACTIONS(379),
+    [sym_universe] = ACTIONS(379),
+    [sym_number] = ACTIONS(377),
+    [sym_comment] = ACTIONS(3),
+  },
+  [120] = {
+    [sym_expression] = STATE(252),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [121] = {
+    [sym_expression] = STATE(254),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [122] = {
+    [sym_expression] = STATE(255),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [123] = {
+    [sym_expression] = STATE(256),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [124] = {
+    [sym_expression] = STATE(211),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [aux_sym_function_type_repeat1] = STATE(124),
+    [anon_sym_LPAREN] = ACTIONS(381),
+    [anon_sym_RPAREN] = ACTIONS(384),
+    [anon_sym_SQUOTE] = ACTIONS(386),
+    [anon_sym_Atom] = ACTIONS(389),
+    [anon_sym_Nat] = ACTIONS(392),
+    [anon_sym_Trivial] = ACTIONS(395),
+    [anon_sym_Absurd] = ACTIONS(398),
+    [sym_zero] = ACTIONS(401),
+    [sym_nil] = ACTIONS(404),
+    [sym_vecnil] = ACTIONS(407),
+    [sym_sole] = ACTIONS(410),
+    [sym_todo] = ACTIONS(413),
+    [sym_identifier] = ACTIONS(416),
+    [sym_universe] = ACTIONS(419),
+    [sym_number] = ACTIONS(422),
+    [sym_comment] = ACTIONS(3),
+  },
+  [125] = {
+    [ts_builtin_sym_end] = ACTIONS(425),
+    [anon_sym_LPAREN] = ACTIONS(425),
+    [anon_sym_RPAREN] = ACTIONS(425),
+    [anon_sym_SQUOTE] = ACTIONS(425),
+    [anon_sym_Atom] = ACTIONS(427),
+    [anon_sym_Nat] = ACTIONS(427),
+    [anon_sym_Trivial] = ACTIONS(427),
+    [anon_sym_Absurd] = ACTIONS(427),
+    [sym_zero] = ACTIONS(427),
+    [sym_nil] = ACTIONS(427),
+    [sym_vecnil] = ACTIONS(427),
+    [sym_sole] = ACTIONS(427),
+    [sym_todo] = ACTIONS(427),
+    [sym_identifier] = ACTIONS(427),
+    [sym_universe] = ACTIONS(427),
+    [sym_number] = ACTIONS(425),
+    [sym_comment] = ACTIONS(3),
+  },
+  [126] = {
+    [ts_builtin_sym_end] = ACTIONS(429),
+    [anon_sym_LPAREN] = ACTIONS(429),
+    [anon_sym_RPAREN] = ACTIONS(429),
+    [anon_sym_SQUOTE] = ACTIONS(429),
+    [anon_sym_Atom] = ACTIONS(431),
+    [anon_sym_Nat] = ACTIONS(431),
+    [anon_sym_Trivial] = ACTIONS(431),
+    [anon_sym_Absurd] = ACTIONS(431),
+    [sym_zero] = ACTIONS(431),
+    [sym_nil] = ACTIONS(431),
+    [sym_vecnil] = ACTIONS(431),
+    [sym_sole] = ACTIONS(431),
+    [sym_todo] = ACTIONS(431),
+    [sym_identifier] = ACTIONS(431),
+    [sym_universe] = ACTIONS(431),
+    [sym_number] = ACTIONS(429),
+    [sym_comment] = ACTIONS(3),
+  },
+  [127] = {
+    [ts_builtin_sym_end] = ACTIONS(433),
+    [anon_sym_LPAREN] = ACTIONS(433),
+    [anon_sym_RPAREN] = ACTIONS(433),
+    [anon_sym_SQUOTE] = ACTIONS(433),
+    [anon_sym_Atom] = ACTIONS(435),
+    [anon_sym_Nat] = ACTIONS(435),
+    [anon_sym_Trivial] = ACTIONS(435),
+    [anon_sym_Absurd] = ACTIONS(435),
+    [sym_zero] = ACTIONS(435),
+    [sym_nil] = ACTIONS(435),
+    [sym_vecnil] = ACTIONS(435),
+    [sym_sole] = ACTIONS(435),
+    [sym_todo] = ACTIONS(435),
+    [sym_identifier] = ACTIONS(435),
+    [sym_universe] = ACTIONS(435),
+    [sym_number] = ACTIONS(433),
+    [sym_comment] = ACTIONS(3),
+  },
+  [128] = {
+    [sym_expression] = STATE(258),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [129] = {
+    [sym_expression] = STATE(259),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [130] = {
+    [sym_expression] = STATE(260),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [131] = {
+    [sym_expression] = STATE(159),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [132] = {
+    [ts_builtin_sym_end] = ACTIONS(437),
+    [anon_sym_LPAREN] = ACTIONS(437),
+    [anon_sym_RPAREN] = ACTIONS(437),
+    [anon_sym_SQUOTE] = ACTIONS(437),
+    [anon_sym_Atom] = ACTIONS(439),
+    [anon_sym_Nat] = ACTIONS(439),
+    [anon_sym_Trivial] = ACTIONS(439),
+    [anon_sym_Absurd] = ACTIONS(439),
+    [sym_zero] = ACTIONS(439),
+    [sym_nil] = ACTIONS(439),
+    [sym_vecnil] = ACTIONS(439),
+    [sym_sole] = ACTIONS(439),
+    [sym_todo] = ACTIONS(439),
+    [sym_identifier] = ACTIONS(439),
+    [sym_universe] = ACTIONS(439),
+    [sym_number] = ACTIONS(437),
+    [sym_comment] = ACTIONS(3),
+  },
+  [133] = {
+    [sym_expression] = STATE(261),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [134] = {
+    [sym_expression] = STATE(161),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [135] = {
+    [ts_builtin_sym_end] = ACTIONS(441),
+    [anon_sym_LPAREN] = ACTIONS(441),
+    [anon_sym_RPAREN] = ACTIONS(441),
+    [anon_sym_SQUOTE] = ACTIONS(441),
+    [anon_sym_Atom] = ACTIONS(443),
+    [anon_sym_Nat] = ACTIONS(443),
+    [anon_sym_Trivial] = ACTIONS(443),
+    [anon_sym_Absurd] = ACTIONS(443),
+    [sym_zero] = ACTIONS(443),
+    [sym_nil] = ACTIONS(443),
+    [sym_vecnil] = ACTIONS(443),
+    [sym_sole] = ACTIONS(443),
+    [sym_todo] = ACTIONS(443),
+    [sym_identifier] = ACTIONS(443),
+    [sym_universe] = ACTIONS(443),
+    [sym_number] = ACTIONS(441),
+    [sym_comment] = ACTIONS(3),
+  },
+  [136] = {
+    [ts_builtin_sym_end] = ACTIONS(445),
+    [anon_sym_LPAREN] = ACTIONS(445),
+    [anon_sym_RPAREN] = ACTIONS(445),
+    [anon_sym_SQUOTE] = ACTIONS(445),
+    [anon_sym_Atom] = ACTIONS(447),
+    [anon_sym_Nat] = ACTIONS(447),
+    [anon_sym_Trivial] = ACTIONS(447),
+    [anon_sym_Absurd] = ACTIONS(447),
+    [sym_zero] = ACTIONS(447),
+    [sym_nil] = ACTIONS(447),
+    [sym_vecnil] = ACTIONS(447),
+    [sym_sole] = ACTIONS(447),
+    [sym_todo] = ACTIONS(447),
+    [sym_identifier] = ACTIONS(447),
+    [sym_universe] = ACTIONS(447),
+    [sym_number] = ACTIONS(445),
+    [sym_comment] = ACTIONS(3),
+  },
+  [137] = {
+    [sym_expression] = STATE(164),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [138] = {
+    [sym_expression] = STATE(262),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [139] = {
+    [ts_builtin_sym_end] = ACTIONS(449),
+    [anon_sym_LPAREN] = ACTIONS(449),
+    [anon_sym_RPAREN] = ACTIONS(449),
+    [anon_sym_SQUOTE] = ACTIONS(449),
+    [anon_sym_Atom] = ACTIONS(451),
+    [anon_sym_Nat] = ACTIONS(451),
+    [anon_sym_Trivial] = ACTIONS(451),
+    [anon_sym_Absurd] = ACTIONS(451),
+    [sym_zero] = ACTIONS(451),
+    [sym_nil] = ACTIONS(451),
+    [sym_vecnil] = ACTIONS(451),
+    [sym_sole] = ACTIONS(451),
+    [sym_todo] = ACTIONS(451),
+    [sym_identifier] = ACTIONS(451),
+    [sym_universe] = ACTIONS(451),
+    [sym_number] = ACTIONS(449),
+    [sym_comment] = ACTIONS(3),
+  },
+  [140] = {
+    [sym_expression] = STATE(263),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [141] = {
+    [ts_builtin_sym_end] = ACTIONS(453),
+    [anon_sym_LPAREN] = ACTIONS(453),
+    [anon_sym_RPAREN] = ACTIONS(453),
+    [anon_sym_SQUOTE] = ACTIONS(453),
+    [anon_sym_Atom] = ACTIONS(455),
+    [anon_sym_Nat] = ACTIONS(455),
+    [anon_sym_Trivial] = ACTIONS(455),
+    [anon_sym_Absurd] = ACTIONS(455),
+    [sym_zero] = ACTIONS(455),
+    [sym_nil] = ACTIONS(455),
+    [sym_vecnil] = ACTIONS(455),
+    [sym_sole] = ACTIONS(455),
+    [sym_todo] = ACTIONS(455),
+    [sym_identifier] = ACTIONS(455),
+    [sym_universe] = ACTIONS(455),
+    [sym_number] = ACTIONS(453),
+    [sym_comment] = ACTIONS(3),
+  },
+  [142] = {
+    [sym_expression] = STATE(264),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [143] = {
+    [ts_builtin_sym_end] = ACTIONS(457),
+    [anon_sym_LPAREN] = ACTIONS(457),
+    [anon_sym_RPAREN] = ACTIONS(457),
+    [anon_sym_SQUOTE] = ACTIONS(457),
+    [anon_sym_Atom] = ACTIONS(459),
+    [anon_sym_Nat] = ACTIONS(459),
+    [anon_sym_Trivial] = ACTIONS(459),
+    [anon_sym_Absurd] = ACTIONS(459),
+    [sym_zero] = ACTIONS(459),
+    [sym_nil] = ACTIONS(459),
+    [sym_vecnil] = ACTIONS(459),
+    [sym_sole] = ACTIONS(459),
+    [sym_todo] = ACTIONS(459),
+    [sym_identifier] = ACTIONS(459),
+    [sym_universe] = ACTIONS(459),
+    [sym_number] = ACTIONS(457),
+    [sym_comment] = ACTIONS(3),
+  },
+  [144] = {
+    [ts_builtin_sym_end] = ACTIONS(461),
+    [anon_sym_LPAREN] = ACTIONS(461),
+    [anon_sym_RPAREN] = ACTIONS(461),
+    [anon_sym_SQUOTE] = ACTIONS(461),
+    [anon_sym_Atom] = ACTIONS(463),
+    [anon_sym_Nat] = ACTIONS(463),
+    [anon_sym_Trivial] = ACTIONS(463),
+    [anon_sym_Absurd] = ACTIONS(463),
+    [sym_zero] = ACTIONS(463),
+    [sym_nil] = ACTIONS(463),
+    [sym_vecnil] = ACTIONS(463),
+    [sym_sole] = ACTIONS(463),
+    [sym_todo] = ACTIONS(463),
+    [sym_identifier] = ACTIONS(463),
+    [sym_universe] = ACTIONS(463),
+    [sym_number] = ACTIONS(461),
+    [sym_comment] = ACTIONS(3),
+  },
+  [145] = {
+    [sym_expression] = STATE(168),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [146] = {
+    [ts_builtin_sym_end] = ACTIONS(465),
+    [anon_sym_LPAREN] = ACTIONS(465),
+    [anon_sym_RPAREN] = ACTIONS(465),
+    [anon_sym_SQUOTE] = ACTIONS(465),
+    [anon_sym_Atom] = ACTIONS(467),
+    [anon_sym_Nat] = ACTIONS(467),
+    [anon_sym_Trivial] = ACTIONS(467),
+    [anon_sym_Absurd] = ACTIONS(467),
+    [sym_zero] = ACTIONS(467),
+    [sym_nil] = ACTIONS(467),
+    [sym_vecnil] = ACTIONS(467),
+    [sym_sole] = ACTIONS(467),
+    [sym_todo] = ACTIONS(467),
+    [sym_identifier] = ACTIONS(467),
+    [sym_universe] = ACTIONS(467),
+    [sym_number] = ACTIONS(465),
+    [sym_comment] = ACTIONS(3),
+  },
+  [147] = {
+    [sym_expression] = STATE(266),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [148] = {
+    [sym_expression] = STATE(267),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [149] = {
+    [sym_expression] = STATE(268),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [150] = {
+    [ts_builtin_sym_end] = ACTIONS(469),
+    [anon_sym_LPAREN] = ACTIONS(469),
+    [anon_sym_RPAREN] = ACTIONS(469),
+    [anon_sym_SQUOTE] = ACTIONS(469),
+    [anon_sym_Atom] = ACTIONS(471),
+    [anon_sym_Nat] = ACTIONS(471),
+    [anon_sym_Trivial] = ACTIONS(471),
+    [anon_sym_Absurd] = ACTIONS(471),
+    [sym_zero] = ACTIONS(471),
+    [sym_nil] = ACTIONS(471),
+    [sym_vecnil] = ACTIONS(471),
+    [sym_sole] = ACTIONS(471),
+    [sym_todo] = ACTIONS(471),
+    [sym_identifier] = ACTIONS(471),
+    [sym_universe] = ACTIONS(471),
+    [sym_number] = ACTIONS(469),
+    [sym_comment] = ACTIONS(3),
+  },
+  [151] = {
+    [ts_builtin_sym_end] = ACTIONS(473),
+    [anon_sym_LPAREN] = ACTIONS(473),
+    [anon_sym_RPAREN] = ACTIONS(473),
+    [anon_sym_SQUOTE] = ACTIONS(473),
+    [anon_sym_Atom] = ACTIONS(475),
+    [anon_sym_Nat] = ACTIONS(475),
+    [anon_sym_Trivial] = ACTIONS(475),
+    [anon_sym_Absurd] = ACTIONS(475),
+    [sym_zero] = ACTIONS(475),
+    [sym_nil] = ACTIONS(475),
+    [sym_vecnil] = ACTIONS(475),
+    [sym_sole] = ACTIONS(475),
+    [sym_todo] = ACTIONS(475),
+    [sym_identifier] = ACTIONS(475),
+    [sym_universe] = ACTIONS(475),
+    [sym_number] = ACTIONS(473),
+    [sym_comment] = ACTIONS(3),
+  },
+  [152] = {
+    [sym_expression] = STATE(269),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [153] = {
+    [sym_expression] = STATE(270),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [154] = {
+    [sym_expression] = STATE(271),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [155] = {
+    [sym_expression] = STATE(272),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [156] = {
+    [sym_expression] = STATE(273),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [157] = {
+    [ts_builtin_sym_end] = ACTIONS(477),
+    [anon_sym_LPAREN] = ACTIONS(477),
+    [anon_sym_RPAREN] = ACTIONS(477),
+    [anon_sym_SQUOTE] = ACTIONS(477),
+    [anon_sym_Atom] = ACTIONS(479),
+    [anon_sym_Nat] = ACTIONS(479),
+    [anon_sym_Trivial] = ACTIONS(479),
+    [anon_sym_Absurd] = ACTIONS(479),
+    [sym_zero] = ACTIONS(479),
+    [sym_nil] = ACTIONS(479),
+    [sym_vecnil] = ACTIONS(479),
+    [sym_sole] = ACTIONS(479),
+    [sym_todo] = ACTIONS(479),
+    [sym_identifier] = ACTIONS(479),
+    [sym_universe] = ACTIONS(479),
+    [sym_number] = ACTIONS(477),
+    [sym_comment] = ACTIONS(3),
+  },
+  [158] = {
+    [ts_builtin_sym_end] = ACTIONS(481),
+    [anon_sym_LPAREN] = ACTIONS(481),
+    [anon_sym_RPAREN] = ACTIONS(481),
+    [anon_sym_SQUOTE] = ACTIONS(481),
+    [anon_sym_Atom] = ACTIONS(483),
+    [anon_sym_Nat] = ACTIONS(483),
+    [anon_sym_Trivial] = ACTIONS(483),
+    [anon_sym_Absurd] = ACTIONS(483),
+    [sym_zero] = ACTIONS(483),
+    [sym_nil] = ACTIONS(483),
+    [sym_vecnil] = ACTIONS(483),
+    [sym_sole] = ACTIONS(483),
+    [sym_todo] = ACTIONS(483),
+    [sym_identifier] = ACTIONS(483),
+    [sym_universe] = ACTIONS(483),
+    [sym_number] = ACTIONS(481),
+    [sym_comment] = ACTIONS(3),
+  },
+  [159] = {
+    [sym_expression] = STATE(274),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [160] = {
+    [ts_builtin_sym_end] = ACTIONS(485),
+    [anon_sym_LPAREN] = ACTIONS(485),
+    [anon_sym_RPAREN] = ACTIONS(485),
+    [anon_sym_SQUOTE] = ACTIONS(485),
+    [anon_sym_Atom] = ACTIONS(487),
+    [anon_sym_Nat] = ACTIONS(487),
+    [anon_sym_Trivial] = ACTIONS(487),
+    [anon_sym_Absurd] = ACTIONS(487),
+    [sym_zero] = ACTIONS(487),
+    [sym_nil] = ACTIONS(487),
+    [sym_vecnil] = ACTIONS(487),
+    [sym_sole] = ACTIONS(487),
+    [sym_todo] = ACTIONS(487),
+    [sym_identifier] = ACTIONS(487),
+    [sym_universe] = ACTIONS(487),
+    [sym_number] = ACTIONS(485),
+    [sym_comment] = ACTIONS(3),
+  },
+  [161] = {
+    [sym_expression] = STATE(275),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [162] = {
+    [ts_builtin_sym_end] = ACTIONS(489),
+    [anon_sym_LPAREN] = ACTIONS(489),
+    [anon_sym_RPAREN] = ACTIONS(489),
+    [anon_sym_SQUOTE] = ACTIONS(489),
+    [anon_sym_Atom] = ACTIONS(491),
+    [anon_sym_Nat] = ACTIONS(491),
+    [anon_sym_Trivial] = ACTIONS(491),
+    [anon_sym_Absurd] = ACTIONS(491),
+    [sym_zero] = ACTIONS(491),
+    [sym_nil] = ACTIONS(491),
+    [sym_vecnil] = ACTIONS(491),
+    [sym_sole] = ACTIONS(491),
+    [sym_todo] = ACTIONS(491),
+    [sym_identifier] = ACTIONS(491),
+    [sym_universe] = ACTIONS(491),
+    [sym_number] = ACTIONS(489),
+    [sym_comment] = ACTIONS(3),
+  },
+  [163] = {
+    [ts_builtin_sym_end] = ACTIONS(493),
+    [anon_sym_LPAREN] = ACTIONS(493),
+    [anon_sym_RPAREN] = ACTIONS(493),
+    [anon_sym_SQUOTE] = ACTIONS(493),
+    [anon_sym_Atom] = ACTIONS(495),
+    [anon_sym_Nat] = ACTIONS(495),
+    [anon_sym_Trivial] = ACTIONS(495),
+    [anon_sym_Absurd] = ACTIONS(495),
+    [sym_zero] = ACTIONS(495),
+    [sym_nil] = ACTIONS(495),
+    [sym_vecnil] = ACTIONS(495),
+    [sym_sole] = ACTIONS(495),
+    [sym_todo] = ACTIONS(495),
+    [sym_identifier] = ACTIONS(495),
+    [sym_universe] = ACTIONS(495),
+    [sym_number] = ACTIONS(493),
+    [sym_comment] = ACTIONS(3),
+  },
+  [164] = {
+    [sym_expression] = STATE(178),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [165] = {
+    [ts_builtin_sym_end] = ACTIONS(497),
+    [anon_sym_LPAREN] = ACTIONS(497),
+    [anon_sym_RPAREN] = ACTIONS(497),
+    [anon_sym_SQUOTE] = ACTIONS(497),
+    [anon_sym_Atom] = ACTIONS(499),
+    [anon_sym_Nat] = ACTIONS(499),
+    [anon_sym_Trivial] = ACTIONS(499),
+    [anon_sym_Absurd] = ACTIONS(499),
+    [sym_zero] = ACTIONS(499),
+    [sym_nil] = ACTIONS(499),
+    [sym_vecnil] = ACTIONS(499),
+    [sym_sole] = ACTIONS(499),
+    [sym_todo] = ACTIONS(499),
+    [sym_identifier] = ACTIONS(499),
+    [sym_universe] = ACTIONS(499),
+    [sym_number] = ACTIONS(497),
+    [sym_comment] = ACTIONS(3),
+  },
+  [166] = {
+    [ts_builtin_sym_end] = ACTIONS(501),
+    [anon_sym_LPAREN] = ACTIONS(501),
+    [anon_sym_RPAREN] = ACTIONS(501),
+    [anon_sym_SQUOTE] = ACTIONS(501),
+    [anon_sym_Atom] = ACTIONS(503),
+    [anon_sym_Nat] = ACTIONS(503),
+    [anon_sym_Trivial] = ACTIONS(503),
+    [anon_sym_Absurd] = ACTIONS(503),
+    [sym_zero] = ACTIONS(503),
+    [sym_nil] = ACTIONS(503),
+    [sym_vecnil] = ACTIONS(503),
+    [sym_sole] = ACTIONS(503),
+    [sym_todo] = ACTIONS(503),
+    [sym_identifier] = ACTIONS(503),
+    [sym_universe] = ACTIONS(503),
+    [sym_number] = ACTIONS(501),
+    [sym_comment] = ACTIONS(3),
+  },
+  [167] = {
+    [ts_builtin_sym_end] = ACTIONS(505),
+    [anon_sym_LPAREN] = ACTIONS(505),
+    [anon_sym_RPAREN] = ACTIONS(505),
+    [anon_sym_SQUOTE] = ACTIONS(505),
+    [anon_sym_Atom] = ACTIONS(507),
+    [anon_sym_Nat] = ACTIONS(507),
+    [anon_sym_Trivial] = ACTIONS(507),
+    [anon_sym_Absurd] = ACTIONS(507),
+    [sym_zero] = ACTIONS(507),
+    [sym_nil] = ACTIONS(507),
+    [sym_vecnil] = ACTIONS(507),
+    [sym_sole] = ACTIONS(507),
+    [sym_todo] = ACTIONS(507),
+    [sym_identifier] = ACTIONS(507),
+    [sym_universe] = ACTIONS(507),
+    [sym_number] = ACTIONS(505),
+    [sym_comment] = ACTIONS(3),
+  },
+  [168] = {
+    [sym_expression] = STATE(276),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [169] = {
+    [ts_builtin_sym_end] = ACTIONS(509),
+    [anon_sym_LPAREN] = ACTIONS(509),
+    [anon_sym_RPAREN] = ACTIONS(509),
+    [anon_sym_SQUOTE] = ACTIONS(509),
+    [anon_sym_Atom] = ACTIONS(511),
+    [anon_sym_Nat] = ACTIONS(511),
+    [anon_sym_Trivial] = ACTIONS(511),
+    [anon_sym_Absurd] = ACTIONS(511),
+    [sym_zero] = ACTIONS(511),
+    [sym_nil] = ACTIONS(511),
+    [sym_vecnil] = ACTIONS(511),
+    [sym_sole] = ACTIONS(511),
+    [sym_todo] = ACTIONS(511),
+    [sym_identifier] = ACTIONS(511),
+    [sym_universe] = ACTIONS(511),
+    [sym_number] = ACTIONS(509),
+    [sym_comment] = ACTIONS(3),
+  },
+  [170] = {
+    [ts_builtin_sym_end] = ACTIONS(513),
+    [anon_sym_LPAREN] = ACTIONS(513),
+    [anon_sym_RPAREN] = ACTIONS(513),
+    [anon_sym_SQUOTE] = ACTIONS(513),
+    [anon_sym_Atom] = ACTIONS(515),
+    [anon_sym_Nat] = ACTIONS(515),
+    [anon_sym_Trivial] = ACTIONS(515),
+    [anon_sym_Absurd] = ACTIONS(515),
+    [sym_zero] = ACTIONS(515),
+    [sym_nil] = ACTIONS(515),
+    [sym_vecnil] = ACTIONS(515),
+    [sym_sole] = ACTIONS(515),
+    [sym_todo] = ACTIONS(515),
+    [sym_identifier] = ACTIONS(515),
+    [sym_universe] = ACTIONS(515),
+    [sym_number] = ACTIONS(513),
+    [sym_comment] = ACTIONS(3),
+  },
+  [171] = {
+    [ts_builtin_sym_end] = ACTIONS(517),
+    [anon_sym_LPAREN] = ACTIONS(517),
+    [anon_sym_RPAREN] = ACTIONS(517),
+    [anon_sym_SQUOTE] = ACTIONS(517),
+    [anon_sym_Atom] = ACTIONS(519),
+    [anon_sym_Nat] = ACTIONS(519),
+    [anon_sym_Trivial] = ACTIONS(519),
+    [anon_sym_Absurd] = ACTIONS(519),
+    [sym_zero] = ACTIONS(519),
+    [sym_nil] = ACTIONS(519),
+    [sym_vecnil] = ACTIONS(519),
+    [sym_sole] = ACTIONS(519),
+    [sym_todo] = ACTIONS(519),
+    [sym_identifier] = ACTIONS(519),
+    [sym_universe] = ACTIONS(519),
+    [sym_number] = ACTIONS(517),
+    [sym_comment] = ACTIONS(3),
+  },
+  [172] = {
+    [ts_builtin_sym_end] = ACTIONS(521),
+    [anon_sym_LPAREN] = ACTIONS(521),
+    [anon_sym_RPAREN] = ACTIONS(521),
+    [anon_sym_SQUOTE] = ACTIONS(521),
+    [anon_sym_Atom] = ACTIONS(523),
+    [anon_sym_Nat] = ACTIONS(523),
+    [anon_sym_Trivial] = ACTIONS(523),
+    [anon_sym_Absurd] = ACTIONS(523),
+    [sym_zero] = ACTIONS(523),
+    [sym_nil] = ACTIONS(523),
+    [sym_vecnil] = ACTIONS(523),
+    [sym_sole] = ACTIONS(523),
+    [sym_todo] = ACTIONS(523),
+    [sym_identifier] = ACTIONS(523),
+    [sym_universe] = ACTIONS(523),
+    [sym_number] = ACTIONS(521),
+    [sym_comment] = ACTIONS(3),
+  },
+  [173] = {
+    [ts_builtin_sym_end] = ACTIONS(525),
+    [anon_sym_LPAREN] = ACTIONS(525),
+    [anon_sym_RPAREN] = ACTIONS(525),
+    [anon_sym_SQUOTE] = ACTIONS(525),
+    [anon_sym_Atom] = ACTIONS(527),
+    [anon_sym_Nat] = ACTIONS(527),
+    [anon_sym_Trivial] = ACTIONS(527),
+    [anon_sym_Absurd] = ACTIONS(527),
+    [sym_zero] = ACTIONS(527),
+    [sym_nil] = ACTIONS(527),
+    [sym_vecnil] = ACTIONS(527),
+    [sym_sole] = ACTIONS(527),
+    [sym_todo] = ACTIONS(527),
+    [sym_identifier] = ACTIONS(527),
+    [sym_universe] = ACTIONS(527),
+    [sym_number] = ACTIONS(525),
+    [sym_comment] = ACTIONS(3),
+  },
+  [174] = {
+    [ts_builtin_sym_end] = ACTIONS(529),
+    [anon_sym_LPAREN] = ACTIONS(529),
+    [anon_sym_RPAREN] = ACTIONS(529),
+    [anon_sym_SQUOTE] = ACTIONS(529),
+    [anon_sym_Atom] = ACTIONS(531),
+    [anon_sym_Nat] = ACTIONS(531),
+    [anon_sym_Trivial] = ACTIONS(531),
+    [anon_sym_Absurd] = ACTIONS(531),
+    [sym_zero] = ACTIONS(531),
+    [sym_nil] = ACTIONS(531),
+    [sym_vecnil] = ACTIONS(531),
+    [sym_sole] = ACTIONS(531),
+    [sym_todo] = ACTIONS(531),
+    [sym_identifier] = ACTIONS(531),
+    [sym_universe] = ACTIONS(531),
+    [sym_number] = ACTIONS(529),
+    [sym_comment] = ACTIONS(3),
+  },
+  [175] = {
+    [ts_builtin_sym_end] = ACTIONS(533),
+    [anon_sym_LPAREN] = ACTIONS(533),
+    [anon_sym_RPAREN] = ACTIONS(533),
+    [anon_sym_SQUOTE] = ACTIONS(533),
+    [anon_sym_Atom] = ACTIONS(535),
+    [anon_sym_Nat] = ACTIONS(535),
+    [anon_sym_Trivial] = ACTIONS(535),
+    [anon_sym_Absurd] = ACTIONS(535),
+    [sym_zero] = ACTIONS(535),
+    [sym_nil] = ACTIONS(535),
+    [sym_vecnil] = ACTIONS(535),
+    [sym_sole] = ACTIONS(535),
+    [sym_todo] = ACTIONS(535),
+    [sym_identifier] = ACTIONS(535),
+    [sym_universe] = ACTIONS(535),
+    [sym_number] = ACTIONS(533),
+    [sym_comment] = ACTIONS(3),
+  },
+  [176] = {
+    [ts_builtin_sym_end] = ACTIONS(537),
+    [anon_sym_LPAREN] = ACTIONS(537),
+    [anon_sym_RPAREN] = ACTIONS(537),
+    [anon_sym_SQUOTE] = ACTIONS(537),
+    [anon_sym_Atom] = ACTIONS(539),
+    [anon_sym_Nat] = ACTIONS(539),
+    [anon_sym_Trivial] = ACTIONS(539),
+    [anon_sym_Absurd] = ACTIONS(539),
+    [sym_zero] = ACTIONS(539),
+    [sym_nil] = ACTIONS(539),
+    [sym_vecnil] = ACTIONS(539),
+    [sym_sole] = ACTIONS(539),
+    [sym_todo] = ACTIONS(539),
+    [sym_identifier] = ACTIONS(539),
+    [sym_universe] = ACTIONS(539),
+    [sym_number] = ACTIONS(537),
+    [sym_comment] = ACTIONS(3),
+  },
+  [177] = {
+    [ts_builtin_sym_end] = ACTIONS(541),
+    [anon_sym_LPAREN] = ACTIONS(541),
+    [anon_sym_RPAREN] = ACTIONS(541),
+    [anon_sym_SQUOTE] = ACTIONS(541),
+    [anon_sym_Atom] = ACTIONS(543),
+    [anon_sym_Nat] = ACTIONS(543),
+    [anon_sym_Trivial] = ACTIONS(543),
+    [anon_sym_Absurd] = ACTIONS(543),
+    [sym_zero] = ACTIONS(543),
+    [sym_nil] = ACTIONS(543),
+    [sym_vecnil] = ACTIONS(543),
+    [sym_sole] = ACTIONS(543),
+    [sym_todo] = ACTIONS(543),
+    [sym_identifier] = ACTIONS(543),
+    [sym_universe] = ACTIONS(543),
+    [sym_number] = ACTIONS(541),
+    [sym_comment] = ACTIONS(3),
+  },
+  [178] = {
+    [sym_expression] = STATE(278),
+    [sym_atom] = STATE(4),
+    [sym_lambda] = STATE(5),
+    [sym_function_type] = STATE(6),
+    [sym_pi_type] = STATE(7),
+    [sym_sigma_type] = STATE(8),
+    [sym_pair_type] = STATE(9),
+    [sym_cons] = STATE(10),
+    [sym_car] = STATE(11),
+    [sym_cdr] = STATE(12),
+    [sym_add1] = STATE(14),
+    [sym_which_nat] = STATE(16),
+    [sym_iter_nat] = STATE(17),
+    [sym_rec_nat] = STATE(18),
+    [sym_ind_nat] = STATE(19),
+    [sym_list_type] = STATE(20),
+    [sym_list_cons] = STATE(22),
+    [sym_rec_list] = STATE(23),
+    [sym_ind_list] = STATE(24),
+    [sym_vec_type] = STATE(25),
+    [sym_vec_cons] = STATE(27),
+    [sym_head] = STATE(28),
+    [sym_tail] = STATE(29),
+    [sym_ind_vec] = STATE(30),
+    [sym_equal_type] = STATE(31),
+    [sym_same] = STATE(32),
+    [sym_cong] = STATE(33),
+    [sym_replace] = STATE(34),
+    [sym_symm] = STATE(35),
+    [sym_trans] = STATE(36),
+    [sym_ind_eq] = STATE(37),
+    [sym_either_type] = STATE(38),
+    [sym_left] = STATE(39),
+    [sym_right] = STATE(40),
+    [sym_ind_either] = STATE(41),
+    [sym_ind_absurd] = STATE(43),
+    [sym_the] = STATE(44),
+    [sym_application] = STATE(46),
+    [sym_base_type] = STATE(47),
+    [anon_sym_LPAREN] = ACTIONS(37),
+    [anon_sym_SQUOTE] = ACTIONS(9),
+    [anon_sym_Atom] = ACTIONS(11),
+    [anon_sym_Nat] = ACTIONS(13),
+    [anon_sym_Trivial] = ACTIONS(15),
+    [anon_sym_Absurd] = ACTIONS(17),
+    [sym_zero] = ACTIONS(19),
+    [sym_nil] = ACTIONS(21),
+    [sym_vecnil] = ACTIONS(23),
+    [sym_sole] = ACTIONS(25),
+    [sym_todo] = ACTIONS(27),
+    [sym_identifier] = ACTIONS(29),
+    [sym_universe] = ACTIONS(31),
+    [sym_number] = ACTIONS(33),
+    [sym_comment] = ACTIONS(3),
+  },
+  [179] = {
+    [ts_builtin_sym_end] = ACTIONS(545),
+    [anon_sym_LPAREN] = ACTIONS(545),
+    [anon_sym_RPAREN] = ACTIONS(545),
+    [anon_sym_SQUOTE] = ACTIONS(545),
+    [anon_sym_Atom] = ACTIONS(547),
+    [anon_sym_Nat] = ACTIONS(547),
+    [anon_sym_Trivial] = ACTIONS(547),
+    [anon_sym_Absurd] = ACTIONS(547),
+    [sym_zero] = ACTIONS(547),
+    [sym_nil] = ACTIONS(547),
+    [sym_vecnil] = ACTIONS(547),
+    [sym_sole] = ACTIONS(547),
+    [sym_todo] = ACTIONS(547),
+    [sym_identifier] = ACTIONS(547),
+    [sym_universe] = ACTIONS(547),
+    [sym_number] = ACTIONS(545),
+    [sym_comment] = ACTIONS(3),
+  },
+  [180] = {
+    [ts_builtin_sym_end] = ACTIONS(549),
+    [anon_sym_LPAREN] = ACTIONS(549),
+    [anon_sym_RPAREN] = ACTIONS(549),
+    [anon_sym_SQUOTE] = ACTIONS(549),
+    [anon_sym_Atom] = ACTIONS(551),
+    [anon_sym_Nat] = ACTIONS(551),
+    [anon_sym_Trivial] = ACTIONS(551),
+    [anon_sym_Absurd] = ACTIONS(551),
+    [sym_zero] = ACTIONS(551),
+    [sym_nil] = ACTIONS(551),
+    [sym_vecnil] = ACTIONS(551),
+    [sym_sole] = ACTIONS(551),
+    [sym_todo] = ACTIONS(551),
+    [sym_identifier] = ACTIONS(551),
+    [sym_universe] = ACTIONS(551),
+    [sym_number] = ACTIONS(549),
+    [sym_comment] = ACTIONS(3),
+  },
+  [181] = {
+    [ts_builtin_sym_end] = ACTIONS(553),
+    [anon_sym_LPAREN] = ACTIONS(553),
+    [anon_sym_RPAREN] = ACTIONS(553),
+    [anon_sym_SQUOTE] = ACTIONS(553),
+    [anon_sym_Atom] = ACTIONS(555),
+    [anon_sym_Nat] = ACTIONS(555),
+    [anon_sym_Trivial] = ACTIONS(555),
+    [anon_sym_Absurd] = ACTIONS(555),
+    [sym_zero] = ACTIONS(555),
+    [sym_nil] = ACTIONS(555),
+    [sym_vecnil] = ACTIONS(555),
+    [sym_sole] = ACTIONS(555),
+    [sym_todo] = ACTIONS(555),
+    [sym_identifier] = ACTIONS(555),
+    [sym_universe] = ACTIONS(555),
+    [sym_number] = ACTIONS(553),
+    [sym_comment] = ACTIONS(3),
+  },
+  [182] = {
+    [ts_builtin_sym_end] = ACTIONS(557),
+    [anon_sym_LPAREN] = ACTIONS(557),
+    [anon_sym_RPAREN] = ACTIONS(557),
+    [anon_sym_SQUOTE] = ACTIONS(557),
+    [anon_sym_Atom] = ACTIONS(559),
+    [anon_sym_Nat] = ACTIONS(559),
+    [anon_sym_Trivial] = ACTIONS(559),
+    [anon_sym_Absurd] = ACTIONS(559),
+    [sym_zero] = ACTIONS(559),
+    [sym_nil] = ACTIONS(559),
+    [sym_vecnil] = ACTIONS(559),
+    [sym_sole] = ACTIONS(559),
+    [sym_todo] = ACTIONS(559),
+    [sym_identifier] = ACTIONS(559),
+    [sym_universe] = ACTIONS(559),
+    [sym_number] = ACTIONS(557),
+    [sym_comment] = ACTIONS(3),
+  },
+  [183] = {
+    [ts_builtin_sym_end] = ACTIONS(561),
+    [anon_sym_LPAREN] = ACTIONS(561),
+    [anon_sym_RPAREN] = ACTIONS(561),
+    [anon_sym_SQUOTE] = ACTIONS(561),
+    [anon_sym_Atom] = ACTIONS(563),
+    [anon_sym_Nat] = ACTIONS(563),
+    [anon_sym_Trivial] = ACTIONS(563),
+    [anon_sym_Absurd] = ACTIONS(563),
+    [sym_zero] = ACTIONS(563),
+    [sym_nil] = ACTIONS(563),
+    [sym_vecnil] = ACTIONS(563),
+    [sym_sole] = ACTIONS(563),
+    [sym_todo] = ACTIONS(563),
+    [sym_identifier] = ACTIONS(563),
+    [sym_universe] = ACTIONS(563),
+    [sym_number] = ACTIONS(561),
     [sym_comment] = ACTIONS(3),
   },
-  [2] = {
-    [sym_expression] = STATE(8),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_claim] = ACTIONS(15),
-    [anon_sym_define] = ACTIONS(17),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [anon_sym_BSLASH] = ACTIONS(19),
-    [anon_sym_] = ACTIONS(21),
-    [anon_sym_lambda] = ACTIONS(21),
-    [sym_identifier] = ACTIONS(23),
-    [sym_type_identifier] = ACTIONS(11),
+  [184] = {
+    [ts_builtin_sym_end] = ACTIONS(565),
+    [anon_sym_LPAREN] = ACTIONS(565),
+    [anon_sym_RPAREN] = ACTIONS(565),
+    [anon_sym_SQUOTE] = ACTIONS(565),
+    [anon_sym_Atom] = ACTIONS(567),
+    [anon_sym_Nat] = ACTIONS(567),
+    [anon_sym_Trivial] = ACTIONS(567),
+    [anon_sym_Absurd] = ACTIONS(567),
+    [sym_zero] = ACTIONS(567),
+    [sym_nil] = ACTIONS(567),
+    [sym_vecnil] = ACTIONS(567),
+    [sym_sole] = ACTIONS(567),
+    [sym_todo] = ACTIONS(567),
+    [sym_identifier] = ACTIONS(567),
+    [sym_universe] = ACTIONS(567),
+    [sym_number] = ACTIONS(565),
     [sym_comment] = ACTIONS(3),
   },
-  [3] = {
-    [sym_claim] = STATE(4),
-    [sym_define] = STATE(4),
-    [sym_expression] = STATE(4),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_source_repeat1] = STATE(4),
-    [ts_builtin_sym_end] = ACTIONS(25),
-    [anon_sym_LPAREN] = ACTIONS(7),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+  [185] = {
+    [ts_builtin_sym_end] = ACTIONS(569),
+    [anon_sym_LPAREN] = ACTIONS(569),
+    [anon_sym_RPAREN] = ACTIONS(569),
+    [anon_sym_SQUOTE] = ACTIONS(569),
+    [anon_sym_Atom] = ACTIONS(571),
+    [anon_sym_Nat] = ACTIONS(571),
+    [anon_sym_Trivial] = ACTIONS(571),
+    [anon_sym_Absurd] = ACTIONS(571),
+    [sym_zero] = ACTIONS(571),
+    [sym_nil] = ACTIONS(571),
+    [sym_vecnil] = ACTIONS(571),
+    [sym_sole] = ACTIONS(571),
+    [sym_todo] = ACTIONS(571),
+    [sym_identifier] = ACTIONS(571),
+    [sym_universe] = ACTIONS(571),
+    [sym_number] = ACTIONS(569),
     [sym_comment] = ACTIONS(3),
   },
-  [4] = {
-    [sym_claim] = STATE(4),
-    [sym_define] = STATE(4),
-    [sym_expression] = STATE(4),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_source_repeat1] = STATE(4),
-    [ts_builtin_sym_end] = ACTIONS(27),
-    [anon_sym_LPAREN] = ACTIONS(29),
-    [anon_sym_SQUOTE] = ACTIONS(32),
-    [sym_identifier] = ACTIONS(35),
-    [sym_type_identifier] = ACTIONS(35),
+  [186] = {
+    [ts_builtin_sym_end] = ACTIONS(573),
+    [anon_sym_LPAREN] = ACTIONS(573),
+    [anon_sym_RPAREN] = ACTIONS(573),
+    [anon_sym_SQUOTE] = ACTIONS(573),
+    [anon_sym_Atom] = ACTIONS(575),
+    [anon_sym_Nat] = ACTIONS(575),
+    [anon_sym_Trivial] = ACTIONS(575),
+    [anon_sym_Absurd] = ACTIONS(575),
+    [sym_zero] = ACTIONS(575),
+    [sym_nil] = ACTIONS(575),
+    [sym_vecnil] = ACTIONS(575),
+    [sym_sole] = ACTIONS(575),
+    [sym_todo] = ACTIONS(575),
+    [sym_identifier] = ACTIONS(575),
+    [sym_universe] = ACTIONS(575),
+    [sym_number] = ACTIONS(573),
     [sym_comment] = ACTIONS(3),
   },
-  [5] = {
-    [sym_expression] = STATE(8),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [anon_sym_BSLASH] = ACTIONS(19),
-    [anon_sym_] = ACTIONS(21),
-    [anon_sym_lambda] = ACTIONS(21),
-    [sym_identifier] = ACTIONS(23),
-    [sym_type_identifier] = ACTIONS(11),
+  [187] = {
+    [ts_builtin_sym_end] = ACTIONS(577),
+    [anon_sym_LPAREN] = ACTIONS(577),
+    [anon_sym_RPAREN] = ACTIONS(577),
+    [anon_sym_SQUOTE] = ACTIONS(577),
+    [anon_sym_Atom] = ACTIONS(579),
+    [anon_sym_Nat] = ACTIONS(579),
+    [anon_sym_Trivial] = ACTIONS(579),
+    [anon_sym_Absurd] = ACTIONS(579),
+    [sym_zero] = ACTIONS(579),
+    [sym_nil] = ACTIONS(579),
+    [sym_vecnil] = ACTIONS(579),
+    [sym_sole] = ACTIONS(579),
+    [sym_todo] = ACTIONS(579),
+    [sym_identifier] = ACTIONS(579),
+    [sym_universe] = ACTIONS(579),
+    [sym_number] = ACTIONS(577),
     [sym_comment] = ACTIONS(3),
   },
-  [6] = {
-    [sym_expression] = STATE(6),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_lambda_repeat1] = STATE(6),
-    [anon_sym_LPAREN] = ACTIONS(38),
-    [anon_sym_RPAREN] = ACTIONS(41),
-    [anon_sym_SQUOTE] = ACTIONS(43),
-    [sym_identifier] = ACTIONS(46),
-    [sym_type_identifier] = ACTIONS(46),
+  [188] = {
+    [ts_builtin_sym_end] = ACTIONS(581),
+    [anon_sym_LPAREN] = ACTIONS(581),
+    [anon_sym_RPAREN] = ACTIONS(581),
+    [anon_sym_SQUOTE] = ACTIONS(581),
+    [anon_sym_Atom] = ACTIONS(583),
+    [anon_sym_Nat] = ACTIONS(583),
+    [anon_sym_Trivial] = ACTIONS(583),
+    [anon_sym_Absurd] = ACTIONS(583),
+    [sym_zero] = ACTIONS(583),
+    [sym_nil] = ACTIONS(583),
+    [sym_vecnil] = ACTIONS(583),
+    [sym_sole] = ACTIONS(583),
+    [sym_todo] = ACTIONS(583),
+    [sym_identifier] = ACTIONS(583),
+    [sym_universe] = ACTIONS(583),
+    [sym_number] = ACTIONS(581),
     [sym_comment] = ACTIONS(3),
   },
-  [7] = {
-    [sym_expression] = STATE(6),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_lambda_repeat1] = STATE(6),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_RPAREN] = ACTIONS(49),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+  [189] = {
+    [ts_builtin_sym_end] = ACTIONS(585),
+    [anon_sym_LPAREN] = ACTIONS(585),
+    [anon_sym_RPAREN] = ACTIONS(585),
+    [anon_sym_SQUOTE] = ACTIONS(585),
+    [anon_sym_Atom] = ACTIONS(587),
+    [anon_sym_Nat] = ACTIONS(587),
+    [anon_sym_Trivial] = ACTIONS(587),
+    [anon_sym_Absurd] = ACTIONS(587),
+    [sym_zero] = ACTIONS(587),
+    [sym_nil] = ACTIONS(587),
+    [sym_vecnil] = ACTIONS(587),
+    [sym_sole] = ACTIONS(587),
+    [sym_todo] = ACTIONS(587),
+    [sym_identifier] = ACTIONS(587),
+    [sym_universe] = ACTIONS(587),
+    [sym_number] = ACTIONS(585),
     [sym_comment] = ACTIONS(3),
   },
-  [8] = {
-    [sym_expression] = STATE(10),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_lambda_repeat1] = STATE(10),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_RPAREN] = ACTIONS(51),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+  [190] = {
+    [ts_builtin_sym_end] = ACTIONS(589),
+    [anon_sym_LPAREN] = ACTIONS(589),
+    [anon_sym_RPAREN] = ACTIONS(589),
+    [anon_sym_SQUOTE] = ACTIONS(589),
+    [anon_sym_Atom] = ACTIONS(591),
+    [anon_sym_Nat] = ACTIONS(591),
+    [anon_sym_Trivial] = ACTIONS(591),
+    [anon_sym_Absurd] = ACTIONS(591),
+    [sym_zero] = ACTIONS(591),
+    [sym_nil] = ACTIONS(591),
+    [sym_vecnil] = ACTIONS(591),
+    [sym_sole] = ACTIONS(591),
+    [sym_todo] = ACTIONS(591),
+    [sym_identifier] = ACTIONS(591),
+    [sym_universe] = ACTIONS(591),
+    [sym_number] = ACTIONS(589),
     [sym_comment] = ACTIONS(3),
   },
-  [9] = {
-    [sym_expression] = STATE(7),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_lambda_repeat1] = STATE(7),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_RPAREN] = ACTIONS(53),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+  [191] = {
+    [ts_builtin_sym_end] = ACTIONS(593),
+    [anon_sym_LPAREN] = ACTIONS(593),
+    [anon_sym_RPAREN] = ACTIONS(593),
+    [anon_sym_SQUOTE] = ACTIONS(593),
+    [anon_sym_Atom] = ACTIONS(595),
+    [anon_sym_Nat] = ACTIONS(595),
+    [anon_sym_Trivial] = ACTIONS(595),
+    [anon_sym_Absurd] = ACTIONS(595),
+    [sym_zero] = ACTIONS(595),
+    [sym_nil] = ACTIONS(595),
+    [sym_vecnil] = ACTIONS(595),
+    [sym_sole] = ACTIONS(595),
+    [sym_todo] = ACTIONS(595),
+    [sym_identifier] = ACTIONS(595),
+    [sym_universe] = ACTIONS(595),
+    [sym_number] = ACTIONS(593),
     [sym_comment] = ACTIONS(3),
   },
-  [10] = {
-    [sym_expression] = STATE(6),
-    [sym_atom] = STATE(17),
-    [sym_lambda] = STATE(17),
-    [sym_application] = STATE(17),
-    [aux_sym_lambda_repeat1] = STATE(6),
-    [anon_sym_LPAREN] = ACTIONS(13),
-    [anon_sym_RPAREN] = ACTIONS(55),
-    [anon_sym_SQUOTE] = ACTIONS(9),
-    [sym_identifier] = ACTIONS(11),
-    [sym_type_identifier] = ACTIONS(11),
+  [192] = {
+    [ts_builtin_sym_end] = ACTIONS(597),
+    [anon_sym_LPAREN] = ACTIONS(597),
+    [anon_sym_RPAREN] = ACTIONS(597),
+    [anon_sym_SQUOTE] = ACTIONS(597),
+    [anon_sym_Atom] = ACTIONS(599),
+    [anon_sym_Nat] = ACTIONS(599),
+    [anon_sym_Trivial] = ACTIONS(599),
+    [anon_sym_Absurd] = ACTIONS(599),
+    [sym_zero] = ACTIONS(599),
+    [sym_nil] = ACTIONS(599),
+    [sym_vecnil] = ACTIONS(599),
+    [sym_sole] = ACTIONS(599),
+    [sym_todo] = ACTIONS(599),
+    [sym_identifier] = ACTIONS(599),
+    [sym_universe] = ACTIONS(599),
+    [sym_number] = ACTIONS(597),
     [sym_comment] = ACTIONS(3),
   },
 };
 
 static const uint16_t ts_small_parse_table[] = {
-  [0] = 6,
+  [0] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(9), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
-      anon_sym_LPAREN,
-    STATE(30), 1,
-      sym_expression,
-    ACTIONS(11), 2,
+    ACTIONS(601), 1,
+      ts_builtin_sym_end,
+  [7] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(603), 1,
       sym_identifier,
-      sym_type_identifier,
-    STATE(17), 3,
-      sym_atom,
-      sym_lambda,
-      sym_application,
-  [22] = 6,
+  [14] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(9), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
+    ACTIONS(605), 4,
+      ts_builtin_sym_end,
       anon_sym_LPAREN,
-    STATE(26), 1,
-      sym_expression,
-    ACTIONS(11), 2,
+      anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(607), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-    STATE(17), 3,
-      sym_atom,
-      sym_lambda,
-      sym_application,
-  [44] = 6,
+      sym_universe,
+  [37] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(9), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
+    ACTIONS(609), 4,
+      ts_builtin_sym_end,
       anon_sym_LPAREN,
-    STATE(23), 1,
-      sym_expression,
-    ACTIONS(11), 2,
+      anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(611), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-    STATE(17), 3,
-      sym_atom,
-      sym_lambda,
-      sym_application,
-  [66] = 6,
+      sym_universe,
+  [60] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(9), 1,
-      anon_sym_SQUOTE,
-    ACTIONS(13), 1,
+    ACTIONS(613), 4,
+      ts_builtin_sym_end,
       anon_sym_LPAREN,
-    STATE(27), 1,
-      sym_expression,
-    ACTIONS(11), 2,
+      anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(615), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-    STATE(17), 3,
-      sym_atom,
-      sym_lambda,
-      sym_application,
-  [88] = 2,
+      sym_universe,
+  [83] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(57), 6,
+    ACTIONS(617), 4,
       ts_builtin_sym_end,
       anon_sym_LPAREN,
-      anon_sym_RPAREN,
       anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(619), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-  [100] = 2,
+      sym_universe,
+  [106] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(59), 6,
-      ts_builtin_sym_end,
+    ACTIONS(621), 1,
+      sym_identifier,
+  [113] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(623), 1,
+      sym_identifier,
+  [120] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(625), 1,
+      anon_sym_LPAREN,
+  [127] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(627), 1,
+      anon_sym_LPAREN,
+  [134] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(629), 1,
+      anon_sym_LPAREN,
+  [141] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(631), 1,
+      anon_sym_LPAREN,
+  [148] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(633), 1,
+      anon_sym_LPAREN,
+  [155] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(635), 1,
       anon_sym_LPAREN,
+  [162] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(637), 1,
+      anon_sym_LPAREN,
+  [169] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(228), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(639), 1,
       anon_sym_RPAREN,
-      anon_sym_SQUOTE,
+    ACTIONS(641), 1,
       sym_identifier,
-      sym_type_identifier,
-  [112] = 2,
+  [182] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(61), 6,
-      ts_builtin_sym_end,
+    STATE(230), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(641), 1,
+      sym_identifier,
+    ACTIONS(643), 1,
+      anon_sym_RPAREN,
+  [195] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(231), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(641), 1,
+      sym_identifier,
+    ACTIONS(645), 1,
+      anon_sym_RPAREN,
+  [208] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(647), 4,
       anon_sym_LPAREN,
       anon_sym_RPAREN,
       anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(649), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-  [124] = 2,
+      sym_universe,
+  [231] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(63), 6,
-      ts_builtin_sym_end,
+    STATE(234), 1,
+      aux_sym_pi_type_repeat1,
+    STATE(236), 1,
+      sym_typed_binder,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+  [244] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(237), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+  [257] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(238), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+  [270] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(239), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
       anon_sym_LPAREN,
+  [283] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(653), 1,
+      anon_sym_RPAREN,
+  [290] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(655), 1,
+      anon_sym_RPAREN,
+  [297] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(657), 1,
+      anon_sym_RPAREN,
+  [304] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(659), 1,
+      anon_sym_RPAREN,
+  [311] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(661), 1,
+      anon_sym_RPAREN,
+  [318] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(663), 1,
+      anon_sym_RPAREN,
+  [325] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(665), 1,
+      anon_sym_RPAREN,
+  [332] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(667), 1,
+      anon_sym_RPAREN,
+  [339] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(669), 1,
+      anon_sym_RPAREN,
+  [346] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(671), 1,
+      anon_sym_RPAREN,
+  [353] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(673), 1,
+      anon_sym_RPAREN,
+  [360] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(675), 1,
+      anon_sym_RPAREN,
+  [367] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(253), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(641), 1,
+      sym_identifier,
+    ACTIONS(677), 1,
+      anon_sym_RPAREN,
+  [380] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(679), 2,
       anon_sym_RPAREN,
-      anon_sym_SQUOTE,
       sym_identifier,
-      sym_type_identifier,
-  [136] = 2,
+  [388] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(65), 6,
-      ts_builtin_sym_end,
+    STATE(253), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(641), 1,
+      sym_identifier,
+    ACTIONS(681), 1,
+      anon_sym_RPAREN,
+  [401] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(253), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(641), 1,
+      sym_identifier,
+    ACTIONS(683), 1,
+      anon_sym_RPAREN,
+  [414] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(685), 1,
+      anon_sym_RPAREN,
+    ACTIONS(647), 3,
       anon_sym_LPAREN,
+      anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(649), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
+      sym_identifier,
+      sym_universe,
+  [439] = 4,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(687), 1,
       anon_sym_RPAREN,
+    ACTIONS(647), 3,
+      anon_sym_LPAREN,
       anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(649), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-  [148] = 2,
+      sym_universe,
+  [464] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(67), 6,
-      ts_builtin_sym_end,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(257), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
       anon_sym_LPAREN,
+    ACTIONS(689), 1,
       anon_sym_RPAREN,
-      anon_sym_SQUOTE,
+  [480] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(691), 1,
       sym_identifier,
-      sym_type_identifier,
-  [160] = 2,
+  [487] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(693), 2,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+  [495] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(257), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+    ACTIONS(695), 1,
+      anon_sym_RPAREN,
+  [511] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(257), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+    ACTIONS(697), 1,
+      anon_sym_RPAREN,
+  [527] = 5,
+    ACTIONS(3), 1,
+      sym_comment,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(257), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(651), 1,
+      anon_sym_LPAREN,
+    ACTIONS(699), 1,
+      anon_sym_RPAREN,
+  [543] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(69), 5,
+    ACTIONS(701), 1,
+      anon_sym_RPAREN,
+  [550] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(703), 1,
+      anon_sym_RPAREN,
+  [557] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(705), 1,
+      anon_sym_RPAREN,
+  [564] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(707), 1,
+      anon_sym_RPAREN,
+  [571] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(709), 1,
+      anon_sym_RPAREN,
+  [578] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(711), 1,
+      anon_sym_RPAREN,
+  [585] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(713), 1,
+      anon_sym_RPAREN,
+  [592] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(715), 1,
+      anon_sym_RPAREN,
+  [599] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(717), 1,
+      anon_sym_RPAREN,
+  [606] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(719), 1,
+      anon_sym_RPAREN,
+  [613] = 3,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(721), 4,
       ts_builtin_sym_end,
       anon_sym_LPAREN,
       anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(723), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-  [171] = 2,
+      sym_universe,
+  [636] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(71), 5,
+    ACTIONS(725), 4,
       ts_builtin_sym_end,
       anon_sym_LPAREN,
       anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(727), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
-      sym_type_identifier,
-  [182] = 2,
+      sym_universe,
+  [659] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(73), 1,
+    ACTIONS(729), 1,
       anon_sym_RPAREN,
-  [189] = 2,
+  [666] = 4,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(75), 1,
-      ts_builtin_sym_end,
-  [196] = 2,
+    STATE(253), 1,
+      aux_sym_lambda_repeat1,
+    ACTIONS(731), 1,
+      anon_sym_RPAREN,
+    ACTIONS(733), 1,
+      sym_identifier,
+  [679] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(77), 1,
-      sym_identifier,
-  [203] = 2,
+    ACTIONS(736), 1,
+      anon_sym_RPAREN,
+  [686] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(79), 1,
+    ACTIONS(738), 1,
       anon_sym_RPAREN,
-  [210] = 2,
+  [693] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(81), 1,
+    ACTIONS(740), 1,
       anon_sym_RPAREN,
-  [217] = 2,
+  [700] = 5,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(83), 1,
+    STATE(236), 1,
+      sym_typed_binder,
+    STATE(257), 1,
+      aux_sym_pi_type_repeat1,
+    ACTIONS(742), 1,
       anon_sym_LPAREN,
-  [224] = 2,
+    ACTIONS(745), 1,
+      anon_sym_RPAREN,
+  [716] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(85), 1,
-      sym_identifier,
-  [231] = 2,
+    ACTIONS(747), 1,
+      anon_sym_RPAREN,
+  [723] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(749), 1,
+      anon_sym_RPAREN,
+  [730] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(751), 1,
+      anon_sym_RPAREN,
+  [737] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(753), 1,
+      anon_sym_RPAREN,
+  [744] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(755), 1,
+      anon_sym_RPAREN,
+  [751] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(757), 1,
+      anon_sym_RPAREN,
+  [758] = 2,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(87), 1,
+    ACTIONS(759), 1,
       anon_sym_RPAREN,
-  [238] = 2,
+  [765] = 3,
     ACTIONS(3), 1,
       sym_comment,
-    ACTIONS(89), 1,
+    ACTIONS(761), 4,
+      ts_builtin_sym_end,
+      anon_sym_LPAREN,
+      anon_sym_SQUOTE,
+      sym_number,
+    ACTIONS(763), 11,
+      anon_sym_Atom,
+      anon_sym_Nat,
+      anon_sym_Trivial,
+      anon_sym_Absurd,
+      sym_zero,
+      sym_nil,
+      sym_vecnil,
+      sym_sole,
+      sym_todo,
       sym_identifier,
+      sym_universe,
+  [788] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(765), 1,
+      anon_sym_RPAREN,
+  [795] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(767), 1,
+      anon_sym_RPAREN,
+  [802] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(769), 1,
+      anon_sym_RPAREN,
+  [809] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(771), 1,
+      anon_sym_RPAREN,
+  [816] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(773), 1,
+      anon_sym_RPAREN,
+  [823] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(775), 1,
+      anon_sym_RPAREN,
+  [830] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(777), 1,
+      anon_sym_RPAREN,
+  [837] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(779), 1,
+      anon_sym_RPAREN,
+  [844] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(781), 1,
+      anon_sym_RPAREN,
+  [851] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(783), 1,
+      anon_sym_RPAREN,
+  [858] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(785), 1,
+      anon_sym_RPAREN,
+  [865] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(787), 2,
+      anon_sym_LPAREN,
+      anon_sym_RPAREN,
+  [873] = 2,
+    ACTIONS(3), 1,
+      sym_comment,
+    ACTIONS(789), 1,
+      anon_sym_RPAREN,
 };
 
 static const uint32_t ts_small_parse_table_map[] = {
-  [SMALL_STATE(11)] = 0,
-  [SMALL_STATE(12)] = 22,
-  [SMALL_STATE(13)] = 44,
-  [SMALL_STATE(14)] = 66,
-  [SMALL_STATE(15)] = 88,
-  [SMALL_STATE(16)] = 100,
-  [SMALL_STATE(17)] = 112,
-  [SMALL_STATE(18)] = 124,
-  [SMALL_STATE(19)] = 136,
-  [SMALL_STATE(20)] = 148,
-  [SMALL_STATE(21)] = 160,
-  [SMALL_STATE(22)] = 171,
-  [SMALL_STATE(23)] = 182,
-  [SMALL_STATE(24)] = 189,
-  [SMALL_STATE(25)] = 196,
-  [SMALL_STATE(26)] = 203,
-  [SMALL_STATE(27)] = 210,
-  [SMALL_STATE(28)] = 217,
-  [SMALL_STATE(29)] = 224,
-  [SMALL_STATE(30)] = 231,
-  [SMALL_STATE(31)] = 238,
+  [SMALL_STATE(193)] = 0,
+  [SMALL_STATE(194)] = 7,
+  [SMALL_STATE(195)] = 14,
+  [SMALL_STATE(196)] = 37,
+  [SMALL_STATE(197)] = 60,
+  [SMALL_STATE(198)] = 83,
+  [SMALL_STATE(199)] = 106,
+  [SMALL_STATE(200)] = 113,
+  [SMALL_STATE(201)] = 120,
+  [SMALL_STATE(202)] = 127,
+  [SMALL_STATE(203)] = 134,
+  [SMALL_STATE(204)] = 141,
+  [SMALL_STATE(205)] = 148,
+  [SMALL_STATE(206)] = 155,
+  [SMALL_STATE(207)] = 162,
+  [SMALL_STATE(208)] = 169,
+  [SMALL_STATE(209)] = 182,
+  [SMALL_STATE(210)] = 195,
+  [SMALL_STATE(211)] = 208,
+  [SMALL_STATE(212)] = 231,
+  [SMALL_STATE(213)] = 244,
+  [SMALL_STATE(214)] = 257,
+  [SMALL_STATE(215)] = 270,
+  [SMALL_STATE(216)] = 283,
+  [SMALL_STATE(217)] = 290,
+  [SMALL_STATE(218)] = 297,
+  [SMALL_STATE(219)] = 304,
+  [SMALL_STATE(220)] = 311,
+  [SMALL_STATE(221)] = 318,
+  [SMALL_STATE(222)] = 325,
+  [SMALL_STATE(223)] = 332,
+  [SMALL_STATE(224)] = 339,
+  [SMALL_STATE(225)] = 346,
+  [SMALL_STATE(226)] = 353,
+  [SMALL_STATE(227)] = 360,
+  [SMALL_STATE(228)] = 367,
+  [SMALL_STATE(229)] = 380,
+  [SMALL_STATE(230)] = 388,
+  [SMALL_STATE(231)] = 401,
+  [SMALL_STATE(232)] = 414,
+  [SMALL_STATE(233)] = 439,
+  [SMALL_STATE(234)] = 464,
+  [SMALL_STATE(235)] = 480,
+  [SMALL_STATE(236)] = 487,
+  [SMALL_STATE(237)] = 495,
+  [SMALL_STATE(238)] = 511,
+  [SMALL_STATE(239)] = 527,
+  [SMALL_STATE(240)] = 543,
+  [SMALL_STATE(241)] = 550,
+  [SMALL_STATE(242)] = 557,
+  [SMALL_STATE(243)] = 564,
+  [SMALL_STATE(244)] = 571,
+  [SMALL_STATE(245)] = 578,
+  [SMALL_STATE(246)] = 585,
+  [SMALL_STATE(247)] = 592,
+  [SMALL_STATE(248)] = 599,
+  [SMALL_STATE(249)] = 606,
+  [SMALL_STATE(250)] = 613,
+  [SMALL_STATE(251)] = 636,
+  [SMALL_STATE(252)] = 659,
+  [SMALL_STATE(253)] = 666,
+  [SMALL_STATE(254)] = 679,
+  [SMALL_STATE(255)] = 686,
+  [SMALL_STATE(256)] = 693,
+  [SMALL_STATE(257)] = 700,
+  [SMALL_STATE(258)] = 716,
+  [SMALL_STATE(259)] = 723,
+  [SMALL_STATE(260)] = 730,
+  [SMALL_STATE(261)] = 737,
+  [SMALL_STATE(262)] = 744,
+  [SMALL_STATE(263)] = 751,
+  [SMALL_STATE(264)] = 758,
+  [SMALL_STATE(265)] = 765,
+  [SMALL_STATE(266)] = 788,
+  [SMALL_STATE(267)] = 795,
+  [SMALL_STATE(268)] = 802,
+  [SMALL_STATE(269)] = 809,
+  [SMALL_STATE(270)] = 816,
+  [SMALL_STATE(271)] = 823,
+  [SMALL_STATE(272)] = 830,
+  [SMALL_STATE(273)] = 837,
+  [SMALL_STATE(274)] = 844,
+  [SMALL_STATE(275)] = 851,
+  [SMALL_STATE(276)] = 858,
+  [SMALL_STATE(277)] = 865,
+  [SMALL_STATE(278)] = 873,
 };
 
 static const TSParseActionEntry ts_parse_actions[] = {
@@ -2480,45 +13234,383 @@ static const TSParseActionEntry ts_parse_actions[] = {
   [1] = {.entry = {.count = 1, .reusable = false}}, RECOVER(),
   [3] = {.entry = {.count = 1, .reusable = true}}, SHIFT_EXTRA(),
   [5] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source, 0),
-  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(2),
-  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(25),
-  [11] = {.entry = {.count = 1, .reusable = true}}, SHIFT(17),
-  [13] = {.entry = {.count = 1, .reusable = true}}, SHIFT(5),
-  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(31),
-  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(29),
-  [19] = {.entry = {.count = 1, .reusable = true}}, SHIFT(28),
-  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(28),
-  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(17),
-  [25] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source, 1),
-  [27] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2),
-  [29] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(2),
-  [32] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(25),
-  [35] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(17),
-  [38] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2), SHIFT_REPEAT(5),
-  [41] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2),
-  [43] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2), SHIFT_REPEAT(25),
-  [46] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2), SHIFT_REPEAT(17),
-  [49] = {.entry = {.count = 1, .reusable = true}}, SHIFT(11),
-  [51] = {.entry = {.count = 1, .reusable = true}}, SHIFT(19),
-  [53] = {.entry = {.count = 1, .reusable = true}}, SHIFT(14),
-  [55] = {.entry = {.count = 1, .reusable = true}}, SHIFT(20),
-  [57] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 7, .production_id = 7),
-  [59] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 6, .production_id = 6),
-  [61] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
-  [63] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_atom, 2, .production_id = 1),
-  [65] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application, 3, .production_id = 2),
-  [67] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application, 4, .production_id = 3),
-  [69] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_claim, 5, .production_id = 4),
-  [71] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_define, 5, .production_id = 5),
-  [73] = {.entry = {.count = 1, .reusable = true}}, SHIFT(22),
-  [75] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
-  [77] = {.entry = {.count = 1, .reusable = true}}, SHIFT(18),
-  [79] = {.entry = {.count = 1, .reusable = true}}, SHIFT(21),
-  [81] = {.entry = {.count = 1, .reusable = true}}, SHIFT(16),
-  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(9),
-  [85] = {.entry = {.count = 1, .reusable = true}}, SHIFT(13),
-  [87] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
-  [89] = {.entry = {.count = 1, .reusable = true}}, SHIFT(12),
+  [7] = {.entry = {.count = 1, .reusable = true}}, SHIFT(3),
+  [9] = {.entry = {.count = 1, .reusable = true}}, SHIFT(194),
+  [11] = {.entry = {.count = 1, .reusable = false}}, SHIFT(50),
+  [13] = {.entry = {.count = 1, .reusable = false}}, SHIFT(51),
+  [15] = {.entry = {.count = 1, .reusable = false}}, SHIFT(52),
+  [17] = {.entry = {.count = 1, .reusable = false}}, SHIFT(53),
+  [19] = {.entry = {.count = 1, .reusable = false}}, SHIFT(13),
+  [21] = {.entry = {.count = 1, .reusable = false}}, SHIFT(21),
+  [23] = {.entry = {.count = 1, .reusable = false}}, SHIFT(26),
+  [25] = {.entry = {.count = 1, .reusable = false}}, SHIFT(42),
+  [27] = {.entry = {.count = 1, .reusable = false}}, SHIFT(45),
+  [29] = {.entry = {.count = 1, .reusable = false}}, SHIFT(49),
+  [31] = {.entry = {.count = 1, .reusable = false}}, SHIFT(48),
+  [33] = {.entry = {.count = 1, .reusable = true}}, SHIFT(15),
+  [35] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_source, 1),
+  [37] = {.entry = {.count = 1, .reusable = true}}, SHIFT(56),
+  [39] = {.entry = {.count = 1, .reusable = false}}, SHIFT(199),
+  [41] = {.entry = {.count = 1, .reusable = false}}, SHIFT(200),
+  [43] = {.entry = {.count = 1, .reusable = false}}, SHIFT(55),
+  [45] = {.entry = {.count = 1, .reusable = true}}, SHIFT(201),
+  [47] = {.entry = {.count = 1, .reusable = false}}, SHIFT(202),
+  [49] = {.entry = {.count = 1, .reusable = false}}, SHIFT(203),
+  [51] = {.entry = {.count = 1, .reusable = false}}, SHIFT(57),
+  [53] = {.entry = {.count = 1, .reusable = false}}, SHIFT(58),
+  [55] = {.entry = {.count = 1, .reusable = false}}, SHIFT(204),
+  [57] = {.entry = {.count = 1, .reusable = false}}, SHIFT(205),
+  [59] = {.entry = {.count = 1, .reusable = false}}, SHIFT(206),
+  [61] = {.entry = {.count = 1, .reusable = false}}, SHIFT(207),
+  [63] = {.entry = {.count = 1, .reusable = false}}, SHIFT(59),
+  [65] = {.entry = {.count = 1, .reusable = false}}, SHIFT(60),
+  [67] = {.entry = {.count = 1, .reusable = false}}, SHIFT(61),
+  [69] = {.entry = {.count = 1, .reusable = false}}, SHIFT(62),
+  [71] = {.entry = {.count = 1, .reusable = false}}, SHIFT(63),
+  [73] = {.entry = {.count = 1, .reusable = false}}, SHIFT(64),
+  [75] = {.entry = {.count = 1, .reusable = false}}, SHIFT(65),
+  [77] = {.entry = {.count = 1, .reusable = false}}, SHIFT(66),
+  [79] = {.entry = {.count = 1, .reusable = false}}, SHIFT(67),
+  [81] = {.entry = {.count = 1, .reusable = false}}, SHIFT(68),
+  [83] = {.entry = {.count = 1, .reusable = true}}, SHIFT(69),
+  [85] = {.entry = {.count = 1, .reusable = false}}, SHIFT(70),
+  [87] = {.entry = {.count = 1, .reusable = false}}, SHIFT(71),
+  [89] = {.entry = {.count = 1, .reusable = false}}, SHIFT(72),
+  [91] = {.entry = {.count = 1, .reusable = false}}, SHIFT(73),
+  [93] = {.entry = {.count = 1, .reusable = false}}, SHIFT(74),
+  [95] = {.entry = {.count = 1, .reusable = false}}, SHIFT(75),
+  [97] = {.entry = {.count = 1, .reusable = false}}, SHIFT(76),
+  [99] = {.entry = {.count = 1, .reusable = false}}, SHIFT(77),
+  [101] = {.entry = {.count = 1, .reusable = false}}, SHIFT(78),
+  [103] = {.entry = {.count = 1, .reusable = false}}, SHIFT(79),
+  [105] = {.entry = {.count = 1, .reusable = false}}, SHIFT(80),
+  [107] = {.entry = {.count = 1, .reusable = false}}, SHIFT(81),
+  [109] = {.entry = {.count = 1, .reusable = false}}, SHIFT(82),
+  [111] = {.entry = {.count = 1, .reusable = false}}, SHIFT(83),
+  [113] = {.entry = {.count = 1, .reusable = false}}, SHIFT(84),
+  [115] = {.entry = {.count = 1, .reusable = false}}, SHIFT(85),
+  [117] = {.entry = {.count = 1, .reusable = false}}, SHIFT(86),
+  [119] = {.entry = {.count = 1, .reusable = false}}, SHIFT(87),
+  [121] = {.entry = {.count = 1, .reusable = false}}, SHIFT(88),
+  [123] = {.entry = {.count = 1, .reusable = false}}, SHIFT(89),
+  [125] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [127] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [129] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [131] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [133] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [135] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [137] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [139] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [141] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [143] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [145] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [147] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [149] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [151] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [153] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [155] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [157] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [159] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [161] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [163] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [165] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [167] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [169] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [171] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [173] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [175] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [177] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [179] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [181] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [183] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [185] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [187] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [189] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [191] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [193] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [195] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [197] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [199] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [201] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [203] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [205] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [207] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [209] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [211] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [213] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [215] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [217] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [219] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [221] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [223] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [225] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [227] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [229] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [231] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [233] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [235] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [237] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [239] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [241] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [243] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [245] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [247] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [249] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [251] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [253] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [255] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [257] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [259] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [261] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [263] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [265] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [267] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [269] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [271] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [273] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [275] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [277] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [279] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [281] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [283] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [285] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [287] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [289] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [291] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [293] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [295] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [297] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [299] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [301] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [303] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [305] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_expression, 1),
+  [307] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_expression, 1),
+  [309] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
+  [311] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
+  [313] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
+  [315] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
+  [317] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
+  [319] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
+  [321] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_base_type, 1),
+  [323] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_base_type, 1),
+  [325] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2),
+  [327] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(3),
+  [330] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(194),
+  [333] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(50),
+  [336] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(51),
+  [339] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(52),
+  [342] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(53),
+  [345] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(13),
+  [348] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(21),
+  [351] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(26),
+  [354] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(42),
+  [357] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(45),
+  [360] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(49),
+  [363] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(48),
+  [366] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 2), SHIFT_REPEAT(15),
+  [369] = {.entry = {.count = 1, .reusable = true}}, SHIFT(119),
+  [371] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_atom, 2, .production_id = 1),
+  [373] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_atom, 2, .production_id = 1),
+  [375] = {.entry = {.count = 1, .reusable = true}}, SHIFT(146),
+  [377] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application, 3, .production_id = 2),
+  [379] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_application, 3, .production_id = 2),
+  [381] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(56),
+  [384] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_type_repeat1, 2),
+  [386] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(194),
+  [389] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(50),
+  [392] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(51),
+  [395] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(52),
+  [398] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(53),
+  [401] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(13),
+  [404] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(21),
+  [407] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(26),
+  [410] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(42),
+  [413] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(45),
+  [416] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(49),
+  [419] = {.entry = {.count = 2, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(48),
+  [422] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_function_type_repeat1, 2), SHIFT_REPEAT(15),
+  [425] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_car, 4, .production_id = 3),
+  [427] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_car, 4, .production_id = 3),
+  [429] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cdr, 4, .production_id = 3),
+  [431] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cdr, 4, .production_id = 3),
+  [433] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_add1, 4, .production_id = 4),
+  [435] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_add1, 4, .production_id = 4),
+  [437] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_type, 4, .production_id = 5),
+  [439] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_type, 4, .production_id = 5),
+  [441] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_head, 4, .production_id = 6),
+  [443] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_head, 4, .production_id = 6),
+  [445] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_tail, 4, .production_id = 6),
+  [447] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_tail, 4, .production_id = 6),
+  [449] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_same, 4, .production_id = 7),
+  [451] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_same, 4, .production_id = 7),
+  [453] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_symm, 4, .production_id = 8),
+  [455] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_symm, 4, .production_id = 8),
+  [457] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_left, 4, .production_id = 7),
+  [459] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_left, 4, .production_id = 7),
+  [461] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_right, 4, .production_id = 7),
+  [463] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_right, 4, .production_id = 7),
+  [465] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_application, 4, .production_id = 9),
+  [467] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_application, 4, .production_id = 9),
+  [469] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_type, 5, .production_id = 10),
+  [471] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_type, 5, .production_id = 10),
+  [473] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_function_type, 5, .production_id = 10),
+  [475] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_function_type, 5, .production_id = 10),
+  [477] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pair_type, 5, .production_id = 11),
+  [479] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pair_type, 5, .production_id = 11),
+  [481] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cons, 5, .production_id = 12),
+  [483] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cons, 5, .production_id = 12),
+  [485] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_list_cons, 5, .production_id = 13),
+  [487] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_list_cons, 5, .production_id = 13),
+  [489] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_vec_type, 5, .production_id = 14),
+  [491] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_vec_type, 5, .production_id = 14),
+  [493] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_vec_cons, 5, .production_id = 13),
+  [495] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_vec_cons, 5, .production_id = 13),
+  [497] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_cong, 5, .production_id = 15),
+  [499] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_cong, 5, .production_id = 15),
+  [501] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_trans, 5, .production_id = 16),
+  [503] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_trans, 5, .production_id = 16),
+  [505] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_either_type, 5, .production_id = 17),
+  [507] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_either_type, 5, .production_id = 17),
+  [509] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_absurd, 5, .production_id = 18),
+  [511] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_absurd, 5, .production_id = 18),
+  [513] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_the, 5, .production_id = 19),
+  [515] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_the, 5, .production_id = 19),
+  [517] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [519] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [521] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [523] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [525] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [527] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 6, .production_id = 20),
+  [529] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_which_nat, 6, .production_id = 21),
+  [531] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_which_nat, 6, .production_id = 21),
+  [533] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_iter_nat, 6, .production_id = 21),
+  [535] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_iter_nat, 6, .production_id = 21),
+  [537] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rec_nat, 6, .production_id = 21),
+  [539] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rec_nat, 6, .production_id = 21),
+  [541] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_rec_list, 6, .production_id = 21),
+  [543] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_rec_list, 6, .production_id = 21),
+  [545] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_equal_type, 6, .production_id = 22),
+  [547] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_equal_type, 6, .production_id = 22),
+  [549] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_replace, 6, .production_id = 23),
+  [551] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_replace, 6, .production_id = 23),
+  [553] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_eq, 6, .production_id = 23),
+  [555] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_eq, 6, .production_id = 23),
+  [557] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [559] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [561] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [563] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [565] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [567] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_lambda, 7, .production_id = 24),
+  [569] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pi_type, 7, .production_id = 25),
+  [571] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pi_type, 7, .production_id = 25),
+  [573] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_pi_type, 7, .production_id = 25),
+  [575] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_pi_type, 7, .production_id = 25),
+  [577] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sigma_type, 7, .production_id = 26),
+  [579] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sigma_type, 7, .production_id = 26),
+  [581] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_sigma_type, 7, .production_id = 26),
+  [583] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_sigma_type, 7, .production_id = 26),
+  [585] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_nat, 7, .production_id = 27),
+  [587] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_nat, 7, .production_id = 27),
+  [589] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_list, 7, .production_id = 27),
+  [591] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_list, 7, .production_id = 27),
+  [593] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_either, 7, .production_id = 28),
+  [595] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_either, 7, .production_id = 28),
+  [597] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_ind_vec, 8, .production_id = 29),
+  [599] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_ind_vec, 8, .production_id = 29),
+  [601] = {.entry = {.count = 1, .reusable = true}},  ACCEPT_INPUT(),
+  [603] = {.entry = {.count = 1, .reusable = true}}, SHIFT(91),
+  [605] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 1),
+  [607] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 1),
+  [609] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 1),
+  [611] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 1),
+  [613] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 1),
+  [615] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 1),
+  [617] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_source_repeat1, 1),
+  [619] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_source_repeat1, 1),
+  [621] = {.entry = {.count = 1, .reusable = true}}, SHIFT(92),
+  [623] = {.entry = {.count = 1, .reusable = true}}, SHIFT(93),
+  [625] = {.entry = {.count = 1, .reusable = true}}, SHIFT(208),
+  [627] = {.entry = {.count = 1, .reusable = true}}, SHIFT(209),
+  [629] = {.entry = {.count = 1, .reusable = true}}, SHIFT(210),
+  [631] = {.entry = {.count = 1, .reusable = true}}, SHIFT(212),
+  [633] = {.entry = {.count = 1, .reusable = true}}, SHIFT(213),
+  [635] = {.entry = {.count = 1, .reusable = true}}, SHIFT(214),
+  [637] = {.entry = {.count = 1, .reusable = true}}, SHIFT(215),
+  [639] = {.entry = {.count = 1, .reusable = true}}, SHIFT(121),
+  [641] = {.entry = {.count = 1, .reusable = true}}, SHIFT(229),
+  [643] = {.entry = {.count = 1, .reusable = true}}, SHIFT(122),
+  [645] = {.entry = {.count = 1, .reusable = true}}, SHIFT(123),
+  [647] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_function_type_repeat1, 1),
+  [649] = {.entry = {.count = 1, .reusable = false}}, REDUCE(aux_sym_function_type_repeat1, 1),
+  [651] = {.entry = {.count = 1, .reusable = true}}, SHIFT(235),
+  [653] = {.entry = {.count = 1, .reusable = true}}, SHIFT(125),
+  [655] = {.entry = {.count = 1, .reusable = true}}, SHIFT(126),
+  [657] = {.entry = {.count = 1, .reusable = true}}, SHIFT(127),
+  [659] = {.entry = {.count = 1, .reusable = true}}, SHIFT(132),
+  [661] = {.entry = {.count = 1, .reusable = true}}, SHIFT(135),
+  [663] = {.entry = {.count = 1, .reusable = true}}, SHIFT(136),
+  [665] = {.entry = {.count = 1, .reusable = true}}, SHIFT(139),
+  [667] = {.entry = {.count = 1, .reusable = true}}, SHIFT(141),
+  [669] = {.entry = {.count = 1, .reusable = true}}, SHIFT(143),
+  [671] = {.entry = {.count = 1, .reusable = true}}, SHIFT(144),
+  [673] = {.entry = {.count = 1, .reusable = true}}, SHIFT(250),
+  [675] = {.entry = {.count = 1, .reusable = true}}, SHIFT(251),
+  [677] = {.entry = {.count = 1, .reusable = true}}, SHIFT(147),
+  [679] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 1),
+  [681] = {.entry = {.count = 1, .reusable = true}}, SHIFT(148),
+  [683] = {.entry = {.count = 1, .reusable = true}}, SHIFT(149),
+  [685] = {.entry = {.count = 1, .reusable = true}}, SHIFT(150),
+  [687] = {.entry = {.count = 1, .reusable = true}}, SHIFT(151),
+  [689] = {.entry = {.count = 1, .reusable = true}}, SHIFT(152),
+  [691] = {.entry = {.count = 1, .reusable = true}}, SHIFT(153),
+  [693] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pi_type_repeat1, 1),
+  [695] = {.entry = {.count = 1, .reusable = true}}, SHIFT(154),
+  [697] = {.entry = {.count = 1, .reusable = true}}, SHIFT(155),
+  [699] = {.entry = {.count = 1, .reusable = true}}, SHIFT(156),
+  [701] = {.entry = {.count = 1, .reusable = true}}, SHIFT(157),
+  [703] = {.entry = {.count = 1, .reusable = true}}, SHIFT(158),
+  [705] = {.entry = {.count = 1, .reusable = true}}, SHIFT(160),
+  [707] = {.entry = {.count = 1, .reusable = true}}, SHIFT(162),
+  [709] = {.entry = {.count = 1, .reusable = true}}, SHIFT(163),
+  [711] = {.entry = {.count = 1, .reusable = true}}, SHIFT(165),
+  [713] = {.entry = {.count = 1, .reusable = true}}, SHIFT(166),
+  [715] = {.entry = {.count = 1, .reusable = true}}, SHIFT(167),
+  [717] = {.entry = {.count = 1, .reusable = true}}, SHIFT(169),
+  [719] = {.entry = {.count = 1, .reusable = true}}, SHIFT(170),
+  [721] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_claim, 5, .production_id = 30),
+  [723] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_claim, 5, .production_id = 30),
+  [725] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_define, 5, .production_id = 31),
+  [727] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_define, 5, .production_id = 31),
+  [729] = {.entry = {.count = 1, .reusable = true}}, SHIFT(265),
+  [731] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2),
+  [733] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_lambda_repeat1, 2), SHIFT_REPEAT(229),
+  [736] = {.entry = {.count = 1, .reusable = true}}, SHIFT(171),
+  [738] = {.entry = {.count = 1, .reusable = true}}, SHIFT(172),
+  [740] = {.entry = {.count = 1, .reusable = true}}, SHIFT(173),
+  [742] = {.entry = {.count = 2, .reusable = true}}, REDUCE(aux_sym_pi_type_repeat1, 2), SHIFT_REPEAT(235),
+  [745] = {.entry = {.count = 1, .reusable = true}}, REDUCE(aux_sym_pi_type_repeat1, 2),
+  [747] = {.entry = {.count = 1, .reusable = true}}, SHIFT(174),
+  [749] = {.entry = {.count = 1, .reusable = true}}, SHIFT(175),
+  [751] = {.entry = {.count = 1, .reusable = true}}, SHIFT(176),
+  [753] = {.entry = {.count = 1, .reusable = true}}, SHIFT(177),
+  [755] = {.entry = {.count = 1, .reusable = true}}, SHIFT(179),
+  [757] = {.entry = {.count = 1, .reusable = true}}, SHIFT(180),
+  [759] = {.entry = {.count = 1, .reusable = true}}, SHIFT(181),
+  [761] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_check_same, 6, .production_id = 32),
+  [763] = {.entry = {.count = 1, .reusable = false}}, REDUCE(sym_check_same, 6, .production_id = 32),
+  [765] = {.entry = {.count = 1, .reusable = true}}, SHIFT(182),
+  [767] = {.entry = {.count = 1, .reusable = true}}, SHIFT(183),
+  [769] = {.entry = {.count = 1, .reusable = true}}, SHIFT(184),
+  [771] = {.entry = {.count = 1, .reusable = true}}, SHIFT(185),
+  [773] = {.entry = {.count = 1, .reusable = true}}, SHIFT(277),
+  [775] = {.entry = {.count = 1, .reusable = true}}, SHIFT(186),
+  [777] = {.entry = {.count = 1, .reusable = true}}, SHIFT(187),
+  [779] = {.entry = {.count = 1, .reusable = true}}, SHIFT(188),
+  [781] = {.entry = {.count = 1, .reusable = true}}, SHIFT(189),
+  [783] = {.entry = {.count = 1, .reusable = true}}, SHIFT(190),
+  [785] = {.entry = {.count = 1, .reusable = true}}, SHIFT(191),
+  [787] = {.entry = {.count = 1, .reusable = true}}, REDUCE(sym_typed_binder, 4, .production_id = 33),
+  [789] = {.entry = {.count = 1, .reusable = true}}, SHIFT(192),
 };
 
 #ifdef __cplusplus